@@ -0,0 +1,10 @@
+-- Add migration script here
+
+ALTER TABLE users
+ADD COLUMN bio TEXT,
+ADD COLUMN avatar TEXT,
+ADD COLUMN profile_shows_bio BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN profile_shows_avatar BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN profile_shows_habits BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN profile_shows_finished_challenges BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN profile_shows_badges BOOLEAN NOT NULL DEFAULT FALSE;
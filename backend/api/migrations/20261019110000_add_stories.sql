@@ -0,0 +1,31 @@
+-- Add migration script here
+
+CREATE TABLE stories (
+    id UUID PRIMARY KEY,
+    creator UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    habit_id UUID REFERENCES habits(id) ON DELETE CASCADE,
+    challenge_id UUID REFERENCES challenges(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    media TEXT,
+    view_count INT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    CHECK ((habit_id IS NULL) <> (challenge_id IS NULL))
+);
+
+CREATE INDEX idx_stories_expires_at ON stories(expires_at);
+
+CREATE TABLE story_views (
+    story_id UUID NOT NULL REFERENCES stories(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    viewed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (story_id, user_id)
+);
+
+CREATE TABLE story_reports (
+    id UUID PRIMARY KEY,
+    story_id UUID NOT NULL REFERENCES stories(id) ON DELETE CASCADE,
+    reporter UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reason TEXT NOT NULL
+);
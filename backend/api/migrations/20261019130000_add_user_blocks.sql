@@ -0,0 +1,12 @@
+-- Add migration script here
+
+CREATE TABLE user_blocks (
+    id UUID PRIMARY KEY,
+    blocker UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    blocked UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (blocker, blocked),
+    CHECK (blocker <> blocked)
+);
+
+CREATE INDEX idx_user_blocks_blocked ON user_blocks(blocked);
@@ -0,0 +1,32 @@
+-- Add migration script here
+
+-- Time-decayed popularity: each 12.5 hours of age weighs as much as ten times more likes and replies.
+-- The score only depends on the message itself so that pagination cursors stay valid over time.
+ALTER TABLE public_messages
+ADD COLUMN hot_score DOUBLE PRECISION NOT NULL GENERATED ALWAYS AS (
+    LOG((like_count + reply_count + 1)::DOUBLE PRECISION)
+    + EXTRACT(EPOCH FROM (created_at AT TIME ZONE 'UTC'))::DOUBLE PRECISION / 45000
+) STORED;
+
+CREATE INDEX idx_public_messages_habit_newest ON public_messages(habit_id, created_at DESC, id DESC)
+WHERE replies_to IS NULL AND deleted_by_admin = false AND deleted_by_creator = false;
+CREATE INDEX idx_public_messages_habit_top ON public_messages(habit_id, like_count DESC, id DESC)
+WHERE replies_to IS NULL AND deleted_by_admin = false AND deleted_by_creator = false;
+CREATE INDEX idx_public_messages_habit_most_replied ON public_messages(habit_id, reply_count DESC, id DESC)
+WHERE replies_to IS NULL AND deleted_by_admin = false AND deleted_by_creator = false;
+CREATE INDEX idx_public_messages_habit_hot ON public_messages(habit_id, hot_score DESC, id DESC)
+WHERE replies_to IS NULL AND deleted_by_admin = false AND deleted_by_creator = false;
+
+CREATE INDEX idx_public_messages_challenge_newest ON public_messages(challenge_id, created_at DESC, id DESC)
+WHERE replies_to IS NULL AND deleted_by_admin = false AND deleted_by_creator = false;
+CREATE INDEX idx_public_messages_challenge_top ON public_messages(challenge_id, like_count DESC, id DESC)
+WHERE replies_to IS NULL AND deleted_by_admin = false AND deleted_by_creator = false;
+CREATE INDEX idx_public_messages_challenge_most_replied ON public_messages(challenge_id, reply_count DESC, id DESC)
+WHERE replies_to IS NULL AND deleted_by_admin = false AND deleted_by_creator = false;
+CREATE INDEX idx_public_messages_challenge_hot ON public_messages(challenge_id, hot_score DESC, id DESC)
+WHERE replies_to IS NULL AND deleted_by_admin = false AND deleted_by_creator = false;
+
+-- Replies and a user's messages are much smaller sets, only the default sort gets an index
+CREATE INDEX idx_public_messages_replies_newest ON public_messages(replies_to, created_at DESC, id DESC);
+CREATE INDEX idx_public_messages_creator_newest ON public_messages(creator, created_at DESC, id DESC);
+CREATE INDEX idx_public_message_likes_user_id ON public_message_likes(user_id);
@@ -0,0 +1,33 @@
+-- Add migration script here
+
+ALTER TABLE public_messages
+ADD COLUMN hidden_by_moderation BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE public_message_moderation_decisions (
+    id UUID PRIMARY KEY,
+    message_id UUID NOT NULL REFERENCES public_messages(id) ON DELETE CASCADE,
+    moderator UUID REFERENCES users(id) ON DELETE SET NULL,
+    action TEXT NOT NULL,
+    note TEXT,
+    report_count INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_public_message_moderation_decisions_message_id ON public_message_moderation_decisions(message_id);
+
+ALTER TABLE public_message_reports
+ADD COLUMN resolved_at TIMESTAMPTZ,
+ADD COLUMN decision_id UUID REFERENCES public_message_moderation_decisions(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_public_message_reports_pending ON public_message_reports(message_id)
+WHERE resolved_at IS NULL;
+
+CREATE TABLE user_suspensions (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    decision_id UUID REFERENCES public_message_moderation_decisions(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_user_suspensions_user_id ON user_suspensions(user_id, expires_at);
@@ -0,0 +1,19 @@
+-- Add migration script here
+
+CREATE TABLE public_message_revisions (
+    id UUID PRIMARY KEY,
+    message_id UUID NOT NULL REFERENCES public_messages(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_public_message_revisions_message_id ON public_message_revisions(message_id, created_at);
+
+-- Existing messages start their history with their current content
+INSERT INTO public_message_revisions (id, message_id, content, created_at)
+SELECT gen_random_uuid(), id, content, COALESCE(updated_at, created_at)
+FROM public_messages
+WHERE deleted_by_creator = false AND deleted_by_admin = false;
+
+ALTER TABLE public_message_reports
+ADD COLUMN revision_id UUID REFERENCES public_message_revisions(id) ON DELETE SET NULL;
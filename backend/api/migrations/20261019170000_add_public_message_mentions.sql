@@ -0,0 +1,15 @@
+-- Add migration script here
+
+ALTER TABLE users ADD COLUMN notifications_for_public_message_mentions_enabled BOOLEAN NOT NULL DEFAULT TRUE;
+
+CREATE TABLE public_message_mentions (
+    id UUID PRIMARY KEY,
+    message_id UUID NOT NULL REFERENCES public_messages(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    start_index INT NOT NULL,
+    length INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_public_message_mentions_message_id ON public_message_mentions(message_id);
+CREATE INDEX idx_public_message_mentions_user_id ON public_message_mentions(user_id);
@@ -0,0 +1,28 @@
+-- Add migration script here
+
+-- Word lists and regex rules managed by admins. Rules without locale apply to every language.
+CREATE TABLE content_filter_rules (
+    id UUID PRIMARY KEY,
+    locale VARCHAR(10),
+    kind VARCHAR(20) NOT NULL,
+    pattern TEXT NOT NULL,
+    action VARCHAR(20) NOT NULL,
+    hit_count BIGINT NOT NULL DEFAULT 0,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_content_filter_rules_locale ON content_filter_rules(locale);
+
+-- Contents accepted but flagged for review by a rule
+CREATE TABLE content_filter_flags (
+    id UUID PRIMARY KEY,
+    rule_id UUID REFERENCES content_filter_rules(id) ON DELETE SET NULL,
+    target VARCHAR(30) NOT NULL,
+    target_id UUID NOT NULL,
+    content TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_content_filter_flags_target_id ON content_filter_flags(target_id);
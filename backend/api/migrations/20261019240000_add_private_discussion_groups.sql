@@ -0,0 +1,14 @@
+-- Add migration script here
+ALTER TABLE private_discussions
+    ADD COLUMN name VARCHAR(100),
+    ADD COLUMN is_group BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE private_discussion_participations
+    ADD COLUMN is_admin BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE UNIQUE INDEX IF NOT EXISTS private_discussion_participations_discussion_id_user_id_idx
+    ON private_discussion_participations (discussion_id, user_id);
+
+-- Session keys of group messages, encrypted with the public key of each member
+ALTER TABLE private_messages
+    ADD COLUMN encrypted_session_keys JSONB NOT NULL DEFAULT '{}';
@@ -0,0 +1,10 @@
+-- Add migration script here
+ALTER TABLE private_messages
+    ADD COLUMN delivered_at TIMESTAMPTZ,
+    ADD COLUMN seen_at TIMESTAMPTZ;
+
+-- Messages seen before statuses existed were delivered at the latest when they were seen
+UPDATE private_messages
+SET delivered_at = COALESCE(updated_at, created_at),
+    seen_at = COALESCE(updated_at, created_at)
+WHERE seen = true;
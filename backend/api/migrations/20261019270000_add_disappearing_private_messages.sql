@@ -0,0 +1,11 @@
+-- Add migration script here
+ALTER TABLE private_discussions
+    ADD COLUMN message_retention TEXT NOT NULL DEFAULT 'off',
+    ADD COLUMN proposed_message_retention TEXT,
+    ADD COLUMN message_retention_proposed_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+ALTER TABLE private_messages
+    ADD COLUMN expires_at TIMESTAMPTZ;
+
+CREATE INDEX idx_private_messages_expires_at ON private_messages(expires_at)
+    WHERE expires_at IS NOT NULL;
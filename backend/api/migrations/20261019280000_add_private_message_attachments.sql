@@ -0,0 +1,18 @@
+-- Add migration script here
+
+-- Opaque blobs, encrypted by clients with the session key of their message
+CREATE TABLE private_message_attachments (
+    id UUID PRIMARY KEY,
+    uploader UUID REFERENCES users(id) ON DELETE SET NULL,
+    -- Uploads are linked to a message when it is created
+    private_message_id UUID REFERENCES private_messages(id) ON DELETE SET NULL,
+    size_in_bytes BIGINT NOT NULL,
+    storage_key TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_private_message_attachments_uploader ON private_message_attachments(uploader);
+CREATE INDEX idx_private_message_attachments_private_message_id ON private_message_attachments(private_message_id);
+CREATE INDEX idx_private_message_attachments_unused ON private_message_attachments(created_at) WHERE private_message_id IS NULL;
+
+ALTER TABLE private_messages ADD COLUMN attachment_ids UUID[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,24 @@
+-- Add migration script here
+
+ALTER TABLE stories
+ADD COLUMN hidden_by_moderation BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE public_message_reports
+ALTER COLUMN message_id DROP NOT NULL,
+ADD COLUMN story_id UUID REFERENCES stories(id) ON DELETE CASCADE,
+ADD CONSTRAINT public_message_reports_target_check CHECK ((message_id IS NULL) <> (story_id IS NULL));
+
+INSERT INTO public_message_reports (id, story_id, reporter, created_at, reason)
+SELECT id, story_id, reporter, created_at, reason
+FROM story_reports;
+
+DROP TABLE story_reports;
+
+CREATE INDEX idx_public_message_reports_pending_stories ON public_message_reports(story_id)
+WHERE resolved_at IS NULL;
+
+ALTER TABLE public_message_moderation_decisions
+ALTER COLUMN message_id DROP NOT NULL,
+ADD COLUMN story_id UUID REFERENCES stories(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_public_message_moderation_decisions_story_id ON public_message_moderation_decisions(story_id);
@@ -1,10 +1,17 @@
 use api::configuration::get_configuration;
+use api::core::helpers::attachments::delete_unused_attachments;
+use api::core::helpers::mock_now::now;
+use api::core::helpers::private_message_expiration::delete_expired_private_messages;
+use api::core::helpers::public_message_subscriptions::send_pending_public_message_subscription_notifications;
 use api::core::helpers::startup::{
     create_missing_discussions_with_reallystick_user, populate_database, reset_database,
 };
 use api::core::helpers::user_deletion::remove_users_marked_as_deleted;
+use api::features::attachments::infrastructure::services::local_storage_backend::LocalStorageBackend;
 use api::features::auth::domain::repositories::UserTokenRepository;
 use api::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
+use api::features::stories::domain::repositories::story_repository::StoryRepository;
+use api::features::stories::infrastructure::repositories::story_repository::StoryRepositoryImpl;
 use api::startup::get_connection_pool;
 use clap::Parser;
 use redis::Client;
@@ -57,7 +64,10 @@ async fn main() {
             }
         }
         "remove_users_marked_as_deleted" => {
-            if let Err(e) = remove_users_marked_as_deleted(&pool, &redis_client).await {
+            let storage_backend = LocalStorageBackend::new(&configuration.storage.local_directory);
+            if let Err(e) =
+                remove_users_marked_as_deleted(&pool, &redis_client, &storage_backend).await
+            {
                 error!("Failed to remove users marked as deleted: {}", e);
             }
         }
@@ -69,6 +79,35 @@ async fn main() {
                 info!("Successfully deleted expired tokens.");
             }
         }
+        "delete_expired_stories" => {
+            let story_repo = StoryRepositoryImpl::new(pool.clone());
+            match story_repo.delete_expired(now()).await {
+                Ok(count) => info!("Successfully deleted {} expired stories.", count),
+                Err(e) => error!("Failed to delete expired stories: {}", e),
+            }
+        }
+        "send_pending_public_message_subscription_notifications" => {
+            if let Err(e) =
+                send_pending_public_message_subscription_notifications(&pool, &redis_client).await
+            {
+                error!(
+                    "Failed to send pending public message subscription notifications: {:?}",
+                    e
+                );
+            }
+        }
+        "delete_unused_attachments" => {
+            match delete_unused_attachments(&pool, &configuration.storage).await {
+                Ok(count) => info!("Successfully deleted {} unused attachments.", count),
+                Err(e) => error!("Failed to delete unused attachments: {:?}", e),
+            }
+        }
+        "delete_expired_private_messages" => {
+            match delete_expired_private_messages(&pool, &redis_client).await {
+                Ok(count) => info!("Successfully deleted {} expired private messages.", count),
+                Err(e) => error!("Failed to delete expired private messages: {:?}", e),
+            }
+        }
         _ => error!("Unknown action: {}", action),
     }
 }
@@ -8,6 +8,8 @@ use sqlx::postgres::{PgConnectOptions, PgSslMode};
 pub struct Settings {
     pub database: DatabaseSettings,
     pub application: ApplicationSettings,
+    pub moderation: ModerationSettings,
+    pub storage: StorageSettings,
 }
 
 #[derive(serde::Deserialize, Clone)]
@@ -18,6 +20,19 @@ pub struct ApplicationSettings {
     pub secret: String,
 }
 
+#[derive(serde::Deserialize, Clone)]
+pub struct ModerationSettings {
+    // Distinct reporters needed before a message is hidden until a moderator reviews it
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub auto_hide_reporter_threshold: i64,
+}
+
+#[derive(serde::Deserialize, Clone)]
+pub struct StorageSettings {
+    // Directory where the local storage backend keeps attachment files
+    pub local_directory: String,
+}
+
 #[derive(serde::Deserialize, Clone)]
 pub struct DatabaseSettings {
     pub username: String,
@@ -1,22 +1,58 @@
 use crate::{
     core::structs::responses::GenericResponse,
     features::{
+        attachments::domain::entities::attachment::ATTACHMENT_MAX_SIZE_IN_BYTES,
         challenges::domain::entities::{
             challenge::CHALLENGE_DESCRIPTION_MAX_LENGTH,
             challenge_daily_tracking::CHALLENGE_DAILY_TRACKING_NOTE_MAX_LENGTH,
         },
         habits::domain::entities::habit::HABIT_DESCRIPTION_MAX_LENGTH,
-        private_discussions::domain::entities::private_message::PRIVATE_MESSAGE_CONTENT_MAX_LENGTH,
+        private_discussions::domain::entities::{
+            private_discussion::{
+                PRIVATE_DISCUSSION_GROUP_MAX_MEMBERS, PRIVATE_DISCUSSION_GROUP_NAME_MAX_LENGTH,
+                PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_MAX_PER_DAY,
+            },
+            private_discussion_report::{
+                PRIVATE_DISCUSSION_REPORT_MAX_MESSAGES, PRIVATE_DISCUSSION_REPORT_REASON_MAX_LENGTH,
+            },
+            private_message::{
+                PRIVATE_MESSAGE_CONTENT_MAX_LENGTH, PRIVATE_MESSAGE_MAX_ATTACHMENTS,
+            },
+            private_message_attachment::{
+                PRIVATE_MESSAGE_ATTACHMENTS_QUOTA_IN_BYTES,
+                PRIVATE_MESSAGE_ATTACHMENT_MAX_SIZE_IN_BYTES,
+            },
+            socket_message::{
+                SOCKET_PROTOCOL_VERSION, SOCKET_RATE_LIMIT_MAX_MESSAGES,
+                SOCKET_RATE_LIMIT_WINDOW_IN_SECONDS,
+            },
+        },
+        profile::domain::entities::{
+            friend_match::FRIEND_MATCH_SEARCHES_PER_DAY, USER_BIO_MAX_LENGTH,
+            USER_PUBLIC_MESSAGE_LANGUAGES_MAX_COUNT,
+        },
         public_discussions::domain::entities::{
-            public_message::PUBLIC_MESSAGE_CONTENT_MAX_LENGTH,
+            public_message::{PUBLIC_MESSAGE_CONTENT_MAX_LENGTH, PUBLIC_MESSAGE_MAX_ATTACHMENTS},
+            public_message_moderation::{
+                MODERATION_MAX_SUSPENSION_IN_DAYS, MODERATION_NOTE_MAX_LENGTH,
+            },
             public_message_report::PUBLIC_MESSAGE_REPORT_CONTENT_MAX_LENGTH,
         },
+        stories::domain::entities::story::{STORY_CONTENT_MAX_LENGTH, STORY_MEDIA_MAX_LENGTH},
     },
 };
 
 #[derive(Debug)]
 pub enum AppError {
     AccessTokenExpired,
+    AttachmentCreation,
+    AttachmentDeletion,
+    AttachmentFileMissing,
+    AttachmentInvalidContentType,
+    AttachmentInvalidImage,
+    AttachmentNotFound,
+    AttachmentStorage,
+    AttachmentTooLarge,
     BothHabitAndChallengePassed,
     ChallengeCreation,
     ChallengeDailyTrackingCreation,
@@ -33,14 +69,31 @@ pub enum AppError {
     ChallengeParticipationNotFound,
     ChallengeParticipationUpdate,
     ChallengeUpdate,
+    ContentFilterFlagCreation,
+    ContentFilterFlagDeletion,
+    ContentFilterFlagNotFound,
+    ContentFilterRuleCreation,
+    ContentFilterRuleDeletion,
+    ContentFilterRuleInvalid,
+    ContentFilterRuleNotFound,
+    ContentFilterRuleUpdate,
+    ContentRejectedByFilter,
     DatabaseConnection,
     DatabaseQuery,
     DatabaseTransaction,
+    DeviceKeyCreation,
+    DeviceKeyPublicKeyEmpty,
+    DeviceRevoked,
     FailedToCreateSocketSession,
+    FriendMatchSearchCreation,
+    FriendMatchingNotEnabled,
+    FriendMatchingRateLimited,
     InvalidAccessToken,
     InvalidChallengeCreator,
     InvalidChallengeParticipationUser,
     InvalidOneTimePassword,
+    InvalidPrivateMessageCursor,
+    InvalidPublicMessageCursor,
     InvalidRefreshToken,
     InvalidUsernameOrCodeOrRecoveryCode,
     InvalidUsernameOrPassword,
@@ -63,33 +116,75 @@ pub enum AppError {
     HabitParticipationNotFound,
     HabitParticipationUpdate,
     HabitUpdate,
+    MissingPermission,
+    ModerationDecisionCreation,
+    ModerationNoteEmpty,
+    ModerationNoteTooLong,
+    ModerationSuspensionDurationInvalid,
     NoHabitNorChallengePassed,
     NotAdmin,
     PasswordHash,
     PasswordTooShort,
     PasswordTooWeak,
     PrivateDiscussionCreation,
+    PrivateDiscussionGroupMembersMissing,
+    PrivateDiscussionGroupNameEmpty,
+    PrivateDiscussionGroupNameTooLong,
+    PrivateDiscussionGroupTooManyMembers,
+    PrivateDiscussionLastAdmin,
+    PrivateDiscussionMemberAlreadyExists,
+    PrivateDiscussionMemberNotAdmin,
+    PrivateDiscussionNotAGroup,
     PrivateDiscussionParticipationCreation,
+    PrivateDiscussionParticipationDeletion,
     PrivateDiscussionParticipationNotFound,
     PrivateDiscussionParticipationUpdate,
     PrivateDiscussionNotFound,
+    PrivateDiscussionReportCreation,
+    PrivateDiscussionReportEvidenceInvalid,
+    PrivateDiscussionReportMessagesInvalid,
+    PrivateDiscussionReportNotFound,
+    PrivateDiscussionReportReasonEmpty,
+    PrivateDiscussionReportReasonTooLong,
+    PrivateDiscussionReportUpdate,
+    PrivateDiscussionReportUserInvalid,
+    PrivateDiscussionRequestDeclined,
+    PrivateDiscussionRequestLimitReached,
+    PrivateDiscussionRequestNotFound,
+    PrivateDiscussionRequestUpdate,
+    PrivateDiscussionRetentionInvalid,
+    PrivateMessageAttachmentNotFound,
+    PrivateMessageAttachmentQuotaExceeded,
+    PrivateMessageAttachmentTooLarge,
     PrivateMessageContentEmpty,
     PrivateMessageContentTooLong,
     PrivateMessageCreation,
     PrivateMessageDeletion,
     PrivateMessageDeletionNotDoneByCreator,
+    PrivateMessageDeviceKeysMismatch,
     PrivateMessageNotFound,
+    PrivateMessageSessionKeysMismatch,
+    PrivateMessageSessionKeysUpdate,
+    PrivateMessageTooManyAttachments,
     PrivateMessageUpdate,
     PrivateMessageUpdateNotDoneByCreator,
+    PublicMessageAnnouncementOnlyInChallenges,
+    PublicMessageAttachmentNotFound,
     PublicMessageContentEmpty,
     PublicMessageContentTooLong,
     PublicMessageCreation,
     PublicMessageDeletion,
     PublicMessageDeletionNotDoneByAdmin,
     PublicMessageDeletionNotDoneByCreator,
-    PublicMessageLikeCreation,
-    PublicMessageLikeDeletion,
-    PublicMessageLikeNotFound,
+    PublicMessageHasNoPendingReports,
+    PublicMessageHistoryNotAccessible,
+    PublicMessageMentionCreation,
+    PublicMessagePinLimitReached,
+    PublicMessagePinNotAllowed,
+    PublicMessagePinOnlyForRootMessages,
+    PublicMessageReactionCreation,
+    PublicMessageReactionDeletion,
+    PublicMessageReactionNotFound,
     PublicMessageReportCreation,
     PublicMessageReportDeletion,
     PublicMessageReportNotFound,
@@ -98,17 +193,50 @@ pub enum AppError {
     PublicMessageReportReporterIsNotRequestUser,
     PublicMessageNeedsHabitOrChallenge,
     PublicMessageNotFound,
+    PublicMessageSubscriptionCreation,
+    PublicMessageSubscriptionDeletion,
+    PublicMessageSubscriptionNotFound,
+    PublicMessageSubscriptionUpdate,
+    PublicMessageTooManyAttachments,
     PublicMessageUpdate,
     PublicMessageUpdateNotDoneByCreator,
     RecoveryCodeCreation,
     RecoveryCodeDeletion,
     RecoveryCodeHashCreation,
+    RoleGrantCreation,
+    RoleGrantDeletion,
+    RoleGrantExpirationInPast,
+    RoleGrantNotFound,
+    RoleNotFound,
+    SocketMessageInvalid,
+    SocketMessageRateLimited,
+    SocketProtocolVersionUnsupported,
+    StoryContentEmpty,
+    StoryContentTooLong,
+    StoryCreation,
+    StoryDeletion,
+    StoryDeletionNotDoneByCreator,
+    StoryHasNoPendingReports,
+    StoryMediaTooLong,
+    StoryNotFound,
+    StoryReportCreation,
+    StoryUpdate,
+    StoryViewCreation,
+    StoryViewsNotAccessible,
     TokenGeneration,
     TwoFactorAuthenticationNotEnabled,
     UnitCreation,
     UnitDelete,
     UnitNotFound,
     UnitUpdate,
+    UserBioTooLong,
+    UserBlockCreation,
+    UserBlockDeletion,
+    UserBlockNotFound,
+    UserBlockSelf,
+    UserBlocked,
+    UserPublicMessageLanguagesInvalid,
+    UserSuspended,
     UsernameNotRespectingRules,
     UsernameWrongSize,
     UserAlreadyHasKeys,
@@ -127,6 +255,41 @@ impl AppError {
                 code: "ACCESS_TOKEN_EXPIRED".to_string(),
                 message: "Token expired".to_string(),
             },
+            AppError::AttachmentCreation => GenericResponse {
+                code: "ATTACHMENT_NOT_CREATED".to_string(),
+                message: "Failed to create this attachment".to_string(),
+            },
+            AppError::AttachmentDeletion => GenericResponse {
+                code: "ATTACHMENT_NOT_DELETED".to_string(),
+                message: "Failed to delete this attachment".to_string(),
+            },
+            AppError::AttachmentFileMissing => GenericResponse {
+                code: "ATTACHMENT_FILE_MISSING".to_string(),
+                message: "No image file could be read from this request".to_string(),
+            },
+            AppError::AttachmentInvalidContentType => GenericResponse {
+                code: "ATTACHMENT_INVALID_CONTENT_TYPE".to_string(),
+                message: "This type of attachment is not supported".to_string(),
+            },
+            AppError::AttachmentInvalidImage => GenericResponse {
+                code: "ATTACHMENT_INVALID_IMAGE".to_string(),
+                message: "This attachment is not a valid image".to_string(),
+            },
+            AppError::AttachmentNotFound => GenericResponse {
+                code: "ATTACHMENT_NOT_FOUND".to_string(),
+                message: "This attachment does not exist".to_string(),
+            },
+            AppError::AttachmentStorage => GenericResponse {
+                code: "ATTACHMENT_STORAGE_ERROR".to_string(),
+                message: "Failed to store this attachment".to_string(),
+            },
+            AppError::AttachmentTooLarge => GenericResponse {
+                code: "ATTACHMENT_TOO_LARGE".to_string(),
+                message: format!(
+                    "This attachment is too large. It has to be no more than {} MB.",
+                    ATTACHMENT_MAX_SIZE_IN_BYTES / (1024 * 1024)
+                ),
+            },
             AppError::BothHabitAndChallengePassed => GenericResponse {
                 code: "BOTH_HABIT_AND_CHALLENGE_PASSED".to_string(),
                 message: "Both habit's id and challenge's id were passed".to_string(),
@@ -198,6 +361,44 @@ impl AppError {
                 code: "CHALLENGE_NOT_UPDATED".to_string(),
                 message: "Failed to update challenge participation".to_string(),
             },
+            AppError::ContentFilterFlagCreation => GenericResponse {
+                code: "CONTENT_FILTER_FLAG_NOT_CREATED".to_string(),
+                message: "Failed to flag this content for review".to_string(),
+            },
+            AppError::ContentFilterFlagDeletion => GenericResponse {
+                code: "CONTENT_FILTER_FLAG_NOT_DELETED".to_string(),
+                message: "Failed to delete this content filter flag".to_string(),
+            },
+            AppError::ContentFilterFlagNotFound => GenericResponse {
+                code: "CONTENT_FILTER_FLAG_NOT_FOUND".to_string(),
+                message: "The content filter flag requested does not exist".to_string(),
+            },
+            AppError::ContentFilterRuleCreation => GenericResponse {
+                code: "CONTENT_FILTER_RULE_NOT_CREATED".to_string(),
+                message: "Failed to create this content filter rule".to_string(),
+            },
+            AppError::ContentFilterRuleDeletion => GenericResponse {
+                code: "CONTENT_FILTER_RULE_NOT_DELETED".to_string(),
+                message: "Failed to delete this content filter rule".to_string(),
+            },
+            AppError::ContentFilterRuleInvalid => GenericResponse {
+                code: "CONTENT_FILTER_RULE_INVALID".to_string(),
+                message: "The content filter rule has an invalid locale, kind, action or pattern"
+                    .to_string(),
+            },
+            AppError::ContentFilterRuleNotFound => GenericResponse {
+                code: "CONTENT_FILTER_RULE_NOT_FOUND".to_string(),
+                message: "The content filter rule requested does not exist".to_string(),
+            },
+            AppError::ContentFilterRuleUpdate => GenericResponse {
+                code: "CONTENT_FILTER_RULE_NOT_UPDATED".to_string(),
+                message: "Failed to update this content filter rule".to_string(),
+            },
+            AppError::ContentRejectedByFilter => GenericResponse {
+                code: "CONTENT_REJECTED_BY_FILTER".to_string(),
+                message: "The content contains words or expressions that are not allowed"
+                    .to_string(),
+            },
             AppError::DatabaseConnection => GenericResponse {
                 code: "DATABASE_CONNECTION".to_string(),
                 message: "Failed to get a transaction".to_string(),
@@ -210,10 +411,37 @@ impl AppError {
                 code: "DATABASE_TRANSACTION".to_string(),
                 message: "Failed to commit transaction".to_string(),
             },
+            AppError::DeviceKeyCreation => GenericResponse {
+                code: "DEVICE_KEY_CREATION".to_string(),
+                message: "Failed to register the key of this device.".to_string(),
+            },
+            AppError::DeviceKeyPublicKeyEmpty => GenericResponse {
+                code: "DEVICE_KEY_PUBLIC_KEY_EMPTY".to_string(),
+                message: "The public key of a device must not be empty.".to_string(),
+            },
+            AppError::DeviceRevoked => GenericResponse {
+                code: "DEVICE_REVOKED".to_string(),
+                message: "This device was revoked, its keys must be wiped.".to_string(),
+            },
             AppError::FailedToCreateSocketSession => GenericResponse {
                 code: "FAILED_TO_CREATE_SOCKET_SESSION".to_string(),
                 message: "Failed to create a web socket session".to_string(),
             },
+            AppError::FriendMatchSearchCreation => GenericResponse {
+                code: "FRIEND_MATCH_SEARCH_NOT_CREATED".to_string(),
+                message: "Failed to record the friend search.".to_string(),
+            },
+            AppError::FriendMatchingNotEnabled => GenericResponse {
+                code: "FRIEND_MATCHING_NOT_ENABLED".to_string(),
+                message: "You need to be open to meeting people to search for friends.".to_string(),
+            },
+            AppError::FriendMatchingRateLimited => GenericResponse {
+                code: "FRIEND_MATCHING_RATE_LIMITED".to_string(),
+                message: format!(
+                    "You can only search for friends {} times per day.",
+                    FRIEND_MATCH_SEARCHES_PER_DAY
+                ),
+            },
             AppError::InvalidAccessToken => GenericResponse {
                 code: "INVALID_ACCESS_TOKEN".to_string(),
                 message: "Invalid access token".to_string(),
@@ -230,6 +458,14 @@ impl AppError {
                 code: "INVALID_ONE_TIME_PASSWORD".to_string(),
                 message: "Invalid one time password".to_string(),
             },
+            AppError::InvalidPrivateMessageCursor => GenericResponse {
+                code: "INVALID_PRIVATE_MESSAGE_CURSOR".to_string(),
+                message: "This pagination cursor is not valid, or more than one of before, after and around was given.".to_string(),
+            },
+            AppError::InvalidPublicMessageCursor => GenericResponse {
+                code: "INVALID_PUBLIC_MESSAGE_CURSOR".to_string(),
+                message: "This pagination cursor is not valid for the requested sort.".to_string(),
+            },
             AppError::InvalidRefreshToken => GenericResponse {
                 code: "INVALID_REFRESH_TOKEN".to_string(),
                 message: "Invalid refresh token".to_string(),
@@ -321,6 +557,33 @@ impl AppError {
                 code: "HABIT_UPDATE".to_string(),
                 message: "Failed to update habit".to_string(),
             },
+            AppError::MissingPermission => GenericResponse {
+                code: "MISSING_PERMISSION".to_string(),
+                message: "You do not have the permission to perform this action".to_string(),
+            },
+            AppError::ModerationDecisionCreation => GenericResponse {
+                code: "MODERATION_DECISION_NOT_CREATED".to_string(),
+                message: "Failed to record the moderation decision.".to_string(),
+            },
+            AppError::ModerationNoteEmpty => GenericResponse {
+                code: "MODERATION_NOTE_EMPTY".to_string(),
+                message: "A note explaining the decision is required to warn or suspend a user."
+                    .to_string(),
+            },
+            AppError::ModerationNoteTooLong => GenericResponse {
+                code: "MODERATION_NOTE_TOO_LONG".to_string(),
+                message: format!(
+                    "The moderation note can not exceed {} characters.",
+                    MODERATION_NOTE_MAX_LENGTH
+                ),
+            },
+            AppError::ModerationSuspensionDurationInvalid => GenericResponse {
+                code: "MODERATION_SUSPENSION_DURATION_INVALID".to_string(),
+                message: format!(
+                    "A suspension must last between 1 and {} days.",
+                    MODERATION_MAX_SUSPENSION_IN_DAYS
+                ),
+            },
             AppError::NoHabitNorChallengePassed => GenericResponse {
                 code: "NO_HABIT_NOR_CHALLENGE_PASSED".to_string(),
                 message: "No habit's id nor challenge's id were passed".to_string(),
@@ -345,10 +608,46 @@ impl AppError {
                 code: "PRIVATE_DISCUSSION_CREATION".to_string(),
                 message: "Failed to create this private discussion.".to_string(),
             },
+            AppError::PrivateDiscussionGroupMembersMissing => GenericResponse {
+                code: "PRIVATE_DISCUSSION_GROUP_MEMBERS_MISSING".to_string(),
+                message: "A group discussion needs at least one other member.".to_string(),
+            },
+            AppError::PrivateDiscussionGroupNameEmpty => GenericResponse {
+                code: "PRIVATE_DISCUSSION_GROUP_NAME_EMPTY".to_string(),
+                message: "A group discussion's name must not be empty.".to_string(),
+            },
+            AppError::PrivateDiscussionGroupNameTooLong => GenericResponse {
+                code: "PRIVATE_DISCUSSION_GROUP_NAME_TOO_LONG".to_string(),
+                message: format!("A group discussion's name must be less than {} characters.", PRIVATE_DISCUSSION_GROUP_NAME_MAX_LENGTH).to_string(),
+            },
+            AppError::PrivateDiscussionGroupTooManyMembers => GenericResponse {
+                code: "PRIVATE_DISCUSSION_GROUP_TOO_MANY_MEMBERS".to_string(),
+                message: format!("A group discussion can not have more than {} members.", PRIVATE_DISCUSSION_GROUP_MAX_MEMBERS).to_string(),
+            },
+            AppError::PrivateDiscussionLastAdmin => GenericResponse {
+                code: "PRIVATE_DISCUSSION_LAST_ADMIN".to_string(),
+                message: "A group discussion must keep at least one admin.".to_string(),
+            },
+            AppError::PrivateDiscussionMemberAlreadyExists => GenericResponse {
+                code: "PRIVATE_DISCUSSION_MEMBER_ALREADY_EXISTS".to_string(),
+                message: "This user is already a member of the discussion.".to_string(),
+            },
+            AppError::PrivateDiscussionMemberNotAdmin => GenericResponse {
+                code: "PRIVATE_DISCUSSION_MEMBER_NOT_ADMIN".to_string(),
+                message: "Only an admin of the group discussion can do this.".to_string(),
+            },
+            AppError::PrivateDiscussionNotAGroup => GenericResponse {
+                code: "PRIVATE_DISCUSSION_NOT_A_GROUP".to_string(),
+                message: "This private discussion is not a group discussion.".to_string(),
+            },
             AppError::PrivateDiscussionParticipationCreation => GenericResponse {
                 code: "PRIVATE_DISCUSSION_PARTICIPATION_CREATION".to_string(),
                 message: "Failed to create this private discussion participation.".to_string(),
             },
+            AppError::PrivateDiscussionParticipationDeletion => GenericResponse {
+                code: "PRIVATE_DISCUSSION_PARTICIPATION_DELETION".to_string(),
+                message: "Failed to delete the private discussion participation.".to_string(),
+            },
             AppError::PrivateDiscussionParticipationNotFound => GenericResponse {
                 code: "PRIVATE_DISCUSSION_PARTICIPATION_NOT_FOUND".to_string(),
                 message: "The private discussion participation was not found.".to_string(),
@@ -361,6 +660,70 @@ impl AppError {
                 code: "PRIVATE_DISCUSSION_NOT_FOUND".to_string(),
                 message: "The private discussion was not found.".to_string(),
             },
+            AppError::PrivateDiscussionReportCreation => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REPORT_CREATION".to_string(),
+                message: "Failed to create this report.".to_string(),
+            },
+            AppError::PrivateDiscussionReportEvidenceInvalid => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REPORT_EVIDENCE_INVALID".to_string(),
+                message: "The disclosed messages do not match the messages of the discussion.".to_string(),
+            },
+            AppError::PrivateDiscussionReportMessagesInvalid => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REPORT_MESSAGES_INVALID".to_string(),
+                message: format!("A private discussion report must disclose between 1 and {} messages, at least one of them written by the reported user.", PRIVATE_DISCUSSION_REPORT_MAX_MESSAGES).to_string(),
+            },
+            AppError::PrivateDiscussionReportNotFound => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REPORT_NOT_FOUND".to_string(),
+                message: "This private discussion report does not exist or is already resolved.".to_string(),
+            },
+            AppError::PrivateDiscussionReportReasonEmpty => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REPORT_REASON_EMPTY".to_string(),
+                message: "A private discussion report's reason can't be empty.".to_string(),
+            },
+            AppError::PrivateDiscussionReportReasonTooLong => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REPORT_REASON_TOO_LONG".to_string(),
+                message: format!("A private discussion report's reason must be less than {} characters.", PRIVATE_DISCUSSION_REPORT_REASON_MAX_LENGTH).to_string(),
+            },
+            AppError::PrivateDiscussionReportUpdate => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REPORT_UPDATE".to_string(),
+                message: "Failed to update this report.".to_string(),
+            },
+            AppError::PrivateDiscussionReportUserInvalid => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REPORT_USER_INVALID".to_string(),
+                message: "Only another member of the discussion can be reported.".to_string(),
+            },
+            AppError::PrivateDiscussionRequestDeclined => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REQUEST_DECLINED".to_string(),
+                message: "This message request was declined.".to_string(),
+            },
+            AppError::PrivateDiscussionRequestLimitReached => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REQUEST_LIMIT_REACHED".to_string(),
+                message: format!("New accounts can only send {} message requests per day.", PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_MAX_PER_DAY).to_string(),
+            },
+            AppError::PrivateDiscussionRequestNotFound => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REQUEST_NOT_FOUND".to_string(),
+                message: "This message request does not exist.".to_string(),
+            },
+            AppError::PrivateDiscussionRequestUpdate => GenericResponse {
+                code: "PRIVATE_DISCUSSION_REQUEST_UPDATE".to_string(),
+                message: "Failed to update this message request.".to_string(),
+            },
+            AppError::PrivateDiscussionRetentionInvalid => GenericResponse {
+                code: "PRIVATE_DISCUSSION_RETENTION_INVALID".to_string(),
+                message: "The message retention must be off, 24h, 7d or 30d.".to_string(),
+            },
+            AppError::PrivateMessageAttachmentNotFound => GenericResponse {
+                code: "PRIVATE_MESSAGE_ATTACHMENT_NOT_FOUND".to_string(),
+                message: "This attachment does not exist".to_string(),
+            },
+            AppError::PrivateMessageAttachmentQuotaExceeded => GenericResponse {
+                code: "PRIVATE_MESSAGE_ATTACHMENT_QUOTA_EXCEEDED".to_string(),
+                message: format!("You can not store more than {} MB of attachments in private messages.", PRIVATE_MESSAGE_ATTACHMENTS_QUOTA_IN_BYTES / (1024 * 1024)).to_string(),
+            },
+            AppError::PrivateMessageAttachmentTooLarge => GenericResponse {
+                code: "PRIVATE_MESSAGE_ATTACHMENT_TOO_LARGE".to_string(),
+                message: format!("This attachment is too large. It has to be no more than {} MB.", PRIVATE_MESSAGE_ATTACHMENT_MAX_SIZE_IN_BYTES / (1024 * 1024)).to_string(),
+            },
             AppError::PrivateMessageContentEmpty => GenericResponse {
                 code: "PRIVATE_MESSAGE_CONTENT_EMPTY".to_string(),
                 message: "A private message's content must not be empty.".to_string(),
@@ -384,10 +747,26 @@ impl AppError {
                 code: "PRIVATE_MESSAGE_DELETION_NOT_DONE_BY_CREATOR".to_string(),
                 message: "You can only delete a private message that you created.".to_string(),
             },
+            AppError::PrivateMessageDeviceKeysMismatch => GenericResponse {
+                code: "PRIVATE_MESSAGE_DEVICE_KEYS_MISMATCH".to_string(),
+                message: "A session key must be provided for each registered device of the members of the discussion, and only for them.".to_string(),
+            },
             AppError::PrivateMessageNotFound => GenericResponse {
                 code: "PRIVATE_MESSAGE_NOT_FOUND".to_string(),
                 message: "The private message was not found.".to_string(),
             },
+            AppError::PrivateMessageSessionKeysMismatch => GenericResponse {
+                code: "PRIVATE_MESSAGE_SESSION_KEYS_MISMATCH".to_string(),
+                message: "A session key must be provided for each member of the discussion, and only for them.".to_string(),
+            },
+            AppError::PrivateMessageSessionKeysUpdate => GenericResponse {
+                code: "PRIVATE_MESSAGE_SESSION_KEYS_UPDATE".to_string(),
+                message: "Failed to update the session keys of the private messages.".to_string(),
+            },
+            AppError::PrivateMessageTooManyAttachments => GenericResponse {
+                code: "PRIVATE_MESSAGE_TOO_MANY_ATTACHMENTS".to_string(),
+                message: format!("Too many attachments. A message can have no more than {} attachments.", PRIVATE_MESSAGE_MAX_ATTACHMENTS).to_string(),
+            },
             AppError::PrivateMessageUpdate => GenericResponse {
                 code: "PRIVATE_MESSAGE_UPDATE".to_string(),
                 message: "Failed to update this private message.".to_string(),
@@ -412,6 +791,14 @@ impl AppError {
                 code: "PUBLIC_MESSAGE_DELETION_NOT_DONE_BY_CREATOR".to_string(),
                 message: "You are not the creator of this message".to_string(),
             },
+            AppError::PublicMessageAnnouncementOnlyInChallenges => GenericResponse {
+                code: "PUBLIC_MESSAGE_ANNOUNCEMENT_ONLY_IN_CHALLENGES".to_string(),
+                message: "Announcements can only be made in challenge channels.".to_string(),
+            },
+            AppError::PublicMessageAttachmentNotFound => GenericResponse {
+                code: "PUBLIC_MESSAGE_ATTACHMENT_NOT_FOUND".to_string(),
+                message: "One of the attachments of this message does not exist".to_string(),
+            },
             AppError::PublicMessageContentEmpty => GenericResponse {
                 code: "PUBLIC_MESSAGE_CONTENT_EMPTY".to_string(),
                 message: "A public message's content must not be empty.".to_string(),
@@ -423,17 +810,45 @@ impl AppError {
                     PUBLIC_MESSAGE_CONTENT_MAX_LENGTH
                 ),
             },
-            AppError::PublicMessageLikeCreation => GenericResponse {
-                code: "PUBLIC_MESSAGE_LIKE_CREATION".to_string(),
-                message: "Failed to create this like".to_string(),
+            AppError::PublicMessageHasNoPendingReports => GenericResponse {
+                code: "PUBLIC_MESSAGE_HAS_NO_PENDING_REPORTS".to_string(),
+                message: "This message has no pending report to resolve.".to_string(),
             },
-            AppError::PublicMessageLikeDeletion => GenericResponse {
-                code: "PUBLIC_MESSAGE_LIKE_DELETION".to_string(),
-                message: "Failed to delete this like".to_string(),
+            AppError::PublicMessageHistoryNotAccessible => GenericResponse {
+                code: "PUBLIC_MESSAGE_HISTORY_NOT_ACCESSIBLE".to_string(),
+                message: "Only the author of a message and moderators can see its edit history."
+                    .to_string(),
             },
-            AppError::PublicMessageLikeNotFound => GenericResponse {
-                code: "PUBLIC_MESSAGE_LIKE_NOT_FOUND".to_string(),
-                message: "This like was not found".to_string(),
+            AppError::PublicMessageMentionCreation => GenericResponse {
+                code: "PUBLIC_MESSAGE_MENTION_NOT_CREATED".to_string(),
+                message: "Failed to save the mentions of this message.".to_string(),
+            },
+            AppError::PublicMessagePinLimitReached => GenericResponse {
+                code: "PUBLIC_MESSAGE_PIN_LIMIT_REACHED".to_string(),
+                message: "This channel already has the maximum number of pinned messages."
+                    .to_string(),
+            },
+            AppError::PublicMessagePinNotAllowed => GenericResponse {
+                code: "PUBLIC_MESSAGE_PIN_NOT_ALLOWED".to_string(),
+                message:
+                    "Only the challenge creator and moderators can pin messages in this channel."
+                        .to_string(),
+            },
+            AppError::PublicMessagePinOnlyForRootMessages => GenericResponse {
+                code: "PUBLIC_MESSAGE_PIN_ONLY_FOR_ROOT_MESSAGES".to_string(),
+                message: "Only messages starting a thread can be pinned.".to_string(),
+            },
+            AppError::PublicMessageReactionCreation => GenericResponse {
+                code: "PUBLIC_MESSAGE_REACTION_CREATION".to_string(),
+                message: "Failed to create this reaction".to_string(),
+            },
+            AppError::PublicMessageReactionDeletion => GenericResponse {
+                code: "PUBLIC_MESSAGE_REACTION_DELETION".to_string(),
+                message: "Failed to delete this reaction".to_string(),
+            },
+            AppError::PublicMessageReactionNotFound => GenericResponse {
+                code: "PUBLIC_MESSAGE_REACTION_NOT_FOUND".to_string(),
+                message: "This reaction was not found".to_string(),
             },
             AppError::PublicMessageReportCreation => GenericResponse {
                 code: "PUBLIC_MESSAGE_REPORT_CREATION".to_string(),
@@ -471,6 +886,29 @@ impl AppError {
                 code: "PUBLIC_MESSAGE_NOT_FOUND".to_string(),
                 message: "This public message does not exist.".to_string(),
             },
+            AppError::PublicMessageSubscriptionCreation => GenericResponse {
+                code: "PUBLIC_MESSAGE_SUBSCRIPTION_NOT_CREATED".to_string(),
+                message: "Failed to create this subscription".to_string(),
+            },
+            AppError::PublicMessageSubscriptionDeletion => GenericResponse {
+                code: "PUBLIC_MESSAGE_SUBSCRIPTION_NOT_DELETED".to_string(),
+                message: "Failed to delete this subscription".to_string(),
+            },
+            AppError::PublicMessageSubscriptionNotFound => GenericResponse {
+                code: "PUBLIC_MESSAGE_SUBSCRIPTION_NOT_FOUND".to_string(),
+                message: "The subscription requested does not exist".to_string(),
+            },
+            AppError::PublicMessageSubscriptionUpdate => GenericResponse {
+                code: "PUBLIC_MESSAGE_SUBSCRIPTION_NOT_UPDATED".to_string(),
+                message: "Failed to update this subscription".to_string(),
+            },
+            AppError::PublicMessageTooManyAttachments => GenericResponse {
+                code: "PUBLIC_MESSAGE_TOO_MANY_ATTACHMENTS".to_string(),
+                message: format!(
+                    "Too many attachments. A message can have no more than {} attachments.",
+                    PUBLIC_MESSAGE_MAX_ATTACHMENTS
+                ),
+            },
             AppError::PublicMessageUpdate => GenericResponse {
                 code: "PUBLIC_MESSAGE_UPDATE".to_string(),
                 message: "Failed to update this message".to_string(),
@@ -491,6 +929,92 @@ impl AppError {
                 code: "RECOVERY_CODE_HASH_CREATION".to_string(),
                 message: "Failed to create a hash for the recovery code.".to_string(),
             },
+            AppError::RoleGrantCreation => GenericResponse {
+                code: "ROLE_GRANT_CREATION".to_string(),
+                message: "Failed to grant the role".to_string(),
+            },
+            AppError::RoleGrantDeletion => GenericResponse {
+                code: "ROLE_GRANT_DELETION".to_string(),
+                message: "Failed to revoke the role".to_string(),
+            },
+            AppError::RoleGrantExpirationInPast => GenericResponse {
+                code: "ROLE_GRANT_EXPIRATION_IN_PAST".to_string(),
+                message: "The role grant expiration date must be in the future".to_string(),
+            },
+            AppError::RoleGrantNotFound => GenericResponse {
+                code: "ROLE_GRANT_NOT_FOUND".to_string(),
+                message: "Role grant not found".to_string(),
+            },
+            AppError::RoleNotFound => GenericResponse {
+                code: "ROLE_NOT_FOUND".to_string(),
+                message: "Role not found".to_string(),
+            },
+            AppError::SocketMessageInvalid => GenericResponse {
+                code: "SOCKET_MESSAGE_INVALID".to_string(),
+                message: "This message does not follow the socket protocol.".to_string(),
+            },
+            AppError::SocketMessageRateLimited => GenericResponse {
+                code: "SOCKET_MESSAGE_RATE_LIMITED".to_string(),
+                message: format!("You can only send {} messages every {} seconds.", SOCKET_RATE_LIMIT_MAX_MESSAGES, SOCKET_RATE_LIMIT_WINDOW_IN_SECONDS).to_string(),
+            },
+            AppError::SocketProtocolVersionUnsupported => GenericResponse {
+                code: "SOCKET_PROTOCOL_VERSION_UNSUPPORTED".to_string(),
+                message: format!("Only version {} of the socket protocol is supported.", SOCKET_PROTOCOL_VERSION).to_string(),
+            },
+            AppError::StoryContentEmpty => GenericResponse {
+                code: "STORY_CONTENT_EMPTY".to_string(),
+                message: "A story can not be empty.".to_string(),
+            },
+            AppError::StoryContentTooLong => GenericResponse {
+                code: "STORY_CONTENT_TOO_LONG".to_string(),
+                message: format!(
+                    "A story must be less than {} characters.",
+                    STORY_CONTENT_MAX_LENGTH
+                ),
+            },
+            AppError::StoryCreation => GenericResponse {
+                code: "STORY_NOT_CREATED".to_string(),
+                message: "Failed to create the story.".to_string(),
+            },
+            AppError::StoryDeletion => GenericResponse {
+                code: "STORY_NOT_DELETED".to_string(),
+                message: "Failed to delete the story.".to_string(),
+            },
+            AppError::StoryDeletionNotDoneByCreator => GenericResponse {
+                code: "STORY_DELETION_NOT_DONE_BY_CREATOR".to_string(),
+                message: "You can only delete your own stories.".to_string(),
+            },
+            AppError::StoryHasNoPendingReports => GenericResponse {
+                code: "STORY_HAS_NO_PENDING_REPORTS".to_string(),
+                message: "This story has no pending report to resolve.".to_string(),
+            },
+            AppError::StoryMediaTooLong => GenericResponse {
+                code: "STORY_MEDIA_TOO_LONG".to_string(),
+                message: format!(
+                    "A story media reference must be less than {} characters.",
+                    STORY_MEDIA_MAX_LENGTH
+                ),
+            },
+            AppError::StoryNotFound => GenericResponse {
+                code: "STORY_NOT_FOUND".to_string(),
+                message: "The story does not exist or has expired.".to_string(),
+            },
+            AppError::StoryReportCreation => GenericResponse {
+                code: "STORY_REPORT_NOT_CREATED".to_string(),
+                message: "Failed to create the story report.".to_string(),
+            },
+            AppError::StoryUpdate => GenericResponse {
+                code: "STORY_NOT_UPDATED".to_string(),
+                message: "Failed to update the story.".to_string(),
+            },
+            AppError::StoryViewCreation => GenericResponse {
+                code: "STORY_VIEW_NOT_CREATED".to_string(),
+                message: "Failed to record the story view.".to_string(),
+            },
+            AppError::StoryViewsNotAccessible => GenericResponse {
+                code: "STORY_VIEWS_NOT_ACCESSIBLE".to_string(),
+                message: "Only the creator of a story can see who viewed it.".to_string(),
+            },
             AppError::TokenGeneration => GenericResponse {
                 code: "TOKEN_GENERATION".to_string(),
                 message: "Failed to generate and save token".to_string(),
@@ -515,6 +1039,42 @@ impl AppError {
                 code: "UNIT_UPDATE".to_string(),
                 message: "Failed to update unit".to_string(),
             },
+            AppError::UserBioTooLong => GenericResponse {
+                code: "USER_BIO_TOO_LONG".to_string(),
+                message: format!("The bio is more than {} characters", USER_BIO_MAX_LENGTH),
+            },
+            AppError::UserBlockCreation => GenericResponse {
+                code: "USER_BLOCK_NOT_CREATED".to_string(),
+                message: "Failed to block the user.".to_string(),
+            },
+            AppError::UserBlockDeletion => GenericResponse {
+                code: "USER_BLOCK_NOT_DELETED".to_string(),
+                message: "Failed to unblock the user.".to_string(),
+            },
+            AppError::UserBlockNotFound => GenericResponse {
+                code: "USER_BLOCK_NOT_FOUND".to_string(),
+                message: "This user is not blocked.".to_string(),
+            },
+            AppError::UserBlockSelf => GenericResponse {
+                code: "USER_BLOCK_SELF".to_string(),
+                message: "You can not block yourself.".to_string(),
+            },
+            AppError::UserBlocked => GenericResponse {
+                code: "USER_BLOCKED".to_string(),
+                message: "This action is not possible because one of you blocked the other."
+                    .to_string(),
+            },
+            AppError::UserPublicMessageLanguagesInvalid => GenericResponse {
+                code: "USER_PUBLIC_MESSAGE_LANGUAGES_INVALID".to_string(),
+                message: format!(
+                    "The public message languages must be at most {} ISO 639-1 codes",
+                    USER_PUBLIC_MESSAGE_LANGUAGES_MAX_COUNT
+                ),
+            },
+            AppError::UserSuspended => GenericResponse {
+                code: "USER_SUSPENDED".to_string(),
+                message: "You are suspended from discussions for now.".to_string(),
+            },
             AppError::UsernameNotRespectingRules => GenericResponse {
                 code: "USERNAME_NOT_RESPECTING_RULES".to_string(),
                 message: "This username is not respecting our rules".to_string(),
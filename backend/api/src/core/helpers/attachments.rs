@@ -0,0 +1,47 @@
+use chrono::Duration;
+use sqlx::PgPool;
+
+use crate::configuration::StorageSettings;
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::attachments::application::use_cases::delete_unused_attachments::DeleteUnusedAttachmentsUseCase;
+use crate::features::attachments::domain::services::storage_backend::{
+    delete_attachment_files, delete_private_message_attachment_files,
+};
+use crate::features::attachments::domain::entities::attachment::ATTACHMENT_UNUSED_RETENTION_IN_HOURS;
+use crate::features::attachments::infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl;
+use crate::features::attachments::infrastructure::services::local_storage_backend::LocalStorageBackend;
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
+
+// Removes uploads never posted and the leftovers of deleted messages, with their files
+pub async fn delete_unused_attachments(
+    pool: &PgPool,
+    storage_settings: &StorageSettings,
+) -> Result<usize, AppError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|_| AppError::DatabaseConnection)?;
+
+    let use_case = DeleteUnusedAttachmentsUseCase::new(AttachmentRepositoryImpl::new(pool.clone()));
+    let attachments = use_case.execute(&mut transaction).await?;
+
+    let private_message_attachments = PrivateMessageAttachmentRepositoryImpl::new(pool.clone())
+        .delete_unused_with_executor(
+            now() - Duration::hours(ATTACHMENT_UNUSED_RETENTION_IN_HOURS),
+            &mut *transaction,
+        )
+        .await
+        .map_err(|_| AppError::AttachmentDeletion)?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|_| AppError::DatabaseTransaction)?;
+
+    let storage_backend = LocalStorageBackend::new(&storage_settings.local_directory);
+    delete_attachment_files(&storage_backend, &attachments).await;
+    delete_private_message_attachment_files(&storage_backend, &private_message_attachments).await;
+
+    Ok(attachments.len() + private_message_attachments.len())
+}
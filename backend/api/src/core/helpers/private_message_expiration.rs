@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use actix_web::web::Data;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::services::private_discussion_event_service::PrivateDiscussionEventService;
+
+// Deletes the private messages whose retention is over and tells the members of each
+// discussion so that their clients drop them too
+pub async fn delete_expired_private_messages(
+    pool: &PgPool,
+    redis_client: &Client,
+) -> Result<usize, AppError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|_| AppError::DatabaseConnection)?;
+
+    let message_repo = PrivateMessageRepositoryImpl::new(pool.clone());
+    let deleted_messages = message_repo
+        .delete_expired_with_executor(now(), &mut *transaction)
+        .await
+        .map_err(|_| AppError::DatabaseQuery)?;
+
+    let mut message_ids_by_discussion: BTreeMap<Uuid, Vec<Uuid>> = BTreeMap::new();
+    for (discussion_id, message_id) in &deleted_messages {
+        message_ids_by_discussion
+            .entry(*discussion_id)
+            .or_default()
+            .push(*message_id);
+    }
+
+    let participation_repo = PrivateDiscussionParticipationRepositoryImpl::new(pool.clone());
+    let mut recipients_by_discussion = Vec::new();
+    for (discussion_id, message_ids) in message_ids_by_discussion {
+        let recipients = participation_repo
+            .get_by_discussion_id_with_executor(discussion_id, &mut *transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .iter()
+            .map(|p| p.user_id)
+            .collect::<Vec<_>>();
+        recipients_by_discussion.push((discussion_id, message_ids, recipients));
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|_| AppError::DatabaseTransaction)?;
+
+    let event_service = PrivateDiscussionEventService::new(Data::new(redis_client.clone()));
+    for (discussion_id, message_ids, recipients) in recipients_by_discussion {
+        if let Err(e) = event_service
+            .publish_messages_expired_event(discussion_id, &message_ids, &recipients)
+            .await
+        {
+            error!("Error: {}", e);
+        }
+    }
+
+    Ok(deleted_messages.len())
+}
@@ -0,0 +1,49 @@
+use actix_web::web::Data;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::info;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::translation::Translator;
+use crate::features::profile::domain::entities::UserPublicDataCache;
+use crate::features::public_discussions::application::use_cases::flush_public_message_subscriptions::FlushPublicMessageSubscriptionsUseCase;
+use crate::features::public_discussions::infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::services::subscription_notification_service::SubscriptionNotificationService;
+
+// Sends the batched notifications of subscriptions whose batch window is over
+pub async fn send_pending_public_message_subscription_notifications(
+    pool: &PgPool,
+    redis_client: &Client,
+) -> Result<(), AppError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|_| AppError::DatabaseConnection)?;
+
+    let use_case = FlushPublicMessageSubscriptionsUseCase::new(
+        PublicMessageSubscriptionRepositoryImpl::new(pool.clone()),
+    );
+    let subscriptions = use_case.execute(&mut transaction).await?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|_| AppError::DatabaseTransaction)?;
+
+    info!(
+        "Sending {} batched subscription notifications.",
+        subscriptions.len()
+    );
+
+    SubscriptionNotificationService::new(pool.clone())
+        .notify(
+            subscriptions,
+            None,
+            Data::new(redis_client.clone()),
+            &Translator::new(),
+            &UserPublicDataCache::default(),
+        )
+        .await;
+
+    Ok(())
+}
@@ -23,12 +23,15 @@ use crate::features::habits::infrastructure::repositories::{
     habit_participation_repository::HabitParticipationRepositoryImpl,
     habit_repository::HabitRepositoryImpl, unit_repository::UnitRepositoryImpl,
 };
-use crate::features::private_discussions::domain::entities::private_discussion::PrivateDiscussion;
+use crate::features::private_discussions::domain::entities::private_discussion::{
+    PrivateDiscussion, PrivateDiscussionRequestStatus, PrivateMessageRetention,
+};
 use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
 use crate::features::private_discussions::domain::entities::private_message::PrivateMessage;
 use crate::features::private_discussions::infrastructure::repositories::{
     private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl,
     private_discussion_repository::PrivateDiscussionRepositoryImpl,
+    private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl,
     private_message_repository::PrivateMessageRepositoryImpl,
 };
 use crate::features::profile::domain::entities::User;
@@ -85,7 +88,18 @@ pub async fn populate_database(pool: &PgPool) -> Result<(), sqlx::Error> {
         notifications_for_private_messages_enabled: false,
         notifications_for_public_message_liked_enabled: false,
         notifications_for_public_message_replies_enabled: false,
+        notifications_for_public_message_mentions_enabled: false,
+        public_message_languages: vec![],
         notifications_user_duplicated_your_challenge_enabled: false,
+        bio: None,
+        avatar: None,
+        profile_shows_bio: false,
+        profile_shows_avatar: false,
+        profile_shows_habits: false,
+        profile_shows_finished_challenges: false,
+        profile_shows_badges: false,
+        open_to_meeting_people: false,
+        matching_uses_demographics: false,
         notifications_user_joined_your_challenge_enabled: false,
     };
 
@@ -124,7 +138,18 @@ pub async fn populate_database(pool: &PgPool) -> Result<(), sqlx::Error> {
         notifications_for_private_messages_enabled: false,
         notifications_for_public_message_liked_enabled: false,
         notifications_for_public_message_replies_enabled: false,
+        notifications_for_public_message_mentions_enabled: false,
+        public_message_languages: vec![],
         notifications_user_duplicated_your_challenge_enabled: false,
+        bio: None,
+        avatar: None,
+        profile_shows_bio: false,
+        profile_shows_avatar: false,
+        profile_shows_habits: false,
+        profile_shows_finished_challenges: false,
+        profile_shows_badges: false,
+        open_to_meeting_people: false,
+        matching_uses_demographics: false,
         notifications_user_joined_your_challenge_enabled: false,
     };
 
@@ -816,6 +841,13 @@ pub async fn reset_database(pool: &PgPool) -> Result<(), sqlx::Error> {
     sqlx::query("DELETE FROM recovery_codes;")
         .execute(pool)
         .await?;
+    sqlx::query("DELETE FROM stories;").execute(pool).await?;
+    sqlx::query("DELETE FROM user_blocks;")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM user_role_grants;")
+        .execute(pool)
+        .await?;
     sqlx::query("DELETE FROM public_messages;")
         .execute(pool)
         .await?;
@@ -878,6 +910,11 @@ pub async fn create_missing_discussions_with_reallystick_user(
         let discussion = PrivateDiscussion {
             id: Uuid::new_v4(),
             created_at: now(),
+            name: None,
+            is_group: false,
+            message_retention: PrivateMessageRetention::Off.to_string(),
+            proposed_message_retention: None,
+            message_retention_proposed_by: None,
         };
 
         private_discussion_repo
@@ -894,6 +931,12 @@ pub async fn create_missing_discussions_with_reallystick_user(
             color: "blue".to_string(),
             created_at: now(),
             has_blocked: false,
+            is_admin: false,
+            muted_until: None,
+            archived_at: None,
+            pinned_at: None,
+            request_status: PrivateDiscussionRequestStatus::Accepted.to_string(),
+            requested_by: None,
         };
 
         let discussion_participation_for_reallystick_user = PrivateDiscussionParticipation {
@@ -903,6 +946,12 @@ pub async fn create_missing_discussions_with_reallystick_user(
             color: "blue".to_string(),
             created_at: now(),
             has_blocked: false,
+            is_admin: false,
+            muted_until: None,
+            archived_at: None,
+            pinned_at: None,
+            request_status: PrivateDiscussionRequestStatus::Accepted.to_string(),
+            requested_by: None,
         };
 
         private_discussion_participation_repo
@@ -938,6 +987,12 @@ pub async fn create_missing_discussions_with_reallystick_user(
             recipient_encrypted_session_key: "NOT_ENCRYPTED".to_string(),
             deleted: false,
             seen: false,
+            encrypted_session_keys: serde_json::json!({}),
+            device_encrypted_session_keys: serde_json::json!({}),
+            delivered_at: None,
+            seen_at: None,
+            expires_at: None,
+            attachment_ids: Vec::new(),
         };
 
         let private_message_repo = PrivateMessageRepositoryImpl::new(pool.clone());
@@ -947,6 +1002,13 @@ pub async fn create_missing_discussions_with_reallystick_user(
             .map_err(|e| {
                 sqlx::Error::Configuration(Box::new(std::io::Error::other(e.to_string())))
             })?;
+
+        PrivateMessageReceiptRepositoryImpl::new(pool.clone())
+            .create_for_recipients_with_executor(private_message.id, &[user.id], &mut *transaction)
+            .await
+            .map_err(|e| {
+                sqlx::Error::Configuration(Box::new(std::io::Error::other(e.to_string())))
+            })?;
     }
 
     transaction.commit().await?;
@@ -6,30 +6,46 @@ use sqlx::PgPool;
 use tracing::{error, info};
 
 use crate::core::structs::redis_messages::UserRemovedEvent;
+use crate::features::attachments::domain::services::storage_backend::{
+    delete_private_message_attachment_files, StorageBackend,
+};
 use crate::features::challenges::infrastructure::repositories::{
     challenge_daily_tracking_repository::ChallengeDailyTrackingRepositoryImpl,
     challenge_participation_repository::ChallengeParticipationRepositoryImpl,
     challenge_repository::ChallengeRepositoryImpl,
 };
+use crate::features::content_filters::infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl;
 use crate::features::habits::infrastructure::repositories::{
     habit_daily_tracking_repository::HabitDailyTrackingRepositoryImpl,
     habit_participation_repository::HabitParticipationRepositoryImpl,
 };
-use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::{
+    private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl,
+    private_message_repository::PrivateMessageRepositoryImpl,
+};
 use crate::features::profile::domain::entities::User;
 use crate::features::profile::domain::repositories::UserRepository;
 use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
 use crate::features::public_discussions::infrastructure::repositories::{
-    public_message_like_repository::PublicMessageLikeRepositoryImpl,
+    public_message_reaction_repository::PublicMessageReactionRepositoryImpl,
     public_message_report_repository::PublicMessageReportRepositoryImpl,
     public_message_repository::PublicMessageRepositoryImpl,
+    public_message_revision_repository::PublicMessageRevisionRepositoryImpl,
+    public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl,
+    user_suspension_repository::UserSuspensionRepositoryImpl,
+};
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
+use crate::features::stories::infrastructure::repositories::{
+    story_repository::StoryRepositoryImpl, story_view_repository::StoryViewRepositoryImpl,
 };
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
 
 use super::mock_now::now;
 
 pub async fn remove_users_marked_as_deleted(
     pool: &PgPool,
     redis_client: &Client,
+    storage_backend: &dyn StorageBackend,
 ) -> Result<(), sqlx::Error> {
     let user_repo = UserRepositoryImpl::new(pool.clone());
     let users = user_repo
@@ -38,7 +54,7 @@ pub async fn remove_users_marked_as_deleted(
         .map_err(|e| sqlx::Error::Configuration(Box::new(std::io::Error::other(e))))?;
 
     for user in users.clone() {
-        delete_user_data(pool, user, redis_client).await?;
+        delete_user_data(pool, user, redis_client, storage_backend).await?;
     }
 
     info!("Successfully deleted {} users.", users.len());
@@ -50,6 +66,7 @@ pub async fn delete_user_data(
     pool: &PgPool,
     user: User,
     redis_client: &Client,
+    storage_backend: &dyn StorageBackend,
 ) -> Result<(), sqlx::Error> {
     if let Some(deleted_at) = user.deleted_at {
         if let Some(date_after_which_user_can_be_deleted) =
@@ -71,6 +88,17 @@ pub async fn delete_user_data(
                     return Ok(());
                 }
 
+                let public_message_revision_repo =
+                    PublicMessageRevisionRepositoryImpl::new(pool.clone());
+                if let Err(e) = public_message_revision_repo
+                    .delete_by_creator_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    error!("Error: {}", e);
+                    transaction.rollback().await?;
+                    return Ok(());
+                }
+
                 let habit_daily_tracking_repo = HabitDailyTrackingRepositoryImpl::new(pool.clone());
                 if let Err(e) = habit_daily_tracking_repo
                     .delete_by_user_id_with_executor(user.id, &mut *transaction)
@@ -135,6 +163,21 @@ pub async fn delete_user_data(
                     return Ok(());
                 }
 
+                // Files are removed once the transaction is committed
+                let private_message_attachment_repo =
+                    PrivateMessageAttachmentRepositoryImpl::new(pool.clone());
+                let private_message_attachments = match private_message_attachment_repo
+                    .delete_by_uploader_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    Ok(attachments) => attachments,
+                    Err(e) => {
+                        error!("Error: {}", e);
+                        transaction.rollback().await?;
+                        return Ok(());
+                    }
+                };
+
                 let private_message_repo = PrivateMessageRepositoryImpl::new(pool.clone());
                 if let Err(e) = private_message_repo
                     .delete_by_user_id_with_executor(user.id, &mut *transaction)
@@ -155,8 +198,9 @@ pub async fn delete_user_data(
                     return Ok(());
                 }
 
-                let public_message_like_repo = PublicMessageLikeRepositoryImpl::new(pool.clone());
-                if let Err(e) = public_message_like_repo
+                let public_message_reaction_repo =
+                    PublicMessageReactionRepositoryImpl::new(pool.clone());
+                if let Err(e) = public_message_reaction_repo
                     .delete_by_user_id_with_executor(user.id, &mut *transaction)
                     .await
                 {
@@ -176,8 +220,86 @@ pub async fn delete_user_data(
                     return Ok(());
                 }
 
+                let public_message_subscription_repo =
+                    PublicMessageSubscriptionRepositoryImpl::new(pool.clone());
+                if let Err(e) = public_message_subscription_repo
+                    .delete_by_user_id_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    error!("Error: {}", e);
+                    transaction.rollback().await?;
+                    return Ok(());
+                }
+
+                let story_repo = StoryRepositoryImpl::new(pool.clone());
+                if let Err(e) = story_repo
+                    .delete_by_user_id_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    error!("Error: {}", e);
+                    transaction.rollback().await?;
+                    return Ok(());
+                }
+
+                let story_view_repo = StoryViewRepositoryImpl::new(pool.clone());
+                if let Err(e) = story_view_repo
+                    .delete_by_user_id_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    error!("Error: {}", e);
+                    transaction.rollback().await?;
+                    return Ok(());
+                }
+
+                let user_block_repo = UserBlockRepositoryImpl::new(pool.clone());
+                if let Err(e) = user_block_repo
+                    .delete_by_user_id_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    error!("Error: {}", e);
+                    transaction.rollback().await?;
+                    return Ok(());
+                }
+
+                let user_suspension_repo = UserSuspensionRepositoryImpl::new(pool.clone());
+                if let Err(e) = user_suspension_repo
+                    .delete_by_user_id_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    error!("Error: {}", e);
+                    transaction.rollback().await?;
+                    return Ok(());
+                }
+
+                let role_grant_repo = RoleGrantRepositoryImpl::new(pool.clone());
+                if let Err(e) = role_grant_repo
+                    .delete_by_user_id_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    error!("Error: {}", e);
+                    transaction.rollback().await?;
+                    return Ok(());
+                }
+
+                // The username is gone, so are its flags
+                let content_filter_flag_repo = ContentFilterFlagRepositoryImpl::new(pool.clone());
+                if let Err(e) = content_filter_flag_repo
+                    .delete_by_target_id_with_executor(user.id, &mut *transaction)
+                    .await
+                {
+                    error!("Error: {}", e);
+                    transaction.rollback().await?;
+                    return Ok(());
+                }
+
                 transaction.commit().await?;
 
+                delete_private_message_attachment_files(
+                    storage_backend,
+                    &private_message_attachments,
+                )
+                .await;
+
                 match redis_client.get_multiplexed_async_connection().await {
                     Ok(mut con) => {
                         let result: Result<(), redis::RedisError> = con
@@ -0,0 +1,15 @@
+// Permission guard - rejects requests whose claims lack a given permission
+
+use actix_web::HttpResponse;
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::roles::domain::entities::permission::Permission;
+
+pub fn require_permission(claims: &Claims, permission: Permission) -> Result<(), HttpResponse> {
+    if claims.has_permission(permission) {
+        Ok(())
+    } else {
+        Err(HttpResponse::Forbidden().json(AppError::MissingPermission.to_response()))
+    }
+}
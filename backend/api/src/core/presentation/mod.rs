@@ -1,5 +1,9 @@
 // Core presentation layer - shared routes, middlewares, etc.
 
+pub mod guards {
+    pub mod permission;
+}
+
 pub mod middlewares {
     pub mod token_validator;
 }
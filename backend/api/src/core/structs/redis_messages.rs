@@ -33,3 +33,12 @@ pub struct UserTokenRemovedEvent {
     pub user_id: Uuid,
     pub token_id: Uuid,
 }
+
+// Sent to the sockets of a revoked device so that it wipes its key material
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DeviceRevokedEvent {
+    pub user_id: Uuid,
+    pub token_id: Uuid,
+    // Sockets are registered under the id of the user token
+    pub user_token_id: Uuid,
+}
@@ -0,0 +1,7 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+pub struct AttachmentParams {
+    pub attachment_id: Uuid,
+}
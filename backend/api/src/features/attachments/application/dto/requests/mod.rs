@@ -0,0 +1,3 @@
+// Attachments request DTOs
+
+pub mod attachment;
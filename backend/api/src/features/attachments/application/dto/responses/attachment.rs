@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::attachments::domain::entities::attachment::AttachmentData;
+
+#[derive(Serialize, Deserialize)]
+pub struct AttachmentResponse {
+    pub code: String,
+    pub attachment: Option<AttachmentData>,
+}
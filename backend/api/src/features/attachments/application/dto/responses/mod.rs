@@ -0,0 +1,3 @@
+// Attachments response DTOs
+
+pub mod attachment;
@@ -0,0 +1,101 @@
+// Create attachment use case
+
+use std::{str::FromStr, sync::Arc};
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::attachments::domain::entities::attachment::{
+    Attachment, AttachmentContentType, ATTACHMENT_MAX_SIZE_IN_BYTES,
+};
+use crate::features::attachments::domain::services::storage_backend::{
+    delete_attachment_files, StorageBackend,
+};
+use crate::features::attachments::infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl;
+use crate::features::attachments::infrastructure::services::image_service::ImageService;
+use tracing::error;
+use uuid::Uuid;
+
+pub struct CreateAttachmentUseCase {
+    attachment_repo: AttachmentRepositoryImpl,
+    storage_backend: Arc<dyn StorageBackend>,
+}
+
+impl CreateAttachmentUseCase {
+    pub fn new(
+        attachment_repo: AttachmentRepositoryImpl,
+        storage_backend: Arc<dyn StorageBackend>,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            storage_backend,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        uploader: Uuid,
+        content: Vec<u8>,
+        declared_content_type: &str,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Attachment, AppError> {
+        if content.len() > ATTACHMENT_MAX_SIZE_IN_BYTES {
+            return Err(AppError::AttachmentTooLarge);
+        }
+
+        let declared_content_type = AttachmentContentType::from_str(declared_content_type)
+            .map_err(|_| AppError::AttachmentInvalidContentType)?;
+
+        // Decoding is CPU bound, keep it away from the async workers
+        let image = tokio::task::spawn_blocking(move || {
+            ImageService::new().process(&content, declared_content_type)
+        })
+        .await
+        .map_err(|_| AppError::AttachmentCreation)??;
+
+        let id = Uuid::new_v4();
+        let attachment = Attachment {
+            id,
+            uploader: Some(uploader),
+            public_message_id: None,
+            content_type: image.content_type.to_string(),
+            size_in_bytes: image.content.len() as i64,
+            width: image.width as i32,
+            height: image.height as i32,
+            storage_key: Attachment::storage_key_for(id),
+            thumbnail_storage_key: Attachment::thumbnail_storage_key_for(id),
+            created_at: now(),
+        };
+
+        self.storage_backend
+            .put(&attachment.storage_key, &image.content)
+            .await
+            .map_err(|e| {
+                error!("Error: {}", e);
+                AppError::AttachmentStorage
+            })?;
+
+        let result = match self
+            .storage_backend
+            .put(&attachment.thumbnail_storage_key, &image.thumbnail)
+            .await
+        {
+            Ok(()) => self
+                .attachment_repo
+                .create_with_executor(&attachment, &mut **transaction)
+                .await
+                .map_err(|_| AppError::AttachmentCreation),
+            Err(e) => {
+                error!("Error: {}", e);
+                Err(AppError::AttachmentStorage)
+            }
+        };
+
+        // Files of a failed upload are not referenced anywhere
+        if let Err(e) = result {
+            delete_attachment_files(self.storage_backend.as_ref(), &[attachment]).await;
+            return Err(e);
+        }
+
+        Ok(attachment)
+    }
+}
@@ -0,0 +1,28 @@
+// Delete public message attachments use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::attachments::domain::entities::attachment::Attachment;
+use crate::features::attachments::infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl;
+use uuid::Uuid;
+
+pub struct DeletePublicMessageAttachmentsUseCase {
+    attachment_repo: AttachmentRepositoryImpl,
+}
+
+impl DeletePublicMessageAttachmentsUseCase {
+    pub fn new(attachment_repo: AttachmentRepositoryImpl) -> Self {
+        Self { attachment_repo }
+    }
+
+    // Returns the deleted attachments, whose files have to be removed once committed
+    pub async fn execute(
+        &self,
+        public_message_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<Attachment>, AppError> {
+        self.attachment_repo
+            .delete_by_public_message_id_with_executor(public_message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::AttachmentDeletion)
+    }
+}
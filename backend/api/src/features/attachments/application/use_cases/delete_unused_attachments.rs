@@ -0,0 +1,34 @@
+// Delete unused attachments use case
+
+use chrono::Duration;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::attachments::domain::entities::attachment::{
+    Attachment, ATTACHMENT_UNUSED_RETENTION_IN_HOURS,
+};
+use crate::features::attachments::infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl;
+
+pub struct DeleteUnusedAttachmentsUseCase {
+    attachment_repo: AttachmentRepositoryImpl,
+}
+
+impl DeleteUnusedAttachmentsUseCase {
+    pub fn new(attachment_repo: AttachmentRepositoryImpl) -> Self {
+        Self { attachment_repo }
+    }
+
+    // Returns the deleted attachments, whose files have to be removed once committed
+    pub async fn execute(
+        &self,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<Attachment>, AppError> {
+        self.attachment_repo
+            .delete_unused_with_executor(
+                now() - Duration::hours(ATTACHMENT_UNUSED_RETENTION_IN_HOURS),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::AttachmentDeletion)
+    }
+}
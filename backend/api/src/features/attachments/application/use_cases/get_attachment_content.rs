@@ -0,0 +1,66 @@
+// Get attachment content use case
+
+use std::sync::Arc;
+
+use crate::core::constants::errors::AppError;
+use crate::features::attachments::domain::entities::attachment::Attachment;
+use crate::features::attachments::domain::services::storage_backend::StorageBackend;
+use crate::features::attachments::infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl;
+use tracing::error;
+use uuid::Uuid;
+
+pub struct GetAttachmentContentUseCase {
+    attachment_repo: AttachmentRepositoryImpl,
+    storage_backend: Arc<dyn StorageBackend>,
+}
+
+impl GetAttachmentContentUseCase {
+    pub fn new(
+        attachment_repo: AttachmentRepositoryImpl,
+        storage_backend: Arc<dyn StorageBackend>,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            storage_backend,
+        }
+    }
+
+    // Returns the attachment with the content of the image or of its thumbnail
+    pub async fn execute(
+        &self,
+        attachment_id: Uuid,
+        user_id: Uuid,
+        thumbnail: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(Attachment, Vec<u8>), AppError> {
+        let attachment = self
+            .attachment_repo
+            .get_by_id_with_executor(attachment_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::AttachmentNotFound)?;
+
+        // Until it is posted, an upload is only visible to its uploader
+        if attachment.public_message_id.is_none() && attachment.uploader != Some(user_id) {
+            return Err(AppError::AttachmentNotFound);
+        }
+
+        let key = if thumbnail {
+            &attachment.thumbnail_storage_key
+        } else {
+            &attachment.storage_key
+        };
+
+        let content = self
+            .storage_backend
+            .get(key)
+            .await
+            .map_err(|e| {
+                error!("Error: {}", e);
+                AppError::AttachmentStorage
+            })?
+            .ok_or(AppError::AttachmentNotFound)?;
+
+        Ok((attachment, content))
+    }
+}
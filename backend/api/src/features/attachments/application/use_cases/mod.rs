@@ -0,0 +1,6 @@
+// Attachments use cases
+
+pub mod create_attachment;
+pub mod delete_public_message_attachments;
+pub mod delete_unused_attachments;
+pub mod get_attachment_content;
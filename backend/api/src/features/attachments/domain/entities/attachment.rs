@@ -0,0 +1,103 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+pub const ATTACHMENT_MAX_SIZE_IN_BYTES: usize = 10 * 1024 * 1024;
+pub const ATTACHMENT_MAX_DIMENSION: u32 = 8192;
+pub const ATTACHMENT_THUMBNAIL_SIZE: u32 = 320;
+// Uploads never linked to a message are removed after this delay
+pub const ATTACHMENT_UNUSED_RETENTION_IN_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AttachmentContentType {
+    #[serde(rename = "image/jpeg")]
+    Jpeg,
+    #[serde(rename = "image/png")]
+    Png,
+    #[serde(rename = "image/webp")]
+    Webp,
+}
+
+impl AttachmentContentType {
+    pub const ALL: [AttachmentContentType; 3] = [
+        AttachmentContentType::Jpeg,
+        AttachmentContentType::Png,
+        AttachmentContentType::Webp,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttachmentContentType::Jpeg => "image/jpeg",
+            AttachmentContentType::Png => "image/png",
+            AttachmentContentType::Webp => "image/webp",
+        }
+    }
+}
+
+impl fmt::Display for AttachmentContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AttachmentContentType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AttachmentContentType::ALL
+            .into_iter()
+            .find(|content_type| content_type.as_str() == s)
+            .ok_or_else(|| format!("Unsupported attachment content type: {}", s))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub uploader: Option<Uuid>,
+    pub public_message_id: Option<Uuid>,
+    // Type of the stored image, which is re-encoded on upload
+    pub content_type: String,
+    pub size_in_bytes: i64,
+    pub width: i32,
+    pub height: i32,
+    pub storage_key: String,
+    pub thumbnail_storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AttachmentData {
+    pub id: Uuid,
+    pub public_message_id: Option<Uuid>,
+    pub content_type: String,
+    pub size_in_bytes: i64,
+    pub width: i32,
+    pub height: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    pub fn storage_key_for(id: Uuid) -> String {
+        format!("attachments/{}", id)
+    }
+
+    pub fn thumbnail_storage_key_for(id: Uuid) -> String {
+        format!("attachments/{}_thumbnail", id)
+    }
+
+    pub fn to_attachment_data(&self) -> AttachmentData {
+        AttachmentData {
+            id: self.id,
+            public_message_id: self.public_message_id,
+            content_type: self.content_type.to_owned(),
+            size_in_bytes: self.size_in_bytes,
+            width: self.width,
+            height: self.height,
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+// Attachments domain entities
+
+pub mod attachment;
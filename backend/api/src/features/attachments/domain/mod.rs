@@ -0,0 +1,4 @@
+// Domain layer - entities, repository traits and service traits
+pub mod entities;
+pub mod repositories;
+pub mod services;
@@ -0,0 +1,25 @@
+// Attachment repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::features::attachments::domain::entities::attachment::Attachment;
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    async fn create(&self, attachment: &Attachment) -> Result<(), String>;
+    async fn get_by_id(&self, attachment_id: Uuid) -> Result<Option<Attachment>, String>;
+    async fn attach_to_public_message(
+        &self,
+        attachment_ids: &[Uuid],
+        uploader: Uuid,
+        public_message_id: Uuid,
+    ) -> Result<u64, String>;
+    async fn delete_by_public_message_id(
+        &self,
+        public_message_id: Uuid,
+    ) -> Result<Vec<Attachment>, String>;
+    async fn delete_unused(&self, created_before: DateTime<Utc>)
+        -> Result<Vec<Attachment>, String>;
+}
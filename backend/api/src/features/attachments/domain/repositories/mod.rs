@@ -0,0 +1,3 @@
+// Attachments domain repository traits
+
+pub mod attachment_repository;
@@ -0,0 +1,3 @@
+// Attachments domain service traits
+
+pub mod storage_backend;
@@ -0,0 +1,42 @@
+// Storage backend trait - where attachment files are kept
+
+use async_trait::async_trait;
+use tracing::error;
+
+use crate::features::attachments::domain::entities::attachment::Attachment;
+use crate::features::private_discussions::domain::entities::private_message_attachment::PrivateMessageAttachment;
+
+// Files are addressed by keys such as "attachments/<id>", so that a backend can map them
+// to paths, object names (S3-compatible storage) or anything else.
+#[async_trait]
+pub trait StorageBackend: Send + Sync {
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), String>;
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String>;
+    // Deleting a missing file is not an error
+    async fn delete(&self, key: &str) -> Result<(), String>;
+}
+
+// Removes the files of attachments whose rows are gone, failures are only logged
+pub async fn delete_attachment_files(
+    storage_backend: &dyn StorageBackend,
+    attachments: &[Attachment],
+) {
+    for attachment in attachments {
+        for key in [&attachment.storage_key, &attachment.thumbnail_storage_key] {
+            if let Err(e) = storage_backend.delete(key).await {
+                error!("Error deleting {}: {}", key, e);
+            }
+        }
+    }
+}
+
+pub async fn delete_private_message_attachment_files(
+    storage_backend: &dyn StorageBackend,
+    attachments: &[PrivateMessageAttachment],
+) {
+    for attachment in attachments {
+        if let Err(e) = storage_backend.delete(&attachment.storage_key).await {
+            error!("Error deleting {}: {}", attachment.storage_key, e);
+        }
+    }
+}
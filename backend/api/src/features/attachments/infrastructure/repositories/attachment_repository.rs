@@ -0,0 +1,206 @@
+// AttachmentRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::attachments::domain::entities::attachment::Attachment;
+use crate::features::attachments::domain::repositories::attachment_repository::AttachmentRepository;
+
+pub struct AttachmentRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AttachmentRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        attachment: &Attachment,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO attachments (
+                id,
+                uploader,
+                public_message_id,
+                content_type,
+                size_in_bytes,
+                width,
+                height,
+                storage_key,
+                thumbnail_storage_key,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            attachment.id,
+            attachment.uploader,
+            attachment.public_message_id,
+            attachment.content_type,
+            attachment.size_in_bytes,
+            attachment.width,
+            attachment.height,
+            attachment.storage_key,
+            attachment.thumbnail_storage_key,
+            attachment.created_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        attachment_id: Uuid,
+        executor: E,
+    ) -> Result<Option<Attachment>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            Attachment,
+            r#"
+            SELECT *
+            FROM attachments
+            WHERE id = $1
+            "#,
+            attachment_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    // Only links the unused uploads of the given user
+    pub async fn attach_to_public_message_with_executor<'a, E>(
+        &self,
+        attachment_ids: &[Uuid],
+        uploader: Uuid,
+        public_message_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE attachments
+            SET public_message_id = $1
+            WHERE id = ANY($2) AND uploader = $3 AND public_message_id IS NULL
+            "#,
+            public_message_id,
+            attachment_ids,
+            uploader,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_by_public_message_id_with_executor<'a, E>(
+        &self,
+        public_message_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<Attachment>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            Attachment,
+            r#"
+            DELETE FROM attachments
+            WHERE public_message_id = $1
+            RETURNING *
+            "#,
+            public_message_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Uploads never linked to a message, and leftovers of deleted messages
+    pub async fn delete_unused_with_executor<'a, E>(
+        &self,
+        created_before: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<Attachment>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            Attachment,
+            r#"
+            DELETE FROM attachments a
+            WHERE (a.public_message_id IS NULL AND a.created_at < $1)
+                OR EXISTS (
+                    SELECT 1
+                    FROM public_messages pm
+                    WHERE pm.id = a.public_message_id
+                        AND (pm.deleted_by_creator OR pm.deleted_by_admin)
+                )
+            RETURNING a.*
+            "#,
+            created_before
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for AttachmentRepositoryImpl {
+    async fn create(&self, attachment: &Attachment) -> Result<(), String> {
+        self.create_with_executor(attachment, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_id(&self, attachment_id: Uuid) -> Result<Option<Attachment>, String> {
+        self.get_by_id_with_executor(attachment_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn attach_to_public_message(
+        &self,
+        attachment_ids: &[Uuid],
+        uploader: Uuid,
+        public_message_id: Uuid,
+    ) -> Result<u64, String> {
+        self.attach_to_public_message_with_executor(
+            attachment_ids,
+            uploader,
+            public_message_id,
+            &self.pool,
+        )
+        .await
+        .map(|result| result.rows_affected())
+        .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_public_message_id(
+        &self,
+        public_message_id: Uuid,
+    ) -> Result<Vec<Attachment>, String> {
+        self.delete_by_public_message_id_with_executor(public_message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_unused(
+        &self,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<Attachment>, String> {
+        self.delete_unused_with_executor(created_before, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
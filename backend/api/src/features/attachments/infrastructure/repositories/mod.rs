@@ -0,0 +1,3 @@
+// Attachments infrastructure repository implementations
+
+pub mod attachment_repository;
@@ -0,0 +1,103 @@
+// Image service - validates uploaded images and re-encodes them without their metadata
+
+use std::io::Cursor;
+
+use image::{
+    codecs::{jpeg::JpegEncoder, png::PngEncoder},
+    DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits,
+};
+
+use crate::core::constants::errors::AppError;
+use crate::features::attachments::domain::entities::attachment::{
+    AttachmentContentType, ATTACHMENT_MAX_DIMENSION, ATTACHMENT_THUMBNAIL_SIZE,
+};
+
+const JPEG_QUALITY: u8 = 85;
+
+pub struct ProcessedImage {
+    pub content_type: AttachmentContentType,
+    pub content: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Default)]
+pub struct ImageService {}
+
+impl ImageService {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // Decoding and re-encoding the pixels drops every metadata of the upload (EXIF, GPS
+    // position, camera...). The EXIF orientation is applied first so that the image is
+    // still displayed the right way up. JPEG stays JPEG, other images are stored as PNG.
+    pub fn process(
+        &self,
+        content: &[u8],
+        declared_content_type: AttachmentContentType,
+    ) -> Result<ProcessedImage, AppError> {
+        let mut reader = ImageReader::new(Cursor::new(content))
+            .with_guessed_format()
+            .map_err(|_| AppError::AttachmentInvalidImage)?;
+
+        // The declared type must match the actual content
+        let detected_content_type = match reader.format() {
+            Some(ImageFormat::Jpeg) => AttachmentContentType::Jpeg,
+            Some(ImageFormat::Png) => AttachmentContentType::Png,
+            Some(ImageFormat::WebP) => AttachmentContentType::Webp,
+            _ => return Err(AppError::AttachmentInvalidContentType),
+        };
+        if detected_content_type != declared_content_type {
+            return Err(AppError::AttachmentInvalidContentType);
+        }
+
+        let mut limits = Limits::default();
+        limits.max_image_width = Some(ATTACHMENT_MAX_DIMENSION);
+        limits.max_image_height = Some(ATTACHMENT_MAX_DIMENSION);
+        reader.limits(limits);
+
+        let mut decoder = reader
+            .into_decoder()
+            .map_err(|_| AppError::AttachmentInvalidImage)?;
+        let orientation = decoder
+            .orientation()
+            .map_err(|_| AppError::AttachmentInvalidImage)?;
+        let mut image =
+            DynamicImage::from_decoder(decoder).map_err(|_| AppError::AttachmentInvalidImage)?;
+        image.apply_orientation(orientation);
+
+        let content_type = match detected_content_type {
+            AttachmentContentType::Jpeg => AttachmentContentType::Jpeg,
+            _ => AttachmentContentType::Png,
+        };
+
+        let thumbnail = image.thumbnail(ATTACHMENT_THUMBNAIL_SIZE, ATTACHMENT_THUMBNAIL_SIZE);
+
+        Ok(ProcessedImage {
+            content_type,
+            content: self.encode(&image, content_type)?,
+            thumbnail: self.encode(&thumbnail, content_type)?,
+            width: image.width(),
+            height: image.height(),
+        })
+    }
+
+    fn encode(
+        &self,
+        image: &DynamicImage,
+        content_type: AttachmentContentType,
+    ) -> Result<Vec<u8>, AppError> {
+        let mut content = Vec::new();
+
+        let result = match content_type {
+            AttachmentContentType::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+                .write_with_encoder(JpegEncoder::new_with_quality(&mut content, JPEG_QUALITY)),
+            _ => image.write_with_encoder(PngEncoder::new(&mut content)),
+        };
+
+        result.map_err(|_| AppError::AttachmentInvalidImage)?;
+        Ok(content)
+    }
+}
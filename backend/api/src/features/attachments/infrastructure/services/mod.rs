@@ -0,0 +1,4 @@
+// Infrastructure services
+
+pub mod image_service;
+pub mod local_storage_backend;
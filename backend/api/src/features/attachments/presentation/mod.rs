@@ -0,0 +1,2 @@
+// Presentation layer - routes/controllers
+pub mod routes;
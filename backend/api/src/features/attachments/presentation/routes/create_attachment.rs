@@ -0,0 +1,123 @@
+use actix_multipart::Multipart;
+use actix_web::{
+    post,
+    web::{Data, ReqData},
+    HttpResponse, Responder,
+};
+use futures_util::StreamExt;
+use sqlx::PgPool;
+use tracing::error;
+
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        attachments::{
+            application::{
+                dto::responses::attachment::AttachmentResponse,
+                use_cases::create_attachment::CreateAttachmentUseCase,
+            },
+            domain::{
+                entities::attachment::ATTACHMENT_MAX_SIZE_IN_BYTES,
+                services::storage_backend::StorageBackend,
+            },
+            infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl,
+        },
+        auth::domain::entities::Claims,
+    },
+};
+
+// Reads the "file" field of the form, without keeping more than the maximum size in memory.
+// Shared with the private message attachments route.
+pub async fn read_file_field(
+    mut payload: Multipart,
+    max_size_in_bytes: usize,
+) -> Result<(Vec<u8>, String), AppError> {
+    while let Some(field) = payload.next().await {
+        let mut field = field.map_err(|_| AppError::AttachmentFileMissing)?;
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let content_type = field
+            .content_type()
+            .map(|mime| mime.essence_str().to_string())
+            .unwrap_or_default();
+
+        let mut content = Vec::new();
+        while let Some(chunk) = field.next().await {
+            let chunk = chunk.map_err(|_| AppError::AttachmentFileMissing)?;
+            if content.len() + chunk.len() > max_size_in_bytes {
+                return Err(AppError::AttachmentTooLarge);
+            }
+            content.extend_from_slice(&chunk);
+        }
+
+        return Ok((content, content_type));
+    }
+
+    Err(AppError::AttachmentFileMissing)
+}
+
+#[post("/")]
+pub async fn create_attachment(
+    pool: Data<PgPool>,
+    storage_backend: Data<dyn StorageBackend>,
+    payload: Multipart,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let (content, content_type) = match read_file_field(payload, ATTACHMENT_MAX_SIZE_IN_BYTES).await
+    {
+        Ok(file) => file,
+        Err(AppError::AttachmentTooLarge) => {
+            return HttpResponse::PayloadTooLarge().json(AppError::AttachmentTooLarge.to_response())
+        }
+        Err(e) => return HttpResponse::BadRequest().json(e.to_response()),
+    };
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let attachment_repo = AttachmentRepositoryImpl::new(pool.get_ref().clone());
+    let use_case = CreateAttachmentUseCase::new(attachment_repo, storage_backend.into_inner());
+
+    let result = use_case
+        .execute(
+            request_claims.user_id,
+            content,
+            &content_type,
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(attachment) => HttpResponse::Ok().json(AttachmentResponse {
+            code: "ATTACHMENT_CREATED".to_string(),
+            attachment: Some(attachment.to_attachment_data()),
+        }),
+        Err(AppError::AttachmentTooLarge) => {
+            HttpResponse::PayloadTooLarge().json(AppError::AttachmentTooLarge.to_response())
+        }
+        Err(AppError::AttachmentInvalidContentType) => HttpResponse::UnsupportedMediaType()
+            .json(AppError::AttachmentInvalidContentType.to_response()),
+        Err(AppError::AttachmentInvalidImage) => {
+            HttpResponse::BadRequest().json(AppError::AttachmentInvalidImage.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
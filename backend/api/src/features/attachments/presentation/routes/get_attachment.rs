@@ -0,0 +1,87 @@
+use actix_web::{
+    get,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        attachments::{
+            application::{
+                dto::requests::attachment::AttachmentParams,
+                use_cases::get_attachment_content::GetAttachmentContentUseCase,
+            },
+            domain::services::storage_backend::StorageBackend,
+            infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl,
+        },
+        auth::domain::entities::Claims,
+    },
+};
+
+#[get("/{attachment_id}")]
+pub async fn get_attachment(
+    pool: Data<PgPool>,
+    storage_backend: Data<dyn StorageBackend>,
+    params: Path<AttachmentParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    get_attachment_content(
+        pool,
+        storage_backend,
+        params.attachment_id,
+        request_claims.user_id,
+        false,
+    )
+    .await
+}
+
+// Shared with the thumbnail route
+pub async fn get_attachment_content(
+    pool: Data<PgPool>,
+    storage_backend: Data<dyn StorageBackend>,
+    attachment_id: Uuid,
+    user_id: Uuid,
+    thumbnail: bool,
+) -> HttpResponse {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let attachment_repo = AttachmentRepositoryImpl::new(pool.get_ref().clone());
+    let use_case = GetAttachmentContentUseCase::new(attachment_repo, storage_backend.into_inner());
+
+    let result = use_case
+        .execute(attachment_id, user_id, thumbnail, &mut transaction)
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        // Attachments never change, their id is enough to cache them
+        Ok((attachment, content)) => HttpResponse::Ok()
+            .content_type(attachment.content_type)
+            .insert_header(("Cache-Control", "private, max-age=31536000, immutable"))
+            .body(content),
+        Err(AppError::AttachmentNotFound) => {
+            HttpResponse::NotFound().json(AppError::AttachmentNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
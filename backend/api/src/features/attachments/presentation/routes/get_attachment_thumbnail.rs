@@ -0,0 +1,33 @@
+use actix_web::{
+    get,
+    web::{Data, Path, ReqData},
+    Responder,
+};
+use sqlx::PgPool;
+
+use crate::features::{
+    attachments::{
+        application::dto::requests::attachment::AttachmentParams,
+        domain::services::storage_backend::StorageBackend,
+    },
+    auth::domain::entities::Claims,
+};
+
+use super::get_attachment::get_attachment_content;
+
+#[get("/{attachment_id}/thumbnail")]
+pub async fn get_attachment_thumbnail(
+    pool: Data<PgPool>,
+    storage_backend: Data<dyn StorageBackend>,
+    params: Path<AttachmentParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    get_attachment_content(
+        pool,
+        storage_backend,
+        params.attachment_id,
+        request_claims.user_id,
+        true,
+    )
+    .await
+}
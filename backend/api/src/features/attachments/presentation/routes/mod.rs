@@ -0,0 +1,5 @@
+// Attachments presentation routes
+
+pub mod create_attachment;
+pub mod get_attachment;
+pub mod get_attachment_thumbnail;
@@ -68,3 +68,8 @@ pub struct SaveKeysRequest {
     pub private_key_encrypted: String,
     pub salt_used_to_derive_key_from_password: String,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RegisterDeviceKeyRequest {
+    pub public_key: String,
+}
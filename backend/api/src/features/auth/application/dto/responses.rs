@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::features::auth::domain::entities::DeviceKeyData;
+
 #[derive(Serialize, Debug, Deserialize)]
 pub struct UserSignupResponse {
     pub code: String,
@@ -56,3 +58,15 @@ pub struct DisableOtpResponse {
 pub struct SaveRecoveryCodeResponse {
     pub code: String,
 }
+
+#[derive(Serialize, Deserialize)]
+pub struct DeviceKeyResponse {
+    pub code: String,
+    pub device_key: Option<DeviceKeyData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeviceKeysResponse {
+    pub code: String,
+    pub device_keys: Vec<DeviceKeyData>,
+}
@@ -1,41 +1,88 @@
 // Delete device use case - deletes a user token/device
 
 use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::auth::domain::entities::{DeviceKey, DeviceRevocation, UserToken};
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
+use crate::features::auth::infrastructure::repositories::device_revocation_repository::DeviceRevocationRepositoryImpl;
 use crate::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
 use uuid::Uuid;
 
 pub struct DeleteDeviceUseCase {
     token_repo: UserTokenRepositoryImpl,
+    device_key_repo: DeviceKeyRepositoryImpl,
+    device_revocation_repo: DeviceRevocationRepositoryImpl,
+    message_repo: PrivateMessageRepositoryImpl,
 }
 
 impl DeleteDeviceUseCase {
-    pub fn new(token_repo: UserTokenRepositoryImpl) -> Self {
-        Self { token_repo }
+    pub fn new(
+        token_repo: UserTokenRepositoryImpl,
+        device_key_repo: DeviceKeyRepositoryImpl,
+        device_revocation_repo: DeviceRevocationRepositoryImpl,
+        message_repo: PrivateMessageRepositoryImpl,
+    ) -> Self {
+        Self {
+            token_repo,
+            device_key_repo,
+            device_revocation_repo,
+            message_repo,
+        }
     }
 
+    // Returns the deleted token and the key of the device if it had registered one
     pub async fn execute(
         &self,
         user_id: Uuid,
         token_id: Uuid,
         transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
-    ) -> Result<(), AppError> {
+    ) -> Result<(UserToken, Option<DeviceKey>), AppError> {
         // Verify token exists
         let token = self
             .token_repo
             .get_by_user_and_token_id_with_executor(user_id, token_id, &mut **transaction)
             .await
-            .map_err(|_| AppError::DatabaseQuery)?;
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::DatabaseQuery)?;
 
-        if token.is_none() {
-            return Err(AppError::DatabaseQuery);
-        }
+        let device_key = self
+            .device_key_repo
+            .get_by_token_id_with_executor(token_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
 
-        // Delete token
+        // Delete token, its device key goes with it
         self.token_repo
             .delete_by_token_id_with_executor(token_id, &mut **transaction)
             .await
             .map_err(|_| AppError::DatabaseQuery)?;
 
-        Ok(())
+        // The revocation is kept for the device to learn about it, should it be offline
+        self.device_revocation_repo
+            .create_with_executor(
+                &DeviceRevocation {
+                    token_id,
+                    user_id,
+                    device_key_id: device_key.as_ref().map(|k| k.id),
+                    revoked_at: now(),
+                },
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        // A revoked device can not fetch the session keys encrypted for it anymore
+        if let Some(device_key) = &device_key {
+            self.message_repo
+                .remove_device_encrypted_session_keys_with_executor(
+                    device_key.id,
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::PrivateMessageSessionKeysUpdate)?;
+        }
+
+        Ok((token, device_key))
     }
 }
@@ -6,17 +6,25 @@ use crate::features::auth::domain::entities::UserToken;
 use crate::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::profile::domain::entities::{ParsedDeviceInfo, User};
+use crate::features::roles::application::use_cases::get_user_permissions::GetUserPermissionsUseCase;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
 
 pub struct GenerateTokensUseCase {
     token_repo: UserTokenRepositoryImpl,
     token_service: TokenService,
+    get_user_permissions_use_case: GetUserPermissionsUseCase,
 }
 
 impl GenerateTokensUseCase {
-    pub fn new(token_repo: UserTokenRepositoryImpl, token_service: TokenService) -> Self {
+    pub fn new(
+        token_repo: UserTokenRepositoryImpl,
+        token_service: TokenService,
+        role_grant_repo: RoleGrantRepositoryImpl,
+    ) -> Self {
         Self {
             token_repo,
             token_service,
+            get_user_permissions_use_case: GetUserPermissionsUseCase::new(role_grant_repo),
         }
     }
 
@@ -29,12 +37,20 @@ impl GenerateTokensUseCase {
     ) -> Result<(String, String), String> {
         let jti = Uuid::new_v4();
 
+        // Permissions are embedded in the tokens so routes don't have to query them
+        let permissions = self
+            .get_user_permissions_use_case
+            .execute(&user, transaction)
+            .await
+            .map_err(|e| format!("Failed to get user permissions: {:?}", e))?;
+
         // Generate tokens
         let (access_token, _) = self.token_service.generate_access_token(
             secret_key,
             jti,
             user.id,
             user.is_admin,
+            permissions.clone(),
             user.username.clone(),
         );
         let (refresh_token, refresh_token_expires_at) = self.token_service.generate_refresh_token(
@@ -42,6 +58,7 @@ impl GenerateTokensUseCase {
             jti,
             user.id,
             user.is_admin,
+            permissions,
             user.username.clone(),
         );
 
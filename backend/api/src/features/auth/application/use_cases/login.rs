@@ -6,6 +6,7 @@ use crate::features::auth::infrastructure::services::password_service::PasswordS
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::profile::domain::entities::{ParsedDeviceInfo, User};
 use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
 
 pub struct LoginUseCase {
     user_repo: UserRepositoryImpl,
@@ -18,9 +19,11 @@ impl LoginUseCase {
         user_repo: UserRepositoryImpl,
         token_repo: UserTokenRepositoryImpl,
         token_service: TokenService,
+        role_grant_repo: RoleGrantRepositoryImpl,
     ) -> Self {
         let password_service = PasswordService::new();
-        let generate_tokens_use_case = GenerateTokensUseCase::new(token_repo, token_service);
+        let generate_tokens_use_case =
+            GenerateTokensUseCase::new(token_repo, token_service, role_grant_repo);
         Self {
             user_repo,
             password_service,
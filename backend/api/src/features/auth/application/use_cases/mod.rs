@@ -11,6 +11,7 @@ pub mod recover_account_using_2fa;
 pub mod recover_account_using_password;
 pub mod recover_account_without_2fa;
 pub mod refresh_token;
+pub mod register_device_key;
 pub mod save_keys;
 pub mod save_recovery_code;
 pub mod set_fcm_token;
@@ -3,20 +3,36 @@
 use crate::core::helpers::mock_now::now;
 use uuid::Uuid;
 
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
+use crate::features::auth::infrastructure::repositories::device_revocation_repository::DeviceRevocationRepositoryImpl;
 use crate::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::profile::domain::entities::{ParsedDeviceInfo, User};
+use crate::features::roles::application::use_cases::get_user_permissions::GetUserPermissionsUseCase;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
 
 pub struct RefreshTokenUseCase {
     token_repo: UserTokenRepositoryImpl,
+    device_key_repo: DeviceKeyRepositoryImpl,
+    device_revocation_repo: DeviceRevocationRepositoryImpl,
     token_service: TokenService,
+    get_user_permissions_use_case: GetUserPermissionsUseCase,
 }
 
 impl RefreshTokenUseCase {
-    pub fn new(token_repo: UserTokenRepositoryImpl, token_service: TokenService) -> Self {
+    pub fn new(
+        token_repo: UserTokenRepositoryImpl,
+        device_key_repo: DeviceKeyRepositoryImpl,
+        device_revocation_repo: DeviceRevocationRepositoryImpl,
+        token_service: TokenService,
+        role_grant_repo: RoleGrantRepositoryImpl,
+    ) -> Self {
         Self {
             token_repo,
+            device_key_repo,
+            device_revocation_repo,
             token_service,
+            get_user_permissions_use_case: GetUserPermissionsUseCase::new(role_grant_repo),
         }
     }
 
@@ -76,31 +92,27 @@ impl RefreshTokenUseCase {
                 }
             }
             None => {
+                // A revoked device is told so, for it to wipe its keys
+                if self
+                    .device_revocation_repo
+                    .get_by_token_id_with_executor(claims.jti, &mut **transaction)
+                    .await
+                    .map_err(|e| format!("Failed to check device revocation: {}", e))?
+                    .is_some()
+                {
+                    return Err("DEVICE_REVOKED".to_string());
+                }
+
                 return Err("Invalid refresh token".to_string());
             }
         }
 
-        // Delete old token
-        self.token_repo
-            .delete_by_token_id_with_executor(claims.jti, &mut **transaction)
+        // Permissions are re-resolved so granted or revoked roles apply on refresh
+        let permissions = self
+            .get_user_permissions_use_case
+            .execute(&user, transaction)
             .await
-            .map_err(|e| format!("Failed to delete old token: {}", e))?;
-
-        // Publish token removal event (non-blocking)
-        let token_service_clone = self.token_service.clone();
-        let jti_clone = claims.jti;
-        let user_id_clone = claims.user_id;
-        tokio::spawn(async move {
-            if let Err(e) = token_service_clone
-                .publish_token_removed_event(jti_clone, user_id_clone)
-                .await
-            {
-                tracing::error!(
-                    "Failed to publish token removal event (non-critical): {}",
-                    e
-                );
-            }
-        });
+            .map_err(|e| format!("Failed to get user permissions: {:?}", e))?;
 
         // Generate new tokens
         let new_jti = Uuid::new_v4();
@@ -109,6 +121,7 @@ impl RefreshTokenUseCase {
             new_jti,
             user.id,
             user.is_admin,
+            permissions.clone(),
             user.username.clone(),
         );
         let (new_refresh_token, refresh_token_expires_at) =
@@ -117,6 +130,7 @@ impl RefreshTokenUseCase {
                 new_jti,
                 user.id,
                 user.is_admin,
+                permissions,
                 user.username.clone(),
             );
 
@@ -139,6 +153,35 @@ impl RefreshTokenUseCase {
             .await
             .map_err(|e| format!("Failed to save new token: {}", e))?;
 
+        // The device keeps its key, it must move before the old token is deleted since the
+        // key is deleted with it
+        self.device_key_repo
+            .update_token_id_with_executor(claims.jti, new_jti, &mut **transaction)
+            .await
+            .map_err(|e| format!("Failed to move device key: {}", e))?;
+
+        // Delete old token
+        self.token_repo
+            .delete_by_token_id_with_executor(claims.jti, &mut **transaction)
+            .await
+            .map_err(|e| format!("Failed to delete old token: {}", e))?;
+
+        // Publish token removal event (non-blocking)
+        let token_service_clone = self.token_service.clone();
+        let jti_clone = claims.jti;
+        let user_id_clone = claims.user_id;
+        tokio::spawn(async move {
+            if let Err(e) = token_service_clone
+                .publish_token_removed_event(jti_clone, user_id_clone)
+                .await
+            {
+                tracing::error!(
+                    "Failed to publish token removal event (non-critical): {}",
+                    e
+                );
+            }
+        });
+
         // Publish token updated event (non-blocking)
         let token_service_clone = self.token_service.clone();
         let new_token_clone = new_token.clone();
@@ -0,0 +1,84 @@
+// Register device key use case - saves the key pair of the current device
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::auth::domain::entities::DeviceKey;
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
+use crate::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use uuid::Uuid;
+
+pub struct RegisterDeviceKeyUseCase {
+    device_key_repo: DeviceKeyRepositoryImpl,
+    token_repo: UserTokenRepositoryImpl,
+    message_repo: PrivateMessageRepositoryImpl,
+}
+
+impl RegisterDeviceKeyUseCase {
+    pub fn new(
+        device_key_repo: DeviceKeyRepositoryImpl,
+        token_repo: UserTokenRepositoryImpl,
+        message_repo: PrivateMessageRepositoryImpl,
+    ) -> Self {
+        Self {
+            device_key_repo,
+            token_repo,
+            message_repo,
+        }
+    }
+
+    // A device registering a new key replaces its previous one, the session keys encrypted
+    // for the previous key are dropped with it
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        token_id: Uuid,
+        public_key: &str,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<DeviceKey, AppError> {
+        if public_key.trim().is_empty() {
+            return Err(AppError::DeviceKeyPublicKeyEmpty);
+        }
+
+        self.token_repo
+            .get_by_user_and_token_id_with_executor(user_id, token_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::UserTokenNotFound)?;
+
+        if let Some(previous_device_key) = self
+            .device_key_repo
+            .get_by_token_id_with_executor(token_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+        {
+            self.device_key_repo
+                .delete_by_token_id_with_executor(token_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+
+            self.message_repo
+                .remove_device_encrypted_session_keys_with_executor(
+                    previous_device_key.id,
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::PrivateMessageSessionKeysUpdate)?;
+        }
+
+        let device_key = DeviceKey {
+            id: Uuid::new_v4(),
+            user_id,
+            token_id,
+            public_key: public_key.to_string(),
+            created_at: now(),
+        };
+
+        self.device_key_repo
+            .create_with_executor(&device_key, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DeviceKeyCreation)?;
+
+        Ok(device_key)
+    }
+}
@@ -99,8 +99,19 @@ impl SignupUseCase {
             notifications_for_private_messages_enabled: true,
             notifications_for_public_message_liked_enabled: true,
             notifications_for_public_message_replies_enabled: true,
+            notifications_for_public_message_mentions_enabled: true,
+            public_message_languages: vec![],
             notifications_user_joined_your_challenge_enabled: true,
             notifications_user_duplicated_your_challenge_enabled: true,
+            bio: None,
+            avatar: None,
+            profile_shows_bio: false,
+            profile_shows_avatar: false,
+            profile_shows_habits: false,
+            profile_shows_finished_challenges: false,
+            profile_shows_badges: false,
+            open_to_meeting_people: false,
+            matching_uses_demographics: false,
         };
 
         // Save user
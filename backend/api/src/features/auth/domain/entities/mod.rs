@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 use uuid::Uuid;
 
+use crate::features::roles::domain::entities::permission::Permission;
+
 // Domain entities are pure business objects without infrastructure dependencies
 // They are moved from structs/models
 // Note: FromRow is kept for SQLx compatibility, but this is an infrastructure concern
@@ -35,6 +37,45 @@ pub struct UserToken {
     pub fcm_token: Option<String>,
 }
 
+// Key pair registered by a device, so that private messages can be encrypted for it alone.
+// Devices without one keep using the key pair of the account.
+#[derive(Debug, Deserialize, Serialize, Clone, FromRow)]
+pub struct DeviceKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_id: Uuid,
+    pub public_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DeviceKeyData {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub public_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl DeviceKey {
+    pub fn to_device_key_data(&self) -> DeviceKeyData {
+        DeviceKeyData {
+            id: self.id,
+            user_id: self.user_id,
+            public_key: self.public_key.to_owned(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+// Device revoked by its user. A device that was offline at the time is told when it comes back.
+#[derive(Debug, Deserialize, Serialize, Clone, FromRow)]
+pub struct DeviceRevocation {
+    pub token_id: Uuid,
+    pub user_id: Uuid,
+    pub device_key_id: Option<Uuid>,
+    pub revoked_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
     pub exp: i64,
@@ -42,4 +83,12 @@ pub struct Claims {
     pub user_id: Uuid,
     pub username: String,
     pub is_admin: bool,
+    #[serde(default)]
+    pub permissions: Vec<Permission>,
+}
+
+impl Claims {
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.is_admin || self.permissions.contains(&permission)
+    }
 }
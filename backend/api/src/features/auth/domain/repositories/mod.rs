@@ -3,7 +3,9 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::features::auth::domain::entities::{RecoveryCode, UserToken};
+use crate::features::auth::domain::entities::{
+    DeviceKey, DeviceRevocation, RecoveryCode, UserToken,
+};
 
 #[async_trait]
 pub trait RecoveryCodeRepository: Send + Sync {
@@ -32,3 +34,22 @@ pub trait UserTokenRepository: Send + Sync {
     async fn delete_expired(&self) -> Result<(), String>;
     async fn count(&self) -> Result<i64, String>;
 }
+
+#[async_trait]
+pub trait DeviceKeyRepository: Send + Sync {
+    async fn create(&self, device_key: &DeviceKey) -> Result<(), String>;
+    async fn get_by_token_id(&self, token_id: Uuid) -> Result<Option<DeviceKey>, String>;
+    async fn get_by_user_ids(&self, user_ids: Vec<Uuid>) -> Result<Vec<DeviceKey>, String>;
+    async fn get_user_ids_relying_on_device_keys(
+        &self,
+        user_ids: Vec<Uuid>,
+    ) -> Result<Vec<Uuid>, String>;
+    async fn update_token_id(&self, token_id: Uuid, new_token_id: Uuid) -> Result<(), String>;
+    async fn delete_by_token_id(&self, token_id: Uuid) -> Result<(), String>;
+}
+
+#[async_trait]
+pub trait DeviceRevocationRepository: Send + Sync {
+    async fn create(&self, device_revocation: &DeviceRevocation) -> Result<(), String>;
+    async fn get_by_token_id(&self, token_id: Uuid) -> Result<Option<DeviceRevocation>, String>;
+}
@@ -0,0 +1,201 @@
+// DeviceKeyRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::auth::domain::entities::DeviceKey;
+use crate::features::auth::domain::repositories::DeviceKeyRepository;
+
+pub struct DeviceKeyRepositoryImpl {
+    pool: PgPool,
+}
+
+impl DeviceKeyRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        device_key: &DeviceKey,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO device_keys (id, user_id, token_id, public_key, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            device_key.id,
+            device_key.user_id,
+            device_key.token_id,
+            device_key.public_key,
+            device_key.created_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_token_id_with_executor<'a, E>(
+        &self,
+        token_id: Uuid,
+        executor: E,
+    ) -> Result<Option<DeviceKey>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            DeviceKey,
+            r#"
+            SELECT *
+            FROM device_keys
+            WHERE token_id = $1
+            "#,
+            token_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn get_by_user_ids_with_executor<'a, E>(
+        &self,
+        user_ids: Vec<Uuid>,
+        executor: E,
+    ) -> Result<Vec<DeviceKey>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            DeviceKey,
+            r#"
+            SELECT *
+            FROM device_keys
+            WHERE user_id = ANY($1)
+            ORDER BY created_at
+            "#,
+            &user_ids
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Users with a registered device key, or who revoked a device that had one: the copy of
+    // their account key pair on that device must not open new messages
+    pub async fn get_user_ids_relying_on_device_keys_with_executor<'a, E>(
+        &self,
+        user_ids: Vec<Uuid>,
+        executor: E,
+    ) -> Result<Vec<Uuid>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_scalar!(
+            r#"
+            SELECT user_id AS "user_id!"
+            FROM device_keys
+            WHERE user_id = ANY($1)
+            UNION
+            SELECT user_id
+            FROM device_revocations
+            WHERE user_id = ANY($1)
+                AND device_key_id IS NOT NULL
+            "#,
+            &user_ids
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Refreshing the tokens of a device must not lose its key
+    pub async fn update_token_id_with_executor<'a, E>(
+        &self,
+        token_id: Uuid,
+        new_token_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE device_keys
+            SET token_id = $2
+            WHERE token_id = $1
+            "#,
+            token_id,
+            new_token_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_by_token_id_with_executor<'a, E>(
+        &self,
+        token_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE
+            FROM device_keys
+            WHERE token_id = $1
+            "#,
+            token_id
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl DeviceKeyRepository for DeviceKeyRepositoryImpl {
+    async fn create(&self, device_key: &DeviceKey) -> Result<(), String> {
+        self.create_with_executor(device_key, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_token_id(&self, token_id: Uuid) -> Result<Option<DeviceKey>, String> {
+        self.get_by_token_id_with_executor(token_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_user_ids(&self, user_ids: Vec<Uuid>) -> Result<Vec<DeviceKey>, String> {
+        self.get_by_user_ids_with_executor(user_ids, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_user_ids_relying_on_device_keys(
+        &self,
+        user_ids: Vec<Uuid>,
+    ) -> Result<Vec<Uuid>, String> {
+        self.get_user_ids_relying_on_device_keys_with_executor(user_ids, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn update_token_id(&self, token_id: Uuid, new_token_id: Uuid) -> Result<(), String> {
+        self.update_token_id_with_executor(token_id, new_token_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete_by_token_id(&self, token_id: Uuid) -> Result<(), String> {
+        self.delete_by_token_id_with_executor(token_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
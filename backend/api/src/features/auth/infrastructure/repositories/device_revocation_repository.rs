@@ -0,0 +1,80 @@
+// DeviceRevocationRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::auth::domain::entities::DeviceRevocation;
+use crate::features::auth::domain::repositories::DeviceRevocationRepository;
+
+pub struct DeviceRevocationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl DeviceRevocationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        device_revocation: &DeviceRevocation,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO device_revocations (token_id, user_id, device_key_id, revoked_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (token_id) DO NOTHING
+            "#,
+            device_revocation.token_id,
+            device_revocation.user_id,
+            device_revocation.device_key_id,
+            device_revocation.revoked_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_token_id_with_executor<'a, E>(
+        &self,
+        token_id: Uuid,
+        executor: E,
+    ) -> Result<Option<DeviceRevocation>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            DeviceRevocation,
+            r#"
+            SELECT *
+            FROM device_revocations
+            WHERE token_id = $1
+            "#,
+            token_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl DeviceRevocationRepository for DeviceRevocationRepositoryImpl {
+    async fn create(&self, device_revocation: &DeviceRevocation) -> Result<(), String> {
+        self.create_with_executor(device_revocation, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_token_id(&self, token_id: Uuid) -> Result<Option<DeviceRevocation>, String> {
+        self.get_by_token_id_with_executor(token_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
@@ -1,4 +1,6 @@
 // Auth infrastructure repository implementations
 
+pub mod device_key_repository;
+pub mod device_revocation_repository;
 pub mod recovery_code_repository;
 pub mod user_token_repository;
@@ -12,6 +12,7 @@ use crate::core::helpers::mock_now::now;
 use crate::core::structs::redis_messages::UserTokenUpdatedEvent;
 use crate::features::auth::domain::entities::{Claims, UserToken};
 use crate::features::profile::domain::entities::User;
+use crate::features::roles::domain::entities::permission::Permission;
 
 #[derive(Clone)]
 pub struct TokenService {
@@ -35,6 +36,7 @@ impl TokenService {
         jti: Uuid,
         user_id: Uuid,
         is_admin: bool,
+        permissions: Vec<Permission>,
         username: String,
     ) -> (String, DateTime<Utc>) {
         let access_token_expires_at = now()
@@ -46,6 +48,7 @@ impl TokenService {
             jti,
             user_id,
             is_admin,
+            permissions,
             username,
         };
 
@@ -65,6 +68,7 @@ impl TokenService {
         jti: Uuid,
         user_id: Uuid,
         is_admin: bool,
+        permissions: Vec<Permission>,
         username: String,
     ) -> (String, DateTime<Utc>) {
         let refresh_token_expires_at = now()
@@ -76,6 +80,7 @@ impl TokenService {
             jti,
             user_id,
             is_admin,
+            permissions,
             username,
         };
 
@@ -138,6 +143,32 @@ impl TokenService {
         }
     }
 
+    pub async fn publish_device_revoked_event(&self, token: &UserToken) -> Result<(), String> {
+        use crate::core::structs::redis_messages::DeviceRevokedEvent;
+        match self
+            .redis_client
+            .get_ref()
+            .get_multiplexed_async_connection()
+            .await
+        {
+            Ok(mut con) => {
+                let result: Result<(), redis::RedisError> = con
+                    .publish(
+                        "device_revoked",
+                        json!(DeviceRevokedEvent {
+                            user_id: token.user_id,
+                            token_id: token.token_id,
+                            user_token_id: token.id,
+                        })
+                        .to_string(),
+                    )
+                    .await;
+                result.map_err(|e| format!("Redis publish error: {}", e))
+            }
+            Err(e) => Err(format!("Redis connection error: {}", e)),
+        }
+    }
+
     pub fn validate_token(&self, token: &str, secret_key: &[u8]) -> Result<Claims, String> {
         let validation = Validation::default();
         let token_data =
@@ -3,9 +3,12 @@
 use crate::core::constants::errors::AppError;
 use crate::features::auth::application::use_cases::delete_device::DeleteDeviceUseCase;
 use crate::features::auth::domain::entities::Claims;
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
+use crate::features::auth::infrastructure::repositories::device_revocation_repository::DeviceRevocationRepositoryImpl;
 use crate::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::auth::structs::models::TokenCache;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
 use crate::features::profile::application::dto::requests::DeleteDeviceParams;
 use crate::features::profile::application::dto::responses::DeviceDeleteResponse;
 use actix_web::web::{Data, Path, ReqData};
@@ -33,8 +36,16 @@ pub async fn delete_device(
 
     // Create repository and use case
     let pool_clone = pool.get_ref().clone();
-    let token_repo = UserTokenRepositoryImpl::new(pool_clone);
-    let delete_device_use_case = DeleteDeviceUseCase::new(token_repo);
+    let token_repo = UserTokenRepositoryImpl::new(pool_clone.clone());
+    let device_key_repo = DeviceKeyRepositoryImpl::new(pool_clone.clone());
+    let device_revocation_repo = DeviceRevocationRepositoryImpl::new(pool_clone.clone());
+    let message_repo = PrivateMessageRepositoryImpl::new(pool_clone);
+    let delete_device_use_case = DeleteDeviceUseCase::new(
+        token_repo,
+        device_key_repo,
+        device_revocation_repo,
+        message_repo,
+    );
 
     // Execute use case
     let result = delete_device_use_case
@@ -51,9 +62,14 @@ pub async fn delete_device(
     }
 
     match result {
-        Ok(_) => {
-            // Publish Redis event
+        Ok((token, _)) => {
+            // Publish Redis events, the revoked device is told to wipe its keys before its
+            // sockets are forgotten
             let token_service = TokenService::new(redis_client);
+            if let Err(e) = token_service.publish_device_revoked_event(&token).await {
+                error!("Error publishing device revoked event: {}", e);
+            }
+
             if let Err(e) = token_service
                 .publish_token_removed_event(params.token_id, claims.user_id)
                 .await
@@ -11,6 +11,7 @@ use crate::features::auth::infrastructure::repositories::user_token_repository::
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::profile::helpers::device_info::get_user_agent;
 use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
 use actix_web::web::{Data, Json};
 use actix_web::{post, HttpRequest, HttpResponse, Responder};
 use redis::Client;
@@ -39,7 +40,8 @@ pub async fn log_user_in(
     let user_repo = UserRepositoryImpl::new(pool_clone.clone());
     let token_repo = UserTokenRepositoryImpl::new(pool_clone.clone());
     let token_service = TokenService::new(redis_client.clone());
-    let login_use_case = LoginUseCase::new(user_repo, token_repo, token_service);
+    let role_grant_repo = RoleGrantRepositoryImpl::new(pool_clone.clone());
+    let login_use_case = LoginUseCase::new(user_repo, token_repo, token_service, role_grant_repo);
 
     // Get device info
     let parsed_device_info = get_user_agent(req).await;
@@ -10,6 +10,7 @@ use crate::features::auth::infrastructure::repositories::user_token_repository::
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::profile::helpers::device_info::get_user_agent;
 use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
 use actix_web::web::{Data, Json};
 use actix_web::{post, HttpRequest, HttpResponse, Responder};
 use redis::Client;
@@ -80,9 +81,13 @@ pub async fn recover_account_without_2fa_enabled(
     let pool_clone = pool.get_ref().clone();
     let token_repo_for_tokens = UserTokenRepositoryImpl::new(pool_clone.clone());
     let token_service_for_tokens = TokenService::new(redis_client.clone());
+    let role_grant_repo = RoleGrantRepositoryImpl::new(pool_clone.clone());
     let parsed_device_info = get_user_agent(req).await;
-    let generate_tokens_use_case =
-        GenerateTokensUseCase::new(token_repo_for_tokens, token_service_for_tokens);
+    let generate_tokens_use_case = GenerateTokensUseCase::new(
+        token_repo_for_tokens,
+        token_service_for_tokens,
+        role_grant_repo,
+    );
     let (access_token, refresh_token) = match generate_tokens_use_case
         .execute(
             secret.as_bytes(),
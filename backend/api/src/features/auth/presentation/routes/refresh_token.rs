@@ -5,10 +5,13 @@ use crate::core::structs::responses::GenericResponse;
 use crate::features::auth::application::dto::requests::RefreshTokenRequest;
 use crate::features::auth::application::dto::responses::RefreshTokenResponse;
 use crate::features::auth::application::use_cases::refresh_token::RefreshTokenUseCase;
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
+use crate::features::auth::infrastructure::repositories::device_revocation_repository::DeviceRevocationRepositoryImpl;
 use crate::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::profile::helpers::device_info::get_user_agent;
 use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
 use actix_web::web::{Data, Json};
 use actix_web::{post, HttpRequest, HttpResponse, Responder};
 use redis::Client;
@@ -51,7 +54,16 @@ pub async fn refresh_token(
     };
 
     // Create use case after validation
-    let refresh_token_use_case = RefreshTokenUseCase::new(token_repo, token_service);
+    let role_grant_repo = RoleGrantRepositoryImpl::new(pool_clone.clone());
+    let device_key_repo = DeviceKeyRepositoryImpl::new(pool_clone.clone());
+    let device_revocation_repo = DeviceRevocationRepositoryImpl::new(pool_clone.clone());
+    let refresh_token_use_case = RefreshTokenUseCase::new(
+        token_repo,
+        device_key_repo,
+        device_revocation_repo,
+        token_service,
+        role_grant_repo,
+    );
 
     // Get user
     let user = match user_repo
@@ -109,6 +121,9 @@ pub async fn refresh_token(
                     message: "Refresh token expired".to_string(),
                 });
             }
+            if e == "DEVICE_REVOKED" {
+                return HttpResponse::Unauthorized().json(AppError::DeviceRevoked.to_response());
+            }
             HttpResponse::Unauthorized().json(AppError::InvalidRefreshToken.to_response())
         }
     }
@@ -0,0 +1,75 @@
+// Register device key route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::application::dto::requests::RegisterDeviceKeyRequest;
+use crate::features::auth::application::dto::responses::DeviceKeyResponse;
+use crate::features::auth::application::use_cases::register_device_key::RegisterDeviceKeyUseCase;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
+use crate::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use actix_web::web::{Data, Json, ReqData};
+use actix_web::{post, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[post("/keys")]
+pub async fn register_device_key(
+    body: Json<RegisterDeviceKeyRequest>,
+    pool: Data<PgPool>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = RegisterDeviceKeyUseCase::new(
+        DeviceKeyRepositoryImpl::new(pool_clone.clone()),
+        UserTokenRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageRepositoryImpl::new(pool_clone),
+    );
+
+    // The key belongs to the device making the request
+    let device_key = match use_case
+        .execute(
+            request_claims.user_id,
+            request_claims.jti,
+            &body.public_key,
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(k) => k,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::DeviceKeyPublicKeyEmpty => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                AppError::UserTokenNotFound => HttpResponse::NotFound().json(e.to_response()),
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(DeviceKeyResponse {
+        code: "DEVICE_KEY_REGISTERED".to_string(),
+        device_key: Some(device_key.to_device_key_data()),
+    })
+}
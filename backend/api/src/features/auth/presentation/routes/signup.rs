@@ -9,27 +9,40 @@ use crate::features::auth::application::dto::requests::UserRegisterRequest;
 use crate::features::auth::application::dto::responses::UserSignupResponse;
 use crate::features::auth::application::use_cases::generate_tokens::GenerateTokensUseCase;
 use crate::features::auth::application::use_cases::signup::SignupUseCase;
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
 use crate::features::auth::infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl;
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::auth::infrastructure::services::username_service::UsernameService;
+use crate::features::content_filters::application::use_cases::apply_content_filter::{
+    content_filter_locales, ApplyContentFilterUseCase,
+};
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
+use crate::features::content_filters::infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl;
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
 use crate::features::private_discussions::{
     application::use_cases::{
         create_private_discussion::CreatePrivateDiscussionUseCase,
         create_private_message::CreatePrivateMessageUseCase,
     },
     domain::entities::{
-        private_discussion::PrivateDiscussion,
+        private_discussion::{
+            PrivateDiscussion, PrivateDiscussionRequestStatus, PrivateMessageRetention,
+        },
         private_discussion_participation::PrivateDiscussionParticipation,
         private_message::PrivateMessage,
     },
     infrastructure::repositories::{
         private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl,
         private_discussion_repository::PrivateDiscussionRepositoryImpl,
+        private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl,
+        private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl,
         private_message_repository::PrivateMessageRepositoryImpl,
     },
 };
 use crate::features::profile::helpers::device_info::get_user_agent;
 use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::user_suspension_repository::UserSuspensionRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
 use actix_web::web::{Data, Json};
 use actix_web::{post, HttpRequest, HttpResponse, Responder};
 use fluent::FluentArgs;
@@ -119,6 +132,30 @@ pub async fn register_user(
         }
     };
 
+    // Usernames go through the content filter too, flags point to the new user
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool_clone.clone()),
+        ContentFilterFlagRepositoryImpl::new(pool_clone.clone()),
+    );
+    if let Err(e) = content_filter
+        .execute(
+            &body.username,
+            &content_filter_locales(Some(&body.locale), None),
+            ContentFilterTarget::Username,
+            new_user.id,
+            &mut transaction,
+        )
+        .await
+    {
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::ContentRejectedByFilter => HttpResponse::Unauthorized().json(e.to_response()),
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
     // Update user with keys from request
     let mut user_with_keys = new_user.clone();
     user_with_keys.public_key = Some(body.public_key.clone());
@@ -140,7 +177,9 @@ pub async fn register_user(
 
     // Generate tokens
     let parsed_device_info = get_user_agent(req).await;
-    let generate_tokens_use_case = GenerateTokensUseCase::new(token_repo, token_service);
+    let role_grant_repo = RoleGrantRepositoryImpl::new(pool_clone.clone());
+    let generate_tokens_use_case =
+        GenerateTokensUseCase::new(token_repo, token_service, role_grant_repo);
     let (access_token, refresh_token) = match generate_tokens_use_case
         .execute(
             secret.as_bytes(),
@@ -187,6 +226,11 @@ pub async fn register_user(
     let discussion = PrivateDiscussion {
         id: Uuid::new_v4(),
         created_at: now(),
+        name: None,
+        is_group: false,
+        message_retention: PrivateMessageRetention::Off.to_string(),
+        proposed_message_retention: None,
+        message_retention_proposed_by: None,
     };
 
     // Create participations
@@ -197,6 +241,12 @@ pub async fn register_user(
         color: "blue".to_string(),
         created_at: now(),
         has_blocked: false,
+        is_admin: false,
+        muted_until: None,
+        archived_at: None,
+        pinned_at: None,
+        request_status: PrivateDiscussionRequestStatus::Accepted.to_string(),
+        requested_by: None,
     };
 
     let discussion_participation_for_reallystick_user = PrivateDiscussionParticipation {
@@ -206,6 +256,12 @@ pub async fn register_user(
         color: "blue".to_string(),
         created_at: now(),
         has_blocked: false,
+        is_admin: false,
+        muted_until: None,
+        archived_at: None,
+        pinned_at: None,
+        request_status: PrivateDiscussionRequestStatus::Accepted.to_string(),
+        requested_by: None,
     };
 
     // Create repositories and use case for private discussion
@@ -235,7 +291,7 @@ pub async fn register_user(
     let mut args = FluentArgs::new();
     args.set("username", user_with_keys.username.clone());
 
-    let private_message = PrivateMessage {
+    let mut private_message = PrivateMessage {
         id: Uuid::new_v4(),
         discussion_id: discussion.id,
         creator: reallystick_user.id,
@@ -250,16 +306,35 @@ pub async fn register_user(
         recipient_encrypted_session_key: "NOT_ENCRYPTED".to_string(),
         deleted: false,
         seen: false,
+        encrypted_session_keys: serde_json::json!({}),
+        device_encrypted_session_keys: serde_json::json!({}),
+        delivered_at: None,
+        seen_at: None,
+        expires_at: None,
+        attachment_ids: Vec::new(),
     };
 
     // Create repositories and use case for private message
     let pool_clone3 = pool.get_ref().clone();
     let message_repo = PrivateMessageRepositoryImpl::new(pool_clone3.clone());
     let discussion_repo2 = PrivateDiscussionRepositoryImpl::new(pool_clone3.clone());
+    let participation_repo2 =
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone3.clone());
+
+    let device_key_repo = DeviceKeyRepositoryImpl::new(pool_clone3.clone());
+    let attachment_repo = PrivateMessageAttachmentRepositoryImpl::new(pool_clone3.clone());
 
-    let create_message_use_case = CreatePrivateMessageUseCase::new(message_repo, discussion_repo2);
+    let create_message_use_case = CreatePrivateMessageUseCase::new(
+        message_repo,
+        discussion_repo2,
+        participation_repo2,
+        device_key_repo,
+        attachment_repo,
+        UserSuspensionRepositoryImpl::new(pool_clone3.clone()),
+        PrivateMessageReceiptRepositoryImpl::new(pool_clone3.clone()),
+    );
     if let Err(e) = create_message_use_case
-        .execute(&private_message, &mut transaction)
+        .execute(&mut private_message, &mut transaction)
         .await
     {
         error!("Error: {:?}", e);
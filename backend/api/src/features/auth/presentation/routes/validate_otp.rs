@@ -10,6 +10,7 @@ use crate::features::auth::infrastructure::repositories::user_token_repository::
 use crate::features::auth::infrastructure::services::token_service::TokenService;
 use crate::features::profile::helpers::device_info::get_user_agent;
 use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
 use actix_web::web::{Data, Json};
 use actix_web::{post, HttpRequest, HttpResponse, Responder};
 use redis::Client;
@@ -83,7 +84,9 @@ pub async fn validate(
 
     // Generate tokens
     let parsed_device_info = get_user_agent(req).await;
-    let generate_tokens_use_case = GenerateTokensUseCase::new(token_repo, token_service);
+    let role_grant_repo = RoleGrantRepositoryImpl::new(pool_clone.clone());
+    let generate_tokens_use_case =
+        GenerateTokensUseCase::new(token_repo, token_service, role_grant_repo);
     let (access_token, refresh_token) = match generate_tokens_use_case
         .execute(
             secret.as_bytes(),
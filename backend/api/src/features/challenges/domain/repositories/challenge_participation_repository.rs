@@ -25,6 +25,7 @@ pub trait ChallengeParticipationRepository: Send + Sync {
     ) -> Result<Option<ChallengeParticipation>, String>;
     async fn delete(&self, participation_id: Uuid) -> Result<(), String>;
     async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
+    async fn share_a_challenge(&self, user1_id: Uuid, user2_id: Uuid) -> Result<bool, String>;
     async fn count(&self) -> Result<i64, String>;
     async fn get_participants_to_send_reminder_notification(
         &self,
@@ -205,6 +205,40 @@ impl ChallengeParticipationRepositoryImpl {
         .await
     }
 
+    // Both users take part in the same challenge, or one of them joined a challenge of the other
+    pub async fn share_a_challenge_with_executor<'a, E>(
+        &self,
+        user1_id: Uuid,
+        user2_id: Uuid,
+        executor: E,
+    ) -> Result<bool, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM challenge_participations cp1
+                JOIN challenge_participations cp2 ON cp1.challenge_id = cp2.challenge_id
+                WHERE cp1.user_id = $1 AND cp2.user_id = $2
+            ) OR EXISTS (
+                SELECT 1
+                FROM challenges c
+                JOIN challenge_participations cp ON cp.challenge_id = c.id
+                WHERE (c.creator = $1 AND cp.user_id = $2)
+                    OR (c.creator = $2 AND cp.user_id = $1)
+            ) AS "shared!"
+            "#,
+            user1_id,
+            user2_id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.shared)
+    }
+
     pub async fn count_with_executor<'a, E>(&self, executor: E) -> Result<i64, sqlx::Error>
     where
         E: Executor<'a, Database = Postgres>,
@@ -315,6 +349,12 @@ impl ChallengeParticipationRepository for ChallengeParticipationRepositoryImpl {
         Ok(())
     }
 
+    async fn share_a_challenge(&self, user1_id: Uuid, user2_id: Uuid) -> Result<bool, String> {
+        self.share_a_challenge_with_executor(user1_id, user2_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
     async fn count(&self) -> Result<i64, String> {
         self.count_with_executor(&self.pool)
             .await
@@ -9,6 +9,10 @@ use crate::features::challenges::domain::entities::challenge::{
     Challenge, CHALLENGE_DESCRIPTION_MAX_LENGTH,
 };
 use crate::features::challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl;
+use crate::features::content_filters::application::use_cases::apply_content_filter::ApplyContentFilterUseCase;
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
+use crate::features::content_filters::infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl;
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
 use actix_web::web::{Data, Json, ReqData};
 use actix_web::{post, HttpResponse, Responder};
 use chrono::Utc;
@@ -44,11 +48,54 @@ pub async fn create_challenge(
             .json(AppError::ChallengeDescriptionTooLong.to_response());
     }
 
+    let challenge_id = Uuid::new_v4();
+
+    // Names and descriptions go through the content filter rules of their locale
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool.get_ref().clone()),
+        ContentFilterFlagRepositoryImpl::new(pool.get_ref().clone()),
+    );
+    let filtered_texts = async {
+        let name = content_filter
+            .execute_for_translations(
+                &body.name,
+                ContentFilterTarget::Challenge,
+                challenge_id,
+                &mut transaction,
+            )
+            .await?;
+        let description = content_filter
+            .execute_for_translations(
+                &body.description,
+                ContentFilterTarget::Challenge,
+                challenge_id,
+                &mut transaction,
+            )
+            .await?;
+        Ok::<_, AppError>((name, description))
+    }
+    .await;
+    let (name, description) = match filtered_texts {
+        Ok(texts) => texts,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::ContentRejectedByFilter => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
     // Create challenge entity
     let challenge = Challenge {
-        id: Uuid::new_v4(),
-        name: json!(body.name).to_string(),
-        description: json!(body.description).to_string(),
+        id: challenge_id,
+        name: json!(name).to_string(),
+        description: json!(description).to_string(),
         start_date: body.start_date,
         icon: body.icon.clone(),
         created_at: Utc::now(),
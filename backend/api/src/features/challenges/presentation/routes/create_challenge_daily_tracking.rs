@@ -18,6 +18,7 @@ use crate::{
         habits::infrastructure::repositories::{
             habit_repository::HabitRepositoryImpl, unit_repository::UnitRepositoryImpl,
         },
+        roles::domain::entities::permission::Permission,
     },
 };
 use actix_web::{
@@ -59,7 +60,9 @@ pub async fn create_challenge_daily_tracking(
     {
         Ok(r) => match r {
             Some(challenge) => {
-                if !request_claims.is_admin && challenge.creator != request_claims.user_id {
+                if !request_claims.has_permission(Permission::ManageChallenges)
+                    && challenge.creator != request_claims.user_id
+                {
                     return HttpResponse::Forbidden()
                         .json(AppError::InvalidChallengeCreator.to_response());
                 }
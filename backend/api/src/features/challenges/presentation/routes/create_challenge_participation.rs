@@ -15,7 +15,9 @@ use crate::{
                 challenge_repository::ChallengeRepositoryImpl,
             },
         },
-        notifications::infrastructure::services::notification_service::NotificationService,
+        notifications::infrastructure::services::notification_service::{
+            NotificationContent, NotificationService,
+        },
         profile::domain::entities::UserPublicDataCache,
     },
 };
@@ -129,21 +131,24 @@ pub async fn create_challenge_participation(
 
             notification_service
                 .generate_notification(
-                    &mut *transaction,
+                    &mut transaction,
+                    Some(request_claims.user_id),
                     challenge.creator,
-                    &translator.translate(
-                        &creator.locale,
-                        "user-joined-your-challenge-title",
-                        None,
-                    ),
-                    &translator.translate(
-                        &creator.locale,
-                        "user-joined-your-challenge-body",
-                        Some(args),
-                    ),
+                    NotificationContent {
+                        title: &translator.translate(
+                            &creator.locale,
+                            "user-joined-your-challenge-title",
+                            None,
+                        ),
+                        body: &translator.translate(
+                            &creator.locale,
+                            "user-joined-your-challenge-body",
+                            Some(args),
+                        ),
+                        notification_type: "challenge_joined",
+                        url,
+                    },
                     redis_client,
-                    "challenge_joined",
-                    url,
                 )
                 .await;
         }
@@ -7,6 +7,7 @@ use crate::features::challenges::application::dto::responses::challenge::Challen
 use crate::features::challenges::application::use_cases::delete_challenge::DeleteChallengeUseCase;
 use crate::features::challenges::application::use_cases::get_challenge::GetChallengeUseCase;
 use crate::features::challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
 use actix_web::web::{Data, Path, ReqData};
 use actix_web::{delete, HttpResponse, Responder};
 use sqlx::PgPool;
@@ -40,7 +41,9 @@ pub async fn delete_challenge(
     {
         Ok(Some(c)) => {
             // Check authorization
-            if !request_claims.is_admin && c.creator != request_claims.user_id {
+            if !request_claims.has_permission(Permission::ManageChallenges)
+                && c.creator != request_claims.user_id
+            {
                 if let Err(e) = transaction.rollback().await {
                     error!("Error rolling back: {}", e);
                 }
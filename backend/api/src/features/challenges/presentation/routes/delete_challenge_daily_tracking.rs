@@ -12,6 +12,7 @@ use crate::{
                 challenge_repository::ChallengeRepositoryImpl,
             },
         },
+        roles::domain::entities::permission::Permission,
     },
 };
 use actix_web::{
@@ -64,7 +65,9 @@ pub async fn delete_challenge_daily_tracking(
     {
         Ok(r) => match r {
             Some(challenge) => {
-                if !request_claims.is_admin && challenge.creator != request_claims.user_id {
+                if !request_claims.has_permission(Permission::ManageChallenges)
+                    && challenge.creator != request_claims.user_id
+                {
                     return HttpResponse::Forbidden()
                         .json(AppError::InvalidChallengeCreator.to_response());
                 }
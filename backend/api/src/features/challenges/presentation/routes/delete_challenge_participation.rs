@@ -9,6 +9,7 @@ use crate::{
             },
             infrastructure::repositories::challenge_participation_repository::ChallengeParticipationRepositoryImpl,
         },
+        roles::domain::entities::permission::Permission,
     },
 };
 use actix_web::{
@@ -42,7 +43,7 @@ pub async fn delete_challenge_participation(
     {
         Ok(r) => match r {
             Some(challenge_participation) => {
-                if !request_claims.is_admin
+                if !request_claims.has_permission(Permission::ManageChallenges)
                     && challenge_participation.user_id != request_claims.user_id
                 {
                     return HttpResponse::Forbidden()
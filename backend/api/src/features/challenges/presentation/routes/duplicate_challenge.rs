@@ -20,7 +20,9 @@ use crate::{
                 challenge_repository::ChallengeRepositoryImpl,
             },
         },
-        notifications::infrastructure::services::notification_service::NotificationService,
+        notifications::infrastructure::services::notification_service::{
+            NotificationContent, NotificationService,
+        },
         profile::domain::entities::UserPublicDataCache,
     },
 };
@@ -151,21 +153,24 @@ pub async fn duplicate_challenge(
 
             notification_service
                 .generate_notification(
-                    &mut *transaction,
+                    &mut transaction,
+                    Some(request_claims.user_id),
                     challenge_to_duplicate.creator,
-                    &translator.translate(
-                        &creator.locale,
-                        "user-duplicated-your-challenge-title",
-                        None,
-                    ),
-                    &translator.translate(
-                        &creator.locale,
-                        "user-duplicated-your-challenge-body",
-                        Some(args),
-                    ),
+                    NotificationContent {
+                        title: &translator.translate(
+                            &creator.locale,
+                            "user-duplicated-your-challenge-title",
+                            None,
+                        ),
+                        body: &translator.translate(
+                            &creator.locale,
+                            "user-duplicated-your-challenge-body",
+                            Some(args),
+                        ),
+                        notification_type: "challenge_duplicated",
+                        url,
+                    },
                     redis_client,
-                    "challenge_duplicated",
-                    url,
                 )
                 .await;
         }
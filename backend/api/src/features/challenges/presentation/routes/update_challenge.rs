@@ -10,6 +10,11 @@ use crate::features::challenges::application::use_cases::get_challenge::GetChall
 use crate::features::challenges::application::use_cases::update_challenge::UpdateChallengeUseCase;
 use crate::features::challenges::domain::entities::challenge::CHALLENGE_DESCRIPTION_MAX_LENGTH;
 use crate::features::challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl;
+use crate::features::content_filters::application::use_cases::apply_content_filter::ApplyContentFilterUseCase;
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
+use crate::features::content_filters::infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl;
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
 use actix_web::web::{Data, Json, Path, ReqData};
 use actix_web::{put, HttpResponse, Responder};
 use serde_json::json;
@@ -47,7 +52,9 @@ pub async fn update_challenge(
     {
         Ok(Some(c)) => {
             // Check authorization
-            if !request_claims.is_admin && c.creator != request_claims.user_id {
+            if !request_claims.has_permission(Permission::ManageChallenges)
+                && c.creator != request_claims.user_id
+            {
                 if let Err(e) = transaction.rollback().await {
                     error!("Error rolling back: {}", e);
                 }
@@ -84,9 +91,50 @@ pub async fn update_challenge(
             .json(AppError::ChallengeDescriptionTooLong.to_response());
     }
 
+    // Names and descriptions go through the content filter rules of their locale
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool.get_ref().clone()),
+        ContentFilterFlagRepositoryImpl::new(pool.get_ref().clone()),
+    );
+    let filtered_texts = async {
+        let name = content_filter
+            .execute_for_translations(
+                &body.name,
+                ContentFilterTarget::Challenge,
+                challenge.id,
+                &mut transaction,
+            )
+            .await?;
+        let description = content_filter
+            .execute_for_translations(
+                &body.description,
+                ContentFilterTarget::Challenge,
+                challenge.id,
+                &mut transaction,
+            )
+            .await?;
+        Ok::<_, AppError>((name, description))
+    }
+    .await;
+    let (name, description) = match filtered_texts {
+        Ok(texts) => texts,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::ContentRejectedByFilter => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
     // Update challenge fields
-    challenge.name = json!(body.name).to_string();
-    challenge.description = json!(body.description).to_string();
+    challenge.name = json!(name).to_string();
+    challenge.description = json!(description).to_string();
     challenge.icon = body.icon.clone();
     challenge.start_date = body.start_date;
 
@@ -11,6 +11,7 @@ use crate::{
             },
             infrastructure::repositories::challenge_participation_repository::ChallengeParticipationRepositoryImpl,
         },
+        roles::domain::entities::permission::Permission,
     },
 };
 use actix_web::{
@@ -46,7 +47,7 @@ pub async fn update_challenge_participation(
     let mut challenge_participation = match get_challenge_participation_result {
         Ok(r) => match r {
             Some(challenge_participation) => {
-                if !request_claims.is_admin
+                if !request_claims.has_permission(Permission::ManageChallenges)
                     && challenge_participation.user_id != request_claims.user_id
                 {
                     return HttpResponse::Forbidden()
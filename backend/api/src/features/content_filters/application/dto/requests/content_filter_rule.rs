@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::features::content_filters::domain::entities::content_filter_rule::{
+    ContentFilterAction, ContentFilterRuleKind,
+};
+
+#[derive(Deserialize)]
+pub struct ContentFilterRuleParams {
+    pub rule_id: Uuid,
+}
+
+#[derive(Deserialize)]
+pub struct ContentFilterFlagParams {
+    pub flag_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ContentFilterRuleCreateRequest {
+    pub locale: Option<String>,
+    pub kind: ContentFilterRuleKind,
+    pub pattern: String,
+    pub action: ContentFilterAction,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ContentFilterRuleUpdateRequest {
+    pub locale: Option<String>,
+    pub kind: ContentFilterRuleKind,
+    pub pattern: String,
+    pub action: ContentFilterAction,
+}
@@ -0,0 +1,3 @@
+// Content filters request DTOs
+
+pub mod content_filter_rule;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterFlagData;
+
+#[derive(Serialize, Deserialize)]
+pub struct ContentFilterFlagResponse {
+    pub code: String,
+    pub flag: Option<ContentFilterFlagData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ContentFilterFlagsResponse {
+    pub code: String,
+    pub flags: Vec<ContentFilterFlagData>,
+}
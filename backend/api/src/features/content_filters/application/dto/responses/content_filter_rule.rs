@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::content_filters::domain::entities::content_filter_rule::ContentFilterRuleData;
+
+#[derive(Serialize, Deserialize)]
+pub struct ContentFilterRuleResponse {
+    pub code: String,
+    pub rule: Option<ContentFilterRuleData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ContentFilterRulesResponse {
+    pub code: String,
+    pub rules: Vec<ContentFilterRuleData>,
+}
@@ -0,0 +1,4 @@
+// Content filters response DTOs
+
+pub mod content_filter_flag;
+pub mod content_filter_rule;
@@ -0,0 +1,3 @@
+// Application layer - use cases and DTOs
+pub mod dto;
+pub mod use_cases;
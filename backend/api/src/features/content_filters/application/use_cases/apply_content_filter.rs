@@ -0,0 +1,127 @@
+// Apply content filter use case
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::content_filters::domain::entities::content_filter_flag::{
+    ContentFilterFlag, ContentFilterTarget,
+};
+use crate::features::content_filters::domain::repositories::content_filter_rule_repository::ContentFilterRuleRepository;
+use crate::features::content_filters::infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl;
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
+use crate::features::content_filters::infrastructure::services::content_filter_service::ContentFilterService;
+
+pub struct ApplyContentFilterUseCase {
+    rule_repo: ContentFilterRuleRepositoryImpl,
+    flag_repo: ContentFilterFlagRepositoryImpl,
+}
+
+impl ApplyContentFilterUseCase {
+    pub fn new(
+        rule_repo: ContentFilterRuleRepositoryImpl,
+        flag_repo: ContentFilterFlagRepositoryImpl,
+    ) -> Self {
+        Self {
+            rule_repo,
+            flag_repo,
+        }
+    }
+
+    // Returns the content to store, masked if needed
+    pub async fn execute(
+        &self,
+        content: &str,
+        locales: &[String],
+        target: ContentFilterTarget,
+        target_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<String, AppError> {
+        let rules = self
+            .rule_repo
+            .get_for_locales_with_executor(locales, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let outcome = ContentFilterService::new().apply(&rules, content, target.can_be_masked());
+
+        // Counted outside of the transaction so that rejected contents are counted too
+        if !outcome.matched_rule_ids.is_empty() {
+            self.rule_repo
+                .increment_hit_counts(&outcome.matched_rule_ids)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+        }
+
+        if outcome.rejected {
+            return Err(AppError::ContentRejectedByFilter);
+        }
+
+        for rule_id in outcome.flagging_rule_ids {
+            self.flag_repo
+                .create_with_executor(
+                    &ContentFilterFlag {
+                        id: Uuid::new_v4(),
+                        rule_id: Some(rule_id),
+                        target: target.to_string(),
+                        target_id,
+                        content: outcome.content.to_owned(),
+                        created_at: now(),
+                    },
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::ContentFilterFlagCreation)?;
+        }
+
+        Ok(outcome.content)
+    }
+
+    // Filters texts translated by locale, like habit and challenge names, each with the rules of its locale
+    pub async fn execute_for_translations(
+        &self,
+        translations: &HashMap<String, String>,
+        target: ContentFilterTarget,
+        target_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<HashMap<String, String>, AppError> {
+        let mut filtered_translations = HashMap::new();
+
+        for (locale, text) in translations {
+            let filtered_text = self
+                .execute(
+                    text,
+                    &content_filter_locales(Some(locale), None),
+                    target,
+                    target_id,
+                    transaction,
+                )
+                .await?;
+            filtered_translations.insert(locale.to_owned(), filtered_text);
+        }
+
+        Ok(filtered_translations)
+    }
+}
+
+// Rules written for the author's locale or for the detected language of the content apply
+pub fn content_filter_locales(
+    author_locale: Option<&str>,
+    language_code: Option<&str>,
+) -> Vec<String> {
+    let mut locales: Vec<String> = vec![];
+    let author_language = author_locale.and_then(|locale| locale.split(['-', '_']).next());
+
+    for locale in [author_locale, author_language, language_code]
+        .into_iter()
+        .flatten()
+    {
+        if !locales.iter().any(|l| l == locale) {
+            locales.push(locale.to_string());
+        }
+    }
+
+    locales
+}
@@ -0,0 +1,64 @@
+// Create content filter rule use case
+
+use std::str::FromStr;
+
+use crate::core::constants::errors::AppError;
+use crate::features::content_filters::domain::entities::content_filter_rule::{
+    ContentFilterAction, ContentFilterRule, ContentFilterRuleKind,
+    CONTENT_FILTER_PATTERN_MAX_LENGTH,
+};
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
+use crate::features::content_filters::infrastructure::services::content_filter_service::ContentFilterService;
+
+// Locales are language codes like "en" or "pt-BR"
+fn is_valid_locale(locale: &str) -> bool {
+    (2..=10).contains(&locale.len())
+        && locale
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+pub fn validate_content_filter_rule(rule: &ContentFilterRule) -> Result<(), AppError> {
+    if rule.locale.as_deref().is_some_and(|l| !is_valid_locale(l)) {
+        return Err(AppError::ContentFilterRuleInvalid);
+    }
+    if ContentFilterAction::from_str(&rule.action).is_err() {
+        return Err(AppError::ContentFilterRuleInvalid);
+    }
+    let kind = ContentFilterRuleKind::from_str(&rule.kind)
+        .map_err(|_| AppError::ContentFilterRuleInvalid)?;
+
+    if rule.pattern.trim().is_empty() || rule.pattern.len() > CONTENT_FILTER_PATTERN_MAX_LENGTH {
+        return Err(AppError::ContentFilterRuleInvalid);
+    }
+    // A regex matching the empty string would match every content
+    match ContentFilterService::new().compile(kind, &rule.pattern) {
+        Some(regex) if !regex.is_match("") => Ok(()),
+        _ => Err(AppError::ContentFilterRuleInvalid),
+    }
+}
+
+pub struct CreateContentFilterRuleUseCase {
+    rule_repo: ContentFilterRuleRepositoryImpl,
+}
+
+impl CreateContentFilterRuleUseCase {
+    pub fn new(rule_repo: ContentFilterRuleRepositoryImpl) -> Self {
+        Self { rule_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        rule: &ContentFilterRule,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        validate_content_filter_rule(rule)?;
+
+        self.rule_repo
+            .create_with_executor(rule, &mut **transaction)
+            .await
+            .map_err(|_| AppError::ContentFilterRuleCreation)?;
+
+        Ok(())
+    }
+}
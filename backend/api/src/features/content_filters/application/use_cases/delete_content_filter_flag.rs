@@ -0,0 +1,36 @@
+// Delete content filter flag use case
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::features::content_filters::infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl;
+
+pub struct DeleteContentFilterFlagUseCase {
+    flag_repo: ContentFilterFlagRepositoryImpl,
+}
+
+impl DeleteContentFilterFlagUseCase {
+    pub fn new(flag_repo: ContentFilterFlagRepositoryImpl) -> Self {
+        Self { flag_repo }
+    }
+
+    // Reviewed flags are removed
+    pub async fn execute(
+        &self,
+        flag_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        self.flag_repo
+            .get_by_id_with_executor(flag_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::ContentFilterFlagNotFound)?;
+
+        self.flag_repo
+            .delete_with_executor(flag_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::ContentFilterFlagDeletion)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+// Delete content filter rule use case
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
+
+pub struct DeleteContentFilterRuleUseCase {
+    rule_repo: ContentFilterRuleRepositoryImpl,
+}
+
+impl DeleteContentFilterRuleUseCase {
+    pub fn new(rule_repo: ContentFilterRuleRepositoryImpl) -> Self {
+        Self { rule_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        rule_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        self.rule_repo
+            .get_by_id_with_executor(rule_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::ContentFilterRuleNotFound)?;
+
+        // Flags raised by the rule are kept for review
+        self.rule_repo
+            .delete_with_executor(rule_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::ContentFilterRuleDeletion)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+// Content filters use cases
+
+pub mod apply_content_filter;
+pub mod create_content_filter_rule;
+pub mod delete_content_filter_flag;
+pub mod delete_content_filter_rule;
+pub mod update_content_filter_rule;
@@ -0,0 +1,31 @@
+// Update content filter rule use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::content_filters::application::use_cases::create_content_filter_rule::validate_content_filter_rule;
+use crate::features::content_filters::domain::entities::content_filter_rule::ContentFilterRule;
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
+
+pub struct UpdateContentFilterRuleUseCase {
+    rule_repo: ContentFilterRuleRepositoryImpl,
+}
+
+impl UpdateContentFilterRuleUseCase {
+    pub fn new(rule_repo: ContentFilterRuleRepositoryImpl) -> Self {
+        Self { rule_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        rule: &ContentFilterRule,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        validate_content_filter_rule(rule)?;
+
+        self.rule_repo
+            .update_with_executor(rule, &mut **transaction)
+            .await
+            .map_err(|_| AppError::ContentFilterRuleUpdate)?;
+
+        Ok(())
+    }
+}
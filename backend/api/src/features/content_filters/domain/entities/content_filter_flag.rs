@@ -0,0 +1,73 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+// Where the filtered content comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentFilterTarget {
+    PublicMessage,
+    PublicMessageReport,
+    Habit,
+    Challenge,
+    Username,
+}
+
+impl ContentFilterTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentFilterTarget::PublicMessage => "public_message",
+            ContentFilterTarget::PublicMessageReport => "public_message_report",
+            ContentFilterTarget::Habit => "habit",
+            ContentFilterTarget::Challenge => "challenge",
+            ContentFilterTarget::Username => "username",
+        }
+    }
+
+    // A masked username would still need to be unique and readable, so it is rejected instead
+    pub fn can_be_masked(&self) -> bool {
+        !matches!(self, ContentFilterTarget::Username)
+    }
+}
+
+impl fmt::Display for ContentFilterTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct ContentFilterFlag {
+    pub id: Uuid,
+    pub rule_id: Option<Uuid>,
+    pub target: String,
+    pub target_id: Uuid,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContentFilterFlagData {
+    pub id: Uuid,
+    pub rule_id: Option<Uuid>,
+    pub target: String,
+    pub target_id: Uuid,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ContentFilterFlag {
+    pub fn to_content_filter_flag_data(&self) -> ContentFilterFlagData {
+        ContentFilterFlagData {
+            id: self.id,
+            rule_id: self.rule_id,
+            target: self.target.to_owned(),
+            target_id: self.target_id,
+            content: self.content.to_owned(),
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+pub const CONTENT_FILTER_PATTERN_MAX_LENGTH: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentFilterRuleKind {
+    // Whole word, case insensitive
+    Word,
+    Regex,
+}
+
+impl ContentFilterRuleKind {
+    pub const ALL: [ContentFilterRuleKind; 2] =
+        [ContentFilterRuleKind::Word, ContentFilterRuleKind::Regex];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentFilterRuleKind::Word => "word",
+            ContentFilterRuleKind::Regex => "regex",
+        }
+    }
+}
+
+impl fmt::Display for ContentFilterRuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContentFilterRuleKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ContentFilterRuleKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| format!("Unknown content filter rule kind: {}", s))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentFilterAction {
+    Reject,
+    Mask,
+    Flag,
+}
+
+impl ContentFilterAction {
+    pub const ALL: [ContentFilterAction; 3] = [
+        ContentFilterAction::Reject,
+        ContentFilterAction::Mask,
+        ContentFilterAction::Flag,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentFilterAction::Reject => "reject",
+            ContentFilterAction::Mask => "mask",
+            ContentFilterAction::Flag => "flag",
+        }
+    }
+}
+
+impl fmt::Display for ContentFilterAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContentFilterAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ContentFilterAction::ALL
+            .into_iter()
+            .find(|action| action.as_str() == s)
+            .ok_or_else(|| format!("Unknown content filter action: {}", s))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct ContentFilterRule {
+    pub id: Uuid,
+    // None when the rule applies to every language
+    pub locale: Option<String>,
+    pub kind: String,
+    pub pattern: String,
+    pub action: String,
+    pub hit_count: i64,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContentFilterRuleData {
+    pub id: Uuid,
+    pub locale: Option<String>,
+    pub kind: String,
+    pub pattern: String,
+    pub action: String,
+    pub hit_count: i64,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl ContentFilterRule {
+    pub fn to_content_filter_rule_data(&self) -> ContentFilterRuleData {
+        ContentFilterRuleData {
+            id: self.id,
+            locale: self.locale.to_owned(),
+            kind: self.kind.to_owned(),
+            pattern: self.pattern.to_owned(),
+            action: self.action.to_owned(),
+            hit_count: self.hit_count,
+            created_by: self.created_by,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// ContentFilterFlag repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterFlag;
+
+#[async_trait]
+pub trait ContentFilterFlagRepository: Send + Sync {
+    async fn create(&self, flag: &ContentFilterFlag) -> Result<(), String>;
+    async fn delete(&self, flag_id: Uuid) -> Result<(), String>;
+    async fn get_by_id(&self, flag_id: Uuid) -> Result<Option<ContentFilterFlag>, String>;
+    async fn get_all(&self) -> Result<Vec<ContentFilterFlag>, String>;
+    async fn delete_by_target_id(&self, target_id: Uuid) -> Result<(), String>;
+}
@@ -0,0 +1,17 @@
+// ContentFilterRule repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::content_filters::domain::entities::content_filter_rule::ContentFilterRule;
+
+#[async_trait]
+pub trait ContentFilterRuleRepository: Send + Sync {
+    async fn create(&self, rule: &ContentFilterRule) -> Result<(), String>;
+    async fn update(&self, rule: &ContentFilterRule) -> Result<(), String>;
+    async fn delete(&self, rule_id: Uuid) -> Result<(), String>;
+    async fn get_by_id(&self, rule_id: Uuid) -> Result<Option<ContentFilterRule>, String>;
+    async fn get_all(&self) -> Result<Vec<ContentFilterRule>, String>;
+    async fn get_for_locales(&self, locales: &[String]) -> Result<Vec<ContentFilterRule>, String>;
+    async fn increment_hit_counts(&self, rule_ids: &[Uuid]) -> Result<(), String>;
+}
@@ -0,0 +1,4 @@
+// Content filters domain repository traits
+
+pub mod content_filter_flag_repository;
+pub mod content_filter_rule_repository;
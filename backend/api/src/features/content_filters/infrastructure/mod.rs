@@ -0,0 +1,3 @@
+// Infrastructure layer - repository implementations and services
+pub mod repositories;
+pub mod services;
@@ -0,0 +1,165 @@
+// ContentFilterFlagRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterFlag;
+use crate::features::content_filters::domain::repositories::content_filter_flag_repository::ContentFilterFlagRepository;
+
+pub struct ContentFilterFlagRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ContentFilterFlagRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        flag: &ContentFilterFlag,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO content_filter_flags (
+                id,
+                rule_id,
+                target,
+                target_id,
+                content,
+                created_at
+            )
+            VALUES ( $1, $2, $3, $4, $5, $6 )
+            "#,
+            flag.id,
+            flag.rule_id,
+            flag.target,
+            flag.target_id,
+            flag.content,
+            flag.created_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        flag_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM content_filter_flags
+            WHERE id = $1
+            "#,
+            flag_id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        flag_id: Uuid,
+        executor: E,
+    ) -> Result<Option<ContentFilterFlag>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            ContentFilterFlag,
+            r#"
+            SELECT *
+            FROM content_filter_flags
+            WHERE id = $1
+            "#,
+            flag_id,
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn get_all_with_executor<'a, E>(
+        &self,
+        executor: E,
+    ) -> Result<Vec<ContentFilterFlag>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            ContentFilterFlag,
+            r#"
+            SELECT *
+            FROM content_filter_flags
+            ORDER BY created_at
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn delete_by_target_id_with_executor<'a, E>(
+        &self,
+        target_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM content_filter_flags
+            WHERE target_id = $1
+            "#,
+            target_id,
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl ContentFilterFlagRepository for ContentFilterFlagRepositoryImpl {
+    async fn create(&self, flag: &ContentFilterFlag) -> Result<(), String> {
+        self.create_with_executor(flag, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete(&self, flag_id: Uuid) -> Result<(), String> {
+        self.delete_with_executor(flag_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_id(&self, flag_id: Uuid) -> Result<Option<ContentFilterFlag>, String> {
+        self.get_by_id_with_executor(flag_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_all(&self) -> Result<Vec<ContentFilterFlag>, String> {
+        self.get_all_with_executor(&self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_target_id(&self, target_id: Uuid) -> Result<(), String> {
+        self.delete_by_target_id_with_executor(target_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+// ContentFilterRuleRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::content_filters::domain::entities::content_filter_rule::ContentFilterRule;
+use crate::features::content_filters::domain::repositories::content_filter_rule_repository::ContentFilterRuleRepository;
+
+pub struct ContentFilterRuleRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ContentFilterRuleRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        rule: &ContentFilterRule,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO content_filter_rules (
+                id,
+                locale,
+                kind,
+                pattern,
+                action,
+                hit_count,
+                created_by,
+                created_at,
+                updated_at
+            )
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9 )
+            "#,
+            rule.id,
+            rule.locale,
+            rule.kind,
+            rule.pattern,
+            rule.action,
+            rule.hit_count,
+            rule.created_by,
+            rule.created_at,
+            rule.updated_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn update_with_executor<'a, E>(
+        &self,
+        rule: &ContentFilterRule,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE content_filter_rules
+            SET locale = $1, kind = $2, pattern = $3, action = $4, updated_at = $5
+            WHERE id = $6
+            "#,
+            rule.locale,
+            rule.kind,
+            rule.pattern,
+            rule.action,
+            rule.updated_at,
+            rule.id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        rule_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM content_filter_rules
+            WHERE id = $1
+            "#,
+            rule_id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        rule_id: Uuid,
+        executor: E,
+    ) -> Result<Option<ContentFilterRule>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            ContentFilterRule,
+            r#"
+            SELECT *
+            FROM content_filter_rules
+            WHERE id = $1
+            "#,
+            rule_id,
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn get_all_with_executor<'a, E>(
+        &self,
+        executor: E,
+    ) -> Result<Vec<ContentFilterRule>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            ContentFilterRule,
+            r#"
+            SELECT *
+            FROM content_filter_rules
+            ORDER BY hit_count DESC, created_at
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Rules without locale apply to every content
+    pub async fn get_for_locales_with_executor<'a, E>(
+        &self,
+        locales: &[String],
+        executor: E,
+    ) -> Result<Vec<ContentFilterRule>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            ContentFilterRule,
+            r#"
+            SELECT *
+            FROM content_filter_rules
+            WHERE locale IS NULL OR locale = ANY($1)
+            ORDER BY created_at
+            "#,
+            locales,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn increment_hit_counts_with_executor<'a, E>(
+        &self,
+        rule_ids: &[Uuid],
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE content_filter_rules
+            SET hit_count = hit_count + 1
+            WHERE id = ANY($1)
+            "#,
+            rule_ids,
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl ContentFilterRuleRepository for ContentFilterRuleRepositoryImpl {
+    async fn create(&self, rule: &ContentFilterRule) -> Result<(), String> {
+        self.create_with_executor(rule, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn update(&self, rule: &ContentFilterRule) -> Result<(), String> {
+        self.update_with_executor(rule, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete(&self, rule_id: Uuid) -> Result<(), String> {
+        self.delete_with_executor(rule_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_id(&self, rule_id: Uuid) -> Result<Option<ContentFilterRule>, String> {
+        self.get_by_id_with_executor(rule_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_all(&self) -> Result<Vec<ContentFilterRule>, String> {
+        self.get_all_with_executor(&self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_for_locales(&self, locales: &[String]) -> Result<Vec<ContentFilterRule>, String> {
+        self.get_for_locales_with_executor(locales, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn increment_hit_counts(&self, rule_ids: &[Uuid]) -> Result<(), String> {
+        self.increment_hit_counts_with_executor(rule_ids, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+// Content filter service - matches contents against the admin-managed rules
+
+use std::str::FromStr;
+
+use regex::{Regex, RegexBuilder};
+use uuid::Uuid;
+
+use crate::features::content_filters::domain::entities::content_filter_rule::{
+    ContentFilterAction, ContentFilterRule, ContentFilterRuleKind,
+};
+
+// Keeps a single rule from taking too much memory once compiled
+const CONTENT_FILTER_REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+#[derive(Debug, Default)]
+pub struct ContentFilterOutcome {
+    // The content once masked
+    pub content: String,
+    pub rejected: bool,
+    pub matched_rule_ids: Vec<Uuid>,
+    pub flagging_rule_ids: Vec<Uuid>,
+}
+
+#[derive(Default)]
+pub struct ContentFilterService;
+
+impl ContentFilterService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    // Returns None when the pattern can not be compiled
+    pub fn compile(&self, kind: ContentFilterRuleKind, pattern: &str) -> Option<Regex> {
+        let pattern = match kind {
+            ContentFilterRuleKind::Word => format!(r"(?i)\b{}\b", regex::escape(pattern.trim())),
+            ContentFilterRuleKind::Regex => pattern.to_string(),
+        };
+
+        RegexBuilder::new(&pattern)
+            .size_limit(CONTENT_FILTER_REGEX_SIZE_LIMIT)
+            .build()
+            .ok()
+    }
+
+    pub fn apply(
+        &self,
+        rules: &[ContentFilterRule],
+        content: &str,
+        can_be_masked: bool,
+    ) -> ContentFilterOutcome {
+        let mut outcome = ContentFilterOutcome {
+            content: content.to_string(),
+            ..Default::default()
+        };
+
+        for rule in rules {
+            let (Ok(kind), Ok(action)) = (
+                ContentFilterRuleKind::from_str(&rule.kind),
+                ContentFilterAction::from_str(&rule.action),
+            ) else {
+                continue;
+            };
+            let Some(regex) = self.compile(kind, &rule.pattern) else {
+                continue;
+            };
+
+            if !regex.is_match(content) {
+                continue;
+            }
+            outcome.matched_rule_ids.push(rule.id);
+
+            match action {
+                ContentFilterAction::Reject => outcome.rejected = true,
+                ContentFilterAction::Mask if !can_be_masked => outcome.rejected = true,
+                ContentFilterAction::Mask => {
+                    outcome.content = regex
+                        .replace_all(&outcome.content, |captures: &regex::Captures| {
+                            "*".repeat(captures[0].chars().count())
+                        })
+                        .into_owned();
+                }
+                ContentFilterAction::Flag => outcome.flagging_rule_ids.push(rule.id),
+            }
+        }
+
+        outcome
+    }
+}
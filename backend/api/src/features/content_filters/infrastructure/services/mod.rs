@@ -0,0 +1,3 @@
+// Infrastructure services
+
+pub mod content_filter_service;
@@ -0,0 +1,89 @@
+use crate::{
+    core::{
+        constants::errors::AppError, helpers::mock_now::now,
+        presentation::guards::permission::require_permission,
+    },
+    features::{
+        auth::domain::entities::Claims,
+        content_filters::{
+            application::{
+                dto::{
+                    requests::content_filter_rule::ContentFilterRuleCreateRequest,
+                    responses::content_filter_rule::ContentFilterRuleResponse,
+                },
+                use_cases::create_content_filter_rule::CreateContentFilterRuleUseCase,
+            },
+            domain::entities::content_filter_rule::ContentFilterRule,
+            infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl,
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+#[post("/")]
+pub async fn create_content_filter_rule(
+    pool: Data<PgPool>,
+    body: Json<ContentFilterRuleCreateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageContentFilters) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+    let rule = ContentFilterRule {
+        id: Uuid::new_v4(),
+        locale: body.locale,
+        kind: body.kind.to_string(),
+        pattern: body.pattern,
+        action: body.action.to_string(),
+        hit_count: 0,
+        created_by: Some(request_claims.user_id),
+        created_at: now(),
+        updated_at: None,
+    };
+
+    // Create repository and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case =
+        CreateContentFilterRuleUseCase::new(ContentFilterRuleRepositoryImpl::new(pool_clone));
+
+    if let Err(e) = use_case.execute(&rule, &mut transaction).await {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::ContentFilterRuleInvalid => HttpResponse::BadRequest().json(e.to_response()),
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(ContentFilterRuleResponse {
+        code: "CONTENT_FILTER_RULE_CREATED".to_string(),
+        rule: Some(rule.to_content_filter_rule_data()),
+    })
+}
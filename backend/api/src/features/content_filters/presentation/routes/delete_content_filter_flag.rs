@@ -0,0 +1,71 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        content_filters::{
+            application::{
+                dto::{
+                    requests::content_filter_rule::ContentFilterFlagParams,
+                    responses::content_filter_flag::ContentFilterFlagResponse,
+                },
+                use_cases::delete_content_filter_flag::DeleteContentFilterFlagUseCase,
+            },
+            infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl,
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/flags/{flag_id}")]
+pub async fn delete_content_filter_flag(
+    pool: Data<PgPool>,
+    params: Path<ContentFilterFlagParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageContentFilters) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repository and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case =
+        DeleteContentFilterFlagUseCase::new(ContentFilterFlagRepositoryImpl::new(pool_clone));
+
+    if let Err(e) = use_case.execute(params.flag_id, &mut transaction).await {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::ContentFilterFlagNotFound => HttpResponse::NotFound().json(e.to_response()),
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(ContentFilterFlagResponse {
+        code: "CONTENT_FILTER_FLAG_DELETED".to_string(),
+        flag: None,
+    })
+}
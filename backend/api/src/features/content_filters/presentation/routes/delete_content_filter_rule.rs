@@ -0,0 +1,71 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        content_filters::{
+            application::{
+                dto::{
+                    requests::content_filter_rule::ContentFilterRuleParams,
+                    responses::content_filter_rule::ContentFilterRuleResponse,
+                },
+                use_cases::delete_content_filter_rule::DeleteContentFilterRuleUseCase,
+            },
+            infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl,
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/{rule_id}")]
+pub async fn delete_content_filter_rule(
+    pool: Data<PgPool>,
+    params: Path<ContentFilterRuleParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageContentFilters) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repository and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case =
+        DeleteContentFilterRuleUseCase::new(ContentFilterRuleRepositoryImpl::new(pool_clone));
+
+    if let Err(e) = use_case.execute(params.rule_id, &mut transaction).await {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::ContentFilterRuleNotFound => HttpResponse::NotFound().json(e.to_response()),
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(ContentFilterRuleResponse {
+        code: "CONTENT_FILTER_RULE_DELETED".to_string(),
+        rule: None,
+    })
+}
@@ -0,0 +1,44 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        content_filters::{
+            application::dto::responses::content_filter_flag::ContentFilterFlagsResponse,
+            infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl,
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/flags")]
+pub async fn get_content_filter_flags(
+    pool: Data<PgPool>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageContentFilters) {
+        return response;
+    }
+
+    let flag_repo = ContentFilterFlagRepositoryImpl::new(pool.get_ref().clone());
+
+    match flag_repo.get_all_with_executor(pool.get_ref()).await {
+        Ok(flags) => HttpResponse::Ok().json(ContentFilterFlagsResponse {
+            code: "CONTENT_FILTER_FLAGS_FETCHED".to_string(),
+            flags: flags
+                .iter()
+                .map(|f| f.to_content_filter_flag_data())
+                .collect(),
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response())
+        }
+    }
+}
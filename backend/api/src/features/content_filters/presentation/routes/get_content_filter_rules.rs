@@ -0,0 +1,45 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        content_filters::{
+            application::dto::responses::content_filter_rule::ContentFilterRulesResponse,
+            infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl,
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/")]
+pub async fn get_content_filter_rules(
+    pool: Data<PgPool>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageContentFilters) {
+        return response;
+    }
+
+    let rule_repo = ContentFilterRuleRepositoryImpl::new(pool.get_ref().clone());
+
+    // Rules come with their hit counts, most hit first
+    match rule_repo.get_all_with_executor(pool.get_ref()).await {
+        Ok(rules) => HttpResponse::Ok().json(ContentFilterRulesResponse {
+            code: "CONTENT_FILTER_RULES_FETCHED".to_string(),
+            rules: rules
+                .iter()
+                .map(|r| r.to_content_filter_rule_data())
+                .collect(),
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response())
+        }
+    }
+}
@@ -0,0 +1,8 @@
+// Content filters presentation routes
+
+pub mod create_content_filter_rule;
+pub mod delete_content_filter_flag;
+pub mod delete_content_filter_rule;
+pub mod get_content_filter_flags;
+pub mod get_content_filter_rules;
+pub mod update_content_filter_rule;
@@ -0,0 +1,106 @@
+use crate::{
+    core::{
+        constants::errors::AppError, helpers::mock_now::now,
+        presentation::guards::permission::require_permission,
+    },
+    features::{
+        auth::domain::entities::Claims,
+        content_filters::{
+            application::{
+                dto::{
+                    requests::content_filter_rule::{
+                        ContentFilterRuleParams, ContentFilterRuleUpdateRequest,
+                    },
+                    responses::content_filter_rule::ContentFilterRuleResponse,
+                },
+                use_cases::update_content_filter_rule::UpdateContentFilterRuleUseCase,
+            },
+            infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl,
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    put,
+    web::{Data, Json, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[put("/{rule_id}")]
+pub async fn update_content_filter_rule(
+    pool: Data<PgPool>,
+    params: Path<ContentFilterRuleParams>,
+    body: Json<ContentFilterRuleUpdateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageContentFilters) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let rule_repo = ContentFilterRuleRepositoryImpl::new(pool_clone.clone());
+
+    let mut rule = match rule_repo
+        .get_by_id_with_executor(params.rule_id, &mut *transaction)
+        .await
+    {
+        Ok(Some(rule)) => rule,
+        Ok(None) => {
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return HttpResponse::NotFound()
+                .json(AppError::ContentFilterRuleNotFound.to_response());
+        }
+        Err(e) => {
+            error!("Error: {}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+    rule.locale = body.locale;
+    rule.kind = body.kind.to_string();
+    rule.pattern = body.pattern;
+    rule.action = body.action.to_string();
+    rule.updated_at = Some(now());
+
+    let use_case = UpdateContentFilterRuleUseCase::new(rule_repo);
+
+    if let Err(e) = use_case.execute(&rule, &mut transaction).await {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::ContentFilterRuleInvalid => HttpResponse::BadRequest().json(e.to_response()),
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(ContentFilterRuleResponse {
+        code: "CONTENT_FILTER_RULE_UPDATED".to_string(),
+        rule: Some(rule.to_content_filter_rule_data()),
+    })
+}
@@ -1,6 +1,10 @@
 // Create habit route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
+use crate::features::content_filters::application::use_cases::apply_content_filter::ApplyContentFilterUseCase;
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
+use crate::features::content_filters::infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl;
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
 use crate::features::habits::application::dto::requests::habit::HabitCreateRequest;
 use crate::features::habits::application::dto::responses::habit::HabitResponse;
 use crate::features::habits::application::use_cases::create_habit::CreateHabitUseCase;
@@ -89,11 +93,54 @@ pub async fn create_habit(pool: Data<PgPool>, body: Json<HabitCreateRequest>) ->
         }
     }
 
+    let habit_id = Uuid::new_v4();
+
+    // Names and descriptions go through the content filter rules of their locale
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool.get_ref().clone()),
+        ContentFilterFlagRepositoryImpl::new(pool.get_ref().clone()),
+    );
+    let filtered_texts = async {
+        let name = content_filter
+            .execute_for_translations(
+                &body.name,
+                ContentFilterTarget::Habit,
+                habit_id,
+                &mut transaction,
+            )
+            .await?;
+        let description = content_filter
+            .execute_for_translations(
+                &body.description,
+                ContentFilterTarget::Habit,
+                habit_id,
+                &mut transaction,
+            )
+            .await?;
+        Ok::<_, AppError>((name, description))
+    }
+    .await;
+    let (name, description) = match filtered_texts {
+        Ok(texts) => texts,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::ContentRejectedByFilter => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
     // Create habit entity
     let habit = Habit {
-        id: Uuid::new_v4(),
-        name: json!(body.name).to_string(),
-        description: json!(body.description).to_string(),
+        id: habit_id,
+        name: json!(name).to_string(),
+        description: json!(description).to_string(),
         category_id: category.id,
         reviewed: false,
         icon: body.icon.clone(),
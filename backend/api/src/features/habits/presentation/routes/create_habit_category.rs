@@ -1,12 +1,14 @@
 // Create habit category route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
+use crate::core::presentation::guards::permission::require_permission;
 use crate::features::auth::domain::entities::Claims;
 use crate::features::habits::application::dto::requests::habit_category::HabitCategoryCreateRequest;
 use crate::features::habits::application::dto::responses::habit_category::HabitCategoryResponse;
 use crate::features::habits::application::use_cases::create_habit_category::CreateHabitCategoryUseCase;
 use crate::features::habits::domain::entities::habit_category::HabitCategory;
 use crate::features::habits::infrastructure::repositories::habit_category_repository::HabitCategoryRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
 use actix_web::web::{Data, Json, ReqData};
 use actix_web::{post, HttpResponse, Responder};
 use chrono::Utc;
@@ -21,8 +23,8 @@ pub async fn create_habit_category(
     body: Json<HabitCategoryCreateRequest>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
-    if !request_claims.is_admin {
-        return HttpResponse::Forbidden().body("Access denied");
+    if let Err(response) = require_permission(&request_claims, Permission::ManageHabits) {
+        return response;
     }
 
     let mut transaction = match pool.begin().await {
@@ -1,12 +1,14 @@
 // Create unit route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
+use crate::core::presentation::guards::permission::require_permission;
 use crate::features::auth::domain::entities::Claims;
 use crate::features::habits::application::dto::requests::unit::UnitCreateRequest;
 use crate::features::habits::application::dto::responses::unit::UnitResponse;
 use crate::features::habits::application::use_cases::create_unit::CreateUnitUseCase;
 use crate::features::habits::domain::entities::unit::Unit;
 use crate::features::habits::infrastructure::repositories::unit_repository::UnitRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
 use actix_web::web::{Data, Json, ReqData};
 use actix_web::{post, HttpResponse, Responder};
 use chrono::Utc;
@@ -21,8 +23,8 @@ pub async fn create_unit(
     body: Json<UnitCreateRequest>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
-    if !request_claims.is_admin {
-        return HttpResponse::Forbidden().body("Access denied");
+    if let Err(response) = require_permission(&request_claims, Permission::ManageHabits) {
+        return response;
     }
 
     let mut transaction = match pool.begin().await {
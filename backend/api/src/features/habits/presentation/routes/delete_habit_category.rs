@@ -1,11 +1,13 @@
 // Delete habit category route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
+use crate::core::presentation::guards::permission::require_permission;
 use crate::features::auth::domain::entities::Claims;
 use crate::features::habits::application::dto::requests::habit_category::GetHabitCategoryParams;
 use crate::features::habits::application::dto::responses::habit::HabitResponse;
 use crate::features::habits::application::use_cases::delete_habit_category::DeleteHabitCategoryUseCase;
 use crate::features::habits::infrastructure::repositories::habit_category_repository::HabitCategoryRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
 use actix_web::web::{Data, Path, ReqData};
 use actix_web::{delete, HttpResponse, Responder};
 use sqlx::PgPool;
@@ -17,8 +19,8 @@ pub async fn delete_habit_category(
     params: Path<GetHabitCategoryParams>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
-    if !request_claims.is_admin {
-        return HttpResponse::Forbidden().body("Access denied");
+    if let Err(response) = require_permission(&request_claims, Permission::ManageHabits) {
+        return response;
     }
 
     let mut transaction = match pool.begin().await {
@@ -5,6 +5,7 @@ use crate::features::auth::domain::entities::Claims;
 use crate::features::habits::application::dto::responses::habit::HabitsResponse;
 use crate::features::habits::application::use_cases::get_habits::GetHabitsUseCase;
 use crate::features::habits::infrastructure::repositories::habit_repository::HabitRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
 use actix_web::web::{Data, ReqData};
 use actix_web::{get, HttpResponse, Responder};
 use sqlx::PgPool;
@@ -27,8 +28,8 @@ pub async fn get_habits(pool: Data<PgPool>, request_claims: ReqData<Claims>) ->
     let get_habits_use_case = GetHabitsUseCase::new(habit_repo);
 
     // Execute use case - use admin check for all habits vs user-specific
-    let user_id = if request_claims.is_admin {
-        None // Admin gets all habits
+    let user_id = if request_claims.has_permission(Permission::ManageHabits) {
+        None // Habit managers get all habits
     } else {
         Some(request_claims.user_id) // Regular user gets reviewed and personal
     };
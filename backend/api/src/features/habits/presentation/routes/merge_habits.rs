@@ -1,5 +1,5 @@
 use crate::{
-    core::constants::errors::AppError,
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
     features::{
         auth::domain::entities::Claims,
         habits::{
@@ -15,6 +15,7 @@ use crate::{
                 habit_repository::HabitRepositoryImpl,
             },
         },
+        roles::domain::entities::permission::Permission,
     },
 };
 use actix_web::{
@@ -33,8 +34,8 @@ pub async fn merge_habits(
     body: Json<HabitUpdateRequest>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
-    if !request_claims.is_admin {
-        return HttpResponse::Forbidden().body("Access denied");
+    if let Err(response) = require_permission(&request_claims, Permission::ManageHabits) {
+        return response;
     }
 
     let mut transaction = match pool.begin().await {
@@ -1,7 +1,12 @@
 // Update habit route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
+use crate::core::presentation::guards::permission::require_permission;
 use crate::features::auth::domain::entities::Claims;
+use crate::features::content_filters::application::use_cases::apply_content_filter::ApplyContentFilterUseCase;
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
+use crate::features::content_filters::infrastructure::repositories::content_filter_flag_repository::ContentFilterFlagRepositoryImpl;
+use crate::features::content_filters::infrastructure::repositories::content_filter_rule_repository::ContentFilterRuleRepositoryImpl;
 use crate::features::habits::application::dto::requests::habit::{
     HabitUpdateRequest, UpdateHabitParams,
 };
@@ -12,6 +17,7 @@ use crate::features::habits::domain::entities::habit::HABIT_DESCRIPTION_MAX_LENG
 use crate::features::habits::infrastructure::repositories::habit_category_repository::HabitCategoryRepositoryImpl;
 use crate::features::habits::infrastructure::repositories::habit_repository::HabitRepositoryImpl;
 use crate::features::habits::infrastructure::repositories::unit_repository::UnitRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
 use actix_web::web::{Data, Json, Path, ReqData};
 use actix_web::{put, HttpResponse, Responder};
 use serde_json::json;
@@ -25,8 +31,8 @@ pub async fn update_habit(
     body: Json<HabitUpdateRequest>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
-    if !request_claims.is_admin {
-        return HttpResponse::Forbidden().body("Access denied");
+    if let Err(response) = require_permission(&request_claims, Permission::ManageHabits) {
+        return response;
     }
 
     let mut transaction = match pool.begin().await {
@@ -123,9 +129,50 @@ pub async fn update_habit(
         }
     }
 
+    // Names and descriptions go through the content filter rules of their locale
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool.get_ref().clone()),
+        ContentFilterFlagRepositoryImpl::new(pool.get_ref().clone()),
+    );
+    let filtered_texts = async {
+        let name = content_filter
+            .execute_for_translations(
+                &body.name,
+                ContentFilterTarget::Habit,
+                habit.id,
+                &mut transaction,
+            )
+            .await?;
+        let description = content_filter
+            .execute_for_translations(
+                &body.description,
+                ContentFilterTarget::Habit,
+                habit.id,
+                &mut transaction,
+            )
+            .await?;
+        Ok::<_, AppError>((name, description))
+    }
+    .await;
+    let (name, description) = match filtered_texts {
+        Ok(texts) => texts,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::ContentRejectedByFilter => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
     // Update habit fields
-    habit.name = json!(body.name).to_string();
-    habit.description = json!(body.description).to_string();
+    habit.name = json!(name).to_string();
+    habit.description = json!(description).to_string();
     habit.reviewed = body.reviewed;
     habit.icon = body.icon.clone();
     habit.category_id = body.category_id;
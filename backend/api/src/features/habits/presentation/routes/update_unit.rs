@@ -1,6 +1,7 @@
 // Update unit route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
+use crate::core::presentation::guards::permission::require_permission;
 use crate::features::auth::domain::entities::Claims;
 use crate::features::habits::application::dto::requests::unit::{
     UnitUpdateRequest, UpdateUnitParams,
@@ -8,6 +9,7 @@ use crate::features::habits::application::dto::requests::unit::{
 use crate::features::habits::application::dto::responses::unit::UnitResponse;
 use crate::features::habits::application::use_cases::update_unit::UpdateUnitUseCase;
 use crate::features::habits::infrastructure::repositories::unit_repository::UnitRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
 use actix_web::web::{Data, Json, Path, ReqData};
 use actix_web::{put, HttpResponse, Responder};
 use serde_json::json;
@@ -21,8 +23,8 @@ pub async fn update_unit(
     body: Json<UnitUpdateRequest>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
-    if !request_claims.is_admin {
-        return HttpResponse::Forbidden().body("Access denied");
+    if let Err(response) = require_permission(&request_claims, Permission::ManageHabits) {
+        return response;
     }
 
     let mut transaction = match pool.begin().await {
@@ -3,37 +3,68 @@
 use actix_web::web::Data;
 use redis::{AsyncCommands, Client};
 use serde_json::json;
-use sqlx::{Executor, PgPool, Postgres};
+use sqlx::{PgConnection, PgPool};
 use tracing::error;
 use uuid::Uuid;
 
 use crate::core::{helpers::mock_now::now, structs::redis_messages::NotificationEvent};
 use crate::features::notifications::domain::entities::Notification;
 use crate::features::notifications::infrastructure::repositories::notification_repository::NotificationRepositoryImpl;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+
+// What the user reads, and where the notification is published and leads to
+pub struct NotificationContent<'a> {
+    pub title: &'a str,
+    pub body: &'a str,
+    pub notification_type: &'a str,
+    pub url: Option<String>,
+}
 
 pub struct NotificationService {
     repository: NotificationRepositoryImpl,
+    user_block_repository: UserBlockRepositoryImpl,
 }
 
 impl NotificationService {
     pub fn new(pool: PgPool) -> Self {
         Self {
-            repository: NotificationRepositoryImpl::new(pool),
+            repository: NotificationRepositoryImpl::new(pool.clone()),
+            user_block_repository: UserBlockRepositoryImpl::new(pool),
         }
     }
 
-    pub async fn generate_notification<'a, E>(
+    pub async fn generate_notification(
         &self,
-        executor: E,
+        executor: &mut PgConnection,
+        sender_id: Option<Uuid>,
         user_id: Uuid,
-        title: &str,
-        body: &str,
+        content: NotificationContent<'_>,
         redis_client: Data<Client>,
-        notification_type: &str,
-        url: Option<String>,
-    ) where
-        E: Executor<'a, Database = Postgres>,
-    {
+    ) {
+        let NotificationContent {
+            title,
+            body,
+            notification_type,
+            url,
+        } = content;
+
+        // Users never hear from someone they blocked, nor from someone who blocked them.
+        // System notifications (moderation decisions, ...) have no sender.
+        if let Some(sender_id) = sender_id {
+            match self
+                .user_block_repository
+                .exists_between_with_executor(sender_id, user_id, &mut *executor)
+                .await
+            {
+                Ok(false) => {}
+                Ok(true) => return,
+                Err(e) => {
+                    error!("Error: {}", e);
+                    return;
+                }
+            }
+        }
+
         // Create a notification
         let notification = Notification {
             id: Uuid::new_v4(),
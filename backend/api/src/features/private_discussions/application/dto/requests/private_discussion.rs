@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::features::private_discussions::domain::entities::private_discussion::PrivateDiscussionRequestAnswer;
+
 #[derive(Deserialize)]
 pub struct GetPrivateDiscussionMessagesParams {
     pub discussion_id: Uuid,
@@ -9,7 +11,22 @@ pub struct GetPrivateDiscussionMessagesParams {
 
 #[derive(Deserialize)]
 pub struct GetPrivateDiscussionMessagesQuery {
+    // Superseded by the before cursor
     pub before_date: Option<DateTime<Utc>>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub around: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct GetPrivateDiscussionsQuery {
+    // Archived discussions are only listed when asked for
+    #[serde(default)]
+    pub archived: bool,
+    // Message requests waiting for an answer of the user are only listed when asked for
+    #[serde(default)]
+    pub requests: bool,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -17,3 +34,47 @@ pub struct PrivateDiscussionCreateRequest {
     pub recipient: Uuid,
     pub color: String,
 }
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionGroupCreateRequest {
+    pub name: String,
+    pub color: String,
+    pub members: Vec<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct PrivateDiscussionSessionKeysParams {
+    pub discussion_id: Uuid,
+}
+
+#[derive(Deserialize)]
+pub struct PrivateDiscussionMarkAsSeenParams {
+    pub discussion_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionMarkAsSeenRequest {
+    // Last message read by the user, every message received before it is marked as seen too
+    pub message_id: Uuid,
+}
+
+#[derive(Deserialize)]
+pub struct PrivateDiscussionRetentionParams {
+    pub discussion_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionRetentionUpdateRequest {
+    // One of off, 24h, 7d or 30d
+    pub message_retention: String,
+}
+
+#[derive(Deserialize)]
+pub struct PrivateDiscussionRequestParams {
+    pub discussion_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionRequestAnswerRequest {
+    pub answer: PrivateDiscussionRequestAnswer,
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -11,3 +12,37 @@ pub struct PrivateDiscussionParticipationUpdateRequest {
     pub has_blocked: bool,
     pub color: String,
 }
+
+#[derive(Deserialize)]
+pub struct PrivateDiscussionSettingsParams {
+    pub discussion_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionSettingsUpdateRequest {
+    // The discussion is unmuted when absent
+    pub muted_until: Option<DateTime<Utc>>,
+    pub is_archived: bool,
+    pub is_pinned: bool,
+}
+
+#[derive(Deserialize)]
+pub struct PrivateDiscussionMembersParams {
+    pub discussion_id: Uuid,
+}
+
+#[derive(Deserialize)]
+pub struct PrivateDiscussionMemberParams {
+    pub discussion_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionMemberAddRequest {
+    pub user_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionMemberUpdateRequest {
+    pub is_admin: bool,
+}
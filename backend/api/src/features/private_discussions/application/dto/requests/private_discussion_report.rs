@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_discussion_report::PrivateDiscussionReportAction;
+
+#[derive(Deserialize)]
+pub struct PrivateDiscussionReportParams {
+    pub report_id: Uuid,
+}
+
+// A message the reporter chose to disclose, with the session key to check it
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionReportMessageRequest {
+    pub message_id: Uuid,
+    pub content: String,
+    pub session_key: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateDiscussionReportCreateRequest {
+    pub discussion_id: Uuid,
+    pub reported_user: Uuid,
+    pub reason: String,
+    pub messages: Vec<PrivateDiscussionReportMessageRequest>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ResolvePrivateDiscussionReportRequest {
+    pub action: PrivateDiscussionReportAction,
+    pub note: Option<String>,
+    pub suspension_in_days: Option<i64>,
+}
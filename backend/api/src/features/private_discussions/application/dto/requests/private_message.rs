@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -35,6 +37,30 @@ pub struct PrivateMessageUpdateRequest {
 pub struct PrivateMessageCreateRequest {
     pub discussion_id: Uuid,
     pub content: String,
+    // One to one discussions
+    #[serde(default)]
     pub creator_encrypted_session_key: String,
+    #[serde(default)]
     pub recipient_encrypted_session_key: String,
+    // Group discussions: the session key encrypted for each member, by user id
+    #[serde(default)]
+    pub encrypted_session_keys: BTreeMap<Uuid, String>,
+    // Devices with their own key pair: the session key encrypted for each, by device key id
+    #[serde(default)]
+    pub device_encrypted_session_keys: BTreeMap<Uuid, String>,
+    // Uploaded beforehand, encrypted with the session key of the message
+    #[serde(default)]
+    pub attachment_ids: Vec<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct PrivateMessageAttachmentParams {
+    pub attachment_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PrivateMessageSessionKeysDistributeRequest {
+    pub user_id: Uuid,
+    // Session key encrypted for the user, by message id
+    pub session_keys: BTreeMap<Uuid, String>,
 }
@@ -2,4 +2,5 @@
 
 pub mod private_discussion;
 pub mod private_discussion_participation;
+pub mod private_discussion_report;
 pub mod private_message;
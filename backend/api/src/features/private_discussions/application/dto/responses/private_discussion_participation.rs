@@ -1,6 +1,22 @@
 use serde::{Deserialize, Serialize};
 
+use crate::features::private_discussions::domain::entities::private_discussion_participation::{
+    PrivateDiscussionMemberData, PrivateDiscussionParticipationData,
+};
+
 #[derive(Serialize, Deserialize)]
 pub struct PrivateDiscussionParticipationResponse {
     pub code: String,
 }
+
+#[derive(Serialize, Deserialize)]
+pub struct PrivateDiscussionSettingsResponse {
+    pub code: String,
+    pub participation: Option<PrivateDiscussionParticipationData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PrivateDiscussionMembersResponse {
+    pub code: String,
+    pub members: Vec<PrivateDiscussionMemberData>,
+}
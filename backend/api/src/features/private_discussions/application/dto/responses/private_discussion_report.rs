@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::private_discussions::domain::entities::private_discussion_report::{
+    PrivateDiscussionReportData, PrivateDiscussionReportEntry,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PrivateDiscussionReportResponse {
+    pub code: String,
+    pub report: Option<PrivateDiscussionReportData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PrivateDiscussionReportsResponse {
+    pub code: String,
+    pub reports: Vec<PrivateDiscussionReportEntry>,
+}
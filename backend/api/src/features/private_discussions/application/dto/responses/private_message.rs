@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::features::private_discussions::domain::entities::private_message::PrivateMessageData;
+use crate::features::private_discussions::domain::entities::{
+    private_message::PrivateMessageData, private_message_attachment::PrivateMessageAttachmentData,
+};
 
 #[derive(Serialize, Deserialize)]
 pub struct PrivateMessageResponse {
@@ -11,5 +13,20 @@ pub struct PrivateMessageResponse {
 #[derive(Serialize, Deserialize)]
 pub struct PrivateMessagesResponse {
     pub code: String,
+    // Newest first
     pub messages: Vec<PrivateMessageData>,
+    // Cursors to load the previous and the next messages, when there are some
+    pub older_cursor: Option<String>,
+    pub newer_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PrivateMessageSessionKeysResponse {
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PrivateMessageAttachmentResponse {
+    pub code: String,
+    pub attachment: Option<PrivateMessageAttachmentData>,
 }
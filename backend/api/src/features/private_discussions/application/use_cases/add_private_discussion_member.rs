@@ -0,0 +1,126 @@
+// Add private discussion member use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::private_discussions::domain::entities::private_discussion::{
+    PrivateDiscussionRequestStatus, PRIVATE_DISCUSSION_GROUP_MAX_MEMBERS,
+};
+use crate::features::private_discussions::application::use_cases::apply_message_request_rules::ApplyMessageRequestRulesUseCase;
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+use uuid::Uuid;
+
+pub struct AddPrivateDiscussionMemberUseCase {
+    discussion_repo: PrivateDiscussionRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    user_repo: UserRepositoryImpl,
+    user_block_repo: UserBlockRepositoryImpl,
+    message_request_rules: ApplyMessageRequestRulesUseCase,
+}
+
+impl AddPrivateDiscussionMemberUseCase {
+    pub fn new(
+        discussion_repo: PrivateDiscussionRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        user_repo: UserRepositoryImpl,
+        user_block_repo: UserBlockRepositoryImpl,
+        message_request_rules: ApplyMessageRequestRulesUseCase,
+    ) -> Self {
+        Self {
+            discussion_repo,
+            participation_repo,
+            user_repo,
+            user_block_repo,
+            message_request_rules,
+        }
+    }
+
+    // Returns the members of the group, the new one included. The new member can only read
+    // the messages whose session keys are then distributed to them.
+    pub async fn execute(
+        &self,
+        requester: Uuid,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<PrivateDiscussionParticipation>, AppError> {
+        let discussion = self
+            .discussion_repo
+            .get_by_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        let mut participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        // Non-members do not learn that the discussion exists
+        let requester_participation = participations
+            .iter()
+            .find(|p| p.user_id == requester)
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        if !discussion.is_group {
+            return Err(AppError::PrivateDiscussionNotAGroup);
+        }
+        if !requester_participation.is_admin {
+            return Err(AppError::PrivateDiscussionMemberNotAdmin);
+        }
+        if participations.iter().any(|p| p.user_id == user_id) {
+            return Err(AppError::PrivateDiscussionMemberAlreadyExists);
+        }
+        if participations.len() >= PRIVATE_DISCUSSION_GROUP_MAX_MEMBERS {
+            return Err(AppError::PrivateDiscussionGroupTooManyMembers);
+        }
+
+        self.user_repo
+            .get_by_id_with_executor(user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::UserNotFound)?;
+
+        if self
+            .user_block_repo
+            .exists_between_with_executor(requester, user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+        {
+            return Err(AppError::UserBlocked);
+        }
+
+        let mut participation = PrivateDiscussionParticipation {
+            id: Uuid::new_v4(),
+            user_id,
+            discussion_id,
+            color: "blue".to_string(),
+            created_at: now(),
+            has_blocked: false,
+            is_admin: false,
+            muted_until: None,
+            archived_at: None,
+            pinned_at: None,
+            request_status: PrivateDiscussionRequestStatus::Accepted.to_string(),
+            requested_by: None,
+        };
+
+        // A stranger to the one adding them has to accept before joining
+        self.message_request_rules
+            .execute(&mut participation, requester, transaction)
+            .await?;
+
+        self.participation_repo
+            .create_with_executor(&participation, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionParticipationCreation)?;
+
+        participations.push(participation);
+
+        Ok(participations)
+    }
+}
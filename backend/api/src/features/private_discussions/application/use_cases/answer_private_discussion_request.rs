@@ -0,0 +1,101 @@
+// Answer private discussion request use case
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::private_discussions::domain::entities::private_discussion::{
+    PrivateDiscussion, PrivateDiscussionRequestAnswer, PrivateDiscussionRequestStatus,
+};
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::user_blocks::domain::entities::user_block::UserBlock;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+
+pub struct AnswerPrivateDiscussionRequestUseCase {
+    discussion_repo: PrivateDiscussionRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    user_block_repo: UserBlockRepositoryImpl,
+}
+
+impl AnswerPrivateDiscussionRequestUseCase {
+    pub fn new(
+        discussion_repo: PrivateDiscussionRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        user_block_repo: UserBlockRepositoryImpl,
+    ) -> Self {
+        Self {
+            discussion_repo,
+            participation_repo,
+            user_block_repo,
+        }
+    }
+
+    // Only the recipient of a message request can answer it, a declined request can still
+    // be accepted later. Blocking declines the request and blocks the requester everywhere.
+    // Returns the discussion, the participation of the user and the requester.
+    pub async fn execute(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        answer: PrivateDiscussionRequestAnswer,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(PrivateDiscussion, PrivateDiscussionParticipation, Uuid), AppError> {
+        let mut participation = self
+            .participation_repo
+            .get_by_user_and_discussion_with_executor(user_id, discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        let discussion = self
+            .discussion_repo
+            .get_by_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        if !participation.is_request_pending() && !participation.is_request_declined() {
+            return Err(AppError::PrivateDiscussionRequestNotFound);
+        }
+        let requester = participation
+            .requested_by
+            .ok_or(AppError::PrivateDiscussionRequestNotFound)?;
+
+        participation.request_status = match answer {
+            PrivateDiscussionRequestAnswer::Accept => PrivateDiscussionRequestStatus::Accepted,
+            PrivateDiscussionRequestAnswer::Decline | PrivateDiscussionRequestAnswer::Block => {
+                PrivateDiscussionRequestStatus::Declined
+            }
+        }
+        .to_string();
+
+        self.participation_repo
+            .update_request_status_with_executor(&participation, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionRequestUpdate)?;
+
+        if answer == PrivateDiscussionRequestAnswer::Block {
+            participation.has_blocked = true;
+            self.participation_repo
+                .update_with_executor(&participation, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PrivateDiscussionParticipationUpdate)?;
+
+            // Blocking twice is a no-op
+            let block = UserBlock {
+                id: Uuid::new_v4(),
+                blocker: user_id,
+                blocked: requester,
+                created_at: now(),
+            };
+            self.user_block_repo
+                .create_with_executor(&block, &mut **transaction)
+                .await
+                .map_err(|_| AppError::UserBlockCreation)?;
+        }
+
+        Ok((discussion, participation, requester))
+    }
+}
@@ -0,0 +1,95 @@
+// Apply message request rules use case - joining someone to a discussion without a
+// relationship with them needs their approval
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::challenges::infrastructure::repositories::challenge_participation_repository::ChallengeParticipationRepositoryImpl;
+use crate::features::private_discussions::domain::entities::private_discussion::{
+    PrivateDiscussionRequestStatus, PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_AGE_IN_DAYS,
+    PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_MAX_PER_DAY,
+};
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+
+pub struct ApplyMessageRequestRulesUseCase {
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    challenge_participation_repo: ChallengeParticipationRepositoryImpl,
+    user_repo: UserRepositoryImpl,
+}
+
+impl ApplyMessageRequestRulesUseCase {
+    pub fn new(
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        challenge_participation_repo: ChallengeParticipationRepositoryImpl,
+        user_repo: UserRepositoryImpl,
+    ) -> Self {
+        Self {
+            participation_repo,
+            challenge_participation_repo,
+            user_repo,
+        }
+    }
+
+    // The participation of someone the requester shares no challenge or discussion with
+    // starts as a message request
+    pub async fn execute(
+        &self,
+        participation: &mut PrivateDiscussionParticipation,
+        requester: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        let have_a_relationship = self
+            .challenge_participation_repo
+            .share_a_challenge_with_executor(requester, participation.user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            || self
+                .participation_repo
+                .share_a_discussion_with_executor(
+                    requester,
+                    participation.user_id,
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+        if have_a_relationship {
+            return Ok(());
+        }
+
+        let user = self
+            .user_repo
+            .get_by_id_with_executor(requester, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::UserNotFound)?;
+
+        // New accounts can only send a few requests per day
+        let at = now();
+        if user.created_at
+            > at - Duration::days(PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_AGE_IN_DAYS)
+        {
+            let request_count = self
+                .participation_repo
+                .count_requests_since_with_executor(
+                    requester,
+                    at - Duration::days(1),
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+
+            if request_count >= PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_MAX_PER_DAY {
+                return Err(AppError::PrivateDiscussionRequestLimitReached);
+            }
+        }
+
+        participation.request_status = PrivateDiscussionRequestStatus::Pending.to_string();
+        participation.requested_by = Some(requester);
+
+        Ok(())
+    }
+}
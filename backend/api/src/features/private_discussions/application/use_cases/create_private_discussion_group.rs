@@ -0,0 +1,146 @@
+// Create private discussion group use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::private_discussions::application::use_cases::apply_message_request_rules::ApplyMessageRequestRulesUseCase;
+use crate::features::private_discussions::domain::entities::private_discussion::PrivateDiscussionRequestStatus;
+use crate::features::private_discussions::domain::entities::private_discussion::{
+    PrivateDiscussion, PrivateMessageRetention, PRIVATE_DISCUSSION_GROUP_MAX_MEMBERS,
+    PRIVATE_DISCUSSION_GROUP_NAME_MAX_LENGTH,
+};
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+use uuid::Uuid;
+
+pub struct CreatePrivateDiscussionGroupUseCase {
+    discussion_repo: PrivateDiscussionRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    user_repo: UserRepositoryImpl,
+    user_block_repo: UserBlockRepositoryImpl,
+    message_request_rules: ApplyMessageRequestRulesUseCase,
+}
+
+impl CreatePrivateDiscussionGroupUseCase {
+    pub fn new(
+        discussion_repo: PrivateDiscussionRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        user_repo: UserRepositoryImpl,
+        user_block_repo: UserBlockRepositoryImpl,
+        message_request_rules: ApplyMessageRequestRulesUseCase,
+    ) -> Self {
+        Self {
+            discussion_repo,
+            participation_repo,
+            user_repo,
+            user_block_repo,
+            message_request_rules,
+        }
+    }
+
+    // The creator becomes the first admin of the group
+    pub async fn execute(
+        &self,
+        creator: Uuid,
+        name: &str,
+        color: &str,
+        members: &[Uuid],
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(PrivateDiscussion, Vec<PrivateDiscussionParticipation>), AppError> {
+        // Validate name
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(AppError::PrivateDiscussionGroupNameEmpty);
+        }
+        if name.chars().count() > PRIVATE_DISCUSSION_GROUP_NAME_MAX_LENGTH {
+            return Err(AppError::PrivateDiscussionGroupNameTooLong);
+        }
+
+        // Validate members
+        let mut member_ids: Vec<Uuid> = Vec::new();
+        for member in members {
+            if *member != creator && !member_ids.contains(member) {
+                member_ids.push(*member);
+            }
+        }
+        if member_ids.is_empty() {
+            return Err(AppError::PrivateDiscussionGroupMembersMissing);
+        }
+        if member_ids.len() + 1 > PRIVATE_DISCUSSION_GROUP_MAX_MEMBERS {
+            return Err(AppError::PrivateDiscussionGroupTooManyMembers);
+        }
+
+        for member in &member_ids {
+            self.user_repo
+                .get_by_id_with_executor(*member, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+                .ok_or(AppError::UserNotFound)?;
+
+            // Nobody can be put in a group by someone they blocked or who blocked them
+            if self
+                .user_block_repo
+                .exists_between_with_executor(creator, *member, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+            {
+                return Err(AppError::UserBlocked);
+            }
+        }
+
+        // Create discussion
+        let discussion = PrivateDiscussion {
+            id: Uuid::new_v4(),
+            created_at: now(),
+            name: Some(name.to_string()),
+            is_group: true,
+            message_retention: PrivateMessageRetention::Off.to_string(),
+            proposed_message_retention: None,
+            message_retention_proposed_by: None,
+        };
+
+        self.discussion_repo
+            .create_with_executor(&discussion, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionCreation)?;
+
+        // Create participations, members who are strangers to the creator get a message request
+        let mut participations = Vec::new();
+        for user_id in std::iter::once(creator).chain(member_ids) {
+            let mut participation = PrivateDiscussionParticipation {
+                id: Uuid::new_v4(),
+                user_id,
+                discussion_id: discussion.id,
+                color: if user_id == creator {
+                    color.to_string()
+                } else {
+                    "blue".to_string()
+                },
+                created_at: now(),
+                has_blocked: false,
+                is_admin: user_id == creator,
+                muted_until: None,
+                archived_at: None,
+                pinned_at: None,
+                request_status: PrivateDiscussionRequestStatus::Accepted.to_string(),
+                requested_by: None,
+            };
+            if user_id != creator {
+                self.message_request_rules
+                    .execute(&mut participation, creator, transaction)
+                    .await?;
+            }
+
+            self.participation_repo
+                .create_with_executor(&participation, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PrivateDiscussionParticipationCreation)?;
+
+            participations.push(participation);
+        }
+
+        Ok((discussion, participations))
+    }
+}
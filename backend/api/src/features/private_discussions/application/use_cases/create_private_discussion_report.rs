@@ -0,0 +1,139 @@
+// Create private discussion report use case
+
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::features::private_discussions::application::dto::requests::private_discussion_report::PrivateDiscussionReportMessageRequest;
+use crate::features::private_discussions::domain::entities::private_discussion_report::{
+    PrivateDiscussionReport, PrivateDiscussionReportMessage,
+    PRIVATE_DISCUSSION_REPORT_MAX_MESSAGES, PRIVATE_DISCUSSION_REPORT_REASON_MAX_LENGTH,
+};
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_message_repository::PrivateDiscussionReportMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_repository::PrivateDiscussionReportRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+
+pub struct CreatePrivateDiscussionReportUseCase {
+    report_repo: PrivateDiscussionReportRepositoryImpl,
+    report_message_repo: PrivateDiscussionReportMessageRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    message_repo: PrivateMessageRepositoryImpl,
+}
+
+impl CreatePrivateDiscussionReportUseCase {
+    pub fn new(
+        report_repo: PrivateDiscussionReportRepositoryImpl,
+        report_message_repo: PrivateDiscussionReportMessageRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        message_repo: PrivateMessageRepositoryImpl,
+    ) -> Self {
+        Self {
+            report_repo,
+            report_message_repo,
+            participation_repo,
+            message_repo,
+        }
+    }
+
+    // Each disclosed message is decrypted with the session key given by the reporter
+    // and must match the content they sent, so a report can't put words in someone's
+    // mouth. Only the verified plaintext is kept, the session keys are dropped.
+    pub async fn execute(
+        &self,
+        report: &mut PrivateDiscussionReport,
+        disclosed_messages: &[PrivateDiscussionReportMessageRequest],
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        // Both users must take part in the discussion
+        let participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(report.discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        if !participations.iter().any(|p| p.user_id == report.reporter) {
+            return Err(AppError::PrivateDiscussionNotFound);
+        }
+        if report.reported_user == report.reporter
+            || !participations
+                .iter()
+                .any(|p| p.user_id == report.reported_user)
+        {
+            return Err(AppError::PrivateDiscussionReportUserInvalid);
+        }
+
+        // Validate reason
+        report.reason = report.reason.trim().to_string();
+        if report.reason.is_empty() {
+            return Err(AppError::PrivateDiscussionReportReasonEmpty);
+        }
+        if report.reason.len() > PRIVATE_DISCUSSION_REPORT_REASON_MAX_LENGTH {
+            return Err(AppError::PrivateDiscussionReportReasonTooLong);
+        }
+
+        let message_ids = disclosed_messages
+            .iter()
+            .map(|m| m.message_id)
+            .collect::<HashSet<Uuid>>();
+        if message_ids.is_empty()
+            || message_ids.len() != disclosed_messages.len()
+            || message_ids.len() > PRIVATE_DISCUSSION_REPORT_MAX_MESSAGES
+        {
+            return Err(AppError::PrivateDiscussionReportMessagesInvalid);
+        }
+
+        // Check the disclosed messages against the stored ciphertext
+        let mut report_messages = Vec::new();
+        for disclosed_message in disclosed_messages {
+            let message = self
+                .message_repo
+                .get_by_id_with_executor(disclosed_message.message_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+                .filter(|m| m.discussion_id == report.discussion_id && !m.deleted)
+                .ok_or(AppError::PrivateDiscussionReportEvidenceInvalid)?;
+
+            if message
+                .decrypt_content(&disclosed_message.session_key)
+                .as_ref()
+                != Some(&disclosed_message.content)
+            {
+                return Err(AppError::PrivateDiscussionReportEvidenceInvalid);
+            }
+
+            report_messages.push(PrivateDiscussionReportMessage {
+                id: Uuid::new_v4(),
+                report_id: report.id,
+                message_id: Some(message.id),
+                creator: Some(message.creator),
+                content: disclosed_message.content.to_owned(),
+                message_created_at: message.created_at,
+            });
+        }
+
+        if !report_messages
+            .iter()
+            .any(|m| m.creator == Some(report.reported_user))
+        {
+            return Err(AppError::PrivateDiscussionReportMessagesInvalid);
+        }
+        report_messages.sort_by_key(|m| m.message_created_at);
+
+        // Create report
+        self.report_repo
+            .create_with_executor(report, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionReportCreation)?;
+
+        for report_message in &report_messages {
+            self.report_message_repo
+                .create_with_executor(report_message, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PrivateDiscussionReportCreation)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,52 +1,230 @@
 // Create private message use case
 
+use std::collections::BTreeSet;
+
 use crate::core::constants::errors::AppError;
-use crate::features::private_discussions::domain::entities::private_message::PrivateMessage;
+use crate::core::helpers::mock_now::now;
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
+use crate::features::private_discussions::domain::entities::private_discussion::PrivateDiscussionRequestStatus;
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::domain::entities::private_message::{
+    PrivateMessage, PRIVATE_MESSAGE_CONTENT_MAX_LENGTH, PRIVATE_MESSAGE_MAX_ATTACHMENTS,
+};
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::user_suspension_repository::UserSuspensionRepositoryImpl;
+use uuid::Uuid;
 
 pub struct CreatePrivateMessageUseCase {
     message_repo: PrivateMessageRepositoryImpl,
     discussion_repo: PrivateDiscussionRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    device_key_repo: DeviceKeyRepositoryImpl,
+    attachment_repo: PrivateMessageAttachmentRepositoryImpl,
+    suspension_repo: UserSuspensionRepositoryImpl,
+    receipt_repo: PrivateMessageReceiptRepositoryImpl,
 }
 
 impl CreatePrivateMessageUseCase {
     pub fn new(
         message_repo: PrivateMessageRepositoryImpl,
         discussion_repo: PrivateDiscussionRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        device_key_repo: DeviceKeyRepositoryImpl,
+        attachment_repo: PrivateMessageAttachmentRepositoryImpl,
+        suspension_repo: UserSuspensionRepositoryImpl,
+        receipt_repo: PrivateMessageReceiptRepositoryImpl,
     ) -> Self {
         Self {
             message_repo,
             discussion_repo,
+            participation_repo,
+            device_key_repo,
+            attachment_repo,
+            suspension_repo,
+            receipt_repo,
         }
     }
 
+    // Returns the participations of the discussion, to notify its members
     pub async fn execute(
         &self,
-        message: &PrivateMessage,
+        message: &mut PrivateMessage,
         transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
-    ) -> Result<(), AppError> {
-        // Verify discussion exists
-        self.discussion_repo
+    ) -> Result<Vec<PrivateDiscussionParticipation>, AppError> {
+        // Suspended users can not write until their suspension expires
+        if self
+            .suspension_repo
+            .get_active_by_user_id_with_executor(message.creator, now(), &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .is_some()
+        {
+            return Err(AppError::UserSuspended);
+        }
+
+        // Verify discussion exists and the creator takes part in it
+        let discussion = self
+            .discussion_repo
             .get_by_id_with_executor(message.discussion_id, &mut **transaction)
             .await
             .map_err(|_| AppError::DatabaseQuery)?
             .ok_or(AppError::PrivateDiscussionNotFound)?;
 
-        // Validate content
-        if message.content.is_empty() {
+        let participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(message.discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let mut creator_participation = participations
+            .iter()
+            .find(|p| p.user_id == message.creator)
+            .cloned()
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        // Nobody can write in a declined message request, replying to a request accepts it.
+        // In a group, a member who declined only leaves the others alone.
+        if creator_participation.is_request_declined()
+            || (!discussion.is_group && participations.iter().any(|p| p.is_request_declined()))
+        {
+            return Err(AppError::PrivateDiscussionRequestDeclined);
+        }
+        if creator_participation.is_request_pending() {
+            creator_participation.request_status =
+                PrivateDiscussionRequestStatus::Accepted.to_string();
+            self.participation_repo
+                .update_request_status_with_executor(&creator_participation, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PrivateDiscussionRequestUpdate)?;
+        }
+
+        // Messages of discussions with disappearing messages get an expiry date
+        message.expires_at = discussion.message_expires_at(message.created_at);
+
+        // Check attachments
+        let mut attachment_ids = Vec::new();
+        for attachment_id in &message.attachment_ids {
+            if !attachment_ids.contains(attachment_id) {
+                attachment_ids.push(*attachment_id);
+            }
+        }
+        if attachment_ids.len() > PRIVATE_MESSAGE_MAX_ATTACHMENTS {
+            return Err(AppError::PrivateMessageTooManyAttachments);
+        }
+        message.attachment_ids = attachment_ids;
+
+        // Validate content, a message with attachments may have no text
+        if message.content.is_empty() && message.attachment_ids.is_empty() {
             return Err(AppError::PrivateMessageContentEmpty);
         }
-        if message.content.len() > crate::features::private_discussions::domain::entities::private_message::PRIVATE_MESSAGE_CONTENT_MAX_LENGTH {
+        if message.content.len() > PRIVATE_MESSAGE_CONTENT_MAX_LENGTH {
             return Err(AppError::PrivateMessageContentTooLong);
         }
 
+        let member_ids: Vec<Uuid> = participations.iter().map(|p| p.user_id).collect();
+
+        // Members relying on device keys only read new messages through them: the copy of their
+        // account key pair on a revoked device must not open anything new, so the session keys
+        // encrypted for their account are dropped
+        let device_key_user_ids: BTreeSet<Uuid> = self
+            .device_key_repo
+            .get_user_ids_relying_on_device_keys_with_executor(
+                member_ids.clone(),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .into_iter()
+            .collect();
+
+        // In a group, the session key is encrypted for each current member, no more no less
+        if discussion.is_group {
+            let mut session_keys = message.get_encrypted_session_keys();
+            session_keys.retain(|user_id, _| !device_key_user_ids.contains(user_id));
+            let account_key_member_ids: BTreeSet<_> = member_ids
+                .iter()
+                .copied()
+                .filter(|id| !device_key_user_ids.contains(id))
+                .collect();
+            if !session_keys.keys().copied().eq(account_key_member_ids) {
+                return Err(AppError::PrivateMessageSessionKeysMismatch);
+            }
+
+            message.creator_encrypted_session_key = session_keys
+                .get(&message.creator)
+                .cloned()
+                .unwrap_or_default();
+            message.recipient_encrypted_session_key = String::new();
+            message.encrypted_session_keys = serde_json::json!(session_keys);
+        } else {
+            message.encrypted_session_keys = serde_json::json!({});
+
+            if device_key_user_ids.contains(&message.creator) {
+                message.creator_encrypted_session_key = String::new();
+            }
+            if member_ids
+                .iter()
+                .any(|id| *id != message.creator && device_key_user_ids.contains(id))
+            {
+                message.recipient_encrypted_session_key = String::new();
+            }
+        }
+
+        // The session key is encrypted for every device currently registered by a member, so
+        // that a revoked device is never included and no registered device is left out
+        let device_session_keys = message.get_device_encrypted_session_keys();
+        let device_key_ids: BTreeSet<_> = self
+            .device_key_repo
+            .get_by_user_ids_with_executor(member_ids, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .into_iter()
+            .map(|k| k.id)
+            .collect();
+        if !device_session_keys.keys().copied().eq(device_key_ids) {
+            return Err(AppError::PrivateMessageDeviceKeysMismatch);
+        }
+
         // Create message
         self.message_repo
             .create_with_executor(message, &mut **transaction)
             .await
             .map_err(|_| AppError::PrivateMessageCreation)?;
 
-        Ok(())
+        // Each member but the creator tracks the delivery of the message on their own
+        let recipients: Vec<Uuid> = participations
+            .iter()
+            .map(|p| p.user_id)
+            .filter(|id| *id != message.creator)
+            .collect();
+        self.receipt_repo
+            .create_for_recipients_with_executor(message.id, &recipients, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateMessageCreation)?;
+
+        // Only unused uploads of the creator can be attached
+        if !message.attachment_ids.is_empty() {
+            let attached_count = self
+                .attachment_repo
+                .attach_to_private_message_with_executor(
+                    &message.attachment_ids,
+                    message.creator,
+                    message.id,
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::PrivateMessageCreation)?
+                .rows_affected();
+
+            if attached_count != message.attachment_ids.len() as u64 {
+                return Err(AppError::PrivateMessageAttachmentNotFound);
+            }
+        }
+
+        Ok(participations)
     }
 }
@@ -0,0 +1,93 @@
+// Create private message attachment use case
+
+use std::sync::Arc;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::attachments::domain::services::storage_backend::{
+    delete_private_message_attachment_files, StorageBackend,
+};
+use crate::features::private_discussions::domain::entities::private_message_attachment::{
+    PrivateMessageAttachment, PRIVATE_MESSAGE_ATTACHMENTS_QUOTA_IN_BYTES,
+    PRIVATE_MESSAGE_ATTACHMENT_MAX_SIZE_IN_BYTES,
+};
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
+use tracing::error;
+use uuid::Uuid;
+
+pub struct CreatePrivateMessageAttachmentUseCase {
+    attachment_repo: PrivateMessageAttachmentRepositoryImpl,
+    storage_backend: Arc<dyn StorageBackend>,
+}
+
+impl CreatePrivateMessageAttachmentUseCase {
+    pub fn new(
+        attachment_repo: PrivateMessageAttachmentRepositoryImpl,
+        storage_backend: Arc<dyn StorageBackend>,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            storage_backend,
+        }
+    }
+
+    // The content is stored as is, it is encrypted by the client
+    pub async fn execute(
+        &self,
+        uploader: Uuid,
+        content: Vec<u8>,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<PrivateMessageAttachment, AppError> {
+        if content.is_empty() {
+            return Err(AppError::AttachmentFileMissing);
+        }
+        if content.len() > PRIVATE_MESSAGE_ATTACHMENT_MAX_SIZE_IN_BYTES {
+            return Err(AppError::PrivateMessageAttachmentTooLarge);
+        }
+
+        self.attachment_repo
+            .lock_uploader_with_executor(uploader, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+        let used_size = self
+            .attachment_repo
+            .get_total_size_by_uploader_with_executor(uploader, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+        if used_size + content.len() as i64 > PRIVATE_MESSAGE_ATTACHMENTS_QUOTA_IN_BYTES {
+            return Err(AppError::PrivateMessageAttachmentQuotaExceeded);
+        }
+
+        let id = Uuid::new_v4();
+        let attachment = PrivateMessageAttachment {
+            id,
+            uploader: Some(uploader),
+            private_message_id: None,
+            size_in_bytes: content.len() as i64,
+            storage_key: PrivateMessageAttachment::storage_key_for(id),
+            created_at: now(),
+        };
+
+        self.storage_backend
+            .put(&attachment.storage_key, &content)
+            .await
+            .map_err(|e| {
+                error!("Error: {}", e);
+                AppError::AttachmentStorage
+            })?;
+
+        // Files of a failed upload are not referenced anywhere
+        if self
+            .attachment_repo
+            .create_with_executor(&attachment, &mut **transaction)
+            .await
+            .is_err()
+        {
+            delete_private_message_attachment_files(self.storage_backend.as_ref(), &[attachment])
+                .await;
+            return Err(AppError::AttachmentCreation);
+        }
+
+        Ok(attachment)
+    }
+}
@@ -1,24 +1,34 @@
 // Delete private message use case
 
 use crate::core::constants::errors::AppError;
+use crate::features::private_discussions::domain::entities::private_message_attachment::PrivateMessageAttachment;
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
 use uuid::Uuid;
 
 pub struct DeletePrivateMessageUseCase {
     message_repo: PrivateMessageRepositoryImpl,
+    attachment_repo: PrivateMessageAttachmentRepositoryImpl,
 }
 
 impl DeletePrivateMessageUseCase {
-    pub fn new(message_repo: PrivateMessageRepositoryImpl) -> Self {
-        Self { message_repo }
+    pub fn new(
+        message_repo: PrivateMessageRepositoryImpl,
+        attachment_repo: PrivateMessageAttachmentRepositoryImpl,
+    ) -> Self {
+        Self {
+            message_repo,
+            attachment_repo,
+        }
     }
 
+    // Returns the deleted attachments, whose files have to be removed once committed
     pub async fn execute(
         &self,
         message_id: Uuid,
         creator_id: Uuid,
         transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<PrivateMessageAttachment>, AppError> {
         // Verify message exists and user is creator
         let message = self
             .message_repo
@@ -37,6 +47,9 @@ impl DeletePrivateMessageUseCase {
             .await
             .map_err(|_| AppError::PrivateMessageDeletion)?;
 
-        Ok(())
+        self.attachment_repo
+            .delete_by_private_message_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateMessageDeletion)
     }
 }
@@ -0,0 +1,65 @@
+// Distribute private message session keys use case
+
+use std::collections::BTreeMap;
+
+use crate::core::constants::errors::AppError;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use uuid::Uuid;
+
+pub struct DistributePrivateMessageSessionKeysUseCase {
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    message_repo: PrivateMessageRepositoryImpl,
+}
+
+impl DistributePrivateMessageSessionKeysUseCase {
+    pub fn new(
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        message_repo: PrivateMessageRepositoryImpl,
+    ) -> Self {
+        Self {
+            participation_repo,
+            message_repo,
+        }
+    }
+
+    // The server never sees the session keys in clear: a member who can decrypt them
+    // re-encrypts them with the public key of another member, typically a new one.
+    pub async fn execute(
+        &self,
+        requester: Uuid,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        session_keys: BTreeMap<Uuid, String>,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        let participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        if !participations.iter().any(|p| p.user_id == requester) {
+            return Err(AppError::PrivateDiscussionNotFound);
+        }
+        if !participations.iter().any(|p| p.user_id == user_id) {
+            return Err(AppError::PrivateDiscussionParticipationNotFound);
+        }
+
+        let (message_ids, encrypted_session_keys): (Vec<Uuid>, Vec<String>) =
+            session_keys.into_iter().unzip();
+
+        self.message_repo
+            .add_encrypted_session_keys_with_executor(
+                discussion_id,
+                user_id,
+                message_ids,
+                encrypted_session_keys,
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::PrivateMessageSessionKeysUpdate)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+// Get private discussion messages use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::private_discussions::domain::entities::private_message::{
+    split_private_message_page, PrivateMessage, PrivateMessageCursor, PrivateMessagePage,
+    PrivateMessagePageAnchor,
+};
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+pub struct GetPrivateDiscussionMessagesUseCase {
+    message_repo: PrivateMessageRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+}
+
+impl GetPrivateDiscussionMessagesUseCase {
+    pub fn new(
+        message_repo: PrivateMessageRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    ) -> Self {
+        Self {
+            message_repo,
+            participation_repo,
+        }
+    }
+
+    // Returns a page of messages, newest first, with the cursors to load the older
+    // and the newer messages. Expired messages are hidden until they are purged.
+    pub async fn execute(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        page: PrivateMessagePage,
+        now: DateTime<Utc>,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(Vec<PrivateMessage>, Option<String>, Option<String>), AppError> {
+        // Only members can read the messages of a discussion
+        self.participation_repo
+            .get_by_user_and_discussion_with_executor(user_id, discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        match page.anchor {
+            PrivateMessagePageAnchor::Latest => {
+                let (messages, older_cursor) = self
+                    .get_older(discussion_id, None, page.limit, now, transaction)
+                    .await?;
+                Ok((messages, older_cursor, None))
+            }
+            PrivateMessagePageAnchor::Before(cursor) => {
+                let (messages, older_cursor) = self
+                    .get_older(discussion_id, Some(cursor), page.limit, now, transaction)
+                    .await?;
+                Ok((messages, older_cursor, None))
+            }
+            PrivateMessagePageAnchor::After(cursor) => {
+                let (messages, newer_cursor) = self
+                    .get_newer(discussion_id, cursor, page.limit, now, transaction)
+                    .await?;
+                Ok((messages, None, newer_cursor))
+            }
+            PrivateMessagePageAnchor::Around(message_id) => {
+                let message = self
+                    .message_repo
+                    .get_by_id_with_executor(message_id, &mut **transaction)
+                    .await
+                    .map_err(|_| AppError::DatabaseQuery)?
+                    .filter(|m| m.discussion_id == discussion_id)
+                    .filter(|m| m.expires_at.is_none_or(|expires_at| expires_at > now))
+                    .ok_or(AppError::PrivateMessageNotFound)?;
+                let cursor = PrivateMessageCursor::from_message(&message);
+
+                // The message is in the middle of the page
+                let older_limit = (page.limit - 1) / 2;
+                let newer_limit = page.limit - 1 - older_limit;
+
+                let (older_messages, older_cursor) = self
+                    .get_older(discussion_id, Some(cursor), older_limit, now, transaction)
+                    .await?;
+                let (mut messages, newer_cursor) = self
+                    .get_newer(discussion_id, cursor, newer_limit, now, transaction)
+                    .await?;
+
+                messages.push(message);
+                messages.extend(older_messages);
+
+                Ok((messages, older_cursor, newer_cursor))
+            }
+        }
+    }
+
+    async fn get_older(
+        &self,
+        discussion_id: Uuid,
+        cursor: Option<PrivateMessageCursor>,
+        limit: i64,
+        now: DateTime<Utc>,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(Vec<PrivateMessage>, Option<String>), AppError> {
+        let messages = self
+            .message_repo
+            .get_before_with_executor(discussion_id, cursor, limit + 1, now, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        Ok(split_private_message_page(messages, limit, cursor))
+    }
+
+    async fn get_newer(
+        &self,
+        discussion_id: Uuid,
+        cursor: PrivateMessageCursor,
+        limit: i64,
+        now: DateTime<Utc>,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(Vec<PrivateMessage>, Option<String>), AppError> {
+        let messages = self
+            .message_repo
+            .get_after_with_executor(discussion_id, cursor, limit + 1, now, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let (mut messages, newer_cursor) =
+            split_private_message_page(messages, limit, Some(cursor));
+        messages.reverse();
+
+        Ok((messages, newer_cursor))
+    }
+}
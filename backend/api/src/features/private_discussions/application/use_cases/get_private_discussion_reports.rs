@@ -0,0 +1,49 @@
+// Get private discussion reports use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::private_discussions::domain::entities::private_discussion_report::PrivateDiscussionReportEntry;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_message_repository::PrivateDiscussionReportMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_repository::PrivateDiscussionReportRepositoryImpl;
+
+pub struct GetPrivateDiscussionReportsUseCase {
+    report_repo: PrivateDiscussionReportRepositoryImpl,
+    report_message_repo: PrivateDiscussionReportMessageRepositoryImpl,
+}
+
+impl GetPrivateDiscussionReportsUseCase {
+    pub fn new(
+        report_repo: PrivateDiscussionReportRepositoryImpl,
+        report_message_repo: PrivateDiscussionReportMessageRepositoryImpl,
+    ) -> Self {
+        Self {
+            report_repo,
+            report_message_repo,
+        }
+    }
+
+    // Pending reports, oldest first, with the excerpt disclosed by each reporter
+    pub async fn execute(
+        &self,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<PrivateDiscussionReportEntry>, AppError> {
+        let reports = self
+            .report_repo
+            .get_pending_with_executor(&mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let messages = self
+            .report_message_repo
+            .get_by_report_ids_with_executor(
+                reports.iter().map(|r| r.id).collect(),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        Ok(reports
+            .iter()
+            .map(|r| r.to_private_discussion_report_entry(&messages))
+            .collect())
+    }
+}
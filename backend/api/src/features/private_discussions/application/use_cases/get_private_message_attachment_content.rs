@@ -0,0 +1,88 @@
+// Get private message attachment content use case
+
+use std::sync::Arc;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::attachments::domain::services::storage_backend::StorageBackend;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use tracing::error;
+use uuid::Uuid;
+
+pub struct GetPrivateMessageAttachmentContentUseCase {
+    attachment_repo: PrivateMessageAttachmentRepositoryImpl,
+    message_repo: PrivateMessageRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    storage_backend: Arc<dyn StorageBackend>,
+}
+
+impl GetPrivateMessageAttachmentContentUseCase {
+    pub fn new(
+        attachment_repo: PrivateMessageAttachmentRepositoryImpl,
+        message_repo: PrivateMessageRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        storage_backend: Arc<dyn StorageBackend>,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            message_repo,
+            participation_repo,
+            storage_backend,
+        }
+    }
+
+    // Attachments are only visible to their uploader until they are sent,
+    // then to the members of the discussion of their message
+    pub async fn execute(
+        &self,
+        attachment_id: Uuid,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<u8>, AppError> {
+        let attachment = self
+            .attachment_repo
+            .get_by_id_with_executor(attachment_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateMessageAttachmentNotFound)?;
+
+        match attachment.private_message_id {
+            None => {
+                if attachment.uploader != Some(user_id) {
+                    return Err(AppError::PrivateMessageAttachmentNotFound);
+                }
+            }
+            Some(private_message_id) => {
+                let message = self
+                    .message_repo
+                    .get_by_id_with_executor(private_message_id, &mut **transaction)
+                    .await
+                    .map_err(|_| AppError::DatabaseQuery)?
+                    .filter(|m| !m.deleted)
+                    .filter(|m| m.expires_at.is_none_or(|expires_at| expires_at > now()))
+                    .ok_or(AppError::PrivateMessageAttachmentNotFound)?;
+
+                self.participation_repo
+                    .get_by_user_and_discussion_with_executor(
+                        user_id,
+                        message.discussion_id,
+                        &mut **transaction,
+                    )
+                    .await
+                    .map_err(|_| AppError::DatabaseQuery)?
+                    .ok_or(AppError::PrivateMessageAttachmentNotFound)?;
+            }
+        }
+
+        self.storage_backend
+            .get(&attachment.storage_key)
+            .await
+            .map_err(|e| {
+                error!("Error: {}", e);
+                AppError::AttachmentStorage
+            })?
+            .ok_or(AppError::PrivateMessageAttachmentNotFound)
+    }
+}
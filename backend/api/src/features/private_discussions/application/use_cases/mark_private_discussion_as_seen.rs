@@ -0,0 +1,95 @@
+// Mark private discussion as seen use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::domain::entities::private_message::PrivateMessage;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use uuid::Uuid;
+
+pub struct MarkPrivateDiscussionAsSeenUseCase {
+    message_repo: PrivateMessageRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    receipt_repo: PrivateMessageReceiptRepositoryImpl,
+}
+
+impl MarkPrivateDiscussionAsSeenUseCase {
+    pub fn new(
+        message_repo: PrivateMessageRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        receipt_repo: PrivateMessageReceiptRepositoryImpl,
+    ) -> Self {
+        Self {
+            message_repo,
+            participation_repo,
+            receipt_repo,
+        }
+    }
+
+    // Marks every message received up to the given one as seen by the user.
+    // Returns the messages whose receipt was updated and the members of the discussion.
+    pub async fn execute(
+        &self,
+        discussion_id: Uuid,
+        message_id: Uuid,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(Vec<PrivateMessage>, Vec<PrivateDiscussionParticipation>), AppError> {
+        let participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        if !participations.iter().any(|p| p.user_id == user_id) {
+            return Err(AppError::PrivateDiscussionNotFound);
+        }
+
+        let last_message = self
+            .message_repo
+            .get_by_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .filter(|m| m.discussion_id == discussion_id)
+            .ok_or(AppError::PrivateMessageNotFound)?;
+
+        let seen_message_ids: Vec<Uuid> = self
+            .receipt_repo
+            .mark_as_seen_with_executor(
+                discussion_id,
+                user_id,
+                last_message.created_at,
+                now(),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::PrivateMessageUpdate)?
+            .into_iter()
+            .map(|r| r.message_id)
+            .collect();
+
+        let mut messages = self
+            .message_repo
+            .get_by_ids_with_executor(&seen_message_ids, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+        let receipts = self
+            .receipt_repo
+            .get_by_message_ids_with_executor(&seen_message_ids, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        // Other recipients may still have to see them
+        for message in messages.iter_mut() {
+            message.update_status(&receipts);
+            self.message_repo
+                .update_with_executor(message, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PrivateMessageUpdate)?;
+        }
+
+        Ok((messages, participations))
+    }
+}
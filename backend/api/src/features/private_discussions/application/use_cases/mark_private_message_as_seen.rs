@@ -0,0 +1,88 @@
+// Mark private message as seen use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::domain::entities::private_message::PrivateMessage;
+use crate::features::private_discussions::domain::entities::private_message_receipt::PrivateMessageReceipt;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use uuid::Uuid;
+
+pub struct MarkPrivateMessageAsSeenUseCase {
+    message_repo: PrivateMessageRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    receipt_repo: PrivateMessageReceiptRepositoryImpl,
+}
+
+impl MarkPrivateMessageAsSeenUseCase {
+    pub fn new(
+        message_repo: PrivateMessageRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        receipt_repo: PrivateMessageReceiptRepositoryImpl,
+    ) -> Self {
+        Self {
+            message_repo,
+            participation_repo,
+            receipt_repo,
+        }
+    }
+
+    // Returns the updated message and the members of its discussion
+    pub async fn execute(
+        &self,
+        message_id: Uuid,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(PrivateMessage, Vec<PrivateDiscussionParticipation>), AppError> {
+        let mut private_message = self
+            .message_repo
+            .get_by_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateMessageNotFound)?;
+
+        let participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(private_message.discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        // Any member but the creator of the message can mark it as seen
+        if private_message.creator == user_id
+            || !participations.iter().any(|p| p.user_id == user_id)
+        {
+            return Err(AppError::PrivateMessageUpdateNotDoneByCreator);
+        }
+
+        let mut receipts = self
+            .receipt_repo
+            .get_by_message_ids_with_executor(&[private_message.id], &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        // Members who joined after the message was sent get their receipt on the way
+        let index = match receipts.iter().position(|r| r.user_id == user_id) {
+            Some(index) => index,
+            None => {
+                receipts.push(PrivateMessageReceipt::new(private_message.id, user_id));
+                receipts.len() - 1
+            }
+        };
+        let receipt = &mut receipts[index];
+        receipt.mark_as_seen(now());
+        self.receipt_repo
+            .upsert_with_executor(receipt, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateMessageUpdate)?;
+
+        private_message.update_status(&receipts);
+        self.message_repo
+            .update_with_executor(&private_message, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateMessageUpdate)?;
+
+        Ok((private_message, participations))
+    }
+}
@@ -1,7 +1,25 @@
 // Private discussions use cases
 
+pub mod add_private_discussion_member;
+pub mod answer_private_discussion_request;
+pub mod apply_message_request_rules;
 pub mod create_private_discussion;
+pub mod create_private_discussion_group;
+pub mod create_private_discussion_report;
 pub mod create_private_message;
+pub mod create_private_message_attachment;
 pub mod delete_private_message;
+pub mod distribute_private_message_session_keys;
+pub mod get_private_discussion_messages;
+pub mod get_private_discussion_reports;
+pub mod get_private_message_attachment_content;
+pub mod mark_private_discussion_as_seen;
+pub mod mark_private_message_as_delivered;
+pub mod mark_private_message_as_seen;
+pub mod remove_private_discussion_member;
+pub mod resolve_private_discussion_report;
+pub mod update_private_discussion_member;
 pub mod update_private_discussion_participation;
+pub mod update_private_discussion_retention;
+pub mod update_private_discussion_settings;
 pub mod update_private_message;
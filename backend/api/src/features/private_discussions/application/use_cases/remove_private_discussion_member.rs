@@ -0,0 +1,107 @@
+// Remove private discussion member use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use uuid::Uuid;
+
+pub struct RemovePrivateDiscussionMemberUseCase {
+    discussion_repo: PrivateDiscussionRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    message_repo: PrivateMessageRepositoryImpl,
+    receipt_repo: PrivateMessageReceiptRepositoryImpl,
+}
+
+impl RemovePrivateDiscussionMemberUseCase {
+    pub fn new(
+        discussion_repo: PrivateDiscussionRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+        message_repo: PrivateMessageRepositoryImpl,
+        receipt_repo: PrivateMessageReceiptRepositoryImpl,
+    ) -> Self {
+        Self {
+            discussion_repo,
+            participation_repo,
+            message_repo,
+            receipt_repo,
+        }
+    }
+
+    // Members can leave a group, admins can also kick other members. Returns the remaining
+    // members of the group.
+    pub async fn execute(
+        &self,
+        requester: Uuid,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<PrivateDiscussionParticipation>, AppError> {
+        let discussion = self
+            .discussion_repo
+            .get_by_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        let participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let requester_participation = participations
+            .iter()
+            .find(|p| p.user_id == requester)
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        if !discussion.is_group {
+            return Err(AppError::PrivateDiscussionNotAGroup);
+        }
+        if requester != user_id && !requester_participation.is_admin {
+            return Err(AppError::PrivateDiscussionMemberNotAdmin);
+        }
+
+        let removed_participation = participations
+            .iter()
+            .find(|p| p.user_id == user_id)
+            .ok_or(AppError::PrivateDiscussionParticipationNotFound)?;
+
+        self.participation_repo
+            .delete_with_executor(removed_participation.id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionParticipationDeletion)?;
+
+        // Without their session keys, the former member can not read the discussion anymore
+        self.message_repo
+            .remove_encrypted_session_keys_with_executor(discussion_id, user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateMessageSessionKeysUpdate)?;
+
+        self.receipt_repo
+            .delete_by_discussion_and_user_with_executor(discussion_id, user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionParticipationDeletion)?;
+
+        let mut remaining_participations: Vec<PrivateDiscussionParticipation> = participations
+            .into_iter()
+            .filter(|p| p.user_id != user_id)
+            .collect();
+
+        // When the last admin leaves, the oldest member takes over
+        if !remaining_participations.iter().any(|p| p.is_admin) {
+            if let Some(oldest_participation) = remaining_participations.first_mut() {
+                oldest_participation.is_admin = true;
+
+                self.participation_repo
+                    .update_is_admin_with_executor(oldest_participation, &mut **transaction)
+                    .await
+                    .map_err(|_| AppError::PrivateDiscussionParticipationUpdate)?;
+            }
+        }
+
+        Ok(remaining_participations)
+    }
+}
@@ -0,0 +1,105 @@
+// Resolve private discussion report use case
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::private_discussions::domain::entities::private_discussion_report::{
+    PrivateDiscussionReport, PrivateDiscussionReportAction,
+};
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_repository::PrivateDiscussionReportRepositoryImpl;
+use crate::features::public_discussions::domain::entities::public_message_moderation::{
+    MODERATION_DEFAULT_SUSPENSION_IN_DAYS, MODERATION_MAX_SUSPENSION_IN_DAYS,
+    MODERATION_NOTE_MAX_LENGTH,
+};
+use crate::features::public_discussions::domain::entities::user_suspension::UserSuspension;
+use crate::features::public_discussions::infrastructure::repositories::user_suspension_repository::UserSuspensionRepositoryImpl;
+
+pub struct ResolvePrivateDiscussionReportUseCase {
+    report_repo: PrivateDiscussionReportRepositoryImpl,
+    suspension_repo: UserSuspensionRepositoryImpl,
+}
+
+impl ResolvePrivateDiscussionReportUseCase {
+    pub fn new(
+        report_repo: PrivateDiscussionReportRepositoryImpl,
+        suspension_repo: UserSuspensionRepositoryImpl,
+    ) -> Self {
+        Self {
+            report_repo,
+            suspension_repo,
+        }
+    }
+
+    // Closes the report and applies the decision to the reported user
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute(
+        &self,
+        report_id: Uuid,
+        moderator: Uuid,
+        action: PrivateDiscussionReportAction,
+        note: Option<String>,
+        suspension_in_days: Option<i64>,
+        can_suspend: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<PrivateDiscussionReport, AppError> {
+        if action == PrivateDiscussionReportAction::SuspendOffender && !can_suspend {
+            return Err(AppError::MissingPermission);
+        }
+
+        // Validate note
+        let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        if let Some(note) = &note {
+            if note.len() > MODERATION_NOTE_MAX_LENGTH {
+                return Err(AppError::ModerationNoteTooLong);
+            }
+        } else if action != PrivateDiscussionReportAction::Dismiss {
+            return Err(AppError::ModerationNoteEmpty);
+        }
+
+        let suspension_in_days =
+            suspension_in_days.unwrap_or(MODERATION_DEFAULT_SUSPENSION_IN_DAYS);
+        if action == PrivateDiscussionReportAction::SuspendOffender
+            && !(1..=MODERATION_MAX_SUSPENSION_IN_DAYS).contains(&suspension_in_days)
+        {
+            return Err(AppError::ModerationSuspensionDurationInvalid);
+        }
+
+        let mut report = self
+            .report_repo
+            .get_by_id_with_executor(report_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .filter(|r| r.resolved_at.is_none())
+            .ok_or(AppError::PrivateDiscussionReportNotFound)?;
+
+        let resolved_at = now();
+        report.resolved_at = Some(resolved_at);
+        report.moderator = Some(moderator);
+        report.action = Some(action.to_string());
+        report.note = note;
+
+        self.report_repo
+            .resolve_with_executor(&report, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionReportUpdate)?;
+
+        if action == PrivateDiscussionReportAction::SuspendOffender {
+            let suspension = UserSuspension {
+                id: Uuid::new_v4(),
+                user_id: report.reported_user,
+                decision_id: None,
+                created_at: resolved_at,
+                expires_at: resolved_at + Duration::days(suspension_in_days),
+            };
+
+            self.suspension_repo
+                .create_with_executor(&suspension, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PrivateDiscussionReportUpdate)?;
+        }
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,80 @@
+// Update private discussion member use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use uuid::Uuid;
+
+pub struct UpdatePrivateDiscussionMemberUseCase {
+    discussion_repo: PrivateDiscussionRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+}
+
+impl UpdatePrivateDiscussionMemberUseCase {
+    pub fn new(
+        discussion_repo: PrivateDiscussionRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    ) -> Self {
+        Self {
+            discussion_repo,
+            participation_repo,
+        }
+    }
+
+    // Only admins can promote or demote members, a group always keeps an admin. Returns the
+    // members of the group.
+    pub async fn execute(
+        &self,
+        requester: Uuid,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        is_admin: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<PrivateDiscussionParticipation>, AppError> {
+        let discussion = self
+            .discussion_repo
+            .get_by_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        let mut participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let requester_participation = participations
+            .iter()
+            .find(|p| p.user_id == requester)
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        if !discussion.is_group {
+            return Err(AppError::PrivateDiscussionNotAGroup);
+        }
+        if !requester_participation.is_admin {
+            return Err(AppError::PrivateDiscussionMemberNotAdmin);
+        }
+
+        let admin_count = participations.iter().filter(|p| p.is_admin).count();
+
+        let participation = participations
+            .iter_mut()
+            .find(|p| p.user_id == user_id)
+            .ok_or(AppError::PrivateDiscussionParticipationNotFound)?;
+
+        if participation.is_admin && !is_admin && admin_count == 1 {
+            return Err(AppError::PrivateDiscussionLastAdmin);
+        }
+
+        participation.is_admin = is_admin;
+
+        self.participation_repo
+            .update_is_admin_with_executor(participation, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionParticipationUpdate)?;
+
+        Ok(participations)
+    }
+}
@@ -0,0 +1,80 @@
+// Update private discussion retention use case
+
+use std::str::FromStr;
+
+use crate::core::constants::errors::AppError;
+use crate::features::private_discussions::domain::entities::private_discussion::{
+    PrivateDiscussion, PrivateMessageRetention,
+};
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use uuid::Uuid;
+
+pub struct UpdatePrivateDiscussionRetentionUseCase {
+    discussion_repo: PrivateDiscussionRepositoryImpl,
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+}
+
+impl UpdatePrivateDiscussionRetentionUseCase {
+    pub fn new(
+        discussion_repo: PrivateDiscussionRepositoryImpl,
+        participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+    ) -> Self {
+        Self {
+            discussion_repo,
+            participation_repo,
+        }
+    }
+
+    // A member proposes a retention and it applies once another member asks for the same one.
+    // Asking for the current retention withdraws the pending proposal.
+    pub async fn execute(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        message_retention: &str,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(PrivateDiscussion, Vec<PrivateDiscussionParticipation>), AppError> {
+        let message_retention = PrivateMessageRetention::from_str(message_retention)
+            .map_err(|_| AppError::PrivateDiscussionRetentionInvalid)?
+            .to_string();
+
+        let participations = self
+            .participation_repo
+            .get_by_discussion_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        if !participations.iter().any(|p| p.user_id == user_id) {
+            return Err(AppError::PrivateDiscussionNotFound);
+        }
+
+        let mut discussion = self
+            .discussion_repo
+            .get_by_id_with_executor(discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateDiscussionNotFound)?;
+
+        let accepts_proposal = discussion.proposed_message_retention.as_ref()
+            == Some(&message_retention)
+            && discussion.message_retention_proposed_by != Some(user_id);
+
+        if accepts_proposal || discussion.message_retention == message_retention {
+            discussion.message_retention = message_retention;
+            discussion.proposed_message_retention = None;
+            discussion.message_retention_proposed_by = None;
+        } else {
+            discussion.proposed_message_retention = Some(message_retention);
+            discussion.message_retention_proposed_by = Some(user_id);
+        }
+
+        self.discussion_repo
+            .update_message_retention_with_executor(&discussion, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        Ok((discussion, participations))
+    }
+}
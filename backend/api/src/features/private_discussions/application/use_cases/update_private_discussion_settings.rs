@@ -0,0 +1,54 @@
+// Update private discussion settings use case
+
+use chrono::{DateTime, Utc};
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use uuid::Uuid;
+
+pub struct UpdatePrivateDiscussionSettingsUseCase {
+    participation_repo: PrivateDiscussionParticipationRepositoryImpl,
+}
+
+impl UpdatePrivateDiscussionSettingsUseCase {
+    pub fn new(participation_repo: PrivateDiscussionParticipationRepositoryImpl) -> Self {
+        Self { participation_repo }
+    }
+
+    // Settings only concern the user changing them, other members are not told about it
+    pub async fn execute(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        muted_until: Option<DateTime<Utc>>,
+        is_archived: bool,
+        is_pinned: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<PrivateDiscussionParticipation, AppError> {
+        let mut participation = self
+            .participation_repo
+            .get_by_user_and_discussion_with_executor(user_id, discussion_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PrivateDiscussionParticipationNotFound)?;
+
+        participation.muted_until = muted_until.filter(|muted_until| *muted_until > now());
+        // Archiving again hides the discussion until the next message
+        participation.archived_at = if is_archived { Some(now()) } else { None };
+        // Pinning again keeps the position of the discussion among the pinned ones
+        participation.pinned_at = if is_pinned {
+            participation.pinned_at.or(Some(now()))
+        } else {
+            None
+        };
+
+        self.participation_repo
+            .update_settings_with_executor(&participation, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PrivateDiscussionParticipationUpdate)?;
+
+        Ok(participation)
+    }
+}
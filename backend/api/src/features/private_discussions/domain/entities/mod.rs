@@ -3,5 +3,9 @@
 pub mod channels_data;
 pub mod private_discussion;
 pub mod private_discussion_participation;
+pub mod private_discussion_report;
 pub mod private_message;
+pub mod private_message_attachment;
+pub mod private_message_receipt;
+pub mod socket_message;
 pub mod users_data;
@@ -1,14 +1,140 @@
-use chrono::{DateTime, Utc};
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 use uuid::Uuid;
 
+use super::private_discussion_participation::PrivateDiscussionParticipation;
 use super::private_message::PrivateMessageData;
 
+pub const PRIVATE_DISCUSSION_GROUP_NAME_MAX_LENGTH: usize = 100;
+// Including the creator of the group
+pub const PRIVATE_DISCUSSION_GROUP_MAX_MEMBERS: usize = 50;
+// Accounts younger than this can only send a few message requests per day
+pub const PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_AGE_IN_DAYS: i64 = 7;
+pub const PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_MAX_PER_DAY: i64 = 5;
+
+// How long messages are kept before disappearing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PrivateMessageRetention {
+    #[serde(rename = "off")]
+    Off,
+    #[serde(rename = "24h")]
+    OneDay,
+    #[serde(rename = "7d")]
+    OneWeek,
+    #[serde(rename = "30d")]
+    OneMonth,
+}
+
+impl PrivateMessageRetention {
+    pub const ALL: [PrivateMessageRetention; 4] = [
+        PrivateMessageRetention::Off,
+        PrivateMessageRetention::OneDay,
+        PrivateMessageRetention::OneWeek,
+        PrivateMessageRetention::OneMonth,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrivateMessageRetention::Off => "off",
+            PrivateMessageRetention::OneDay => "24h",
+            PrivateMessageRetention::OneWeek => "7d",
+            PrivateMessageRetention::OneMonth => "30d",
+        }
+    }
+
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            PrivateMessageRetention::Off => None,
+            PrivateMessageRetention::OneDay => Some(Duration::hours(24)),
+            PrivateMessageRetention::OneWeek => Some(Duration::days(7)),
+            PrivateMessageRetention::OneMonth => Some(Duration::days(30)),
+        }
+    }
+}
+
+impl fmt::Display for PrivateMessageRetention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PrivateMessageRetention {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PrivateMessageRetention::ALL
+            .into_iter()
+            .find(|retention| retention.as_str() == s)
+            .ok_or_else(|| format!("Unknown private message retention: {}", s))
+    }
+}
+
+// Participations created by someone the user has no relationship with are message requests
+// until the user accepts them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrivateDiscussionRequestStatus {
+    Pending,
+    Accepted,
+    Declined,
+}
+
+impl PrivateDiscussionRequestStatus {
+    pub const ALL: [PrivateDiscussionRequestStatus; 3] = [
+        PrivateDiscussionRequestStatus::Pending,
+        PrivateDiscussionRequestStatus::Accepted,
+        PrivateDiscussionRequestStatus::Declined,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrivateDiscussionRequestStatus::Pending => "pending",
+            PrivateDiscussionRequestStatus::Accepted => "accepted",
+            PrivateDiscussionRequestStatus::Declined => "declined",
+        }
+    }
+}
+
+impl fmt::Display for PrivateDiscussionRequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PrivateDiscussionRequestStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PrivateDiscussionRequestStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == s)
+            .ok_or_else(|| format!("Unknown private discussion request status: {}", s))
+    }
+}
+
+// What the recipient of a message request can do with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrivateDiscussionRequestAnswer {
+    Accept,
+    Decline,
+    Block,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
 pub struct PrivateDiscussion {
     pub id: Uuid,
     pub created_at: DateTime<Utc>,
+    // Only groups have a name
+    pub name: Option<String>,
+    pub is_group: bool,
+    pub message_retention: String,
+    // A change of retention only applies once another member agrees with it
+    pub proposed_message_retention: Option<String>,
+    pub message_retention_proposed_by: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -20,13 +146,23 @@ pub struct PrivateDiscussionData {
     pub last_message: Option<PrivateMessageData>,
     pub recipient_id: Option<Uuid>,
     pub unseen_messages: i64,
+    pub name: Option<String>,
+    pub is_group: bool,
+    pub message_retention: String,
+    pub proposed_message_retention: Option<String>,
+    pub message_retention_proposed_by: Option<Uuid>,
+    pub muted_until: Option<DateTime<Utc>>,
+    pub is_archived: bool,
+    pub is_pinned: bool,
+    pub request_status: String,
+    pub requested_by: Option<Uuid>,
 }
 
 impl PrivateDiscussion {
+    // The participation is the one of the user the discussion is sent to
     pub fn to_private_discussion_data(
         &self,
-        color: Option<String>,
-        has_blocked: Option<bool>,
+        participation: Option<&PrivateDiscussionParticipation>,
         last_message: Option<PrivateMessageData>,
         recipient_id: Option<Uuid>,
         unseen_messages: i64,
@@ -34,11 +170,32 @@ impl PrivateDiscussion {
         PrivateDiscussionData {
             id: self.id,
             created_at: self.created_at,
-            color,
-            has_blocked,
+            color: participation.map(|p| p.color.clone()),
+            has_blocked: participation.map(|p| p.has_blocked),
+            muted_until: participation.and_then(|p| p.muted_until),
+            is_archived: participation
+                .is_some_and(|p| p.is_archived(last_message.as_ref().map(|m| m.created_at))),
+            is_pinned: participation.is_some_and(|p| p.pinned_at.is_some()),
             last_message,
             recipient_id,
             unseen_messages,
+            name: self.name.to_owned(),
+            is_group: self.is_group,
+            message_retention: self.message_retention.to_owned(),
+            proposed_message_retention: self.proposed_message_retention.to_owned(),
+            message_retention_proposed_by: self.message_retention_proposed_by,
+            request_status: participation
+                .map(|p| p.request_status.clone())
+                .unwrap_or_else(|| PrivateDiscussionRequestStatus::Accepted.to_string()),
+            requested_by: participation.and_then(|p| p.requested_by),
         }
     }
+
+    // Date at which a message sent now in this discussion disappears
+    pub fn message_expires_at(&self, created_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        PrivateMessageRetention::from_str(&self.message_retention)
+            .ok()
+            .and_then(|retention| retention.duration())
+            .map(|duration| created_at + duration)
+    }
 }
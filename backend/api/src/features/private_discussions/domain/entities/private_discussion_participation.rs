@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 use uuid::Uuid;
 
+use super::private_discussion::PrivateDiscussionRequestStatus;
+
 #[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
 pub struct PrivateDiscussionParticipation {
     pub id: Uuid,
@@ -11,6 +13,16 @@ pub struct PrivateDiscussionParticipation {
     pub discussion_id: Uuid,
     pub created_at: DateTime<Utc>,
     pub has_blocked: bool,
+    // Admins of a group manage its members
+    pub is_admin: bool,
+    // No push notifications are sent for this discussion until then
+    pub muted_until: Option<DateTime<Utc>>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub pinned_at: Option<DateTime<Utc>>,
+    // Someone joined by a stranger has to accept the message request first
+    pub request_status: String,
+    // Only set for participations that started as a message request
+    pub requested_by: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -21,6 +33,20 @@ pub struct PrivateDiscussionParticipationData {
     pub discussion_id: Uuid,
     pub created_at: DateTime<Utc>,
     pub has_blocked: bool,
+    pub is_admin: bool,
+    pub muted_until: Option<DateTime<Utc>>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub pinned_at: Option<DateTime<Utc>>,
+    pub request_status: String,
+    pub requested_by: Option<Uuid>,
+}
+
+// What the other members of a group can see about a participation
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PrivateDiscussionMemberData {
+    pub user_id: Uuid,
+    pub is_admin: bool,
+    pub joined_at: DateTime<Utc>,
 }
 
 impl PrivateDiscussionParticipation {
@@ -32,6 +58,48 @@ impl PrivateDiscussionParticipation {
             discussion_id: self.discussion_id,
             created_at: self.created_at,
             has_blocked: self.has_blocked,
+            is_admin: self.is_admin,
+            muted_until: self.muted_until,
+            archived_at: self.archived_at,
+            pinned_at: self.pinned_at,
+            request_status: self.request_status.to_owned(),
+            requested_by: self.requested_by,
+        }
+    }
+
+    pub fn is_muted(&self, now: DateTime<Utc>) -> bool {
+        self.muted_until
+            .is_some_and(|muted_until| muted_until > now)
+    }
+
+    // A discussion stays archived until a message newer than the archiving is sent
+    pub fn is_archived(&self, last_message_created_at: Option<DateTime<Utc>>) -> bool {
+        self.archived_at.is_some_and(|archived_at| {
+            last_message_created_at.is_none_or(|created_at| created_at <= archived_at)
+        })
+    }
+
+    // The message request is waiting for an answer of this participant
+    pub fn is_request_pending(&self) -> bool {
+        self.request_status == PrivateDiscussionRequestStatus::Pending.as_str()
+    }
+
+    // The participant declined the message request, the discussion is hidden from them
+    pub fn is_request_declined(&self) -> bool {
+        self.request_status == PrivateDiscussionRequestStatus::Declined.as_str()
+    }
+
+    // Typing and presence only reach participants who accepted the discussion and did not block it
+    pub fn receives_activity(&self) -> bool {
+        !self.has_blocked
+            && self.request_status == PrivateDiscussionRequestStatus::Accepted.as_str()
+    }
+
+    pub fn to_private_discussion_member_data(&self) -> PrivateDiscussionMemberData {
+        PrivateDiscussionMemberData {
+            user_id: self.user_id,
+            is_admin: self.is_admin,
+            joined_at: self.created_at,
         }
     }
 }
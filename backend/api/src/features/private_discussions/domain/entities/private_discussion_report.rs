@@ -0,0 +1,148 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+pub const PRIVATE_DISCUSSION_REPORT_REASON_MAX_LENGTH: usize = 2000;
+pub const PRIVATE_DISCUSSION_REPORT_MAX_MESSAGES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrivateDiscussionReportAction {
+    Dismiss,
+    WarnOffender,
+    SuspendOffender,
+}
+
+impl PrivateDiscussionReportAction {
+    pub const ALL: [PrivateDiscussionReportAction; 3] = [
+        PrivateDiscussionReportAction::Dismiss,
+        PrivateDiscussionReportAction::WarnOffender,
+        PrivateDiscussionReportAction::SuspendOffender,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrivateDiscussionReportAction::Dismiss => "dismiss",
+            PrivateDiscussionReportAction::WarnOffender => "warn_offender",
+            PrivateDiscussionReportAction::SuspendOffender => "suspend_offender",
+        }
+    }
+}
+
+impl fmt::Display for PrivateDiscussionReportAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PrivateDiscussionReportAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PrivateDiscussionReportAction::ALL
+            .into_iter()
+            .find(|action| action.as_str() == s)
+            .ok_or_else(|| format!("Unknown private discussion report action: {}", s))
+    }
+}
+
+// The server can not read private discussions: a report only holds what the
+// reporter chose to disclose
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct PrivateDiscussionReport {
+    pub id: Uuid,
+    pub discussion_id: Uuid,
+    pub reporter: Uuid,
+    pub reported_user: Uuid,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub moderator: Option<Uuid>,
+    pub action: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PrivateDiscussionReportData {
+    pub id: Uuid,
+    pub discussion_id: Uuid,
+    pub reporter: Uuid,
+    pub reported_user: Uuid,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub action: Option<String>,
+}
+
+// A disclosed message, whose content was checked against the stored ciphertext
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct PrivateDiscussionReportMessage {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub message_id: Option<Uuid>,
+    pub creator: Option<Uuid>,
+    pub content: String,
+    pub message_created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PrivateDiscussionReportMessageData {
+    pub message_id: Option<Uuid>,
+    pub creator: Option<Uuid>,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// What moderators review: the report and the verified excerpt of the discussion
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PrivateDiscussionReportEntry {
+    pub report: PrivateDiscussionReportData,
+    pub messages: Vec<PrivateDiscussionReportMessageData>,
+    pub moderator: Option<Uuid>,
+    pub note: Option<String>,
+}
+
+impl PrivateDiscussionReport {
+    pub fn to_private_discussion_report_data(&self) -> PrivateDiscussionReportData {
+        PrivateDiscussionReportData {
+            id: self.id,
+            discussion_id: self.discussion_id,
+            reporter: self.reporter,
+            reported_user: self.reported_user,
+            reason: self.reason.to_owned(),
+            created_at: self.created_at,
+            resolved_at: self.resolved_at,
+            action: self.action.to_owned(),
+        }
+    }
+
+    pub fn to_private_discussion_report_entry(
+        &self,
+        messages: &[PrivateDiscussionReportMessage],
+    ) -> PrivateDiscussionReportEntry {
+        PrivateDiscussionReportEntry {
+            report: self.to_private_discussion_report_data(),
+            messages: messages
+                .iter()
+                .filter(|m| m.report_id == self.id)
+                .map(|m| m.to_private_discussion_report_message_data())
+                .collect(),
+            moderator: self.moderator,
+            note: self.note.to_owned(),
+        }
+    }
+}
+
+impl PrivateDiscussionReportMessage {
+    pub fn to_private_discussion_report_message_data(&self) -> PrivateDiscussionReportMessageData {
+        PrivateDiscussionReportMessageData {
+            message_id: self.message_id,
+            creator: self.creator,
+            content: self.content.to_owned(),
+            created_at: self.message_created_at,
+        }
+    }
+}
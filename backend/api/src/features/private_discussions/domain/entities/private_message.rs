@@ -1,10 +1,25 @@
-use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+use aes_gcm::{
+    aead::{consts::U16, Aead, KeyInit},
+    aes::Aes256,
+    AesGcm, Nonce,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
 use uuid::Uuid;
 
+use super::private_message_receipt::PrivateMessageReceipt;
+
 pub const PRIVATE_MESSAGE_CONTENT_MAX_LENGTH: usize = 10_000;
+pub const PRIVATE_MESSAGE_MAX_ATTACHMENTS: usize = 10;
+pub const PRIVATE_MESSAGES_PAGE_SIZE: i64 = 50;
+pub const PRIVATE_MESSAGES_MAX_PAGE_SIZE: i64 = 100;
+// Clients prefix the AES-GCM ciphertext with a 16 bytes nonce
+const PRIVATE_MESSAGE_NONCE_LENGTH: usize = 16;
 
 #[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
 pub struct PrivateMessage {
@@ -18,6 +33,23 @@ pub struct PrivateMessage {
     pub recipient_encrypted_session_key: String,
     pub deleted: bool,
     pub seen: bool,
+    // Group messages only: session key encrypted for each member, by user id
+    pub encrypted_session_keys: serde_json::Value,
+    // Session key encrypted for each device that registered a key pair, by device key id
+    pub device_encrypted_session_keys: serde_json::Value,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub seen_at: Option<DateTime<Utc>>,
+    // Set when the discussion has disappearing messages
+    pub expires_at: Option<DateTime<Utc>>,
+    pub attachment_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrivateMessageStatus {
+    Sent,
+    Delivered,
+    Seen,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -32,6 +64,13 @@ pub struct PrivateMessageData {
     pub recipient_encrypted_session_key: String,
     pub deleted: bool,
     pub seen: bool,
+    pub encrypted_session_keys: BTreeMap<Uuid, String>,
+    pub device_encrypted_session_keys: BTreeMap<Uuid, String>,
+    pub status: PrivateMessageStatus,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub seen_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub attachment_ids: Vec<Uuid>,
 }
 
 impl PrivateMessage {
@@ -47,6 +86,177 @@ impl PrivateMessage {
             recipient_encrypted_session_key: self.recipient_encrypted_session_key.to_owned(),
             deleted: self.deleted,
             seen: self.seen,
+            encrypted_session_keys: self.get_encrypted_session_keys(),
+            device_encrypted_session_keys: self.get_device_encrypted_session_keys(),
+            status: self.status(),
+            delivered_at: self.delivered_at,
+            seen_at: self.seen_at,
+            expires_at: self.expires_at,
+            attachment_ids: self.attachment_ids.to_owned(),
+        }
+    }
+
+    // Only used with a session key given away by a member of the discussion, e.g. when
+    // reporting it. Returns None when the key does not open the stored content.
+    pub fn decrypt_content(&self, session_key: &str) -> Option<String> {
+        let key = STANDARD.decode(session_key).ok()?;
+        let content = STANDARD.decode(&self.content).ok()?;
+        if content.len() <= PRIVATE_MESSAGE_NONCE_LENGTH {
+            return None;
+        }
+
+        let (nonce, ciphertext) = content.split_at(PRIVATE_MESSAGE_NONCE_LENGTH);
+        let cipher = AesGcm::<Aes256, U16>::new_from_slice(&key).ok()?;
+        let plaintext = cipher
+            .decrypt(Nonce::<U16>::from_slice(nonce), ciphertext)
+            .ok()?;
+
+        String::from_utf8(plaintext).ok()
+    }
+
+    pub fn status(&self) -> PrivateMessageStatus {
+        if self.seen {
+            PrivateMessageStatus::Seen
+        } else if self.delivered_at.is_some() {
+            PrivateMessageStatus::Delivered
+        } else {
+            PrivateMessageStatus::Sent
+        }
+    }
+
+    // A message is delivered, then seen, once it is for every one of its recipients
+    pub fn update_status(&mut self, receipts: &[PrivateMessageReceipt]) {
+        let receipts: Vec<&PrivateMessageReceipt> = receipts
+            .iter()
+            .filter(|r| r.message_id == self.id)
+            .collect();
+        if receipts.is_empty() {
+            return;
+        }
+
+        if receipts.iter().all(|r| r.delivered_at.is_some()) {
+            self.delivered_at = receipts.iter().filter_map(|r| r.delivered_at).max();
+        }
+        if receipts.iter().all(|r| r.seen_at.is_some()) {
+            self.seen = true;
+            self.seen_at = receipts.iter().filter_map(|r| r.seen_at).max();
+        }
+    }
+
+    pub fn get_encrypted_session_keys(&self) -> BTreeMap<Uuid, String> {
+        serde_json::from_value(self.encrypted_session_keys.clone()).unwrap_or_default()
+    }
+
+    pub fn get_device_encrypted_session_keys(&self) -> BTreeMap<Uuid, String> {
+        serde_json::from_value(self.device_encrypted_session_keys.clone()).unwrap_or_default()
+    }
+}
+
+// Position of a message in its discussion: its creation date, with the id to break ties
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrivateMessageCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl PrivateMessageCursor {
+    pub fn from_message(message: &PrivateMessage) -> Self {
+        Self {
+            created_at: message.created_at,
+            id: message.id,
         }
     }
+
+    pub fn encode(&self) -> String {
+        format!(
+            "{}_{}",
+            self.created_at.to_rfc3339_opts(SecondsFormat::Micros, true),
+            self.id
+        )
+    }
+
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let (created_at, id) = cursor.rsplit_once('_')?;
+
+        Some(Self {
+            created_at: DateTime::parse_from_rfc3339(created_at)
+                .ok()?
+                .with_timezone(&Utc),
+            id: Uuid::parse_str(id).ok()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrivateMessagePageAnchor {
+    Latest,
+    Before(PrivateMessageCursor),
+    After(PrivateMessageCursor),
+    // Opens the discussion on a message, e.g. from a notification
+    Around(Uuid),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrivateMessagePage {
+    pub anchor: PrivateMessagePageAnchor,
+    pub limit: i64,
+}
+
+impl PrivateMessagePage {
+    // Returns None when a cursor is invalid or when several anchors are given
+    pub fn new(
+        before: Option<&str>,
+        after: Option<&str>,
+        around: Option<Uuid>,
+        before_date: Option<DateTime<Utc>>,
+        limit: Option<i64>,
+    ) -> Option<Self> {
+        let anchor = match (before, after, around, before_date) {
+            (None, None, None, None) => PrivateMessagePageAnchor::Latest,
+            (Some(before), None, None, None) => {
+                PrivateMessagePageAnchor::Before(PrivateMessageCursor::decode(before)?)
+            }
+            (None, Some(after), None, None) => {
+                PrivateMessagePageAnchor::After(PrivateMessageCursor::decode(after)?)
+            }
+            (None, None, Some(around), None) => PrivateMessagePageAnchor::Around(around),
+            // Kept for older clients: the nil id sorts first, so messages created
+            // at that exact date are excluded like before
+            (None, None, None, Some(before_date)) => {
+                PrivateMessagePageAnchor::Before(PrivateMessageCursor {
+                    created_at: before_date,
+                    id: Uuid::nil(),
+                })
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            anchor,
+            limit: limit
+                .unwrap_or(PRIVATE_MESSAGES_PAGE_SIZE)
+                .clamp(1, PRIVATE_MESSAGES_MAX_PAGE_SIZE),
+        })
+    }
+}
+
+// Repositories fetch one extra message to know whether another page exists.
+// Returns the page and the cursor to continue from, in the order of the messages.
+pub fn split_private_message_page(
+    mut messages: Vec<PrivateMessage>,
+    limit: i64,
+    anchor: Option<PrivateMessageCursor>,
+) -> (Vec<PrivateMessage>, Option<String>) {
+    if messages.len() as i64 <= limit {
+        return (messages, None);
+    }
+
+    messages.truncate(limit as usize);
+    let cursor = messages
+        .last()
+        .map(PrivateMessageCursor::from_message)
+        .or(anchor)
+        .map(|c| c.encode());
+
+    (messages, cursor)
 }
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+pub const PRIVATE_MESSAGE_ATTACHMENT_MAX_SIZE_IN_BYTES: usize = 25 * 1024 * 1024;
+// Total size of the attachments a user can keep
+pub const PRIVATE_MESSAGE_ATTACHMENTS_QUOTA_IN_BYTES: i64 = 500 * 1024 * 1024;
+
+// Encrypted by the client with the session key of its message, the server never
+// knows what it contains
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct PrivateMessageAttachment {
+    pub id: Uuid,
+    pub uploader: Option<Uuid>,
+    pub private_message_id: Option<Uuid>,
+    pub size_in_bytes: i64,
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PrivateMessageAttachmentData {
+    pub id: Uuid,
+    pub private_message_id: Option<Uuid>,
+    pub size_in_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PrivateMessageAttachment {
+    pub fn storage_key_for(id: Uuid) -> String {
+        format!("private_message_attachments/{}", id)
+    }
+
+    pub fn to_private_message_attachment_data(&self) -> PrivateMessageAttachmentData {
+        PrivateMessageAttachmentData {
+            id: self.id,
+            private_message_id: self.private_message_id,
+            size_in_bytes: self.size_in_bytes,
+            created_at: self.created_at,
+        }
+    }
+}
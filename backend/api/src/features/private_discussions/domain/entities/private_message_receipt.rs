@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+// Delivery and reading state of a message for one of its recipients
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct PrivateMessageReceipt {
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub seen_at: Option<DateTime<Utc>>,
+}
+
+impl PrivateMessageReceipt {
+    pub fn new(message_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            message_id,
+            user_id,
+            delivered_at: None,
+            seen_at: None,
+        }
+    }
+
+    // Receipts only move forward: a seen message stays seen and keeps its first timestamps
+    pub fn mark_as_delivered(&mut self, now: DateTime<Utc>) {
+        self.delivered_at.get_or_insert(now);
+    }
+
+    pub fn mark_as_seen(&mut self, now: DateTime<Utc>) {
+        self.mark_as_delivered(now);
+        self.seen_at.get_or_insert(now);
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+
+pub const SOCKET_PROTOCOL_VERSION: u64 = 1;
+pub const SOCKET_RATE_LIMIT_MAX_MESSAGES: usize = 20;
+pub const SOCKET_RATE_LIMIT_WINDOW_IN_SECONDS: i64 = 10;
+
+// Frame sent by clients on the notifications socket, e.g.
+// {"version": 1, "type": "typing_started", "discussion_id": "..."}
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct SocketClientFrame {
+    pub version: u64,
+    #[serde(flatten)]
+    pub message: SocketClientMessage,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SocketClientMessage {
+    TypingStarted { discussion_id: Uuid },
+    TypingStopped { discussion_id: Uuid },
+    PresenceHeartbeat,
+    MessageDelivered { message_id: Uuid },
+    MessageRead { message_id: Uuid },
+}
+
+impl SocketClientFrame {
+    pub fn parse(text: &str) -> Result<SocketClientMessage, AppError> {
+        let value: serde_json::Value =
+            serde_json::from_str(text).map_err(|_| AppError::SocketMessageInvalid)?;
+
+        // Check the version first so that clients speaking a newer protocol get a clear error
+        match value.get("version").and_then(|v| v.as_u64()) {
+            Some(SOCKET_PROTOCOL_VERSION) => {}
+            Some(_) => return Err(AppError::SocketProtocolVersionUnsupported),
+            None => return Err(AppError::SocketMessageInvalid),
+        }
+
+        serde_json::from_value::<SocketClientFrame>(value)
+            .map(|frame| frame.message)
+            .map_err(|_| AppError::SocketMessageInvalid)
+    }
+}
+
+// Sliding window limiting the number of frames a single connection can send
+#[derive(Debug, Default)]
+pub struct SocketRateLimiter {
+    received_at: VecDeque<DateTime<Utc>>,
+}
+
+impl SocketRateLimiter {
+    pub fn allow(&mut self, now: DateTime<Utc>) -> bool {
+        let window_start = now - Duration::seconds(SOCKET_RATE_LIMIT_WINDOW_IN_SECONDS);
+
+        while self
+            .received_at
+            .front()
+            .is_some_and(|received_at| *received_at <= window_start)
+        {
+            self.received_at.pop_front();
+        }
+
+        if self.received_at.len() >= SOCKET_RATE_LIMIT_MAX_MESSAGES {
+            return false;
+        }
+
+        self.received_at.push_back(now);
+        true
+    }
+}
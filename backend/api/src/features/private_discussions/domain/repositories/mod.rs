@@ -1,5 +1,9 @@
 // Private discussions domain repository traits
 
 pub mod private_discussion_participation_repository;
+pub mod private_discussion_report_message_repository;
+pub mod private_discussion_report_repository;
 pub mod private_discussion_repository;
+pub mod private_message_attachment_repository;
+pub mod private_message_receipt_repository;
 pub mod private_message_repository;
@@ -1,6 +1,7 @@
 // PrivateDiscussionParticipation repository trait
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
@@ -9,6 +10,19 @@ use crate::features::private_discussions::domain::entities::private_discussion_p
 pub trait PrivateDiscussionParticipationRepository: Send + Sync {
     async fn create(&self, participation: &PrivateDiscussionParticipation) -> Result<(), String>;
     async fn update(&self, participation: &PrivateDiscussionParticipation) -> Result<(), String>;
+    async fn update_is_admin(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+    ) -> Result<(), String>;
+    async fn update_settings(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+    ) -> Result<(), String>;
+    async fn update_request_status(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+    ) -> Result<(), String>;
+    async fn delete(&self, participation_id: Uuid) -> Result<(), String>;
     async fn get_by_id(
         &self,
         participation_id: Uuid,
@@ -31,4 +45,10 @@ pub trait PrivateDiscussionParticipationRepository: Send + Sync {
         discussion_ids: Vec<Uuid>,
         user_id: Uuid,
     ) -> Result<Vec<PrivateDiscussionParticipation>, String>;
+    async fn share_a_discussion(&self, user1_id: Uuid, user2_id: Uuid) -> Result<bool, String>;
+    async fn count_requests_since(
+        &self,
+        requested_by: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<i64, String>;
 }
@@ -0,0 +1,15 @@
+// PrivateDiscussionReportMessage repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_discussion_report::PrivateDiscussionReportMessage;
+
+#[async_trait]
+pub trait PrivateDiscussionReportMessageRepository: Send + Sync {
+    async fn create(&self, message: &PrivateDiscussionReportMessage) -> Result<(), String>;
+    async fn get_by_report_ids(
+        &self,
+        report_ids: Vec<Uuid>,
+    ) -> Result<Vec<PrivateDiscussionReportMessage>, String>;
+}
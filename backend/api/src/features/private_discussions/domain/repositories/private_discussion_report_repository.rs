@@ -0,0 +1,14 @@
+// PrivateDiscussionReport repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_discussion_report::PrivateDiscussionReport;
+
+#[async_trait]
+pub trait PrivateDiscussionReportRepository: Send + Sync {
+    async fn create(&self, report: &PrivateDiscussionReport) -> Result<(), String>;
+    async fn get_by_id(&self, report_id: Uuid) -> Result<Option<PrivateDiscussionReport>, String>;
+    async fn get_pending(&self) -> Result<Vec<PrivateDiscussionReport>, String>;
+    async fn resolve(&self, report: &PrivateDiscussionReport) -> Result<(), String>;
+}
@@ -8,6 +8,7 @@ use crate::features::private_discussions::domain::entities::private_discussion::
 #[async_trait]
 pub trait PrivateDiscussionRepository: Send + Sync {
     async fn create(&self, discussion: &PrivateDiscussion) -> Result<(), String>;
+    async fn update_message_retention(&self, discussion: &PrivateDiscussion) -> Result<(), String>;
     async fn get_by_id(&self, discussion_id: Uuid) -> Result<Option<PrivateDiscussion>, String>;
     async fn get_by_users(
         &self,
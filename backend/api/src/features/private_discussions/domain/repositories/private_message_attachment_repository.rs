@@ -0,0 +1,35 @@
+// PrivateMessageAttachment repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_message_attachment::PrivateMessageAttachment;
+
+#[async_trait]
+pub trait PrivateMessageAttachmentRepository: Send + Sync {
+    async fn create(&self, attachment: &PrivateMessageAttachment) -> Result<(), String>;
+    async fn get_by_id(
+        &self,
+        attachment_id: Uuid,
+    ) -> Result<Option<PrivateMessageAttachment>, String>;
+    async fn get_total_size_by_uploader(&self, uploader: Uuid) -> Result<i64, String>;
+    async fn attach_to_private_message(
+        &self,
+        attachment_ids: &[Uuid],
+        uploader: Uuid,
+        private_message_id: Uuid,
+    ) -> Result<u64, String>;
+    async fn delete_by_private_message_id(
+        &self,
+        private_message_id: Uuid,
+    ) -> Result<Vec<PrivateMessageAttachment>, String>;
+    async fn delete_by_uploader(
+        &self,
+        uploader: Uuid,
+    ) -> Result<Vec<PrivateMessageAttachment>, String>;
+    async fn delete_unused(
+        &self,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<PrivateMessageAttachment>, String>;
+}
@@ -0,0 +1,33 @@
+// PrivateMessageReceipt repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_message_receipt::PrivateMessageReceipt;
+
+#[async_trait]
+pub trait PrivateMessageReceiptRepository: Send + Sync {
+    async fn create_for_recipients(
+        &self,
+        message_id: Uuid,
+        recipients: &[Uuid],
+    ) -> Result<(), String>;
+    async fn upsert(&self, receipt: &PrivateMessageReceipt) -> Result<(), String>;
+    async fn get_by_message_ids(
+        &self,
+        message_ids: &[Uuid],
+    ) -> Result<Vec<PrivateMessageReceipt>, String>;
+    async fn mark_as_seen(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        before_date: DateTime<Utc>,
+        seen_at: DateTime<Utc>,
+    ) -> Result<Vec<PrivateMessageReceipt>, String>;
+    async fn delete_by_discussion_and_user(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), String>;
+}
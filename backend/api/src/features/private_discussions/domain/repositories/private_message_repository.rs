@@ -4,34 +4,57 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::features::private_discussions::domain::entities::private_message::PrivateMessage;
+use crate::features::private_discussions::domain::entities::private_message::{
+    PrivateMessage, PrivateMessageCursor,
+};
 
 #[async_trait]
 pub trait PrivateMessageRepository: Send + Sync {
     async fn create(&self, message: &PrivateMessage) -> Result<(), String>;
     async fn update(&self, message: &PrivateMessage) -> Result<(), String>;
     async fn get_by_id(&self, message_id: Uuid) -> Result<Option<PrivateMessage>, String>;
-    async fn get_by_discussion_id(
+    async fn get_before(
         &self,
         discussion_id: Uuid,
-        before_date: Option<DateTime<Utc>>,
+        cursor: Option<PrivateMessageCursor>,
+        limit: i64,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<PrivateMessage>, String>;
+    async fn get_after(
+        &self,
+        discussion_id: Uuid,
+        cursor: PrivateMessageCursor,
+        limit: i64,
+        now: DateTime<Utc>,
     ) -> Result<Vec<PrivateMessage>, String>;
     async fn get_last_messages_for_discussions(
         &self,
         discussion_ids: Vec<Uuid>,
+        now: DateTime<Utc>,
     ) -> Result<Vec<PrivateMessage>, String>;
     async fn get_unseen_count_for_discussions(
         &self,
         discussion_ids: Vec<Uuid>,
         user_id: Uuid,
+        now: DateTime<Utc>,
     ) -> Result<Vec<(Uuid, i64)>, String>;
-    async fn mark_as_seen(
+    async fn get_by_ids(&self, message_ids: &[Uuid]) -> Result<Vec<PrivateMessage>, String>;
+    async fn delete(&self, message_id: Uuid) -> Result<(), String>;
+    async fn add_encrypted_session_keys(
         &self,
         discussion_id: Uuid,
         user_id: Uuid,
-        before_date: DateTime<Utc>,
+        message_ids: Vec<Uuid>,
+        encrypted_session_keys: Vec<String>,
     ) -> Result<(), String>;
-    async fn delete(&self, message_id: Uuid) -> Result<(), String>;
+    async fn remove_encrypted_session_keys(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), String>;
+    async fn remove_device_encrypted_session_keys(&self, device_key_id: Uuid)
+        -> Result<(), String>;
+    async fn delete_expired(&self, at: DateTime<Utc>) -> Result<Vec<(Uuid, Uuid)>, String>;
     async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
     async fn count(&self) -> Result<i64, String>;
 }
@@ -2,6 +2,7 @@
 // Supports both PgPool and transactions via Executor trait
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
 use uuid::Uuid;
 
@@ -34,16 +35,22 @@ impl PrivateDiscussionParticipationRepositoryImpl {
                 user_id,
                 color,
                 created_at,
-                has_blocked
+                has_blocked,
+                is_admin,
+                request_status,
+                requested_by
             )
-            VALUES ( $1, $2, $3, $4, $5, $6)
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
             participation.id,
             participation.discussion_id,
             participation.user_id,
             participation.color,
             participation.created_at,
-            participation.has_blocked
+            participation.has_blocked,
+            participation.is_admin,
+            participation.request_status,
+            participation.requested_by,
         )
         .execute(executor)
         .await
@@ -73,6 +80,93 @@ impl PrivateDiscussionParticipationRepositoryImpl {
         .await
     }
 
+    pub async fn update_is_admin_with_executor<'a, E>(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE private_discussion_participations
+            SET is_admin = $1
+            WHERE id = $2
+            "#,
+            participation.is_admin,
+            participation.id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn update_settings_with_executor<'a, E>(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE private_discussion_participations
+            SET
+                muted_until = $1,
+                archived_at = $2,
+                pinned_at = $3
+            WHERE id = $4
+            "#,
+            participation.muted_until,
+            participation.archived_at,
+            participation.pinned_at,
+            participation.id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn update_request_status_with_executor<'a, E>(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE private_discussion_participations
+            SET request_status = $1
+            WHERE id = $2
+            "#,
+            participation.request_status,
+            participation.id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        participation_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM private_discussion_participations
+            WHERE id = $1
+            "#,
+            participation_id,
+        )
+        .execute(executor)
+        .await
+    }
+
     pub async fn get_by_id_with_executor<'a, E>(
         &self,
         participation_id: Uuid,
@@ -152,6 +246,7 @@ impl PrivateDiscussionParticipationRepositoryImpl {
             SELECT *
             FROM private_discussion_participations
             WHERE discussion_id = $1
+            ORDER BY created_at
             "#,
             discussion_id
         )
@@ -181,6 +276,63 @@ impl PrivateDiscussionParticipationRepositoryImpl {
         .fetch_all(executor)
         .await
     }
+
+    // Whether both users take part in a common discussion, a group for instance.
+    // Message requests waiting for an answer or declined do not count.
+    pub async fn share_a_discussion_with_executor<'a, E>(
+        &self,
+        user1_id: Uuid,
+        user2_id: Uuid,
+        executor: E,
+    ) -> Result<bool, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM private_discussion_participations p1
+                JOIN private_discussion_participations p2 ON p1.discussion_id = p2.discussion_id
+                WHERE p1.user_id = $1 AND p2.user_id = $2
+                    AND p1.request_status = 'accepted'
+                    AND p2.request_status = 'accepted'
+            ) AS "shared!"
+            "#,
+            user1_id,
+            user2_id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.shared)
+    }
+
+    // Message requests sent by the user since the given date, whatever their answer
+    pub async fn count_requests_since_with_executor<'a, E>(
+        &self,
+        requested_by: Uuid,
+        since: DateTime<Utc>,
+        executor: E,
+    ) -> Result<i64, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM private_discussion_participations
+            WHERE requested_by = $1
+                AND created_at >= $2
+            "#,
+            requested_by,
+            since,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.count.unwrap_or(0))
+    }
 }
 
 #[async_trait]
@@ -199,6 +351,43 @@ impl PrivateDiscussionParticipationRepository for PrivateDiscussionParticipation
         Ok(())
     }
 
+    async fn update_is_admin(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+    ) -> Result<(), String> {
+        self.update_is_admin_with_executor(participation, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn update_settings(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+    ) -> Result<(), String> {
+        self.update_settings_with_executor(participation, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn update_request_status(
+        &self,
+        participation: &PrivateDiscussionParticipation,
+    ) -> Result<(), String> {
+        self.update_request_status_with_executor(participation, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete(&self, participation_id: Uuid) -> Result<(), String> {
+        self.delete_with_executor(participation_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
     async fn get_by_id(
         &self,
         participation_id: Uuid,
@@ -245,4 +434,20 @@ impl PrivateDiscussionParticipationRepository for PrivateDiscussionParticipation
             .await
             .map_err(|e| e.to_string())
     }
+
+    async fn share_a_discussion(&self, user1_id: Uuid, user2_id: Uuid) -> Result<bool, String> {
+        self.share_a_discussion_with_executor(user1_id, user2_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn count_requests_since(
+        &self,
+        requested_by: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<i64, String> {
+        self.count_requests_since_with_executor(requested_by, since, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
 }
@@ -0,0 +1,92 @@
+// PrivateDiscussionReportMessageRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_discussion_report::PrivateDiscussionReportMessage;
+use crate::features::private_discussions::domain::repositories::private_discussion_report_message_repository::PrivateDiscussionReportMessageRepository;
+
+pub struct PrivateDiscussionReportMessageRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PrivateDiscussionReportMessageRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        message: &PrivateDiscussionReportMessage,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO private_discussion_report_messages (
+                id,
+                report_id,
+                message_id,
+                creator,
+                content,
+                message_created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            message.id,
+            message.report_id,
+            message.message_id,
+            message.creator,
+            message.content,
+            message.message_created_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_report_ids_with_executor<'a, E>(
+        &self,
+        report_ids: Vec<Uuid>,
+        executor: E,
+    ) -> Result<Vec<PrivateDiscussionReportMessage>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateDiscussionReportMessage,
+            r#"
+            SELECT *
+            FROM private_discussion_report_messages
+            WHERE report_id = ANY($1)
+            ORDER BY message_created_at
+            "#,
+            &report_ids
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl PrivateDiscussionReportMessageRepository for PrivateDiscussionReportMessageRepositoryImpl {
+    async fn create(&self, message: &PrivateDiscussionReportMessage) -> Result<(), String> {
+        self.create_with_executor(message, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_report_ids(
+        &self,
+        report_ids: Vec<Uuid>,
+    ) -> Result<Vec<PrivateDiscussionReportMessage>, String> {
+        self.get_by_report_ids_with_executor(report_ids, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,157 @@
+// PrivateDiscussionReportRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_discussion_report::PrivateDiscussionReport;
+use crate::features::private_discussions::domain::repositories::private_discussion_report_repository::PrivateDiscussionReportRepository;
+
+pub struct PrivateDiscussionReportRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PrivateDiscussionReportRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        report: &PrivateDiscussionReport,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO private_discussion_reports (
+                id,
+                discussion_id,
+                reporter,
+                reported_user,
+                reason,
+                created_at,
+                resolved_at,
+                moderator,
+                action,
+                note
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            report.id,
+            report.discussion_id,
+            report.reporter,
+            report.reported_user,
+            report.reason,
+            report.created_at,
+            report.resolved_at,
+            report.moderator,
+            report.action,
+            report.note,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        report_id: Uuid,
+        executor: E,
+    ) -> Result<Option<PrivateDiscussionReport>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateDiscussionReport,
+            r#"
+            SELECT *
+            FROM private_discussion_reports
+            WHERE id = $1
+            "#,
+            report_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn get_pending_with_executor<'a, E>(
+        &self,
+        executor: E,
+    ) -> Result<Vec<PrivateDiscussionReport>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateDiscussionReport,
+            r#"
+            SELECT *
+            FROM private_discussion_reports
+            WHERE resolved_at IS NULL
+            ORDER BY created_at
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn resolve_with_executor<'a, E>(
+        &self,
+        report: &PrivateDiscussionReport,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE private_discussion_reports
+            SET
+                resolved_at = $1,
+                moderator = $2,
+                action = $3,
+                note = $4
+            WHERE id = $5
+            "#,
+            report.resolved_at,
+            report.moderator,
+            report.action,
+            report.note,
+            report.id,
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl PrivateDiscussionReportRepository for PrivateDiscussionReportRepositoryImpl {
+    async fn create(&self, report: &PrivateDiscussionReport) -> Result<(), String> {
+        self.create_with_executor(report, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_id(&self, report_id: Uuid) -> Result<Option<PrivateDiscussionReport>, String> {
+        self.get_by_id_with_executor(report_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_pending(&self) -> Result<Vec<PrivateDiscussionReport>, String> {
+        self.get_pending_with_executor(&self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn resolve(&self, report: &PrivateDiscussionReport) -> Result<(), String> {
+        self.resolve_with_executor(report, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -30,12 +30,47 @@ impl PrivateDiscussionRepositoryImpl {
             r#"
             INSERT INTO private_discussions (
                 id,
-                created_at
+                created_at,
+                name,
+                is_group,
+                message_retention,
+                proposed_message_retention,
+                message_retention_proposed_by
             )
-            VALUES ($1, $2)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             "#,
             discussion.id,
             discussion.created_at,
+            discussion.name,
+            discussion.is_group,
+            discussion.message_retention,
+            discussion.proposed_message_retention,
+            discussion.message_retention_proposed_by,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn update_message_retention_with_executor<'a, E>(
+        &self,
+        discussion: &PrivateDiscussion,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE private_discussions
+            SET message_retention = $1,
+                proposed_message_retention = $2,
+                message_retention_proposed_by = $3
+            WHERE id = $4
+            "#,
+            discussion.message_retention,
+            discussion.proposed_message_retention,
+            discussion.message_retention_proposed_by,
+            discussion.id,
         )
         .execute(executor)
         .await
@@ -84,6 +119,7 @@ impl PrivateDiscussionRepositoryImpl {
             SELECT pd.*
             FROM private_discussions pd
             JOIN participants p ON pd.id = p.discussion_id
+            WHERE pd.is_group = false
             "#,
             user1_id,
             user2_id
@@ -139,6 +175,13 @@ impl PrivateDiscussionRepository for PrivateDiscussionRepositoryImpl {
         Ok(())
     }
 
+    async fn update_message_retention(&self, discussion: &PrivateDiscussion) -> Result<(), String> {
+        self.update_message_retention_with_executor(discussion, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
     async fn get_by_id(&self, discussion_id: Uuid) -> Result<Option<PrivateDiscussion>, String> {
         self.get_by_id_with_executor(discussion_id, &self.pool)
             .await
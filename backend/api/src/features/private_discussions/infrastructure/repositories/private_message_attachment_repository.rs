@@ -0,0 +1,285 @@
+// PrivateMessageAttachmentRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_message_attachment::PrivateMessageAttachment;
+use crate::features::private_discussions::domain::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepository;
+
+pub struct PrivateMessageAttachmentRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PrivateMessageAttachmentRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        attachment: &PrivateMessageAttachment,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO private_message_attachments (
+                id,
+                uploader,
+                private_message_id,
+                size_in_bytes,
+                storage_key,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            attachment.id,
+            attachment.uploader,
+            attachment.private_message_id,
+            attachment.size_in_bytes,
+            attachment.storage_key,
+            attachment.created_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        attachment_id: Uuid,
+        executor: E,
+    ) -> Result<Option<PrivateMessageAttachment>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateMessageAttachment,
+            r#"
+            SELECT *
+            FROM private_message_attachments
+            WHERE id = $1
+            "#,
+            attachment_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    // Holds the row of the uploader until the end of the transaction, so that concurrent
+    // uploads of the same user check their quota one after the other. Only meaningful
+    // within a transaction.
+    pub async fn lock_uploader_with_executor<'a, E>(
+        &self,
+        uploader: Uuid,
+        executor: E,
+    ) -> Result<(), sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            SELECT id
+            FROM users
+            WHERE id = $1
+            FOR NO KEY UPDATE
+            "#,
+            uploader
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_total_size_by_uploader_with_executor<'a, E>(
+        &self,
+        uploader: Uuid,
+        executor: E,
+    ) -> Result<i64, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT COALESCE(SUM(size_in_bytes), 0)::BIGINT AS "total_size!"
+            FROM private_message_attachments
+            WHERE uploader = $1
+            "#,
+            uploader
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.total_size)
+    }
+
+    // Only links the unused uploads of the given user
+    pub async fn attach_to_private_message_with_executor<'a, E>(
+        &self,
+        attachment_ids: &[Uuid],
+        uploader: Uuid,
+        private_message_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE private_message_attachments
+            SET private_message_id = $1
+            WHERE id = ANY($2) AND uploader = $3 AND private_message_id IS NULL
+            "#,
+            private_message_id,
+            attachment_ids,
+            uploader,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_by_private_message_id_with_executor<'a, E>(
+        &self,
+        private_message_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<PrivateMessageAttachment>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateMessageAttachment,
+            r#"
+            DELETE FROM private_message_attachments
+            WHERE private_message_id = $1
+            RETURNING *
+            "#,
+            private_message_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn delete_by_uploader_with_executor<'a, E>(
+        &self,
+        uploader: Uuid,
+        executor: E,
+    ) -> Result<Vec<PrivateMessageAttachment>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateMessageAttachment,
+            r#"
+            DELETE FROM private_message_attachments
+            WHERE uploader = $1
+            RETURNING *
+            "#,
+            uploader
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Uploads never linked to a message, and leftovers of deleted or expired messages
+    pub async fn delete_unused_with_executor<'a, E>(
+        &self,
+        created_before: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<PrivateMessageAttachment>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateMessageAttachment,
+            r#"
+            DELETE FROM private_message_attachments a
+            WHERE (a.private_message_id IS NULL AND a.created_at < $1)
+                OR EXISTS (
+                    SELECT 1
+                    FROM private_messages pm
+                    WHERE pm.id = a.private_message_id
+                        AND pm.deleted
+                )
+            RETURNING a.*
+            "#,
+            created_before
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl PrivateMessageAttachmentRepository for PrivateMessageAttachmentRepositoryImpl {
+    async fn create(&self, attachment: &PrivateMessageAttachment) -> Result<(), String> {
+        self.create_with_executor(attachment, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_id(
+        &self,
+        attachment_id: Uuid,
+    ) -> Result<Option<PrivateMessageAttachment>, String> {
+        self.get_by_id_with_executor(attachment_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_total_size_by_uploader(&self, uploader: Uuid) -> Result<i64, String> {
+        self.get_total_size_by_uploader_with_executor(uploader, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn attach_to_private_message(
+        &self,
+        attachment_ids: &[Uuid],
+        uploader: Uuid,
+        private_message_id: Uuid,
+    ) -> Result<u64, String> {
+        self.attach_to_private_message_with_executor(
+            attachment_ids,
+            uploader,
+            private_message_id,
+            &self.pool,
+        )
+        .await
+        .map(|result| result.rows_affected())
+        .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_private_message_id(
+        &self,
+        private_message_id: Uuid,
+    ) -> Result<Vec<PrivateMessageAttachment>, String> {
+        self.delete_by_private_message_id_with_executor(private_message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_uploader(
+        &self,
+        uploader: Uuid,
+    ) -> Result<Vec<PrivateMessageAttachment>, String> {
+        self.delete_by_uploader_with_executor(uploader, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_unused(
+        &self,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<PrivateMessageAttachment>, String> {
+        self.delete_unused_with_executor(created_before, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,202 @@
+// PrivateMessageReceiptRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::private_discussions::domain::entities::private_message_receipt::PrivateMessageReceipt;
+use crate::features::private_discussions::domain::repositories::private_message_receipt_repository::PrivateMessageReceiptRepository;
+
+pub struct PrivateMessageReceiptRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PrivateMessageReceiptRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_for_recipients_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        recipients: &[Uuid],
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO private_message_receipts (message_id, user_id)
+            SELECT $1, user_id
+            FROM UNNEST($2::uuid[]) AS user_id
+            "#,
+            message_id,
+            recipients,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn upsert_with_executor<'a, E>(
+        &self,
+        receipt: &PrivateMessageReceipt,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO private_message_receipts (message_id, user_id, delivered_at, seen_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (message_id, user_id)
+            DO UPDATE SET delivered_at = EXCLUDED.delivered_at, seen_at = EXCLUDED.seen_at
+            "#,
+            receipt.message_id,
+            receipt.user_id,
+            receipt.delivered_at,
+            receipt.seen_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_message_ids_with_executor<'a, E>(
+        &self,
+        message_ids: &[Uuid],
+        executor: E,
+    ) -> Result<Vec<PrivateMessageReceipt>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateMessageReceipt,
+            r#"
+            SELECT *
+            FROM private_message_receipts
+            WHERE message_id = ANY($1)
+            "#,
+            message_ids,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Marks every message the user received in the discussion up to the given date as seen,
+    // returning the receipts that were updated
+    pub async fn mark_as_seen_with_executor<'a, E>(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        before_date: DateTime<Utc>,
+        seen_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<PrivateMessageReceipt>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateMessageReceipt,
+            r#"
+            UPDATE private_message_receipts r
+            SET seen_at = $4,
+                delivered_at = COALESCE(r.delivered_at, $4)
+            FROM private_messages pm
+            WHERE r.message_id = pm.id
+              AND pm.discussion_id = $1
+              AND r.user_id = $2
+              AND pm.created_at <= $3
+              AND r.seen_at IS NULL
+            RETURNING r.message_id, r.user_id, r.delivered_at, r.seen_at
+            "#,
+            discussion_id,
+            user_id,
+            before_date,
+            seen_at
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // A former member of a group no longer holds back the status of its messages
+    pub async fn delete_by_discussion_and_user_with_executor<'a, E>(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM private_message_receipts r
+            USING private_messages pm
+            WHERE r.message_id = pm.id
+              AND pm.discussion_id = $1
+              AND r.user_id = $2
+            "#,
+            discussion_id,
+            user_id,
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl PrivateMessageReceiptRepository for PrivateMessageReceiptRepositoryImpl {
+    async fn create_for_recipients(
+        &self,
+        message_id: Uuid,
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        self.create_for_recipients_with_executor(message_id, recipients, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn upsert(&self, receipt: &PrivateMessageReceipt) -> Result<(), String> {
+        self.upsert_with_executor(receipt, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_message_ids(
+        &self,
+        message_ids: &[Uuid],
+    ) -> Result<Vec<PrivateMessageReceipt>, String> {
+        self.get_by_message_ids_with_executor(message_ids, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn mark_as_seen(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        before_date: DateTime<Utc>,
+        seen_at: DateTime<Utc>,
+    ) -> Result<Vec<PrivateMessageReceipt>, String> {
+        self.mark_as_seen_with_executor(discussion_id, user_id, before_date, seen_at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_discussion_and_user(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), String> {
+        self.delete_by_discussion_and_user_with_executor(discussion_id, user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -6,7 +6,9 @@ use chrono::{DateTime, Utc};
 use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
 use uuid::Uuid;
 
-use crate::features::private_discussions::domain::entities::private_message::PrivateMessage;
+use crate::features::private_discussions::domain::entities::private_message::{
+    PrivateMessage, PrivateMessageCursor,
+};
 use crate::features::private_discussions::domain::repositories::private_message_repository::PrivateMessageRepository;
 
 pub struct PrivateMessageRepositoryImpl {
@@ -39,9 +41,13 @@ impl PrivateMessageRepositoryImpl {
                 creator_encrypted_session_key,
                 recipient_encrypted_session_key,
                 deleted,
-                seen
+                seen,
+                encrypted_session_keys,
+                device_encrypted_session_keys,
+                expires_at,
+                attachment_ids
             )
-            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
             "#,
             message.id,
             message.discussion_id,
@@ -53,6 +59,10 @@ impl PrivateMessageRepositoryImpl {
             message.recipient_encrypted_session_key,
             message.deleted,
             message.seen,
+            message.encrypted_session_keys,
+            message.device_encrypted_session_keys,
+            message.expires_at,
+            &message.attachment_ids,
         )
         .execute(executor)
         .await
@@ -69,12 +79,14 @@ impl PrivateMessageRepositoryImpl {
         sqlx::query!(
             r#"
             UPDATE private_messages
-            SET updated_at = $1, content = $2, seen = $3
-            WHERE id = $4
+            SET updated_at = $1, content = $2, seen = $3, delivered_at = $4, seen_at = $5
+            WHERE id = $6
             "#,
             message.updated_at,
             message.content,
             message.seen,
+            message.delivered_at,
+            message.seen_at,
             message.id,
         )
         .execute(executor)
@@ -102,51 +114,76 @@ impl PrivateMessageRepositoryImpl {
         .await
     }
 
-    pub async fn get_by_discussion_id_with_executor<'a, E>(
+    // Newest first, starting right before the cursor when there is one
+    pub async fn get_before_with_executor<'a, E>(
         &self,
         discussion_id: Uuid,
-        before_date: Option<DateTime<Utc>>,
+        cursor: Option<PrivateMessageCursor>,
+        limit: i64,
+        now: DateTime<Utc>,
         executor: E,
     ) -> Result<Vec<PrivateMessage>, sqlx::Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        if let Some(before) = before_date {
-            sqlx::query_as!(
-                PrivateMessage,
-                r#"
-                SELECT *
-                FROM private_messages
-                WHERE discussion_id = $1
-                  AND created_at < $2
-                ORDER BY created_at DESC
-                LIMIT 50
-                "#,
-                discussion_id,
-                before
-            )
-            .fetch_all(executor)
-            .await
-        } else {
-            sqlx::query_as!(
-                PrivateMessage,
-                r#"
-                SELECT *
-                FROM private_messages
-                WHERE discussion_id = $1
-                ORDER BY created_at DESC
-                LIMIT 50
-                "#,
-                discussion_id
-            )
-            .fetch_all(executor)
-            .await
-        }
+        sqlx::query_as!(
+            PrivateMessage,
+            r#"
+            SELECT *
+            FROM private_messages
+            WHERE discussion_id = $1
+              AND ($2::timestamptz IS NULL OR (created_at, id) < ($2, $3))
+              AND (expires_at IS NULL OR expires_at > $4)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $5
+            "#,
+            discussion_id,
+            cursor.map(|c| c.created_at),
+            cursor.map(|c| c.id),
+            now,
+            limit
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Oldest first, starting right after the cursor
+    pub async fn get_after_with_executor<'a, E>(
+        &self,
+        discussion_id: Uuid,
+        cursor: PrivateMessageCursor,
+        limit: i64,
+        now: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<PrivateMessage>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateMessage,
+            r#"
+            SELECT *
+            FROM private_messages
+            WHERE discussion_id = $1
+              AND (created_at, id) > ($2, $3)
+              AND (expires_at IS NULL OR expires_at > $4)
+            ORDER BY created_at ASC, id ASC
+            LIMIT $5
+            "#,
+            discussion_id,
+            cursor.created_at,
+            cursor.id,
+            now,
+            limit
+        )
+        .fetch_all(executor)
+        .await
     }
 
     pub async fn get_last_messages_for_discussions_with_executor<'a, E>(
         &self,
         discussion_ids: Vec<Uuid>,
+        now: DateTime<Utc>,
         executor: E,
     ) -> Result<Vec<PrivateMessage>, sqlx::Error>
     where
@@ -161,10 +198,12 @@ impl PrivateMessageRepositoryImpl {
                 SELECT DISTINCT ON (discussion_id) id
                 FROM private_messages
                 WHERE discussion_id = ANY($1)
+                  AND (expires_at IS NULL OR expires_at > $2)
                 ORDER BY discussion_id, created_at DESC
             )
             "#,
             &discussion_ids,
+            now,
         )
         .fetch_all(executor)
         .await
@@ -174,6 +213,7 @@ impl PrivateMessageRepositoryImpl {
         &self,
         discussion_ids: Vec<Uuid>,
         user_id: Uuid,
+        now: DateTime<Utc>,
         executor: E,
     ) -> Result<Vec<(Uuid, i64)>, sqlx::Error>
     where
@@ -183,14 +223,17 @@ impl PrivateMessageRepositoryImpl {
             r#"
             SELECT pm.discussion_id, COUNT(*) AS unseen_count
             FROM private_messages pm
+            JOIN private_message_receipts r ON r.message_id = pm.id AND r.user_id = $2
             WHERE 
                 pm.discussion_id = ANY($1)
-                AND pm.creator != $2
-                AND pm.seen = false
+                AND r.seen_at IS NULL
+                AND pm.deleted = false
+                AND (pm.expires_at IS NULL OR pm.expires_at > $3)
             GROUP BY pm.discussion_id
             "#,
             &discussion_ids,
             user_id,
+            now,
         )
         .fetch_all(executor)
         .await?;
@@ -201,11 +244,84 @@ impl PrivateMessageRepositoryImpl {
             .collect())
     }
 
-    pub async fn mark_as_seen_with_executor<'a, E>(
+    pub async fn get_by_ids_with_executor<'a, E>(
+        &self,
+        message_ids: &[Uuid],
+        executor: E,
+    ) -> Result<Vec<PrivateMessage>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PrivateMessage,
+            r#"
+            SELECT *
+            FROM private_messages
+            WHERE id = ANY($1)
+            ORDER BY created_at ASC, id ASC
+            "#,
+            message_ids
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE private_messages
+            SET deleted = true, content = '', attachment_ids = '{}'
+            WHERE id = $1
+            "#,
+            message_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    // Adds, for each given message of the discussion, the session key encrypted for a member.
+    // Keys the member already has are kept, so that no one can replace them.
+    pub async fn add_encrypted_session_keys_with_executor<'a, E>(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        message_ids: Vec<Uuid>,
+        encrypted_session_keys: Vec<String>,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE private_messages pm
+            SET encrypted_session_keys = pm.encrypted_session_keys || jsonb_build_object($2::text, k.encrypted_session_key)
+            FROM UNNEST($3::uuid[], $4::text[]) AS k(message_id, encrypted_session_key)
+            WHERE pm.id = k.message_id
+              AND pm.discussion_id = $1
+              AND NOT (pm.encrypted_session_keys ? $2::text)
+            "#,
+            discussion_id,
+            user_id.to_string(),
+            &message_ids,
+            &encrypted_session_keys,
+        )
+        .execute(executor)
+        .await
+    }
+
+    // Drops the session keys of a member who left, so that they cannot read the discussion
+    pub async fn remove_encrypted_session_keys_with_executor<'a, E>(
         &self,
         discussion_id: Uuid,
         user_id: Uuid,
-        before_date: DateTime<Utc>,
         executor: E,
     ) -> Result<PgQueryResult, sqlx::Error>
     where
@@ -214,23 +330,20 @@ impl PrivateMessageRepositoryImpl {
         sqlx::query!(
             r#"
             UPDATE private_messages
-            SET seen = true
-            WHERE discussion_id = $1
-              AND creator != $2
-              AND created_at <= $3
-              AND seen = false
+            SET encrypted_session_keys = encrypted_session_keys - $2::text
+            WHERE discussion_id = $1 AND encrypted_session_keys ? $2::text
             "#,
             discussion_id,
-            user_id,
-            before_date
+            user_id.to_string(),
         )
         .execute(executor)
         .await
     }
 
-    pub async fn delete_with_executor<'a, E>(
+    // Drops the session keys of a revoked device from every message
+    pub async fn remove_device_encrypted_session_keys_with_executor<'a, E>(
         &self,
-        message_id: Uuid,
+        device_key_id: Uuid,
         executor: E,
     ) -> Result<PgQueryResult, sqlx::Error>
     where
@@ -239,15 +352,38 @@ impl PrivateMessageRepositoryImpl {
         sqlx::query!(
             r#"
             UPDATE private_messages
-            SET deleted = true, content = ''
-            WHERE id = $1
+            SET device_encrypted_session_keys = device_encrypted_session_keys - $1::text
+            WHERE device_encrypted_session_keys ? $1::text
             "#,
-            message_id
+            device_key_id.to_string(),
         )
         .execute(executor)
         .await
     }
 
+    // Hard-deletes expired messages, returning (discussion id, message id) pairs
+    pub async fn delete_expired_with_executor<'a, E>(
+        &self,
+        at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<(Uuid, Uuid)>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let rows = sqlx::query!(
+            r#"
+            DELETE FROM private_messages
+            WHERE expires_at <= $1
+            RETURNING discussion_id, id
+            "#,
+            at
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(rows.iter().map(|r| (r.discussion_id, r.id)).collect())
+    }
+
     pub async fn delete_by_user_id_with_executor<'a, E>(
         &self,
         user_id: Uuid,
@@ -306,12 +442,26 @@ impl PrivateMessageRepository for PrivateMessageRepositoryImpl {
             .map_err(|e| e.to_string())
     }
 
-    async fn get_by_discussion_id(
+    async fn get_before(
+        &self,
+        discussion_id: Uuid,
+        cursor: Option<PrivateMessageCursor>,
+        limit: i64,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<PrivateMessage>, String> {
+        self.get_before_with_executor(discussion_id, cursor, limit, now, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_after(
         &self,
         discussion_id: Uuid,
-        before_date: Option<DateTime<Utc>>,
+        cursor: PrivateMessageCursor,
+        limit: i64,
+        now: DateTime<Utc>,
     ) -> Result<Vec<PrivateMessage>, String> {
-        self.get_by_discussion_id_with_executor(discussion_id, before_date, &self.pool)
+        self.get_after_with_executor(discussion_id, cursor, limit, now, &self.pool)
             .await
             .map_err(|e| e.to_string())
     }
@@ -319,8 +469,9 @@ impl PrivateMessageRepository for PrivateMessageRepositoryImpl {
     async fn get_last_messages_for_discussions(
         &self,
         discussion_ids: Vec<Uuid>,
+        now: DateTime<Utc>,
     ) -> Result<Vec<PrivateMessage>, String> {
-        self.get_last_messages_for_discussions_with_executor(discussion_ids, &self.pool)
+        self.get_last_messages_for_discussions_with_executor(discussion_ids, now, &self.pool)
             .await
             .map_err(|e| e.to_string())
     }
@@ -329,31 +480,77 @@ impl PrivateMessageRepository for PrivateMessageRepositoryImpl {
         &self,
         discussion_ids: Vec<Uuid>,
         user_id: Uuid,
+        now: DateTime<Utc>,
     ) -> Result<Vec<(Uuid, i64)>, String> {
-        self.get_unseen_count_for_discussions_with_executor(discussion_ids, user_id, &self.pool)
+        self.get_unseen_count_for_discussions_with_executor(
+            discussion_ids,
+            user_id,
+            now,
+            &self.pool,
+        )
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_ids(&self, message_ids: &[Uuid]) -> Result<Vec<PrivateMessage>, String> {
+        self.get_by_ids_with_executor(message_ids, &self.pool)
             .await
             .map_err(|e| e.to_string())
     }
 
-    async fn mark_as_seen(
+    async fn delete(&self, message_id: Uuid) -> Result<(), String> {
+        self.delete_with_executor(message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn add_encrypted_session_keys(
         &self,
         discussion_id: Uuid,
         user_id: Uuid,
-        before_date: DateTime<Utc>,
+        message_ids: Vec<Uuid>,
+        encrypted_session_keys: Vec<String>,
     ) -> Result<(), String> {
-        self.mark_as_seen_with_executor(discussion_id, user_id, before_date, &self.pool)
+        self.add_encrypted_session_keys_with_executor(
+            discussion_id,
+            user_id,
+            message_ids,
+            encrypted_session_keys,
+            &self.pool,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn remove_encrypted_session_keys(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), String> {
+        self.remove_encrypted_session_keys_with_executor(discussion_id, user_id, &self.pool)
             .await
             .map_err(|e| e.to_string())?;
         Ok(())
     }
 
-    async fn delete(&self, message_id: Uuid) -> Result<(), String> {
-        self.delete_with_executor(message_id, &self.pool)
+    async fn remove_device_encrypted_session_keys(
+        &self,
+        device_key_id: Uuid,
+    ) -> Result<(), String> {
+        self.remove_device_encrypted_session_keys_with_executor(device_key_id, &self.pool)
             .await
             .map_err(|e| e.to_string())?;
         Ok(())
     }
 
+    async fn delete_expired(&self, at: DateTime<Utc>) -> Result<Vec<(Uuid, Uuid)>, String> {
+        self.delete_expired_with_executor(at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
     async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
         self.delete_by_user_id_with_executor(user_id, &self.pool)
             .await
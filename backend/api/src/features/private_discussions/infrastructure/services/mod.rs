@@ -1 +1,4 @@
 // Infrastructure services
+
+pub mod private_discussion_event_service;
+pub mod private_discussion_request_notification_service;
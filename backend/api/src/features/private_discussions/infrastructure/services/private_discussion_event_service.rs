@@ -0,0 +1,185 @@
+// Private discussion event service - relays what happens in a discussion to its members
+
+use crate::core::structs::redis_messages::NotificationEvent;
+use crate::features::private_discussions::domain::entities::private_discussion::PrivateDiscussion;
+use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
+use crate::features::private_discussions::domain::entities::private_message::PrivateMessage;
+use actix_web::web::Data;
+use chrono::{DateTime, Utc};
+use redis::{AsyncCommands, Client};
+use serde_json::json;
+use uuid::Uuid;
+
+pub struct PrivateDiscussionEventService {
+    redis_client: Data<Client>,
+}
+
+impl PrivateDiscussionEventService {
+    pub fn new(redis_client: Data<Client>) -> Self {
+        Self { redis_client }
+    }
+
+    // Clients redistribute the session keys to new members and stop encrypting for
+    // the ones who left when they receive this event
+    pub async fn publish_members_updated_event(
+        &self,
+        discussion_id: Uuid,
+        members: &[PrivateDiscussionParticipation],
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let data = json!({
+            "discussion_id": discussion_id,
+            "members": members
+                .iter()
+                .map(|m| m.to_private_discussion_member_data())
+                .collect::<Vec<_>>(),
+        })
+        .to_string();
+
+        self.publish("private_discussion_members_updated", data, recipients)
+            .await
+    }
+
+    pub async fn publish_retention_updated_event(
+        &self,
+        discussion: &PrivateDiscussion,
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let data = json!({
+            "discussion_id": discussion.id,
+            "message_retention": discussion.message_retention,
+            "proposed_message_retention": discussion.proposed_message_retention,
+            "message_retention_proposed_by": discussion.message_retention_proposed_by,
+        })
+        .to_string();
+
+        self.publish("private_discussion_retention_updated", data, recipients)
+            .await
+    }
+
+    // Clients drop the expired messages they still have locally when they receive this event
+    pub async fn publish_messages_expired_event(
+        &self,
+        discussion_id: Uuid,
+        message_ids: &[Uuid],
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let data = json!({
+            "discussion_id": discussion_id,
+            "message_ids": message_ids,
+        })
+        .to_string();
+
+        self.publish("private_messages_expired", data, recipients)
+            .await
+    }
+
+    pub async fn publish_message_seen_event(
+        &self,
+        message: &PrivateMessage,
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let data = json!(message.to_private_message_data()).to_string();
+
+        self.publish("private_message_marked_as_seen", data, recipients)
+            .await
+    }
+
+    pub async fn publish_message_delivered_event(
+        &self,
+        message: &PrivateMessage,
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let data = json!(message.to_private_message_data()).to_string();
+
+        self.publish("private_message_marked_as_delivered", data, recipients)
+            .await
+    }
+
+    pub async fn publish_discussion_seen_event(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        messages: &[PrivateMessage],
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let data = json!({
+            "discussion_id": discussion_id,
+            "user_id": user_id,
+            "message_ids": messages.iter().map(|m| m.id).collect::<Vec<_>>(),
+            "seen_at": messages.iter().filter_map(|m| m.seen_at).max(),
+        })
+        .to_string();
+
+        self.publish("private_discussion_marked_as_seen", data, recipients)
+            .await
+    }
+
+    pub async fn publish_typing_event(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        is_typing: bool,
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let data = json!({
+            "discussion_id": discussion_id,
+            "user_id": user_id,
+            "is_typing": is_typing,
+        })
+        .to_string();
+
+        self.publish("private_discussion_typing", data, recipients)
+            .await
+    }
+
+    // Presence is not stored: clients consider a user online until a few heartbeats are missed
+    pub async fn publish_presence_event(
+        &self,
+        user_id: Uuid,
+        last_seen_at: DateTime<Utc>,
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let data = json!({
+            "user_id": user_id,
+            "last_seen_at": last_seen_at,
+        })
+        .to_string();
+
+        self.publish("user_presence_updated", data, recipients)
+            .await
+    }
+
+    async fn publish(
+        &self,
+        channel: &str,
+        data: String,
+        recipients: &[Uuid],
+    ) -> Result<(), String> {
+        let mut con = self
+            .redis_client
+            .get_ref()
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| format!("Failed to get Redis connection: {}", e))?;
+
+        for recipient in recipients {
+            let result: Result<(), redis::RedisError> = con
+                .publish(
+                    channel,
+                    json!(NotificationEvent {
+                        data: data.clone(),
+                        recipient: *recipient,
+                        title: None,
+                        body: None,
+                        url: None,
+                    })
+                    .to_string(),
+                )
+                .await;
+            result.map_err(|e| format!("Failed to publish {} event: {}", channel, e))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+// Private discussion request notification service - sums up the messages held while a
+// message request waited for an answer
+
+use actix_web::web::Data;
+use fluent::FluentArgs;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::core::helpers::translation::Translator;
+use crate::features::notifications::infrastructure::services::notification_service::{
+    NotificationContent, NotificationService,
+};
+use crate::features::profile::domain::entities::UserPublicDataCache;
+
+pub struct PrivateDiscussionRequestNotificationService {
+    pool: PgPool,
+    notification_service: NotificationService,
+}
+
+impl PrivateDiscussionRequestNotificationService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            notification_service: NotificationService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    // Push notifications are held until the request is accepted, a single one then covers
+    // the `held_count` messages the user did not see yet
+    #[allow(clippy::too_many_arguments)]
+    pub async fn notify(
+        &self,
+        discussion_id: Uuid,
+        user_id: Uuid,
+        requester: Uuid,
+        held_count: i64,
+        redis_client: Data<Client>,
+        translator: &Translator,
+        user_public_data_cache: &UserPublicDataCache,
+    ) {
+        if held_count == 0 {
+            return;
+        }
+
+        let mut notif_transaction = match self.pool.begin().await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Error: {}", e);
+                return;
+            }
+        };
+
+        let Some(user) = user_public_data_cache
+            .get_value_for_key_or_insert_it(&user_id, &mut notif_transaction)
+            .await
+        else {
+            return;
+        };
+        let Some(requester_data) = user_public_data_cache
+            .get_value_for_key_or_insert_it(&requester, &mut notif_transaction)
+            .await
+        else {
+            return;
+        };
+
+        let mut title_args = FluentArgs::new();
+        title_args.set("username", requester_data.username.clone());
+
+        let mut body_args = FluentArgs::new();
+        body_args.set("username", requester_data.username);
+        body_args.set("count", held_count);
+
+        self.notification_service
+            .generate_notification(
+                &mut notif_transaction,
+                Some(requester),
+                user_id,
+                NotificationContent {
+                    title: &translator.translate(
+                        &user.locale,
+                        "held-private-messages-title",
+                        Some(title_args),
+                    ),
+                    body: &translator.translate(
+                        &user.locale,
+                        "held-private-messages-body",
+                        Some(body_args),
+                    ),
+                    notification_type: "private_messages_held",
+                    url: Some(format!("/messages/{}", discussion_id)),
+                },
+                redis_client,
+            )
+            .await;
+
+        let _ = notif_transaction.commit().await;
+    }
+}
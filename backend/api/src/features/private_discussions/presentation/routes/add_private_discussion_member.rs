@@ -0,0 +1,110 @@
+// Add private discussion member route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::challenges::infrastructure::repositories::challenge_participation_repository::ChallengeParticipationRepositoryImpl;
+use crate::features::private_discussions::application::dto::requests::private_discussion_participation::{
+    PrivateDiscussionMemberAddRequest, PrivateDiscussionMembersParams,
+};
+use crate::features::private_discussions::application::dto::responses::private_discussion_participation::PrivateDiscussionMembersResponse;
+use crate::features::private_discussions::application::use_cases::add_private_discussion_member::AddPrivateDiscussionMemberUseCase;
+use crate::features::private_discussions::application::use_cases::apply_message_request_rules::ApplyMessageRequestRulesUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::private_discussions::infrastructure::services::private_discussion_event_service::PrivateDiscussionEventService;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+use actix_web::web::{Data, Json, Path, ReqData};
+use actix_web::{post, HttpResponse, Responder};
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[post("/{discussion_id}/members")]
+pub async fn add_private_discussion_member(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionMembersParams>,
+    body: Json<PrivateDiscussionMemberAddRequest>,
+    redis_client: Data<Client>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = AddPrivateDiscussionMemberUseCase::new(
+        PrivateDiscussionRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        UserRepositoryImpl::new(pool_clone.clone()),
+        UserBlockRepositoryImpl::new(pool_clone.clone()),
+        ApplyMessageRequestRulesUseCase::new(
+            PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+            ChallengeParticipationRepositoryImpl::new(pool_clone.clone()),
+            UserRepositoryImpl::new(pool_clone),
+        ),
+    );
+
+    let participations = match use_case
+        .execute(
+            request_claims.user_id,
+            params.discussion_id,
+            body.user_id,
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::PrivateDiscussionNotFound | AppError::UserNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                AppError::PrivateDiscussionMemberNotAdmin | AppError::UserBlocked => {
+                    HttpResponse::Forbidden().json(e.to_response())
+                }
+                AppError::PrivateDiscussionNotAGroup
+                | AppError::PrivateDiscussionMemberAlreadyExists
+                | AppError::PrivateDiscussionGroupTooManyMembers => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                AppError::PrivateDiscussionRequestLimitReached => {
+                    HttpResponse::TooManyRequests().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    let recipients: Vec<_> = participations.iter().map(|p| p.user_id).collect();
+    if let Err(e) = PrivateDiscussionEventService::new(redis_client)
+        .publish_members_updated_event(params.discussion_id, &participations, &recipients)
+        .await
+    {
+        error!("Error: {}", e);
+    }
+
+    HttpResponse::Ok().json(PrivateDiscussionMembersResponse {
+        code: "PRIVATE_DISCUSSION_MEMBER_ADDED".to_string(),
+        members: participations
+            .iter()
+            .map(|p| p.to_private_discussion_member_data())
+            .collect(),
+    })
+}
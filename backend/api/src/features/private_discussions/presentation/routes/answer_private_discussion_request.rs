@@ -0,0 +1,131 @@
+// Answer private discussion request route - uses clean architecture
+
+use std::sync::Arc;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::translation::Translator;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_discussion::{
+    PrivateDiscussionRequestAnswerRequest, PrivateDiscussionRequestParams,
+};
+use crate::features::private_discussions::application::dto::responses::private_discussion::PrivateDiscussionResponse;
+use crate::features::private_discussions::application::use_cases::answer_private_discussion_request::AnswerPrivateDiscussionRequestUseCase;
+use crate::features::private_discussions::domain::entities::private_discussion::PrivateDiscussionRequestAnswer;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::services::private_discussion_request_notification_service::PrivateDiscussionRequestNotificationService;
+use crate::features::profile::domain::entities::UserPublicDataCache;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+use actix_web::web::{Data, Json, Path, ReqData};
+use actix_web::{put, HttpResponse, Responder};
+use chrono::Utc;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[put("/{discussion_id}/request")]
+pub async fn answer_private_discussion_request(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionRequestParams>,
+    body: Json<PrivateDiscussionRequestAnswerRequest>,
+    request_claims: ReqData<Claims>,
+    redis_client: Data<Client>,
+    translator: Data<Arc<Translator>>,
+    user_public_data_cache: Data<UserPublicDataCache>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = AnswerPrivateDiscussionRequestUseCase::new(
+        PrivateDiscussionRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        UserBlockRepositoryImpl::new(pool_clone.clone()),
+    );
+    let message_repo = PrivateMessageRepositoryImpl::new(pool_clone);
+
+    let (discussion, participation, requester) = match use_case
+        .execute(
+            params.discussion_id,
+            request_claims.user_id,
+            body.answer,
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::PrivateDiscussionNotFound
+                | AppError::PrivateDiscussionRequestNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                _ => {
+                    error!("Error: {:?}", e);
+                    HttpResponse::InternalServerError().json(e.to_response())
+                }
+            };
+        }
+    };
+
+    // Notifications were held while the request was pending, the unseen messages are summed up
+    // in a single one once it is accepted
+    let held_count = if body.answer == PrivateDiscussionRequestAnswer::Accept {
+        match message_repo
+            .get_unseen_count_for_discussions_with_executor(
+                vec![discussion.id],
+                request_claims.user_id,
+                Utc::now(),
+                &mut *transaction,
+            )
+            .await
+        {
+            Ok(counts) => counts.first().map(|(_, count)| *count).unwrap_or_default(),
+            Err(e) => {
+                error!("Error: {}", e);
+                0
+            }
+        }
+    } else {
+        0
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    PrivateDiscussionRequestNotificationService::new(pool.get_ref().clone())
+        .notify(
+            discussion.id,
+            request_claims.user_id,
+            requester,
+            held_count,
+            redis_client,
+            &translator,
+            &user_public_data_cache,
+        )
+        .await;
+
+    HttpResponse::Ok().json(PrivateDiscussionResponse {
+        code: "PRIVATE_DISCUSSION_REQUEST_ANSWERED".to_string(),
+        discussion: Some(discussion.to_private_discussion_data(
+            Some(&participation),
+            None,
+            Some(requester),
+            0,
+        )),
+    })
+}
@@ -3,13 +3,19 @@
 use crate::core::constants::errors::AppError;
 use crate::core::helpers::mock_now::now;
 use crate::features::auth::domain::entities::Claims;
+use crate::features::challenges::infrastructure::repositories::challenge_participation_repository::ChallengeParticipationRepositoryImpl;
+use crate::features::private_discussions::application::use_cases::apply_message_request_rules::ApplyMessageRequestRulesUseCase;
 use crate::features::private_discussions::application::use_cases::create_private_discussion::CreatePrivateDiscussionUseCase;
-use crate::features::private_discussions::domain::entities::private_discussion::PrivateDiscussion;
+use crate::features::private_discussions::domain::entities::private_discussion::{
+    PrivateDiscussion, PrivateDiscussionRequestStatus, PrivateMessageRetention,
+};
 use crate::features::private_discussions::domain::entities::private_discussion_participation::PrivateDiscussionParticipation;
 use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
 use crate::features::private_discussions::application::dto::requests::private_discussion::PrivateDiscussionCreateRequest;
 use crate::features::private_discussions::application::dto::responses::private_discussion::PrivateDiscussionResponse;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
 use actix_web::web::{Data, Json, ReqData};
 use actix_web::{post, HttpResponse, Responder};
 use sqlx::PgPool;
@@ -35,6 +41,7 @@ pub async fn create_private_discussion(
     let pool_clone = pool.get_ref().clone();
     let discussion_repo = PrivateDiscussionRepositoryImpl::new(pool_clone.clone());
     let participation_repo = PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone());
+    let user_block_repo = UserBlockRepositoryImpl::new(pool_clone.clone());
 
     // Check if discussion already exists
     match discussion_repo
@@ -58,8 +65,7 @@ pub async fn create_private_discussion(
                     return HttpResponse::Ok().json(PrivateDiscussionResponse {
                         code: "PRIVATE_DISCUSSION_ALREADY_CREATED".to_string(),
                         discussion: Some(existing_discussion.to_private_discussion_data(
-                            Some(participation.color),
-                            Some(participation.has_blocked),
+                            Some(&participation),
                             None,
                             Some(body.recipient),
                             0,
@@ -91,10 +97,35 @@ pub async fn create_private_discussion(
         }
     }
 
+    // Users who blocked each other can not start a discussion
+    match user_block_repo
+        .exists_between_with_executor(body.recipient, request_claims.user_id, &mut *transaction)
+        .await
+    {
+        Ok(false) => {}
+        Ok(true) => {
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return HttpResponse::Forbidden().json(AppError::UserBlocked.to_response());
+        }
+        Err(_) => {
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+        }
+    }
+
     // Create new discussion
     let discussion = PrivateDiscussion {
         id: Uuid::new_v4(),
         created_at: now(),
+        name: None,
+        is_group: false,
+        message_retention: PrivateMessageRetention::Off.to_string(),
+        proposed_message_retention: None,
+        message_retention_proposed_by: None,
     };
 
     let participation1 = PrivateDiscussionParticipation {
@@ -104,28 +135,54 @@ pub async fn create_private_discussion(
         color: "blue".to_string(),
         created_at: now(),
         has_blocked: false,
+        is_admin: false,
+        muted_until: None,
+        archived_at: None,
+        pinned_at: None,
+        request_status: PrivateDiscussionRequestStatus::Accepted.to_string(),
+        requested_by: None,
     };
 
-    let participation2 = PrivateDiscussionParticipation {
+    let mut participation2 = PrivateDiscussionParticipation {
         id: Uuid::new_v4(),
         user_id: body.recipient,
         discussion_id: discussion.id,
         color: "blue".to_string(),
         created_at: now(),
         has_blocked: false,
+        is_admin: false,
+        muted_until: None,
+        archived_at: None,
+        pinned_at: None,
+        request_status: PrivateDiscussionRequestStatus::Accepted.to_string(),
+        requested_by: None,
     };
 
     // Execute use case
+    let message_request_rules_use_case = ApplyMessageRequestRulesUseCase::new(
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        ChallengeParticipationRepositoryImpl::new(pool_clone.clone()),
+        UserRepositoryImpl::new(pool_clone),
+    );
     let create_discussion_use_case =
         CreatePrivateDiscussionUseCase::new(discussion_repo, participation_repo);
-    let result = create_discussion_use_case
+    let mut result = message_request_rules_use_case
         .execute(
-            &discussion,
-            &participation1,
-            &participation2,
+            &mut participation2,
+            request_claims.user_id,
             &mut transaction,
         )
         .await;
+    if result.is_ok() {
+        result = create_discussion_use_case
+            .execute(
+                &discussion,
+                &participation1,
+                &participation2,
+                &mut transaction,
+            )
+            .await;
+    }
 
     if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
@@ -137,13 +194,14 @@ pub async fn create_private_discussion(
         Ok(_) => HttpResponse::Ok().json(PrivateDiscussionResponse {
             code: "PRIVATE_DISCUSSION_CREATED".to_string(),
             discussion: Some(discussion.to_private_discussion_data(
-                Some(participation1.color),
-                Some(participation1.has_blocked),
+                Some(&participation1),
                 None,
                 Some(body.recipient),
                 0,
             )),
         }),
+        Err(AppError::PrivateDiscussionRequestLimitReached) => HttpResponse::TooManyRequests()
+            .json(AppError::PrivateDiscussionRequestLimitReached.to_response()),
         Err(e) => {
             error!("Error: {:?}", e);
             HttpResponse::InternalServerError().json(e.to_response())
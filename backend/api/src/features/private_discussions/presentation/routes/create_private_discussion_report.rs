@@ -0,0 +1,92 @@
+// Create private discussion report route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_discussion_report::PrivateDiscussionReportCreateRequest;
+use crate::features::private_discussions::application::dto::responses::private_discussion_report::PrivateDiscussionReportResponse;
+use crate::features::private_discussions::application::use_cases::create_private_discussion_report::CreatePrivateDiscussionReportUseCase;
+use crate::features::private_discussions::domain::entities::private_discussion_report::PrivateDiscussionReport;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_message_repository::PrivateDiscussionReportMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_repository::PrivateDiscussionReportRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use actix_web::web::{Data, Json, ReqData};
+use actix_web::{post, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+#[post("/")]
+pub async fn create_private_discussion_report(
+    pool: Data<PgPool>,
+    body: Json<PrivateDiscussionReportCreateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+
+    let mut report = PrivateDiscussionReport {
+        id: Uuid::new_v4(),
+        discussion_id: body.discussion_id,
+        reporter: request_claims.user_id,
+        reported_user: body.reported_user,
+        reason: body.reason,
+        created_at: now(),
+        resolved_at: None,
+        moderator: None,
+        action: None,
+        note: None,
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = CreatePrivateDiscussionReportUseCase::new(
+        PrivateDiscussionReportRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionReportMessageRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageRepositoryImpl::new(pool_clone),
+    );
+
+    if let Err(e) = use_case
+        .execute(&mut report, &body.messages, &mut transaction)
+        .await
+    {
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::PrivateDiscussionNotFound => HttpResponse::NotFound().json(e.to_response()),
+            AppError::PrivateDiscussionReportUserInvalid
+            | AppError::PrivateDiscussionReportReasonEmpty
+            | AppError::PrivateDiscussionReportReasonTooLong
+            | AppError::PrivateDiscussionReportMessagesInvalid
+            | AppError::PrivateDiscussionReportEvidenceInvalid => {
+                HttpResponse::BadRequest().json(e.to_response())
+            }
+            _ => {
+                error!("Error: {:?}", e);
+                HttpResponse::InternalServerError().json(e.to_response())
+            }
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(PrivateDiscussionReportResponse {
+        code: "PRIVATE_DISCUSSION_REPORT_CREATED".to_string(),
+        report: Some(report.to_private_discussion_report_data()),
+    })
+}
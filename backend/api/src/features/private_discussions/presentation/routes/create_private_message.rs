@@ -6,14 +6,18 @@ use crate::core::constants::errors::AppError;
 use crate::core::helpers::{mock_now::now, translation::Translator};
 use crate::core::structs::redis_messages::NotificationEvent;
 use crate::features::auth::domain::entities::Claims;
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
 use crate::features::private_discussions::application::use_cases::create_private_message::CreatePrivateMessageUseCase;
 use crate::features::private_discussions::domain::entities::private_message::PrivateMessage;
 use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
 use crate::features::private_discussions::application::dto::requests::private_message::PrivateMessageCreateRequest;
 use crate::features::private_discussions::application::dto::responses::private_message::PrivateMessageResponse;
 use crate::features::profile::domain::entities::UserPublicDataCache;
+use crate::features::public_discussions::infrastructure::repositories::user_suspension_repository::UserSuspensionRepositoryImpl;
 use actix_web::web::{Data, Json, ReqData};
 use actix_web::{post, HttpResponse, Responder};
 use redis::{AsyncCommands, Client};
@@ -44,10 +48,12 @@ pub async fn create_private_message(
     let pool_clone = pool.get_ref().clone();
     let message_repo = PrivateMessageRepositoryImpl::new(pool_clone.clone());
     let discussion_repo = PrivateDiscussionRepositoryImpl::new(pool_clone.clone());
-    let _participation_repo = PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone());
+    let participation_repo = PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone());
+    let device_key_repo = DeviceKeyRepositoryImpl::new(pool_clone.clone());
+    let attachment_repo = PrivateMessageAttachmentRepositoryImpl::new(pool_clone.clone());
 
     // Create message entity
-    let private_message = PrivateMessage {
+    let mut private_message = PrivateMessage {
         id: Uuid::new_v4(),
         discussion_id: body.discussion_id,
         creator: request_claims.user_id,
@@ -58,13 +64,53 @@ pub async fn create_private_message(
         recipient_encrypted_session_key: body.recipient_encrypted_session_key.clone(),
         deleted: false,
         seen: false,
+        encrypted_session_keys: json!(body.encrypted_session_keys),
+        device_encrypted_session_keys: json!(body.device_encrypted_session_keys),
+        delivered_at: None,
+        seen_at: None,
+        expires_at: None,
+        attachment_ids: body.attachment_ids.clone(),
     };
 
     // Execute use case
-    let create_message_use_case = CreatePrivateMessageUseCase::new(message_repo, discussion_repo);
-    let result = create_message_use_case
-        .execute(&private_message, &mut transaction)
-        .await;
+    let create_message_use_case = CreatePrivateMessageUseCase::new(
+        message_repo,
+        discussion_repo,
+        participation_repo,
+        device_key_repo,
+        attachment_repo,
+        UserSuspensionRepositoryImpl::new(pool.get_ref().clone()),
+        PrivateMessageReceiptRepositoryImpl::new(pool.get_ref().clone()),
+    );
+    let participations = match create_message_use_case
+        .execute(&mut private_message, &mut transaction)
+        .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::PrivateDiscussionNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                AppError::UserSuspended | AppError::PrivateDiscussionRequestDeclined => {
+                    HttpResponse::Forbidden().json(e.to_response())
+                }
+                AppError::PrivateMessageContentEmpty
+                | AppError::PrivateMessageContentTooLong
+                | AppError::PrivateMessageSessionKeysMismatch
+                | AppError::PrivateMessageDeviceKeysMismatch
+                | AppError::PrivateMessageTooManyAttachments
+                | AppError::PrivateMessageAttachmentNotFound => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
 
     if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
@@ -72,88 +118,31 @@ pub async fn create_private_message(
             .json(AppError::DatabaseTransaction.to_response());
     }
 
-    match result {
-        Ok(_) => {
-            // Get recipient for notification - use a new transaction since we already committed
-            let participation_repo_for_notif =
-                PrivateDiscussionParticipationRepositoryImpl::new(pool.get_ref().clone());
-            let recipients = match pool.begin().await {
-                Ok(mut notif_transaction) => {
-                    match participation_repo_for_notif
-                        .get_recipients_with_executor(
-                            vec![private_message.discussion_id],
-                            request_claims.user_id,
-                            &mut *notif_transaction,
-                        )
-                        .await
-                    {
-                        Ok(r) => {
-                            let _ = notif_transaction.commit().await;
-                            r
-                        }
-                        Err(_) => {
-                            let _ = notif_transaction.rollback().await;
-                            vec![]
-                        }
-                    }
-                }
-                Err(_) => vec![],
-            };
-
-            // Publish Redis event
-            if let Ok(mut con) = redis_client
-                .get_ref()
-                .get_multiplexed_async_connection()
-                .await
-            {
-                let _: Result<(), redis::RedisError> = con
-                    .publish(
-                        "private_message_created",
-                        json!(NotificationEvent {
-                            data: json!(private_message.to_private_message_data()).to_string(),
-                            recipient: request_claims.user_id,
-                            title: None,
-                            body: None,
-                            url: None,
-                        })
-                        .to_string(),
-                    )
-                    .await;
-
-                if let Some(recipient) = recipients.first() {
-                    let _: Result<(), redis::RedisError> = con
-                        .publish(
-                            "private_message_created",
-                            json!(NotificationEvent {
-                                data: json!(private_message.to_private_message_data()).to_string(),
-                                recipient: recipient.user_id,
-                                title: None,
-                                body: None,
-                                url: None,
-                            })
-                            .to_string(),
-                        )
-                        .await;
-                }
-            }
-
-            HttpResponse::Ok().json(PrivateMessageResponse {
-                code: "PRIVATE_MESSAGE_CREATED".to_string(),
-                message: Some(private_message.to_private_message_data()),
-            })
-        }
-        Err(AppError::PrivateDiscussionNotFound) => {
-            HttpResponse::NotFound().json(AppError::PrivateDiscussionNotFound.to_response())
-        }
-        Err(AppError::PrivateMessageContentEmpty) => {
-            HttpResponse::BadRequest().json(AppError::PrivateMessageContentEmpty.to_response())
-        }
-        Err(AppError::PrivateMessageContentTooLong) => {
-            HttpResponse::BadRequest().json(AppError::PrivateMessageContentTooLong.to_response())
-        }
-        Err(e) => {
-            error!("Error: {:?}", e);
-            HttpResponse::InternalServerError().json(e.to_response())
+    // Publish Redis event to every member of the discussion, the creator included
+    if let Ok(mut con) = redis_client
+        .get_ref()
+        .get_multiplexed_async_connection()
+        .await
+    {
+        for participation in &participations {
+            let _: Result<(), redis::RedisError> = con
+                .publish(
+                    "private_message_created",
+                    json!(NotificationEvent {
+                        data: json!(private_message.to_private_message_data()).to_string(),
+                        recipient: participation.user_id,
+                        title: None,
+                        body: None,
+                        url: None,
+                    })
+                    .to_string(),
+                )
+                .await;
         }
     }
+
+    HttpResponse::Ok().json(PrivateMessageResponse {
+        code: "PRIVATE_MESSAGE_CREATED".to_string(),
+        message: Some(private_message.to_private_message_data()),
+    })
 }
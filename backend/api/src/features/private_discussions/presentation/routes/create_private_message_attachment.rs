@@ -0,0 +1,84 @@
+// Create private message attachment route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::attachments::domain::services::storage_backend::StorageBackend;
+use crate::features::attachments::presentation::routes::create_attachment::read_file_field;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::responses::private_message::PrivateMessageAttachmentResponse;
+use crate::features::private_discussions::application::use_cases::create_private_message_attachment::CreatePrivateMessageAttachmentUseCase;
+use crate::features::private_discussions::domain::entities::private_message_attachment::PRIVATE_MESSAGE_ATTACHMENT_MAX_SIZE_IN_BYTES;
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
+use actix_multipart::Multipart;
+use actix_web::web::{Data, ReqData};
+use actix_web::{post, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[post("/attachments/")]
+pub async fn create_private_message_attachment(
+    pool: Data<PgPool>,
+    storage_backend: Data<dyn StorageBackend>,
+    payload: Multipart,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    // The declared content type is meaningless for an encrypted file
+    let content = match read_file_field(payload, PRIVATE_MESSAGE_ATTACHMENT_MAX_SIZE_IN_BYTES).await
+    {
+        Ok((content, _)) => content,
+        Err(AppError::AttachmentTooLarge) => {
+            return HttpResponse::PayloadTooLarge()
+                .json(AppError::PrivateMessageAttachmentTooLarge.to_response())
+        }
+        Err(e) => return HttpResponse::BadRequest().json(e.to_response()),
+    };
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let attachment_repo = PrivateMessageAttachmentRepositoryImpl::new(pool.get_ref().clone());
+    let use_case =
+        CreatePrivateMessageAttachmentUseCase::new(attachment_repo, storage_backend.into_inner());
+
+    let attachment = match use_case
+        .execute(request_claims.user_id, content, &mut transaction)
+        .await
+    {
+        Ok(attachment) => attachment,
+        Err(e) => {
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::AttachmentFileMissing => HttpResponse::BadRequest().json(e.to_response()),
+                AppError::PrivateMessageAttachmentTooLarge => {
+                    HttpResponse::PayloadTooLarge().json(e.to_response())
+                }
+                AppError::PrivateMessageAttachmentQuotaExceeded => {
+                    HttpResponse::Forbidden().json(e.to_response())
+                }
+                _ => {
+                    error!("Error: {:?}", e);
+                    HttpResponse::InternalServerError().json(e.to_response())
+                }
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(PrivateMessageAttachmentResponse {
+        code: "PRIVATE_MESSAGE_ATTACHMENT_CREATED".to_string(),
+        attachment: Some(attachment.to_private_message_attachment_data()),
+    })
+}
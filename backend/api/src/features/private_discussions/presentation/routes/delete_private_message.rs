@@ -2,9 +2,13 @@
 
 use crate::core::constants::errors::AppError;
 use crate::core::structs::redis_messages::NotificationEvent;
+use crate::features::attachments::domain::services::storage_backend::{
+    delete_private_message_attachment_files, StorageBackend,
+};
 use crate::features::auth::domain::entities::Claims;
 use crate::features::private_discussions::application::use_cases::delete_private_message::DeletePrivateMessageUseCase;
 use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
 use crate::features::private_discussions::application::dto::requests::private_message::DeletePrivateMessageParams;
 use crate::features::private_discussions::application::dto::responses::private_message::PrivateMessageResponse;
@@ -20,6 +24,7 @@ pub async fn delete_private_message(
     pool: Data<PgPool>,
     query: Query<DeletePrivateMessageParams>,
     redis_client: Data<Client>,
+    storage_backend: Data<dyn StorageBackend>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
     let params = query.into_inner();
@@ -37,6 +42,7 @@ pub async fn delete_private_message(
     let pool_clone = pool.get_ref().clone();
     let message_repo = PrivateMessageRepositoryImpl::new(pool_clone.clone());
     let participation_repo = PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone());
+    let attachment_repo = PrivateMessageAttachmentRepositoryImpl::new(pool_clone.clone());
 
     // Get message for notification
     let private_message = match message_repo
@@ -59,7 +65,7 @@ pub async fn delete_private_message(
     };
 
     // Execute use case
-    let delete_message_use_case = DeletePrivateMessageUseCase::new(message_repo);
+    let delete_message_use_case = DeletePrivateMessageUseCase::new(message_repo, attachment_repo);
     let result = delete_message_use_case
         .execute(params.message_id, request_claims.user_id, &mut transaction)
         .await;
@@ -81,7 +87,9 @@ pub async fn delete_private_message(
     }
 
     match result {
-        Ok(_) => {
+        Ok(attachments) => {
+            delete_private_message_attachment_files(storage_backend.get_ref(), &attachments).await;
+
             // Publish Redis event
             if let Ok(mut con) = redis_client
                 .get_ref()
@@ -0,0 +1,73 @@
+// Distribute private message session keys route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_discussion::PrivateDiscussionSessionKeysParams;
+use crate::features::private_discussions::application::dto::requests::private_message::PrivateMessageSessionKeysDistributeRequest;
+use crate::features::private_discussions::application::dto::responses::private_message::PrivateMessageSessionKeysResponse;
+use crate::features::private_discussions::application::use_cases::distribute_private_message_session_keys::DistributePrivateMessageSessionKeysUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use actix_web::web::{Data, Json, Path, ReqData};
+use actix_web::{post, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[post("/{discussion_id}/session-keys")]
+pub async fn distribute_private_message_session_keys(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionSessionKeysParams>,
+    body: Json<PrivateMessageSessionKeysDistributeRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = DistributePrivateMessageSessionKeysUseCase::new(
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageRepositoryImpl::new(pool_clone),
+    );
+
+    if let Err(e) = use_case
+        .execute(
+            request_claims.user_id,
+            params.discussion_id,
+            body.user_id,
+            body.session_keys,
+            &mut transaction,
+        )
+        .await
+    {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::PrivateDiscussionNotFound
+            | AppError::PrivateDiscussionParticipationNotFound => {
+                HttpResponse::NotFound().json(e.to_response())
+            }
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(PrivateMessageSessionKeysResponse {
+        code: "PRIVATE_MESSAGE_SESSION_KEYS_DISTRIBUTED".to_string(),
+    })
+}
@@ -0,0 +1,61 @@
+// Get private discussion device keys route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::application::dto::responses::DeviceKeysResponse;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::auth::infrastructure::repositories::device_key_repository::DeviceKeyRepositoryImpl;
+use crate::features::private_discussions::application::dto::requests::private_discussion_participation::PrivateDiscussionMembersParams;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use actix_web::web::{Data, Path, ReqData};
+use actix_web::{get, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+// Keys of the devices a message of the discussion must be encrypted for
+#[get("/{discussion_id}/device-keys")]
+pub async fn get_private_discussion_device_keys(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionMembersParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let pool_clone = pool.get_ref().clone();
+    let participation_repo = PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone());
+    let device_key_repo = DeviceKeyRepositoryImpl::new(pool_clone);
+
+    let participations = match participation_repo
+        .get_by_discussion_id_with_executor(params.discussion_id, pool.get_ref())
+        .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+        }
+    };
+
+    if !participations
+        .iter()
+        .any(|p| p.user_id == request_claims.user_id)
+    {
+        return HttpResponse::NotFound().json(AppError::PrivateDiscussionNotFound.to_response());
+    }
+
+    let device_keys = match device_key_repo
+        .get_by_user_ids_with_executor(
+            participations.iter().map(|p| p.user_id).collect(),
+            pool.get_ref(),
+        )
+        .await
+    {
+        Ok(k) => k,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+        }
+    };
+
+    HttpResponse::Ok().json(DeviceKeysResponse {
+        code: "DEVICE_KEYS_FETCHED".to_string(),
+        device_keys: device_keys.iter().map(|k| k.to_device_key_data()).collect(),
+    })
+}
@@ -0,0 +1,48 @@
+// Get private discussion members route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_discussion_participation::PrivateDiscussionMembersParams;
+use crate::features::private_discussions::application::dto::responses::private_discussion_participation::PrivateDiscussionMembersResponse;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use actix_web::web::{Data, Path, ReqData};
+use actix_web::{get, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/{discussion_id}/members")]
+pub async fn get_private_discussion_members(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionMembersParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let participation_repo =
+        PrivateDiscussionParticipationRepositoryImpl::new(pool.get_ref().clone());
+
+    let participations = match participation_repo
+        .get_by_discussion_id_with_executor(params.discussion_id, pool.get_ref())
+        .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+        }
+    };
+
+    // Only members can see who takes part in a discussion
+    if !participations
+        .iter()
+        .any(|p| p.user_id == request_claims.user_id)
+    {
+        return HttpResponse::NotFound().json(AppError::PrivateDiscussionNotFound.to_response());
+    }
+
+    HttpResponse::Ok().json(PrivateDiscussionMembersResponse {
+        code: "PRIVATE_DISCUSSION_MEMBERS_FETCHED".to_string(),
+        members: participations
+            .iter()
+            .map(|p| p.to_private_discussion_member_data())
+            .collect(),
+    })
+}
@@ -1,13 +1,17 @@
 // Get private discussion messages route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
-use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
-use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::core::helpers::mock_now::now;
+use crate::features::auth::domain::entities::Claims;
 use crate::features::private_discussions::application::dto::requests::private_discussion::{
     GetPrivateDiscussionMessagesParams, GetPrivateDiscussionMessagesQuery,
 };
 use crate::features::private_discussions::application::dto::responses::private_message::PrivateMessagesResponse;
-use actix_web::web::{Data, Path, Query};
+use crate::features::private_discussions::application::use_cases::get_private_discussion_messages::GetPrivateDiscussionMessagesUseCase;
+use crate::features::private_discussions::domain::entities::private_message::PrivateMessagePage;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use actix_web::web::{Data, Path, Query, ReqData};
 use actix_web::{get, HttpResponse, Responder};
 use sqlx::PgPool;
 use tracing::error;
@@ -17,9 +21,24 @@ pub async fn get_private_discussion_messages(
     pool: Data<PgPool>,
     query: Query<GetPrivateDiscussionMessagesQuery>,
     params: Path<GetPrivateDiscussionMessagesParams>,
+    request_claims: ReqData<Claims>,
 ) -> impl Responder {
     let query = query.into_inner();
 
+    let page = match PrivateMessagePage::new(
+        query.before.as_deref(),
+        query.after.as_deref(),
+        query.around,
+        query.before_date,
+        query.limit,
+    ) {
+        Some(page) => page,
+        None => {
+            return HttpResponse::BadRequest()
+                .json(AppError::InvalidPrivateMessageCursor.to_response());
+        }
+    };
+
     let mut transaction = match pool.begin().await {
         Ok(t) => t,
         Err(e) => {
@@ -29,47 +48,34 @@ pub async fn get_private_discussion_messages(
         }
     };
 
-    // Create repositories
+    // Create repositories and use case
     let pool_clone = pool.get_ref().clone();
-    let discussion_repo = PrivateDiscussionRepositoryImpl::new(pool_clone.clone());
-    let message_repo = PrivateMessageRepositoryImpl::new(pool_clone.clone());
-
-    // Check if discussion exists
-    match discussion_repo
-        .get_by_id_with_executor(params.discussion_id, &mut *transaction)
-        .await
-    {
-        Ok(Some(_)) => {}
-        Ok(None) => {
-            if let Err(e) = transaction.rollback().await {
-                error!("Error rolling back: {}", e);
-            }
-            return HttpResponse::NotFound()
-                .json(AppError::PrivateDiscussionNotFound.to_response());
-        }
-        Err(_) => {
-            if let Err(e) = transaction.rollback().await {
-                error!("Error rolling back: {}", e);
-            }
-            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
-        }
-    }
+    let use_case = GetPrivateDiscussionMessagesUseCase::new(
+        PrivateMessageRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone),
+    );
 
-    // Get messages
-    let messages = match message_repo
-        .get_by_discussion_id_with_executor(
+    let (messages, older_cursor, newer_cursor) = match use_case
+        .execute(
             params.discussion_id,
-            query.before_date,
-            &mut *transaction,
+            request_claims.user_id,
+            page,
+            now(),
+            &mut transaction,
         )
         .await
     {
-        Ok(m) => m,
-        Err(_) => {
+        Ok(r) => r,
+        Err(e) => {
             if let Err(e) = transaction.rollback().await {
                 error!("Error rolling back: {}", e);
             }
-            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+            return match e {
+                AppError::PrivateDiscussionNotFound | AppError::PrivateMessageNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
         }
     };
 
@@ -85,5 +91,7 @@ pub async fn get_private_discussion_messages(
             .iter()
             .map(|m| m.to_private_message_data())
             .collect(),
+        older_cursor,
+        newer_cursor,
     })
 }
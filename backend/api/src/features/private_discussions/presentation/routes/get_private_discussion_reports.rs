@@ -0,0 +1,58 @@
+// Get private discussion reports route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::core::presentation::guards::permission::require_permission;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::responses::private_discussion_report::PrivateDiscussionReportsResponse;
+use crate::features::private_discussions::application::use_cases::get_private_discussion_reports::GetPrivateDiscussionReportsUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_message_repository::PrivateDiscussionReportMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_repository::PrivateDiscussionReportRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
+use actix_web::web::{Data, ReqData};
+use actix_web::{get, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/")]
+pub async fn get_private_discussion_reports(
+    pool: Data<PgPool>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ReviewPrivateReports) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = GetPrivateDiscussionReportsUseCase::new(
+        PrivateDiscussionReportRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionReportMessageRepositoryImpl::new(pool_clone),
+    );
+    let result = use_case.execute(&mut transaction).await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(reports) => HttpResponse::Ok().json(PrivateDiscussionReportsResponse {
+            code: "PRIVATE_DISCUSSION_REPORTS_FETCHED".to_string(),
+            reports,
+        }),
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
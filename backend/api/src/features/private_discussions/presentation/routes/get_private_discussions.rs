@@ -1,20 +1,24 @@
 // Get private discussions route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
 use crate::features::auth::domain::entities::Claims;
 use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
 use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::private_discussions::application::dto::requests::private_discussion::GetPrivateDiscussionsQuery;
 use crate::features::private_discussions::application::dto::responses::private_discussion::PrivateDiscussionsResponse;
-use actix_web::web::{Data, ReqData};
+use actix_web::web::{Data, Query, ReqData};
 use actix_web::{get, HttpResponse, Responder};
 use sqlx::PgPool;
+use std::cmp::Reverse;
 use tracing::error;
 use uuid::Uuid;
 
 #[get("/")]
 pub async fn get_private_discussions(
     pool: Data<PgPool>,
+    query: Query<GetPrivateDiscussionsQuery>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
     let mut transaction = match pool.begin().await {
@@ -97,7 +101,11 @@ pub async fn get_private_discussions(
 
     // Get last messages
     let messages = match message_repo
-        .get_last_messages_for_discussions_with_executor(discussion_ids.clone(), &mut *transaction)
+        .get_last_messages_for_discussions_with_executor(
+            discussion_ids.clone(),
+            now(),
+            &mut *transaction,
+        )
         .await
     {
         Ok(m) => m,
@@ -114,6 +122,7 @@ pub async fn get_private_discussions(
         .get_unseen_count_for_discussions_with_executor(
             discussion_ids,
             request_claims.user_id,
+            now(),
             &mut *transaction,
         )
         .await
@@ -133,37 +142,59 @@ pub async fn get_private_discussions(
             .json(AppError::DatabaseTransaction.to_response());
     }
 
-    HttpResponse::Ok().json(PrivateDiscussionsResponse {
-        code: "PRIVATE_DISCUSSIONS_FETCHED".to_string(),
-        discussions: discussions
-            .iter()
-            .map(|d| {
-                let participation = discussion_participations
-                    .iter()
-                    .find(|p| p.discussion_id == d.id);
-
-                let last_message = messages
-                    .clone()
-                    .into_iter()
-                    .find(|m| m.discussion_id == d.id);
-
-                let recipient = recipients.iter().find(|p| p.discussion_id == d.id);
-                let unseen_message_for_this_discussion = unseen_messages
-                    .iter()
-                    .filter(|p| p.0 == d.id)
-                    .map(|p| p.1)
-                    .next();
-
-                d.to_private_discussion_data(
-                    participation.map(|p| p.color.clone()),
-                    participation.map(|p| p.has_blocked),
-                    last_message.map(|m| m.to_private_message_data()),
+    let mut discussions = discussions
+        .iter()
+        .filter_map(|d| {
+            discussion_participations
+                .iter()
+                .find(|p| p.discussion_id == d.id)
+                .map(|p| (d, p))
+        })
+        // Declined requests are hidden from their recipient, pending ones have their own inbox
+        .filter(|(_, p)| !p.is_request_declined())
+        .filter(|(_, p)| p.is_request_pending() == query.requests)
+        .map(|(d, participation)| {
+            let last_message = messages
+                .clone()
+                .into_iter()
+                .find(|m| m.discussion_id == d.id);
+
+            let recipient = recipients.iter().find(|p| p.discussion_id == d.id);
+            let unseen_message_for_this_discussion = unseen_messages
+                .iter()
+                .filter(|p| p.0 == d.id)
+                .map(|p| p.1)
+                .next();
+
+            d.to_private_discussion_data(
+                Some(participation),
+                last_message.map(|m| m.to_private_message_data()),
+                // Groups have members instead of a recipient
+                if d.is_group {
+                    None
+                } else {
                     recipient
                         .map(|r| r.user_id)
-                        .or(Some(request_claims.user_id)),
-                    unseen_message_for_this_discussion.unwrap_or_default(),
-                )
-            })
-            .collect(),
+                        .or(Some(request_claims.user_id))
+                },
+                unseen_message_for_this_discussion.unwrap_or_default(),
+            )
+        })
+        .filter(|d| query.requests || d.is_archived == query.archived)
+        .collect::<Vec<_>>();
+
+    // Pinned discussions come first, the last pinned at the top
+    discussions.sort_by_key(|d| {
+        Reverse(
+            discussion_participations
+                .iter()
+                .find(|p| p.discussion_id == d.id)
+                .and_then(|p| p.pinned_at),
+        )
+    });
+
+    HttpResponse::Ok().json(PrivateDiscussionsResponse {
+        code: "PRIVATE_DISCUSSIONS_FETCHED".to_string(),
+        discussions,
     })
 }
@@ -0,0 +1,69 @@
+// Get private message attachment route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::attachments::domain::services::storage_backend::StorageBackend;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_message::PrivateMessageAttachmentParams;
+use crate::features::private_discussions::application::use_cases::get_private_message_attachment_content::GetPrivateMessageAttachmentContentUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_attachment_repository::PrivateMessageAttachmentRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use actix_web::web::{Data, Path, ReqData};
+use actix_web::{get, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/attachments/{attachment_id}")]
+pub async fn get_private_message_attachment(
+    pool: Data<PgPool>,
+    storage_backend: Data<dyn StorageBackend>,
+    params: Path<PrivateMessageAttachmentParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = GetPrivateMessageAttachmentContentUseCase::new(
+        PrivateMessageAttachmentRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone),
+        storage_backend.into_inner(),
+    );
+
+    let result = use_case
+        .execute(
+            params.attachment_id,
+            request_claims.user_id,
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        // Attachments never change, their id is enough to cache them
+        Ok(content) => HttpResponse::Ok()
+            .content_type("application/octet-stream")
+            .insert_header(("Cache-Control", "private, max-age=31536000, immutable"))
+            .body(content),
+        Err(AppError::PrivateMessageAttachmentNotFound) => {
+            HttpResponse::NotFound().json(AppError::PrivateMessageAttachmentNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
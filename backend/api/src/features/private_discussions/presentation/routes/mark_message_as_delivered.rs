@@ -0,0 +1,83 @@
+// Mark message as delivered route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_message::UpdatePrivateMessageParams;
+use crate::features::private_discussions::application::dto::responses::private_message::PrivateMessageResponse;
+use crate::features::private_discussions::application::use_cases::mark_private_message_as_delivered::MarkPrivateMessageAsDeliveredUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::services::private_discussion_event_service::PrivateDiscussionEventService;
+use actix_web::web::{Data, Path, ReqData};
+use actix_web::{get, HttpResponse, Responder};
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/mark-as-delivered/{message_id}")]
+pub async fn mark_message_as_delivered(
+    pool: Data<PgPool>,
+    params: Path<UpdatePrivateMessageParams>,
+    redis_client: Data<Client>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = MarkPrivateMessageAsDeliveredUseCase::new(
+        PrivateMessageRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageReceiptRepositoryImpl::new(pool_clone),
+    );
+
+    let (private_message, participations) = match use_case
+        .execute(params.message_id, request_claims.user_id, &mut transaction)
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::PrivateMessageNotFound => HttpResponse::NotFound().json(e.to_response()),
+                AppError::PrivateMessageUpdateNotDoneByCreator => {
+                    HttpResponse::Unauthorized().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    // Publish Redis event
+    if let Err(e) = PrivateDiscussionEventService::new(redis_client)
+        .publish_message_delivered_event(
+            &private_message,
+            &participations.iter().map(|p| p.user_id).collect::<Vec<_>>(),
+        )
+        .await
+    {
+        error!("Error: {}", e);
+    }
+
+    HttpResponse::Ok().json(PrivateMessageResponse {
+        code: "PRIVATE_MESSAGE_UPDATED".to_string(),
+        message: Some(private_message.to_private_message_data()),
+    })
+}
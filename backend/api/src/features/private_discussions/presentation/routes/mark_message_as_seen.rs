@@ -1,16 +1,17 @@
 // Mark message as seen route - uses clean architecture
 
 use crate::core::constants::errors::AppError;
-use crate::core::structs::redis_messages::NotificationEvent;
 use crate::features::auth::domain::entities::Claims;
-use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
-use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
 use crate::features::private_discussions::application::dto::requests::private_message::UpdatePrivateMessageParams;
 use crate::features::private_discussions::application::dto::responses::private_message::PrivateMessageResponse;
+use crate::features::private_discussions::application::use_cases::mark_private_message_as_seen::MarkPrivateMessageAsSeenUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::services::private_discussion_event_service::PrivateDiscussionEventService;
 use actix_web::web::{Data, Path, ReqData};
 use actix_web::{get, HttpResponse, Responder};
-use redis::{AsyncCommands, Client};
-use serde_json::json;
+use redis::Client;
 use sqlx::PgPool;
 use tracing::error;
 
@@ -30,76 +31,34 @@ pub async fn mark_message_as_seen(
         }
     };
 
-    // Create repositories
+    // Create repositories and use case
     let pool_clone = pool.get_ref().clone();
-    let message_repo = PrivateMessageRepositoryImpl::new(pool_clone.clone());
-    let participation_repo = PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone());
+    let use_case = MarkPrivateMessageAsSeenUseCase::new(
+        PrivateMessageRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageReceiptRepositoryImpl::new(pool_clone),
+    );
 
-    // Get message
-    let mut private_message = match message_repo
-        .get_by_id_with_executor(params.message_id, &mut *transaction)
+    let (private_message, participations) = match use_case
+        .execute(params.message_id, request_claims.user_id, &mut transaction)
         .await
     {
-        Ok(Some(m)) => m,
-        Ok(None) => {
-            if let Err(e) = transaction.rollback().await {
-                error!("Error rolling back: {}", e);
-            }
-            return HttpResponse::NotFound().json(AppError::PrivateMessageNotFound.to_response());
-        }
-        Err(_) => {
-            if let Err(e) = transaction.rollback().await {
-                error!("Error rolling back: {}", e);
-            }
-            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
-        }
-    };
-
-    // Get participations to find recipient
-    let participations = match participation_repo
-        .get_by_discussion_id_with_executor(private_message.discussion_id, &mut *transaction)
-        .await
-    {
-        Ok(p) => p,
-        Err(_) => {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Error: {:?}", e);
             if let Err(e) = transaction.rollback().await {
                 error!("Error rolling back: {}", e);
             }
-            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+            return match e {
+                AppError::PrivateMessageNotFound => HttpResponse::NotFound().json(e.to_response()),
+                AppError::PrivateMessageUpdateNotDoneByCreator => {
+                    HttpResponse::Unauthorized().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
         }
     };
 
-    let recipient_participation = participations
-        .into_iter()
-        .find(|p| p.user_id != private_message.creator);
-
-    // Check if user is recipient
-    if recipient_participation.is_none()
-        || recipient_participation.clone().unwrap().user_id != request_claims.user_id
-    {
-        if let Err(e) = transaction.rollback().await {
-            error!("Error rolling back: {}", e);
-        }
-        return HttpResponse::Unauthorized()
-            .json(AppError::PrivateMessageUpdateNotDoneByCreator.to_response());
-    }
-
-    // Mark as seen
-    private_message.seen = true;
-    match message_repo
-        .update_with_executor(&private_message, &mut *transaction)
-        .await
-    {
-        Ok(_) => {}
-        Err(_) => {
-            if let Err(e) = transaction.rollback().await {
-                error!("Error rolling back: {}", e);
-            }
-            return HttpResponse::InternalServerError()
-                .json(AppError::PrivateMessageUpdate.to_response());
-        }
-    }
-
     if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
         return HttpResponse::InternalServerError()
@@ -107,38 +66,14 @@ pub async fn mark_message_as_seen(
     }
 
     // Publish Redis event
-    if let Ok(mut con) = redis_client
-        .get_ref()
-        .get_multiplexed_async_connection()
+    if let Err(e) = PrivateDiscussionEventService::new(redis_client)
+        .publish_message_seen_event(
+            &private_message,
+            &participations.iter().map(|p| p.user_id).collect::<Vec<_>>(),
+        )
         .await
     {
-        let _: Result<(), redis::RedisError> = con
-            .publish(
-                "private_message_marked_as_seen",
-                json!(NotificationEvent {
-                    data: json!(private_message.to_private_message_data()).to_string(),
-                    recipient: request_claims.user_id,
-                    title: None,
-                    body: None,
-                    url: None,
-                })
-                .to_string(),
-            )
-            .await;
-
-        let _: Result<(), redis::RedisError> = con
-            .publish(
-                "private_message_marked_as_seen",
-                json!(NotificationEvent {
-                    data: json!(private_message.to_private_message_data()).to_string(),
-                    recipient: private_message.creator,
-                    title: None,
-                    body: None,
-                    url: None,
-                })
-                .to_string(),
-            )
-            .await;
+        error!("Error: {}", e);
     }
 
     HttpResponse::Ok().json(PrivateMessageResponse {
@@ -0,0 +1,99 @@
+// Mark private discussion as seen route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_discussion::{
+    PrivateDiscussionMarkAsSeenParams, PrivateDiscussionMarkAsSeenRequest,
+};
+use crate::features::private_discussions::application::dto::responses::private_message::PrivateMessagesResponse;
+use crate::features::private_discussions::application::use_cases::mark_private_discussion_as_seen::MarkPrivateDiscussionAsSeenUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::services::private_discussion_event_service::PrivateDiscussionEventService;
+use actix_web::web::{Data, Json, Path, ReqData};
+use actix_web::{post, HttpResponse, Responder};
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[post("/{discussion_id}/mark-as-seen")]
+pub async fn mark_private_discussion_as_seen(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionMarkAsSeenParams>,
+    body: Json<PrivateDiscussionMarkAsSeenRequest>,
+    redis_client: Data<Client>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = MarkPrivateDiscussionAsSeenUseCase::new(
+        PrivateMessageRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageReceiptRepositoryImpl::new(pool_clone),
+    );
+
+    let (messages, participations) = match use_case
+        .execute(
+            params.discussion_id,
+            body.message_id,
+            request_claims.user_id,
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::PrivateDiscussionNotFound | AppError::PrivateMessageNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    // Publish Redis event
+    if !messages.is_empty() {
+        if let Err(e) = PrivateDiscussionEventService::new(redis_client)
+            .publish_discussion_seen_event(
+                params.discussion_id,
+                request_claims.user_id,
+                &messages,
+                &participations.iter().map(|p| p.user_id).collect::<Vec<_>>(),
+            )
+            .await
+        {
+            error!("Error: {}", e);
+        }
+    }
+
+    HttpResponse::Ok().json(PrivateMessagesResponse {
+        code: "PRIVATE_DISCUSSION_MARKED_AS_SEEN".to_string(),
+        messages: messages
+            .iter()
+            .map(|m| m.to_private_message_data())
+            .collect(),
+        older_cursor: None,
+        newer_cursor: None,
+    })
+}
@@ -1,10 +1,27 @@
 // Private discussions presentation routes
 
+pub mod add_private_discussion_member;
+pub mod answer_private_discussion_request;
 pub mod create_private_discussion;
+pub mod create_private_discussion_group;
+pub mod create_private_discussion_report;
 pub mod create_private_message;
+pub mod create_private_message_attachment;
 pub mod delete_private_message;
+pub mod distribute_private_message_session_keys;
+pub mod get_private_discussion_device_keys;
+pub mod get_private_discussion_members;
 pub mod get_private_discussion_messages;
+pub mod get_private_discussion_reports;
 pub mod get_private_discussions;
+pub mod get_private_message_attachment;
+pub mod mark_message_as_delivered;
 pub mod mark_message_as_seen;
+pub mod mark_private_discussion_as_seen;
+pub mod remove_private_discussion_member;
+pub mod resolve_private_discussion_report;
+pub mod update_private_discussion_member;
 pub mod update_private_discussion_participation;
+pub mod update_private_discussion_retention;
+pub mod update_private_discussion_settings;
 pub mod update_private_message;
@@ -0,0 +1,101 @@
+// Remove private discussion member route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_discussion_participation::PrivateDiscussionMemberParams;
+use crate::features::private_discussions::application::dto::responses::private_discussion_participation::PrivateDiscussionMembersResponse;
+use crate::features::private_discussions::application::use_cases::remove_private_discussion_member::RemovePrivateDiscussionMemberUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_message_repository::PrivateMessageRepositoryImpl;
+use crate::features::private_discussions::infrastructure::services::private_discussion_event_service::PrivateDiscussionEventService;
+use actix_web::web::{Data, Path, ReqData};
+use actix_web::{delete, HttpResponse, Responder};
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/{discussion_id}/members/{user_id}")]
+pub async fn remove_private_discussion_member(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionMemberParams>,
+    redis_client: Data<Client>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = RemovePrivateDiscussionMemberUseCase::new(
+        PrivateDiscussionRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageRepositoryImpl::new(pool_clone.clone()),
+        PrivateMessageReceiptRepositoryImpl::new(pool_clone),
+    );
+
+    let participations = match use_case
+        .execute(
+            request_claims.user_id,
+            params.discussion_id,
+            params.user_id,
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::PrivateDiscussionNotFound
+                | AppError::PrivateDiscussionParticipationNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                AppError::PrivateDiscussionMemberNotAdmin => {
+                    HttpResponse::Forbidden().json(e.to_response())
+                }
+                AppError::PrivateDiscussionNotAGroup => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    // The removed member is told too, so that their clients drop the discussion
+    let recipients: Vec<_> = participations
+        .iter()
+        .map(|p| p.user_id)
+        .chain(std::iter::once(params.user_id))
+        .collect();
+    if let Err(e) = PrivateDiscussionEventService::new(redis_client)
+        .publish_members_updated_event(params.discussion_id, &participations, &recipients)
+        .await
+    {
+        error!("Error: {}", e);
+    }
+
+    HttpResponse::Ok().json(PrivateDiscussionMembersResponse {
+        code: "PRIVATE_DISCUSSION_MEMBER_REMOVED".to_string(),
+        members: participations
+            .iter()
+            .map(|p| p.to_private_discussion_member_data())
+            .collect(),
+    })
+}
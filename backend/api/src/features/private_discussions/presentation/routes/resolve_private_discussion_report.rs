@@ -0,0 +1,181 @@
+// Resolve private discussion report route - uses clean architecture
+
+use std::sync::Arc;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::translation::Translator;
+use crate::core::presentation::guards::permission::require_permission;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::notifications::infrastructure::services::notification_service::{NotificationContent, NotificationService};
+use crate::features::private_discussions::application::dto::requests::private_discussion_report::{
+    PrivateDiscussionReportParams, ResolvePrivateDiscussionReportRequest,
+};
+use crate::features::private_discussions::application::dto::responses::private_discussion_report::PrivateDiscussionReportResponse;
+use crate::features::private_discussions::application::use_cases::resolve_private_discussion_report::ResolvePrivateDiscussionReportUseCase;
+use crate::features::private_discussions::domain::entities::private_discussion_report::PrivateDiscussionReportAction;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_report_repository::PrivateDiscussionReportRepositoryImpl;
+use crate::features::profile::domain::entities::UserPublicDataCache;
+use crate::features::public_discussions::domain::entities::public_message_moderation::MODERATION_DEFAULT_SUSPENSION_IN_DAYS;
+use crate::features::public_discussions::infrastructure::repositories::user_suspension_repository::UserSuspensionRepositoryImpl;
+use crate::features::roles::domain::entities::permission::Permission;
+use actix_web::web::{Data, Json, Path, ReqData};
+use actix_web::{post, HttpResponse, Responder};
+use fluent::FluentArgs;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[allow(clippy::too_many_arguments)]
+#[post("/{report_id}/resolve")]
+pub async fn resolve_private_discussion_report(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionReportParams>,
+    body: Json<ResolvePrivateDiscussionReportRequest>,
+    redis_client: Data<Client>,
+    translator: Data<Arc<Translator>>,
+    user_public_data_cache: Data<UserPublicDataCache>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ReviewPrivateReports) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = ResolvePrivateDiscussionReportUseCase::new(
+        PrivateDiscussionReportRepositoryImpl::new(pool_clone.clone()),
+        UserSuspensionRepositoryImpl::new(pool_clone),
+    );
+
+    let report = match use_case
+        .execute(
+            params.report_id,
+            request_claims.user_id,
+            body.action,
+            body.note,
+            body.suspension_in_days,
+            request_claims.has_permission(Permission::BlockReportedUsers),
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::MissingPermission => HttpResponse::Forbidden().json(e.to_response()),
+                AppError::PrivateDiscussionReportNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                AppError::ModerationNoteEmpty
+                | AppError::ModerationNoteTooLong
+                | AppError::ModerationSuspensionDurationInvalid => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    // Tell the reporter and, when warned or suspended, the reported user about the outcome
+    let notification_service = NotificationService::new(pool.get_ref().clone());
+    if let Ok(mut notif_transaction) = pool.begin().await {
+        let action = body.action;
+        let reporter_body_key = if action == PrivateDiscussionReportAction::Dismiss {
+            "report-resolved-dismissed-body"
+        } else {
+            "report-resolved-action-taken-body"
+        };
+
+        if let Some(reporter) = user_public_data_cache
+            .get_value_for_key_or_insert_it(&report.reporter, &mut notif_transaction)
+            .await
+        {
+            notification_service
+                .generate_notification(
+                    &mut notif_transaction,
+                    None,
+                    report.reporter,
+                    NotificationContent {
+                        title: &translator.translate(
+                            &reporter.locale,
+                            "report-resolved-title",
+                            None,
+                        ),
+                        body: &translator.translate(&reporter.locale, reporter_body_key, None),
+                        notification_type: "moderation_decision",
+                        url: None,
+                    },
+                    redis_client.clone(),
+                )
+                .await;
+        }
+
+        let offender_keys = match action {
+            PrivateDiscussionReportAction::WarnOffender => {
+                Some(("moderation-warning-title", "moderation-warning-body"))
+            }
+            PrivateDiscussionReportAction::SuspendOffender => {
+                Some(("moderation-suspension-title", "moderation-suspension-body"))
+            }
+            PrivateDiscussionReportAction::Dismiss => None,
+        };
+
+        if let Some((title_key, body_key)) = offender_keys {
+            if let Some(offender) = user_public_data_cache
+                .get_value_for_key_or_insert_it(&report.reported_user, &mut notif_transaction)
+                .await
+            {
+                let mut args = FluentArgs::new();
+                args.set("note", report.note.clone().unwrap_or_default());
+                args.set(
+                    "days",
+                    body.suspension_in_days
+                        .unwrap_or(MODERATION_DEFAULT_SUSPENSION_IN_DAYS),
+                );
+
+                notification_service
+                    .generate_notification(
+                        &mut notif_transaction,
+                        None,
+                        report.reported_user,
+                        NotificationContent {
+                            title: &translator.translate(&offender.locale, title_key, None),
+                            body: &translator.translate(&offender.locale, body_key, Some(args)),
+                            notification_type: "moderation_decision",
+                            url: None,
+                        },
+                        redis_client.clone(),
+                    )
+                    .await;
+            }
+        }
+
+        let _ = notif_transaction.commit().await;
+    }
+
+    HttpResponse::Ok().json(PrivateDiscussionReportResponse {
+        code: "PRIVATE_DISCUSSION_REPORT_RESOLVED".to_string(),
+        report: Some(report.to_private_discussion_report_data()),
+    })
+}
@@ -0,0 +1,105 @@
+// Update private discussion retention route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_discussion::{
+    PrivateDiscussionRetentionParams, PrivateDiscussionRetentionUpdateRequest,
+};
+use crate::features::private_discussions::application::dto::responses::private_discussion::PrivateDiscussionResponse;
+use crate::features::private_discussions::application::use_cases::update_private_discussion_retention::UpdatePrivateDiscussionRetentionUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_repository::PrivateDiscussionRepositoryImpl;
+use crate::features::private_discussions::infrastructure::services::private_discussion_event_service::PrivateDiscussionEventService;
+use actix_web::web::{Data, Json, Path, ReqData};
+use actix_web::{put, HttpResponse, Responder};
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[put("/{discussion_id}/retention")]
+pub async fn update_private_discussion_retention(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionRetentionParams>,
+    body: Json<PrivateDiscussionRetentionUpdateRequest>,
+    redis_client: Data<Client>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = UpdatePrivateDiscussionRetentionUseCase::new(
+        PrivateDiscussionRepositoryImpl::new(pool_clone.clone()),
+        PrivateDiscussionParticipationRepositoryImpl::new(pool_clone),
+    );
+
+    let (discussion, participations) = match use_case
+        .execute(
+            params.discussion_id,
+            request_claims.user_id,
+            &body.message_retention,
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::PrivateDiscussionRetentionInvalid => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                AppError::PrivateDiscussionNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    let recipients: Vec<_> = participations.iter().map(|p| p.user_id).collect();
+    if let Err(e) = PrivateDiscussionEventService::new(redis_client)
+        .publish_retention_updated_event(&discussion, &recipients)
+        .await
+    {
+        error!("Error: {}", e);
+    }
+
+    let participation = participations
+        .iter()
+        .find(|p| p.user_id == request_claims.user_id);
+
+    HttpResponse::Ok().json(PrivateDiscussionResponse {
+        code: "PRIVATE_DISCUSSION_RETENTION_UPDATED".to_string(),
+        discussion: Some(discussion.to_private_discussion_data(
+            participation,
+            None,
+            // Groups have members instead of a recipient
+            if discussion.is_group {
+                None
+            } else {
+                participations
+                    .iter()
+                    .find(|p| p.user_id != request_claims.user_id)
+                    .map(|p| p.user_id)
+            },
+            0,
+        )),
+    })
+}
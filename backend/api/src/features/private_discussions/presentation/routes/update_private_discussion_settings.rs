@@ -0,0 +1,75 @@
+// Update private discussion settings route - uses clean architecture
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::private_discussions::application::dto::requests::private_discussion_participation::{
+    PrivateDiscussionSettingsParams, PrivateDiscussionSettingsUpdateRequest,
+};
+use crate::features::private_discussions::application::dto::responses::private_discussion_participation::PrivateDiscussionSettingsResponse;
+use crate::features::private_discussions::application::use_cases::update_private_discussion_settings::UpdatePrivateDiscussionSettingsUseCase;
+use crate::features::private_discussions::infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl;
+use actix_web::web::{Data, Json, Path, ReqData};
+use actix_web::{put, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[put("/{discussion_id}/settings")]
+pub async fn update_private_discussion_settings(
+    pool: Data<PgPool>,
+    params: Path<PrivateDiscussionSettingsParams>,
+    body: Json<PrivateDiscussionSettingsUpdateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let use_case = UpdatePrivateDiscussionSettingsUseCase::new(
+        PrivateDiscussionParticipationRepositoryImpl::new(pool.get_ref().clone()),
+    );
+
+    let participation = match use_case
+        .execute(
+            params.discussion_id,
+            request_claims.user_id,
+            body.muted_until,
+            body.is_archived,
+            body.is_pinned,
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::PrivateDiscussionParticipationNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                _ => {
+                    error!("Error: {:?}", e);
+                    HttpResponse::InternalServerError().json(e.to_response())
+                }
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(PrivateDiscussionSettingsResponse {
+        code: "PRIVATE_DISCUSSION_SETTINGS_UPDATED".to_string(),
+        participation: Some(participation.to_private_discussion_participation_data()),
+    })
+}
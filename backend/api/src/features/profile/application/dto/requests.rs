@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct UserUpdateRequest {
     pub locale: String,
     pub theme: String,
@@ -24,8 +24,37 @@ pub struct UserUpdateRequest {
     pub notifications_for_private_messages_enabled: bool,
     pub notifications_for_public_message_liked_enabled: bool,
     pub notifications_for_public_message_replies_enabled: bool,
+    // Older clients do not send it, mentions are then notified like for new users
+    #[serde(default = "default_notifications_for_public_message_mentions_enabled")]
+    pub notifications_for_public_message_mentions_enabled: bool,
+    // Older clients do not send it, the stored languages are then kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_message_languages: Option<Vec<String>>,
     pub notifications_user_joined_your_challenge_enabled: bool,
     pub notifications_user_duplicated_your_challenge_enabled: bool,
+
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    // Older clients do not send the profile and matching settings, the stored values are
+    // then kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_shows_bio: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_shows_avatar: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_shows_habits: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_shows_finished_challenges: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_shows_badges: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_to_meeting_people: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matching_uses_demographics: Option<bool>,
+}
+
+fn default_notifications_for_public_message_mentions_enabled() -> bool {
+    true
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,3 +96,8 @@ pub struct GetUserPublicDataByUsernameRequest {
 pub struct SetFcmTokenRequest {
     pub fcm_token: Option<String>,
 }
+
+#[derive(Deserialize)]
+pub struct GetUserPublicProfileParams {
+    pub user_id: Uuid,
+}
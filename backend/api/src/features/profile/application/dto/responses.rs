@@ -2,7 +2,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::features::profile::domain::entities::{ParsedDeviceInfo, UserData, UserPublicData};
+use crate::features::profile::domain::entities::{
+    friend_match::FriendMatch, ParsedDeviceInfo, UserData, UserPublicData, UserPublicProfile,
+};
 
 #[derive(Serialize, Deserialize)]
 pub struct UserResponse {
@@ -16,6 +18,18 @@ pub struct UserPublicResponse {
     pub user: UserPublicData,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct UserPublicProfileResponse {
+    pub code: String,
+    pub profile: UserPublicProfile,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FriendMatchesResponse {
+    pub code: String,
+    pub matches: Vec<FriendMatch>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct UsersResponse {
     pub code: String,
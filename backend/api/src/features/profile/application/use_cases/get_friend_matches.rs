@@ -0,0 +1,89 @@
+// Get friend matches use case - suggests people open to meeting others
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::profile::domain::entities::friend_match::{
+    FriendMatch, FriendMatchCandidate, FRIEND_MATCH_MAX_RESULTS, FRIEND_MATCH_SEARCHES_PER_DAY,
+};
+use crate::features::profile::infrastructure::repositories::friend_match_repository::FriendMatchRepositoryImpl;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+
+pub struct GetFriendMatchesUseCase {
+    user_repo: UserRepositoryImpl,
+    friend_match_repo: FriendMatchRepositoryImpl,
+}
+
+impl GetFriendMatchesUseCase {
+    pub fn new(
+        user_repo: UserRepositoryImpl,
+        friend_match_repo: FriendMatchRepositoryImpl,
+    ) -> Self {
+        Self {
+            user_repo,
+            friend_match_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<FriendMatch>, AppError> {
+        let user = self
+            .user_repo
+            .get_by_id_with_executor(user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::UserNotFound)?;
+
+        // Only people who are looking for friends themselves can search
+        if !user.open_to_meeting_people {
+            return Err(AppError::FriendMatchingNotEnabled);
+        }
+
+        let at = now();
+        let search_count = self
+            .friend_match_repo
+            .count_searches_since_with_executor(user_id, at - Duration::days(1), &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        if search_count >= FRIEND_MATCH_SEARCHES_PER_DAY {
+            return Err(AppError::FriendMatchingRateLimited);
+        }
+
+        self.friend_match_repo
+            .create_search_with_executor(user_id, at, &mut **transaction)
+            .await
+            .map_err(|_| AppError::FriendMatchSearchCreation)?;
+
+        let candidates = self
+            .friend_match_repo
+            .get_candidates_with_executor(user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let mut candidates = candidates
+            .into_iter()
+            .map(|c| (c.score(), c))
+            .filter(|(score, _)| *score > 0)
+            .collect::<Vec<(i64, FriendMatchCandidate)>>();
+
+        candidates.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .cmp(a_score)
+                .then_with(|| a.username.cmp(&b.username))
+        });
+
+        let matches = candidates
+            .iter()
+            .take(FRIEND_MATCH_MAX_RESULTS)
+            .map(|(_, c)| c.to_friend_match())
+            .collect::<Vec<FriendMatch>>();
+
+        Ok(matches)
+    }
+}
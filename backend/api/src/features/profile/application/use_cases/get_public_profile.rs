@@ -0,0 +1,199 @@
+// Get public profile use case - assembles only the sections the user agreed to share
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::challenges::infrastructure::repositories::{
+    challenge_participation_repository::ChallengeParticipationRepositoryImpl,
+    challenge_repository::ChallengeRepositoryImpl,
+};
+use crate::features::habits::infrastructure::repositories::{
+    habit_daily_tracking_repository::HabitDailyTrackingRepositoryImpl,
+    habit_participation_repository::HabitParticipationRepositoryImpl,
+    habit_repository::HabitRepositoryImpl,
+};
+use crate::features::profile::domain::entities::{
+    compute_streaks, PublicProfileChallenge, PublicProfileHabit, User, UserPublicProfile,
+};
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_repository::RoleRepositoryImpl;
+
+pub struct GetPublicProfileUseCase {
+    user_repo: UserRepositoryImpl,
+    habit_repo: HabitRepositoryImpl,
+    habit_participation_repo: HabitParticipationRepositoryImpl,
+    habit_daily_tracking_repo: HabitDailyTrackingRepositoryImpl,
+    challenge_repo: ChallengeRepositoryImpl,
+    challenge_participation_repo: ChallengeParticipationRepositoryImpl,
+    role_repo: RoleRepositoryImpl,
+}
+
+impl GetPublicProfileUseCase {
+    pub fn new(
+        user_repo: UserRepositoryImpl,
+        habit_repo: HabitRepositoryImpl,
+        habit_participation_repo: HabitParticipationRepositoryImpl,
+        habit_daily_tracking_repo: HabitDailyTrackingRepositoryImpl,
+        challenge_repo: ChallengeRepositoryImpl,
+        challenge_participation_repo: ChallengeParticipationRepositoryImpl,
+        role_repo: RoleRepositoryImpl,
+    ) -> Self {
+        Self {
+            user_repo,
+            habit_repo,
+            habit_participation_repo,
+            habit_daily_tracking_repo,
+            challenge_repo,
+            challenge_participation_repo,
+            role_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<UserPublicProfile, AppError> {
+        let user = self
+            .user_repo
+            .get_by_id_with_executor(user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .filter(|u| !u.is_deleted)
+            .ok_or(AppError::UserNotFound)?;
+
+        let habits = if user.profile_shows_habits {
+            Some(self.get_habits(&user, transaction).await?)
+        } else {
+            None
+        };
+
+        let finished_challenges = if user.profile_shows_finished_challenges {
+            Some(self.get_finished_challenges(&user, transaction).await?)
+        } else {
+            None
+        };
+
+        let badges = if user.profile_shows_badges {
+            let roles = self
+                .role_repo
+                .get_active_by_user_id_with_executor(user.id, now(), &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+            Some(roles.into_iter().map(|r| r.name).collect())
+        } else {
+            None
+        };
+
+        Ok(UserPublicProfile {
+            id: user.id,
+            username: user.username.clone(),
+            bio: user.bio.clone().filter(|_| user.profile_shows_bio),
+            avatar: user.avatar.clone().filter(|_| user.profile_shows_avatar),
+            habits,
+            finished_challenges,
+            badges,
+        })
+    }
+
+    async fn get_habits(
+        &self,
+        user: &User,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<PublicProfileHabit>, AppError> {
+        let participations = self
+            .habit_participation_repo
+            .get_by_user_id_with_executor(user.id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let trackings = self
+            .habit_daily_tracking_repo
+            .get_by_user_id_with_executor(user.id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let mut days_per_habit = HashMap::<Uuid, Vec<NaiveDate>>::new();
+        for tracking in trackings {
+            days_per_habit
+                .entry(tracking.habit_id)
+                .or_default()
+                .push(tracking.datetime.date());
+        }
+
+        let today = now().date_naive();
+        let mut habits = Vec::new();
+
+        for participation in participations {
+            let habit = match self
+                .habit_repo
+                .get_by_id_with_executor(participation.habit_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+            {
+                Some(habit) => habit,
+                None => continue,
+            };
+
+            // Personal habits that were not reviewed yet stay private
+            if !habit.reviewed {
+                continue;
+            }
+
+            let (current_streak, longest_streak) =
+                compute_streaks(days_per_habit.remove(&habit.id).unwrap_or_default(), today);
+
+            habits.push(PublicProfileHabit {
+                habit_id: habit.id,
+                name: habit.name,
+                icon: habit.icon,
+                current_streak,
+                longest_streak,
+            });
+        }
+
+        Ok(habits)
+    }
+
+    async fn get_finished_challenges(
+        &self,
+        user: &User,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<PublicProfileChallenge>, AppError> {
+        let participations = self
+            .challenge_participation_repo
+            .get_by_user_id_with_executor(user.id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let challenge_ids = participations
+            .iter()
+            .filter(|p| p.finished)
+            .map(|p| p.challenge_id)
+            .collect::<HashSet<Uuid>>();
+
+        let mut challenges = Vec::new();
+
+        for challenge_id in challenge_ids {
+            if let Some(challenge) = self
+                .challenge_repo
+                .get_by_id_with_executor(challenge_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+                .filter(|c| !c.deleted)
+            {
+                challenges.push(PublicProfileChallenge {
+                    challenge_id: challenge.id,
+                    name: challenge.name,
+                    icon: challenge.icon,
+                });
+            }
+        }
+
+        Ok(challenges)
+    }
+}
@@ -1,7 +1,9 @@
 // Profile use cases
 
 pub mod delete_account;
+pub mod get_friend_matches;
 pub mod get_profile;
+pub mod get_public_profile;
 pub mod set_password;
 pub mod update_password;
 pub mod update_profile;
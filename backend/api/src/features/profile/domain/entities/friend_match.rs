@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+pub const FRIEND_MATCH_SEARCHES_PER_DAY: i64 = 10;
+pub const FRIEND_MATCH_MAX_RESULTS: usize = 20;
+
+const SHARED_HABIT_WEIGHT: i64 = 3;
+const SHARED_CHALLENGE_WEIGHT: i64 = 5;
+const SAME_LOCALE_WEIGHT: i64 = 2;
+const SHARED_DEMOGRAPHIC_WEIGHT: i64 = 1;
+
+// Only similarity counts leave the database, never the answers themselves.
+#[derive(Debug, Clone, FromRow)]
+pub struct FriendMatchCandidate {
+    pub user_id: Uuid,
+    pub username: String,
+    pub same_locale: bool,
+    pub timezone_distance_in_minutes: Option<i32>,
+    pub shared_demographic_count: i32,
+    pub shared_habit_count: i64,
+    pub shared_challenge_count: i64,
+}
+
+// The score stays on the server, matches are only sent back in order.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FriendMatch {
+    pub user_id: Uuid,
+    pub username: String,
+    pub shared_habit_count: i64,
+    pub shared_challenge_count: i64,
+}
+
+impl FriendMatchCandidate {
+    pub fn score(&self) -> i64 {
+        let timezone_score = match self.timezone_distance_in_minutes {
+            Some(distance) if distance <= 60 => 2,
+            Some(distance) if distance <= 180 => 1,
+            _ => 0,
+        };
+
+        self.shared_habit_count * SHARED_HABIT_WEIGHT
+            + self.shared_challenge_count * SHARED_CHALLENGE_WEIGHT
+            + if self.same_locale {
+                SAME_LOCALE_WEIGHT
+            } else {
+                0
+            }
+            + timezone_score
+            + self.shared_demographic_count as i64 * SHARED_DEMOGRAPHIC_WEIGHT
+    }
+
+    pub fn to_friend_match(&self) -> FriendMatch {
+        FriendMatch {
+            user_id: self.user_id,
+            username: self.username.to_owned(),
+            shared_habit_count: self.shared_habit_count,
+            shared_challenge_count: self.shared_challenge_count,
+        }
+    }
+}
@@ -1,8 +1,10 @@
 // Profile domain entities
 
+pub mod friend_match;
+
 use actix_http::Payload;
 use actix_web::{FromRequest, HttpRequest};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use futures_util::future::{ok, Ready};
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
@@ -11,6 +13,22 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::core::helpers::mock_now::now;
+
+pub const USER_BIO_MAX_LENGTH: usize = 500;
+pub const USER_PUBLIC_MESSAGE_LANGUAGES_MAX_COUNT: usize = 10;
+
+// Extra languages are ISO 639-1 codes, like the detected language of public messages
+pub fn are_valid_public_message_languages(languages: &[String]) -> bool {
+    languages.len() <= USER_PUBLIC_MESSAGE_LANGUAGES_MAX_COUNT
+        && languages
+            .iter()
+            .all(|language| language.len() == 2 && language.chars().all(|c| c.is_ascii_lowercase()))
+}
+
+// Public profiles aggregate several tables, so they are only cached for a short time.
+const PUBLIC_PROFILE_CACHE_DURATION_IN_MINUTES: i64 = 5;
+
 #[derive(Debug, Default, Deserialize, Serialize, Clone, FromRow)]
 pub struct User {
     pub id: uuid::Uuid,
@@ -53,11 +71,39 @@ pub struct User {
     pub notifications_for_private_messages_enabled: bool,
     pub notifications_for_public_message_liked_enabled: bool,
     pub notifications_for_public_message_replies_enabled: bool,
+    pub notifications_for_public_message_mentions_enabled: bool,
+    pub public_message_languages: Vec<String>,
     pub notifications_user_joined_your_challenge_enabled: bool,
     pub notifications_user_duplicated_your_challenge_enabled: bool,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    pub profile_shows_bio: bool,
+    pub profile_shows_avatar: bool,
+    pub profile_shows_habits: bool,
+    pub profile_shows_finished_challenges: bool,
+    pub profile_shows_badges: bool,
+    pub open_to_meeting_people: bool,
+    pub matching_uses_demographics: bool,
 }
 
 impl User {
+    // Languages of the public messages the user can read: the locale one and the extra ones
+    pub fn readable_public_message_languages(&self) -> Vec<String> {
+        let mut languages = self.public_message_languages.clone();
+        let locale_language = self
+            .locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if !languages.contains(&locale_language) {
+            languages.push(locale_language);
+        }
+
+        languages
+    }
+
     pub fn to_user_public_data(&self) -> UserPublicData {
         UserPublicData {
             id: self.id,
@@ -104,10 +150,22 @@ impl User {
                 .notifications_for_public_message_liked_enabled,
             notifications_for_public_message_replies_enabled: self
                 .notifications_for_public_message_replies_enabled,
+            notifications_for_public_message_mentions_enabled: self
+                .notifications_for_public_message_mentions_enabled,
+            public_message_languages: self.public_message_languages.clone(),
             notifications_user_joined_your_challenge_enabled: self
                 .notifications_user_joined_your_challenge_enabled,
             notifications_user_duplicated_your_challenge_enabled: self
                 .notifications_user_duplicated_your_challenge_enabled,
+            bio: self.bio.to_owned(),
+            avatar: self.avatar.to_owned(),
+            profile_shows_bio: self.profile_shows_bio,
+            profile_shows_avatar: self.profile_shows_avatar,
+            profile_shows_habits: self.profile_shows_habits,
+            profile_shows_finished_challenges: self.profile_shows_finished_challenges,
+            profile_shows_badges: self.profile_shows_badges,
+            open_to_meeting_people: self.open_to_meeting_people,
+            matching_uses_demographics: self.matching_uses_demographics,
         }
     }
 }
@@ -148,8 +206,19 @@ pub struct UserData {
     pub notifications_for_private_messages_enabled: bool,
     pub notifications_for_public_message_liked_enabled: bool,
     pub notifications_for_public_message_replies_enabled: bool,
+    pub notifications_for_public_message_mentions_enabled: bool,
+    pub public_message_languages: Vec<String>,
     pub notifications_user_joined_your_challenge_enabled: bool,
     pub notifications_user_duplicated_your_challenge_enabled: bool,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    pub profile_shows_bio: bool,
+    pub profile_shows_avatar: bool,
+    pub profile_shows_habits: bool,
+    pub profile_shows_finished_challenges: bool,
+    pub profile_shows_badges: bool,
+    pub open_to_meeting_people: bool,
+    pub matching_uses_demographics: bool,
 }
 
 #[derive(Serialize, Debug, Deserialize, Clone)]
@@ -161,9 +230,67 @@ pub struct UserPublicData {
     pub is_deleted: bool,
 }
 
+// Every section is None when the user did not agree to share it.
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct UserPublicProfile {
+    pub id: Uuid,
+    pub username: String,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    pub habits: Option<Vec<PublicProfileHabit>>,
+    pub finished_challenges: Option<Vec<PublicProfileChallenge>>,
+    pub badges: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct PublicProfileHabit {
+    pub habit_id: Uuid,
+    pub name: String,
+    pub icon: String,
+    pub current_streak: i64,
+    pub longest_streak: i64,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone)]
+pub struct PublicProfileChallenge {
+    pub challenge_id: Uuid,
+    pub name: String,
+    pub icon: String,
+}
+
+// Returns the current and the longest streak of consecutive tracked days.
+// The current streak is still alive if the last tracked day is today or yesterday.
+pub fn compute_streaks(mut days: Vec<NaiveDate>, today: NaiveDate) -> (i64, i64) {
+    days.sort();
+    days.dedup();
+
+    let mut longest_streak = 0;
+    let mut streak = 0;
+    let mut previous_day: Option<NaiveDate> = None;
+
+    for day in days.iter() {
+        streak = match previous_day {
+            Some(previous_day) if *day - previous_day == Duration::days(1) => streak + 1,
+            _ => 1,
+        };
+        longest_streak = longest_streak.max(streak);
+        previous_day = Some(*day);
+    }
+
+    let current_streak = match previous_day {
+        Some(last_day) if today - last_day <= Duration::days(1) => streak,
+        _ => 0,
+    };
+
+    (current_streak, longest_streak)
+}
+
+type CachedUserPublicProfile = (UserPublicProfile, DateTime<Utc>);
+
 #[derive(Default, Clone)]
 pub struct UserPublicDataCache {
     data: Arc<RwLock<HashMap<Uuid, UserPublicData>>>,
+    profiles: Arc<RwLock<HashMap<Uuid, CachedUserPublicProfile>>>,
 }
 
 impl UserPublicDataCache {
@@ -178,6 +305,23 @@ impl UserPublicDataCache {
 
     pub async fn remove_key(&self, key: Uuid) {
         self.data.write().await.remove(&key);
+        self.profiles.write().await.remove(&key);
+    }
+
+    pub async fn update_or_insert_profile(&self, key: Uuid, value: UserPublicProfile) {
+        self.profiles.write().await.insert(key, (value, now()));
+    }
+
+    pub async fn get_profile_for_key(&self, key: Uuid) -> Option<UserPublicProfile> {
+        match self.profiles.read().await.get(&key) {
+            Some((profile, cached_at))
+                if now() - *cached_at
+                    < Duration::minutes(PUBLIC_PROFILE_CACHE_DURATION_IN_MINUTES) =>
+            {
+                Some(profile.clone())
+            }
+            _ => None,
+        }
     }
 
     pub async fn get_value_for_key(&self, key: Uuid) -> Option<UserPublicData> {
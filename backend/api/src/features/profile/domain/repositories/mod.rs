@@ -3,7 +3,7 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::features::profile::domain::entities::User;
+use crate::features::profile::domain::entities::{friend_match::FriendMatchCandidate, User};
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
@@ -23,3 +23,18 @@ pub trait UserRepository: Send + Sync {
     async fn get_not_deleted_but_marked_as_deleted(&self) -> Result<Vec<User>, String>;
     async fn count(&self) -> Result<i64, String>;
 }
+
+#[async_trait]
+pub trait FriendMatchRepository: Send + Sync {
+    async fn get_candidates(&self, user_id: Uuid) -> Result<Vec<FriendMatchCandidate>, String>;
+    async fn create_search(
+        &self,
+        user_id: Uuid,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), String>;
+    async fn count_searches_since(
+        &self,
+        user_id: Uuid,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<i64, String>;
+}
@@ -0,0 +1,168 @@
+// FriendMatchRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::profile::domain::entities::friend_match::FriendMatchCandidate;
+use crate::features::profile::domain::repositories::FriendMatchRepository;
+
+pub struct FriendMatchRepositoryImpl {
+    pool: PgPool,
+}
+
+impl FriendMatchRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    // Demographic answers are only compared when both users opted in, and only
+    // the number of matching answers is returned.
+    pub async fn get_candidates_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<FriendMatchCandidate>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            FriendMatchCandidate,
+            r#"
+            SELECT
+                u.id AS "user_id!",
+                u.username AS "username!",
+                (u.locale = me.locale) AS "same_locale!",
+                (ABS(EXTRACT(EPOCH FROM (their_tz.utc_offset - my_tz.utc_offset))) / 60)::INT
+                    AS timezone_distance_in_minutes,
+                (CASE WHEN me.matching_uses_demographics AND u.matching_uses_demographics THEN
+                    (CASE WHEN u.age_category = me.age_category THEN 1 ELSE 0 END)
+                    + (CASE WHEN u.country = me.country THEN 1 ELSE 0 END)
+                    + (CASE WHEN u.region = me.region THEN 1 ELSE 0 END)
+                    + (CASE WHEN u.activity = me.activity THEN 1 ELSE 0 END)
+                    + (CASE WHEN u.lives_in_urban_area = me.lives_in_urban_area THEN 1 ELSE 0 END)
+                    + (CASE WHEN u.relationship_status = me.relationship_status THEN 1 ELSE 0 END)
+                    + (CASE WHEN u.level_of_education = me.level_of_education THEN 1 ELSE 0 END)
+                    + (CASE WHEN u.has_children = me.has_children THEN 1 ELSE 0 END)
+                ELSE 0 END) AS "shared_demographic_count!",
+                (
+                    SELECT COUNT(DISTINCT theirs.habit_id)
+                    FROM habit_participations mine
+                    JOIN habit_participations theirs ON theirs.habit_id = mine.habit_id
+                    WHERE mine.user_id = me.id
+                    AND theirs.user_id = u.id
+                ) AS "shared_habit_count!",
+                (
+                    SELECT COUNT(DISTINCT theirs.challenge_id)
+                    FROM challenge_participations mine
+                    JOIN challenge_participations theirs ON theirs.challenge_id = mine.challenge_id
+                    WHERE mine.user_id = me.id
+                    AND theirs.user_id = u.id
+                    AND mine.finished = false
+                    AND theirs.finished = false
+                ) AS "shared_challenge_count!"
+            FROM users me
+            JOIN users u ON u.id <> me.id
+            LEFT JOIN pg_timezone_names my_tz ON my_tz.name = me.timezone
+            LEFT JOIN pg_timezone_names their_tz ON their_tz.name = u.timezone
+            WHERE me.id = $1
+            AND u.open_to_meeting_people = true
+            AND u.is_deleted = false
+            AND NOT EXISTS (
+                SELECT 1
+                FROM private_discussion_participations mine
+                JOIN private_discussion_participations theirs
+                    ON theirs.discussion_id = mine.discussion_id
+                WHERE mine.user_id = me.id
+                AND theirs.user_id = u.id
+                AND (mine.has_blocked OR theirs.has_blocked)
+            )
+            AND NOT EXISTS (
+                SELECT 1
+                FROM user_blocks b
+                WHERE (b.blocker = me.id AND b.blocked = u.id)
+                OR (b.blocker = u.id AND b.blocked = me.id)
+            )
+            "#,
+            user_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn create_search_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO friend_match_searches (id, user_id, created_at)
+            VALUES ($1, $2, $3)
+            "#,
+            Uuid::new_v4(),
+            user_id,
+            at
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn count_searches_since_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+        executor: E,
+    ) -> Result<i64, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM friend_match_searches
+            WHERE user_id = $1
+            AND created_at > $2
+            "#,
+            user_id,
+            since
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.count.unwrap_or(0))
+    }
+}
+
+#[async_trait]
+impl FriendMatchRepository for FriendMatchRepositoryImpl {
+    async fn get_candidates(&self, user_id: Uuid) -> Result<Vec<FriendMatchCandidate>, String> {
+        self.get_candidates_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn create_search(&self, user_id: Uuid, at: DateTime<Utc>) -> Result<(), String> {
+        self.create_search_with_executor(user_id, at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn count_searches_since(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<i64, String> {
+        self.count_searches_since_with_executor(user_id, since, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
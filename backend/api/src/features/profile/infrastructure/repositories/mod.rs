@@ -1,3 +1,4 @@
 // Profile infrastructure repository implementations
 
+pub mod friend_match_repository;
 pub mod user_repository;
@@ -37,9 +37,11 @@ impl UserRepositoryImpl {
                 notifications_for_public_message_liked_enabled,
                 notifications_for_public_message_replies_enabled,
                 notifications_user_joined_your_challenge_enabled,
-                notifications_user_duplicated_your_challenge_enabled
+                notifications_user_duplicated_your_challenge_enabled,
+                notifications_for_public_message_mentions_enabled,
+                public_message_languages
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25)
             "#,
             user.id,
             user.username,
@@ -64,6 +66,8 @@ impl UserRepositoryImpl {
             user.notifications_for_public_message_replies_enabled,
             user.notifications_user_joined_your_challenge_enabled,
             user.notifications_user_duplicated_your_challenge_enabled,
+            user.notifications_for_public_message_mentions_enabled,
+            &user.public_message_languages,
         )
         .execute(executor)
         .await
@@ -92,8 +96,14 @@ impl UserRepositoryImpl {
                 notifications_user_joined_your_challenge_enabled = $20,
                 notifications_user_duplicated_your_challenge_enabled = $21,
                 timezone = $22, otp_verified = $23, otp_auth_url = $24,
-                otp_base32 = $25, password_is_expired = $26, password = $27
-            WHERE id = $28
+                otp_base32 = $25, password_is_expired = $26, password = $27,
+                bio = $28, avatar = $29, profile_shows_bio = $30,
+                profile_shows_avatar = $31, profile_shows_habits = $32,
+                profile_shows_finished_challenges = $33, profile_shows_badges = $34,
+                open_to_meeting_people = $35, matching_uses_demographics = $36,
+                notifications_for_public_message_mentions_enabled = $37,
+                public_message_languages = $38
+            WHERE id = $39
             "#,
             user.username,
             user.locale,
@@ -122,6 +132,17 @@ impl UserRepositoryImpl {
             user.otp_base32,
             user.password_is_expired,
             user.password,
+            user.bio,
+            user.avatar,
+            user.profile_shows_bio,
+            user.profile_shows_avatar,
+            user.profile_shows_habits,
+            user.profile_shows_finished_challenges,
+            user.profile_shows_badges,
+            user.open_to_meeting_people,
+            user.matching_uses_demographics,
+            user.notifications_for_public_message_mentions_enabled,
+            &user.public_message_languages,
             user.id,
         )
         .execute(executor)
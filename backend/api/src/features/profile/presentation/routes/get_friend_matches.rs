@@ -0,0 +1,63 @@
+// Get friend matches route - people with a similar profile who are also looking for friends
+
+use crate::core::constants::errors::AppError;
+use crate::features::auth::domain::entities::Claims;
+use crate::features::profile::application::dto::responses::FriendMatchesResponse;
+use crate::features::profile::application::use_cases::get_friend_matches::GetFriendMatchesUseCase;
+use crate::features::profile::infrastructure::repositories::friend_match_repository::FriendMatchRepositoryImpl;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use actix_web::web::{Data, ReqData};
+use actix_web::{get, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/matches/")]
+pub async fn get_friend_matches(
+    pool: Data<PgPool>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = GetFriendMatchesUseCase::new(
+        UserRepositoryImpl::new(pool_clone.clone()),
+        FriendMatchRepositoryImpl::new(pool_clone.clone()),
+    );
+    let result = use_case
+        .execute(request_claims.user_id, &mut transaction)
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(matches) => HttpResponse::Ok().json(FriendMatchesResponse {
+            code: "FRIEND_MATCHES_FETCHED".to_string(),
+            matches,
+        }),
+        Err(AppError::FriendMatchingNotEnabled) => {
+            HttpResponse::Forbidden().json(AppError::FriendMatchingNotEnabled.to_response())
+        }
+        Err(AppError::FriendMatchingRateLimited) => {
+            HttpResponse::TooManyRequests().json(AppError::FriendMatchingRateLimited.to_response())
+        }
+        Err(AppError::UserNotFound) => {
+            HttpResponse::NotFound().json(AppError::UserNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Get public profile route - only returns the sections the user opted in to share
+
+use crate::core::constants::errors::AppError;
+use crate::features::challenges::infrastructure::repositories::{
+    challenge_participation_repository::ChallengeParticipationRepositoryImpl,
+    challenge_repository::ChallengeRepositoryImpl,
+};
+use crate::features::habits::infrastructure::repositories::{
+    habit_daily_tracking_repository::HabitDailyTrackingRepositoryImpl,
+    habit_participation_repository::HabitParticipationRepositoryImpl,
+    habit_repository::HabitRepositoryImpl,
+};
+use crate::features::profile::application::dto::requests::GetUserPublicProfileParams;
+use crate::features::profile::application::dto::responses::UserPublicProfileResponse;
+use crate::features::profile::application::use_cases::get_public_profile::GetPublicProfileUseCase;
+use crate::features::profile::domain::entities::UserPublicDataCache;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_repository::RoleRepositoryImpl;
+use actix_web::web::{Data, Path};
+use actix_web::{get, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/{user_id}/profile")]
+pub async fn get_public_profile(
+    params: Path<GetUserPublicProfileParams>,
+    pool: Data<PgPool>,
+    cache: Data<UserPublicDataCache>,
+) -> impl Responder {
+    if let Some(profile) = cache.get_profile_for_key(params.user_id).await {
+        return HttpResponse::Ok().json(UserPublicProfileResponse {
+            code: "PUBLIC_PROFILE_FETCHED".to_string(),
+            profile,
+        });
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = GetPublicProfileUseCase::new(
+        UserRepositoryImpl::new(pool_clone.clone()),
+        HabitRepositoryImpl::new(pool_clone.clone()),
+        HabitParticipationRepositoryImpl::new(pool_clone.clone()),
+        HabitDailyTrackingRepositoryImpl::new(pool_clone.clone()),
+        ChallengeRepositoryImpl::new(pool_clone.clone()),
+        ChallengeParticipationRepositoryImpl::new(pool_clone.clone()),
+        RoleRepositoryImpl::new(pool_clone.clone()),
+    );
+    let result = use_case.execute(params.user_id, &mut transaction).await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(profile) => {
+            cache
+                .update_or_insert_profile(profile.id, profile.clone())
+                .await;
+
+            HttpResponse::Ok().json(UserPublicProfileResponse {
+                code: "PUBLIC_PROFILE_FETCHED".to_string(),
+                profile,
+            })
+        }
+        Err(AppError::UserNotFound) => {
+            HttpResponse::NotFound().json(AppError::UserNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
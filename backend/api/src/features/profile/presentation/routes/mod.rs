@@ -3,7 +3,9 @@
 pub mod delete_account;
 pub mod delete_device;
 pub mod get_devices;
+pub mod get_friend_matches;
 pub mod get_profile_information;
+pub mod get_public_profile;
 pub mod get_user_data_by_username;
 pub mod get_users_data_by_id;
 pub mod is_otp_enabled;
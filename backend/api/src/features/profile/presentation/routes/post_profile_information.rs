@@ -6,6 +6,9 @@ use crate::features::profile::application::dto::requests::UserUpdateRequest;
 use crate::features::profile::application::dto::responses::UserResponse;
 use crate::features::profile::application::use_cases::get_profile::GetProfileUseCase;
 use crate::features::profile::application::use_cases::update_profile::UpdateProfileUseCase;
+use crate::features::profile::domain::entities::{
+    are_valid_public_message_languages, UserPublicDataCache, USER_BIO_MAX_LENGTH,
+};
 use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
 use crate::features::profile::infrastructure::services::user_event_service::UserEventService;
 use actix_web::web::{Data, Json, ReqData};
@@ -19,8 +22,26 @@ pub async fn post_profile_information(
     body: Json<UserUpdateRequest>,
     pool: Data<PgPool>,
     redis_client: Data<Client>,
+    user_public_data_cache: Data<UserPublicDataCache>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
+    if body
+        .bio
+        .as_ref()
+        .is_some_and(|bio| bio.chars().count() > USER_BIO_MAX_LENGTH)
+    {
+        return HttpResponse::BadRequest().json(AppError::UserBioTooLong.to_response());
+    }
+
+    if body
+        .public_message_languages
+        .as_ref()
+        .is_some_and(|languages| !are_valid_public_message_languages(languages))
+    {
+        return HttpResponse::BadRequest()
+            .json(AppError::UserPublicMessageLanguagesInvalid.to_response());
+    }
+
     let mut transaction = match pool.begin().await {
         Ok(t) => t,
         Err(e) => {
@@ -80,10 +101,38 @@ pub async fn post_profile_information(
         body.notifications_for_public_message_liked_enabled;
     request_user.notifications_for_public_message_replies_enabled =
         body.notifications_for_public_message_replies_enabled;
+    request_user.notifications_for_public_message_mentions_enabled =
+        body.notifications_for_public_message_mentions_enabled;
+    if let Some(public_message_languages) = &body.public_message_languages {
+        request_user.public_message_languages = public_message_languages.clone();
+    }
     request_user.notifications_user_duplicated_your_challenge_enabled =
         body.notifications_user_duplicated_your_challenge_enabled;
     request_user.notifications_user_joined_your_challenge_enabled =
         body.notifications_user_joined_your_challenge_enabled;
+    request_user.bio = body.bio.clone();
+    request_user.avatar = body.avatar.clone();
+    if let Some(profile_shows_bio) = body.profile_shows_bio {
+        request_user.profile_shows_bio = profile_shows_bio;
+    }
+    if let Some(profile_shows_avatar) = body.profile_shows_avatar {
+        request_user.profile_shows_avatar = profile_shows_avatar;
+    }
+    if let Some(profile_shows_habits) = body.profile_shows_habits {
+        request_user.profile_shows_habits = profile_shows_habits;
+    }
+    if let Some(profile_shows_finished_challenges) = body.profile_shows_finished_challenges {
+        request_user.profile_shows_finished_challenges = profile_shows_finished_challenges;
+    }
+    if let Some(profile_shows_badges) = body.profile_shows_badges {
+        request_user.profile_shows_badges = profile_shows_badges;
+    }
+    if let Some(open_to_meeting_people) = body.open_to_meeting_people {
+        request_user.open_to_meeting_people = open_to_meeting_people;
+    }
+    if let Some(matching_uses_demographics) = body.matching_uses_demographics {
+        request_user.matching_uses_demographics = matching_uses_demographics;
+    }
 
     // Execute update use case
     let update_profile_use_case = UpdateProfileUseCase::new(user_repo);
@@ -99,6 +148,9 @@ pub async fn post_profile_information(
 
     match result {
         Ok(_) => {
+            // Cached public data and profile may now expose different sections
+            user_public_data_cache.remove_key(request_user.id).await;
+
             // Publish user updated event
             let user_event_service = UserEventService::new(redis_client);
             if let Err(e) = user_event_service
@@ -2,4 +2,7 @@
 
 pub mod public_message;
 pub mod public_message_like;
+pub mod public_message_moderation;
+pub mod public_message_reaction;
 pub mod public_message_report;
+pub mod public_message_subscription;
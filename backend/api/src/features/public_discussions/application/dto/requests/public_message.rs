@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::features::public_discussions::domain::entities::public_message::PublicMessageSort;
+
 #[derive(Deserialize)]
 pub struct GetPublicMessageParams {
     pub message_id: Uuid,
@@ -21,6 +23,18 @@ pub struct DeletePublicMessageParams {
 pub struct GetPublicMessagesParams {
     pub habit_id: Option<Uuid>,
     pub challenge_id: Option<Uuid>,
+    // Only keeps messages in the user's locale and extra public message languages
+    pub filter_by_language: Option<bool>,
+    pub sort: Option<PublicMessageSort>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct PublicMessagePageParams {
+    pub sort: Option<PublicMessageSort>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
 }
 
 #[derive(Deserialize)]
@@ -45,4 +59,14 @@ pub struct PublicMessageCreateRequest {
     pub replies_to: Option<Uuid>,
     pub content: String,
     pub thread_id: Option<Uuid>,
+    // Uploaded beforehand through the attachments routes
+    #[serde(default)]
+    pub attachment_ids: Vec<Uuid>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PublicMessagePinRequest {
+    // Announcements notify every participant of the challenge
+    #[serde(default)]
+    pub is_announcement: bool,
 }
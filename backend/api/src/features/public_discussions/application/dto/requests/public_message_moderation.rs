@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::public_discussions::domain::entities::public_message_moderation::ModerationAction;
+
+#[derive(Deserialize, Serialize)]
+pub struct ResolvePublicMessageReportsRequest {
+    pub action: ModerationAction,
+    pub note: Option<String>,
+    pub suspension_in_days: Option<i64>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message_reaction::PublicMessageReactionKind;
+
+#[derive(Deserialize)]
+pub struct DeletePublicMessageReactionParams {
+    pub message_id: Uuid,
+    pub reaction: PublicMessageReactionKind,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct GetPublicMessageReactionsQuery {
+    // Lists every reaction when not given
+    pub reaction: Option<PublicMessageReactionKind>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PublicMessageReactionCreateRequest {
+    pub message_id: Uuid,
+    pub reaction: PublicMessageReactionKind,
+}
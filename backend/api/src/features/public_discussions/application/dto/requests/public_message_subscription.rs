@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+pub struct PublicMessageSubscriptionParams {
+    pub subscription_id: Uuid,
+}
+
+// Following a thread only needs its id, following a channel needs its habit or challenge.
+// Subscribing muted silences a thread of a followed channel.
+#[derive(Deserialize, Serialize)]
+pub struct PublicMessageSubscriptionCreateRequest {
+    pub habit_id: Option<Uuid>,
+    pub challenge_id: Option<Uuid>,
+    pub thread_id: Option<Uuid>,
+    #[serde(default)]
+    pub muted: bool,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PublicMessageSubscriptionUpdateRequest {
+    pub muted: bool,
+}
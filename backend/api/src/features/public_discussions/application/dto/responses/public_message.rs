@@ -1,15 +1,30 @@
 use serde::{Deserialize, Serialize};
 
-use crate::features::public_discussions::domain::entities::public_message::PublicMessageData;
+use crate::features::public_discussions::domain::entities::{
+    public_message::PublicMessageData, public_message_mention::PublicMessageMentionData,
+    public_message_revision::PublicMessageRevisionData,
+};
 
 #[derive(Serialize, Deserialize)]
 pub struct PublicMessageResponse {
     pub code: String,
     pub message: Option<PublicMessageData>,
+    pub mentions: Vec<PublicMessageMentionData>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct PublicMessagesResponse {
     pub code: String,
+    // Only filled on the first page of a channel
+    #[serde(default)]
+    pub pinned_messages: Vec<PublicMessageData>,
     pub messages: Vec<PublicMessageData>,
+    pub mentions: Vec<PublicMessageMentionData>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicMessageRevisionsResponse {
+    pub code: String,
+    pub revisions: Vec<PublicMessageRevisionData>,
 }
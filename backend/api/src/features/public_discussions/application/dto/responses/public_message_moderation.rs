@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::public_discussions::domain::entities::{
+    public_message::PublicMessageData,
+    public_message_moderation::{ModerationQueueEntry, PublicMessageModerationDecisionData},
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct ModerationQueueResponse {
+    pub code: String,
+    pub entries: Vec<ModerationQueueEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ModerationDecisionResponse {
+    pub code: String,
+    pub decision: Option<PublicMessageModerationDecisionData>,
+    pub message: Option<PublicMessageData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ModerationDecisionsResponse {
+    pub code: String,
+    pub decisions: Vec<PublicMessageModerationDecisionData>,
+}
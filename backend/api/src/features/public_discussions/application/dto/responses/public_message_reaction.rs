@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::public_discussions::domain::entities::public_message_reaction::PublicMessageReactionData;
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicMessageReactionResponse {
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicMessageReactionsResponse {
+    pub code: String,
+    pub reactions: Vec<PublicMessageReactionData>,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::public_discussions::domain::entities::public_message_subscription::PublicMessageSubscriptionData;
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicMessageSubscriptionResponse {
+    pub code: String,
+    pub subscription: Option<PublicMessageSubscriptionData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicMessageSubscriptionsResponse {
+    pub code: String,
+    pub subscriptions: Vec<PublicMessageSubscriptionData>,
+}
@@ -1,38 +1,81 @@
 // Create public message use case
 
 use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::attachments::infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl;
+use crate::features::content_filters::application::use_cases::apply_content_filter::ApplyContentFilterUseCase;
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
 use crate::features::challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl;
 use crate::features::habits::infrastructure::repositories::habit_repository::HabitRepositoryImpl;
 use crate::features::public_discussions::domain::entities::public_message::{
-    PublicMessage, PUBLIC_MESSAGE_CONTENT_MAX_LENGTH,
+    PublicMessage, PUBLIC_MESSAGE_CONTENT_MAX_LENGTH, PUBLIC_MESSAGE_MAX_ATTACHMENTS,
 };
+use crate::features::public_discussions::domain::entities::public_message_revision::PublicMessageRevision;
+use crate::features::public_discussions::domain::entities::public_message_subscription::PublicMessageSubscription;
+use crate::features::public_discussions::application::use_cases::sync_public_message_mentions::sync_public_message_mentions;
+use crate::features::public_discussions::infrastructure::repositories::public_message_mention_repository::PublicMessageMentionRepositoryImpl;
 use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_revision_repository::PublicMessageRevisionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::user_suspension_repository::UserSuspensionRepositoryImpl;
 use sqlx::Postgres;
+use uuid::Uuid;
 
 pub struct CreatePublicMessageUseCase {
     message_repo: PublicMessageRepositoryImpl,
     habit_repo: HabitRepositoryImpl,
     challenge_repo: ChallengeRepositoryImpl,
+    suspension_repo: UserSuspensionRepositoryImpl,
+    revision_repo: PublicMessageRevisionRepositoryImpl,
+    mention_repo: PublicMessageMentionRepositoryImpl,
+    subscription_repo: PublicMessageSubscriptionRepositoryImpl,
+    attachment_repo: AttachmentRepositoryImpl,
+    content_filter: ApplyContentFilterUseCase,
 }
 
 impl CreatePublicMessageUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         message_repo: PublicMessageRepositoryImpl,
         habit_repo: HabitRepositoryImpl,
         challenge_repo: ChallengeRepositoryImpl,
+        suspension_repo: UserSuspensionRepositoryImpl,
+        revision_repo: PublicMessageRevisionRepositoryImpl,
+        mention_repo: PublicMessageMentionRepositoryImpl,
+        subscription_repo: PublicMessageSubscriptionRepositoryImpl,
+        attachment_repo: AttachmentRepositoryImpl,
+        content_filter: ApplyContentFilterUseCase,
     ) -> Self {
         Self {
             message_repo,
             habit_repo,
             challenge_repo,
+            suspension_repo,
+            revision_repo,
+            mention_repo,
+            subscription_repo,
+            attachment_repo,
+            content_filter,
         }
     }
 
     pub async fn execute(
         &self,
-        public_message: &PublicMessage,
+        public_message: &mut PublicMessage,
+        content_filter_locales: &[String],
         transaction: &mut sqlx::Transaction<'_, Postgres>,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<Uuid>, AppError> {
+        // Suspended users can not write until their suspension expires
+        if self
+            .suspension_repo
+            .get_active_by_user_id_with_executor(public_message.creator, now(), &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .is_some()
+        {
+            return Err(AppError::UserSuspended);
+        }
+
         // Check if a habit or a challenge is given
         if public_message.habit_id.is_none() && public_message.challenge_id.is_none() {
             return Err(AppError::NoHabitNorChallengePassed);
@@ -78,19 +121,110 @@ impl CreatePublicMessageUseCase {
                 .map_err(|_| AppError::PublicMessageUpdate)?;
         }
 
-        // Check content size
+        // Check attachments
+        let mut attachment_ids = Vec::new();
+        for attachment_id in &public_message.attachment_ids {
+            if !attachment_ids.contains(attachment_id) {
+                attachment_ids.push(*attachment_id);
+            }
+        }
+        if attachment_ids.len() > PUBLIC_MESSAGE_MAX_ATTACHMENTS {
+            return Err(AppError::PublicMessageTooManyAttachments);
+        }
+        public_message.attachment_ids = attachment_ids;
+
+        // Check content size, a message with images may have no text
         if public_message.content.len() > PUBLIC_MESSAGE_CONTENT_MAX_LENGTH {
             return Err(AppError::PublicMessageContentTooLong);
-        } else if public_message.content.is_empty() {
+        } else if public_message.content.is_empty() && public_message.attachment_ids.is_empty() {
             return Err(AppError::PublicMessageContentEmpty);
         }
 
+        // Rejected contents stop here, masked ones are stored masked
+        public_message.content = self
+            .content_filter
+            .execute(
+                &public_message.content,
+                content_filter_locales,
+                ContentFilterTarget::PublicMessage,
+                public_message.id,
+                transaction,
+            )
+            .await?;
+
         // Create message
         self.message_repo
             .create_with_executor(public_message, &mut **transaction)
             .await
             .map_err(|_| AppError::PublicMessageCreation)?;
 
-        Ok(())
+        // Only unused uploads of the creator can be attached
+        if !public_message.attachment_ids.is_empty() {
+            let attached_count = self
+                .attachment_repo
+                .attach_to_public_message_with_executor(
+                    &public_message.attachment_ids,
+                    public_message.creator,
+                    public_message.id,
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::PublicMessageCreation)?
+                .rows_affected();
+
+            if attached_count != public_message.attachment_ids.len() as u64 {
+                return Err(AppError::PublicMessageAttachmentNotFound);
+            }
+        }
+
+        // The first revision of the message history is its original content
+        self.revision_repo
+            .create_with_executor(
+                &PublicMessageRevision {
+                    id: Uuid::new_v4(),
+                    message_id: public_message.id,
+                    content: public_message.content.to_owned(),
+                    created_at: public_message.created_at,
+                },
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::PublicMessageCreation)?;
+
+        // Writing in a thread follows it, an existing subscription keeps its muted state
+        if self
+            .subscription_repo
+            .get_by_user_and_target_with_executor(
+                public_message.creator,
+                public_message.habit_id,
+                public_message.challenge_id,
+                Some(public_message.thread_id),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .is_none()
+        {
+            self.subscription_repo
+                .create_with_executor(
+                    &PublicMessageSubscription {
+                        id: Uuid::new_v4(),
+                        user_id: public_message.creator,
+                        habit_id: public_message.habit_id,
+                        challenge_id: public_message.challenge_id,
+                        thread_id: Some(public_message.thread_id),
+                        muted: false,
+                        pending_count: 0,
+                        last_notified_at: None,
+                        created_at: public_message.created_at,
+                    },
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::PublicMessageSubscriptionCreation)?;
+        }
+
+        // Returns the users mentioned for the first time in this message
+        sync_public_message_mentions(&self.mention_repo, public_message, transaction).await
     }
 }
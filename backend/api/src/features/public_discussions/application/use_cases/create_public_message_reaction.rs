@@ -0,0 +1,80 @@
+// Create public message reaction use case
+
+use std::str::FromStr;
+
+use crate::core::constants::errors::AppError;
+use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::domain::entities::public_message_reaction::{
+    PublicMessageReaction, PublicMessageReactionKind,
+};
+use crate::features::public_discussions::infrastructure::repositories::public_message_reaction_repository::PublicMessageReactionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+
+pub struct CreatePublicMessageReactionUseCase {
+    reaction_repo: PublicMessageReactionRepositoryImpl,
+    message_repo: PublicMessageRepositoryImpl,
+    user_block_repo: UserBlockRepositoryImpl,
+}
+
+impl CreatePublicMessageReactionUseCase {
+    pub fn new(
+        reaction_repo: PublicMessageReactionRepositoryImpl,
+        message_repo: PublicMessageRepositoryImpl,
+        user_block_repo: UserBlockRepositoryImpl,
+    ) -> Self {
+        Self {
+            reaction_repo,
+            message_repo,
+            user_block_repo,
+        }
+    }
+
+    // Returns the message when the reaction is new, None when the user had already reacted
+    pub async fn execute(
+        &self,
+        reaction: &PublicMessageReaction,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Option<PublicMessage>, AppError> {
+        let kind = PublicMessageReactionKind::from_str(&reaction.reaction)
+            .map_err(|_| AppError::PublicMessageReactionCreation)?;
+
+        // Verify message exists
+        let mut message = self
+            .message_repo
+            .get_by_id_with_executor(reaction.message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PublicMessageNotFound)?;
+
+        // Users who blocked each other can not interact
+        if self
+            .user_block_repo
+            .exists_between_with_executor(reaction.user_id, message.creator, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+        {
+            return Err(AppError::UserBlocked);
+        }
+
+        // Create reaction (ON CONFLICT DO NOTHING handles duplicates)
+        let result = self
+            .reaction_repo
+            .create_with_executor(reaction, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageReactionCreation)?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        // Update reaction counts
+        message.add_reactions(kind, 1);
+        self.message_repo
+            .update_reaction_counts_with_executor(&message, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageUpdate)?;
+
+        Ok(Some(message))
+    }
+}
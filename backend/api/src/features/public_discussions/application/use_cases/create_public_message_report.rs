@@ -1,34 +1,47 @@
 // Create public message report use case
 
 use crate::core::constants::errors::AppError;
+use crate::features::content_filters::application::use_cases::apply_content_filter::ApplyContentFilterUseCase;
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
 use crate::features::public_discussions::domain::entities::public_message_report::PublicMessageReport;
 use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
 use crate::features::public_discussions::infrastructure::repositories::public_message_report_repository::PublicMessageReportRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_revision_repository::PublicMessageRevisionRepositoryImpl;
 
 pub struct CreatePublicMessageReportUseCase {
     report_repo: PublicMessageReportRepositoryImpl,
     message_repo: PublicMessageRepositoryImpl,
+    revision_repo: PublicMessageRevisionRepositoryImpl,
+    content_filter: ApplyContentFilterUseCase,
 }
 
 impl CreatePublicMessageReportUseCase {
     pub fn new(
         report_repo: PublicMessageReportRepositoryImpl,
         message_repo: PublicMessageRepositoryImpl,
+        revision_repo: PublicMessageRevisionRepositoryImpl,
+        content_filter: ApplyContentFilterUseCase,
     ) -> Self {
         Self {
             report_repo,
             message_repo,
+            revision_repo,
+            content_filter,
         }
     }
 
     pub async fn execute(
         &self,
-        report: &PublicMessageReport,
+        report: &mut PublicMessageReport,
+        auto_hide_reporter_threshold: i64,
+        content_filter_locales: &[String],
         transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
     ) -> Result<(), AppError> {
         // Verify message exists
-        self.message_repo
-            .get_by_id_with_executor(report.message_id, &mut **transaction)
+        let message_id = report.message_id.ok_or(AppError::PublicMessageNotFound)?;
+        let message = self
+            .message_repo
+            .get_by_id_with_executor(message_id, &mut **transaction)
             .await
             .map_err(|_| AppError::DatabaseQuery)?
             .ok_or(AppError::PublicMessageNotFound)?;
@@ -41,12 +54,47 @@ impl CreatePublicMessageReportUseCase {
             return Err(AppError::PublicMessageReportReasonTooLong);
         }
 
+        report.reason = self
+            .content_filter
+            .execute(
+                &report.reason,
+                content_filter_locales,
+                ContentFilterTarget::PublicMessageReport,
+                report.id,
+                transaction,
+            )
+            .await?;
+
+        // Pin the revision the reporter saw, so later edits can't hide what was reported
+        report.revision_id = self
+            .revision_repo
+            .get_latest_by_message_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .map(|revision| revision.id);
+
         // Create report
         self.report_repo
             .create_with_executor(report, &mut **transaction)
             .await
             .map_err(|_| AppError::PublicMessageReportCreation)?;
 
+        // Hide the message pending review once enough distinct users reported it
+        if !message.hidden_by_moderation {
+            let reporter_count = self
+                .report_repo
+                .count_pending_reporters_with_executor(message_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+
+            if reporter_count >= auto_hide_reporter_threshold {
+                self.message_repo
+                    .update_hidden_by_moderation_with_executor(message_id, true, &mut **transaction)
+                    .await
+                    .map_err(|_| AppError::PublicMessageUpdate)?;
+            }
+        }
+
         Ok(())
     }
 }
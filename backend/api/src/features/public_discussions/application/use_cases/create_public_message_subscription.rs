@@ -0,0 +1,121 @@
+// Create public message subscription use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl;
+use crate::features::habits::infrastructure::repositories::habit_repository::HabitRepositoryImpl;
+use crate::features::public_discussions::application::dto::requests::public_message_subscription::PublicMessageSubscriptionCreateRequest;
+use crate::features::public_discussions::domain::entities::public_message_subscription::PublicMessageSubscription;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl;
+use uuid::Uuid;
+
+pub struct CreatePublicMessageSubscriptionUseCase {
+    subscription_repo: PublicMessageSubscriptionRepositoryImpl,
+    message_repo: PublicMessageRepositoryImpl,
+    habit_repo: HabitRepositoryImpl,
+    challenge_repo: ChallengeRepositoryImpl,
+}
+
+impl CreatePublicMessageSubscriptionUseCase {
+    pub fn new(
+        subscription_repo: PublicMessageSubscriptionRepositoryImpl,
+        message_repo: PublicMessageRepositoryImpl,
+        habit_repo: HabitRepositoryImpl,
+        challenge_repo: ChallengeRepositoryImpl,
+    ) -> Self {
+        Self {
+            subscription_repo,
+            message_repo,
+            habit_repo,
+            challenge_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        request: &PublicMessageSubscriptionCreateRequest,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<PublicMessageSubscription, AppError> {
+        let (habit_id, challenge_id, thread_id) = if let Some(thread_id) = request.thread_id {
+            // Any message of the thread leads to it, the channel comes from the message
+            let message = self
+                .message_repo
+                .get_by_id_with_executor(thread_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+                .ok_or(AppError::PublicMessageNotFound)?;
+
+            (
+                message.habit_id,
+                message.challenge_id,
+                Some(message.thread_id),
+            )
+        } else {
+            match (request.habit_id, request.challenge_id) {
+                (None, None) => return Err(AppError::NoHabitNorChallengePassed),
+                (Some(_), Some(_)) => return Err(AppError::BothHabitAndChallengePassed),
+                (Some(habit_id), None) => {
+                    self.habit_repo
+                        .get_by_id_with_executor(habit_id, &mut **transaction)
+                        .await
+                        .map_err(|_| AppError::DatabaseQuery)?
+                        .ok_or(AppError::HabitNotFound)?;
+                }
+                (None, Some(challenge_id)) => {
+                    self.challenge_repo
+                        .get_by_id_with_executor(challenge_id, &mut **transaction)
+                        .await
+                        .map_err(|_| AppError::DatabaseQuery)?
+                        .ok_or(AppError::ChallengeNotFound)?;
+                }
+            }
+
+            (request.habit_id, request.challenge_id, None)
+        };
+
+        // Subscribing again only changes whether the subscription is muted
+        if let Some(mut subscription) = self
+            .subscription_repo
+            .get_by_user_and_target_with_executor(
+                user_id,
+                habit_id,
+                challenge_id,
+                thread_id,
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+        {
+            if subscription.muted != request.muted {
+                subscription.muted = request.muted;
+                self.subscription_repo
+                    .update_with_executor(&subscription, &mut **transaction)
+                    .await
+                    .map_err(|_| AppError::PublicMessageSubscriptionUpdate)?;
+            }
+
+            return Ok(subscription);
+        }
+
+        let subscription = PublicMessageSubscription {
+            id: Uuid::new_v4(),
+            user_id,
+            habit_id,
+            challenge_id,
+            thread_id,
+            muted: request.muted,
+            pending_count: 0,
+            last_notified_at: None,
+            created_at: now(),
+        };
+
+        self.subscription_repo
+            .create_with_executor(&subscription, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageSubscriptionCreation)?;
+
+        Ok(subscription)
+    }
+}
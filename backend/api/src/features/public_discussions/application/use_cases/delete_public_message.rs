@@ -17,7 +17,7 @@ impl DeletePublicMessageUseCase {
         &self,
         message_id: Uuid,
         user_id: Uuid,
-        is_admin: bool,
+        can_moderate: bool,
         deleted_by_admin: bool,
         transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
     ) -> Result<Option<Uuid>, AppError> {
@@ -33,7 +33,7 @@ impl DeletePublicMessageUseCase {
         if !deleted_by_admin && message.creator != user_id {
             return Err(AppError::PublicMessageDeletionNotDoneByCreator);
         }
-        if deleted_by_admin && !is_admin {
+        if deleted_by_admin && !can_moderate {
             return Err(AppError::PublicMessageDeletionNotDoneByAdmin);
         }
 
@@ -0,0 +1,68 @@
+// Delete public message reaction use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::public_discussions::domain::entities::public_message_reaction::PublicMessageReactionKind;
+use crate::features::public_discussions::infrastructure::repositories::public_message_reaction_repository::PublicMessageReactionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use uuid::Uuid;
+
+pub struct DeletePublicMessageReactionUseCase {
+    reaction_repo: PublicMessageReactionRepositoryImpl,
+    message_repo: PublicMessageRepositoryImpl,
+}
+
+impl DeletePublicMessageReactionUseCase {
+    pub fn new(
+        reaction_repo: PublicMessageReactionRepositoryImpl,
+        message_repo: PublicMessageRepositoryImpl,
+    ) -> Self {
+        Self {
+            reaction_repo,
+            message_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        message_id: Uuid,
+        user_id: Uuid,
+        kind: PublicMessageReactionKind,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        // Verify message exists
+        let mut message = self
+            .message_repo
+            .get_by_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PublicMessageNotFound)?;
+
+        // Verify reaction exists
+        let reaction = self
+            .reaction_repo
+            .get_by_message_user_and_reaction_with_executor(
+                message_id,
+                user_id,
+                kind.as_str(),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PublicMessageReactionNotFound)?;
+
+        // Delete reaction
+        self.reaction_repo
+            .delete_with_executor(reaction.id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageReactionDeletion)?;
+
+        // Update reaction counts
+        message.add_reactions(kind, -1);
+        self.message_repo
+            .update_reaction_counts_with_executor(&message, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageUpdate)?;
+
+        Ok(())
+    }
+}
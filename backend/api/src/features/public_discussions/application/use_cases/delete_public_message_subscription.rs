@@ -0,0 +1,38 @@
+// Delete public message subscription use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::public_discussions::infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl;
+use uuid::Uuid;
+
+pub struct DeletePublicMessageSubscriptionUseCase {
+    subscription_repo: PublicMessageSubscriptionRepositoryImpl,
+}
+
+impl DeletePublicMessageSubscriptionUseCase {
+    pub fn new(subscription_repo: PublicMessageSubscriptionRepositoryImpl) -> Self {
+        Self { subscription_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        subscription_id: Uuid,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        // Subscriptions of other users are hidden
+        let subscription = self
+            .subscription_repo
+            .get_by_id_with_executor(subscription_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .filter(|s| s.user_id == user_id)
+            .ok_or(AppError::PublicMessageSubscriptionNotFound)?;
+
+        self.subscription_repo
+            .delete_with_executor(subscription.id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageSubscriptionDeletion)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+// Flush public message subscriptions use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::public_discussions::domain::entities::public_message_subscription::{
+    public_message_subscription_batch_window, PublicMessageSubscription,
+};
+use crate::features::public_discussions::infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl;
+
+pub struct FlushPublicMessageSubscriptionsUseCase {
+    subscription_repo: PublicMessageSubscriptionRepositoryImpl,
+}
+
+impl FlushPublicMessageSubscriptionsUseCase {
+    pub fn new(subscription_repo: PublicMessageSubscriptionRepositoryImpl) -> Self {
+        Self { subscription_repo }
+    }
+
+    // Returns the subscriptions whose batch window is over, with the number of
+    // messages counted meanwhile, and starts a new window for them
+    pub async fn execute(
+        &self,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<(PublicMessageSubscription, i32)>, AppError> {
+        let now = now();
+        let subscriptions = self
+            .subscription_repo
+            .get_pending_with_executor(
+                now - public_message_subscription_batch_window(),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let mut to_notify = Vec::new();
+
+        for mut subscription in subscriptions {
+            let count = subscription.pending_count;
+            subscription.pending_count = 0;
+            subscription.last_notified_at = Some(now);
+
+            self.subscription_repo
+                .update_pending_count_with_executor(&subscription, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PublicMessageSubscriptionUpdate)?;
+
+            to_notify.push((subscription, count));
+        }
+
+        Ok(to_notify)
+    }
+}
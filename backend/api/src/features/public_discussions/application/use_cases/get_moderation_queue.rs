@@ -0,0 +1,112 @@
+// Get moderation queue use case
+
+use std::collections::{HashMap, HashSet};
+
+use crate::core::constants::errors::AppError;
+use crate::features::public_discussions::domain::entities::public_message_moderation::ModerationQueueEntry;
+use crate::features::public_discussions::domain::entities::public_message_report::PublicMessageReport;
+use crate::features::public_discussions::infrastructure::repositories::public_message_report_repository::PublicMessageReportRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use crate::features::stories::infrastructure::repositories::story_repository::StoryRepositoryImpl;
+use uuid::Uuid;
+
+pub struct GetModerationQueueUseCase {
+    report_repo: PublicMessageReportRepositoryImpl,
+    message_repo: PublicMessageRepositoryImpl,
+    story_repo: StoryRepositoryImpl,
+}
+
+impl GetModerationQueueUseCase {
+    pub fn new(
+        report_repo: PublicMessageReportRepositoryImpl,
+        message_repo: PublicMessageRepositoryImpl,
+        story_repo: StoryRepositoryImpl,
+    ) -> Self {
+        Self {
+            report_repo,
+            message_repo,
+            story_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<ModerationQueueEntry>, AppError> {
+        let reports = self
+            .report_repo
+            .get_pending_with_executor(&mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        // Group pending reports per message or story, keeping the order of the first report
+        let mut targets: Vec<(Option<Uuid>, Option<Uuid>)> = Vec::new();
+        let mut reports_by_target: HashMap<(Option<Uuid>, Option<Uuid>), Vec<PublicMessageReport>> =
+            HashMap::new();
+        for report in reports {
+            let target = (report.message_id, report.story_id);
+            if !reports_by_target.contains_key(&target) {
+                targets.push(target);
+            }
+            reports_by_target.entry(target).or_default().push(report);
+        }
+
+        let mut entries = Vec::with_capacity(targets.len());
+        for target in targets {
+            let (message, story) = match target {
+                (Some(message_id), _) => {
+                    let Some(message) = self
+                        .message_repo
+                        .get_by_id_with_executor(message_id, &mut **transaction)
+                        .await
+                        .map_err(|_| AppError::DatabaseQuery)?
+                    else {
+                        continue;
+                    };
+                    (Some(message.to_public_message_data()), None)
+                }
+                (None, Some(story_id)) => {
+                    let Some(story) = self
+                        .story_repo
+                        .get_by_id_with_executor(story_id, &mut **transaction)
+                        .await
+                        .map_err(|_| AppError::DatabaseQuery)?
+                    else {
+                        continue;
+                    };
+                    (None, Some(story.to_story_data()))
+                }
+                (None, None) => continue,
+            };
+
+            let reports = &reports_by_target[&target];
+            let reporters: HashSet<Uuid> = reports.iter().map(|r| r.reporter).collect();
+            let mut reported_revision_ids: Vec<Uuid> = Vec::new();
+            for revision_id in reports.iter().filter_map(|r| r.revision_id) {
+                if !reported_revision_ids.contains(&revision_id) {
+                    reported_revision_ids.push(revision_id);
+                }
+            }
+
+            entries.push(ModerationQueueEntry {
+                message,
+                story,
+                report_count: reports.len(),
+                reporter_count: reporters.len(),
+                reasons: reports.iter().map(|r| r.reason.to_owned()).collect(),
+                reported_revision_ids,
+                first_reported_at: reports[0].created_at,
+                last_reported_at: reports[reports.len() - 1].created_at,
+            });
+        }
+
+        // Most reported first, oldest reports first among equals
+        entries.sort_by(|a, b| {
+            b.reporter_count
+                .cmp(&a.reporter_count)
+                .then(a.first_reported_at.cmp(&b.first_reported_at))
+        });
+
+        Ok(entries)
+    }
+}
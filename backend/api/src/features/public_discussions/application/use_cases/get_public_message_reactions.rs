@@ -0,0 +1,67 @@
+// Get public message reactions use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::public_discussions::domain::entities::public_message_reaction::{
+    PublicMessageReaction, PublicMessageReactionKind,
+};
+use crate::features::public_discussions::infrastructure::repositories::public_message_reaction_repository::PublicMessageReactionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+use uuid::Uuid;
+
+pub struct GetPublicMessageReactionsUseCase {
+    reaction_repo: PublicMessageReactionRepositoryImpl,
+    message_repo: PublicMessageRepositoryImpl,
+    user_block_repo: UserBlockRepositoryImpl,
+}
+
+impl GetPublicMessageReactionsUseCase {
+    pub fn new(
+        reaction_repo: PublicMessageReactionRepositoryImpl,
+        message_repo: PublicMessageRepositoryImpl,
+        user_block_repo: UserBlockRepositoryImpl,
+    ) -> Self {
+        Self {
+            reaction_repo,
+            message_repo,
+            user_block_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        message_id: Uuid,
+        kind: Option<PublicMessageReactionKind>,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<PublicMessageReaction>, AppError> {
+        // Verify message exists
+        self.message_repo
+            .get_by_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PublicMessageNotFound)?;
+
+        let reactions = self
+            .reaction_repo
+            .get_by_message_id_with_executor(
+                message_id,
+                kind.as_ref().map(|kind| kind.as_str()),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        // Users the requester blocked are not listed
+        let blocked_ids = self
+            .user_block_repo
+            .get_blocked_ids_with_executor(user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        Ok(reactions
+            .into_iter()
+            .filter(|reaction| !blocked_ids.contains(&reaction.user_id))
+            .collect())
+    }
+}
@@ -0,0 +1,50 @@
+// Get public message revisions use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::public_discussions::domain::entities::public_message_revision::PublicMessageRevision;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_revision_repository::PublicMessageRevisionRepositoryImpl;
+use uuid::Uuid;
+
+pub struct GetPublicMessageRevisionsUseCase {
+    message_repo: PublicMessageRepositoryImpl,
+    revision_repo: PublicMessageRevisionRepositoryImpl,
+}
+
+impl GetPublicMessageRevisionsUseCase {
+    pub fn new(
+        message_repo: PublicMessageRepositoryImpl,
+        revision_repo: PublicMessageRevisionRepositoryImpl,
+    ) -> Self {
+        Self {
+            message_repo,
+            revision_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        message_id: Uuid,
+        user_id: Uuid,
+        can_moderate: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<PublicMessageRevision>, AppError> {
+        // Verify message exists
+        let message = self
+            .message_repo
+            .get_by_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PublicMessageNotFound)?;
+
+        // Only the author and moderators can see previous versions
+        if message.creator != user_id && !can_moderate {
+            return Err(AppError::PublicMessageHistoryNotAccessible);
+        }
+
+        self.revision_repo
+            .get_by_message_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)
+    }
+}
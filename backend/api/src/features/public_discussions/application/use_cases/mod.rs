@@ -1,9 +1,21 @@
 // Public discussions use cases
 
 pub mod create_public_message;
-pub mod create_public_message_like;
+pub mod create_public_message_reaction;
 pub mod create_public_message_report;
+pub mod create_public_message_subscription;
 pub mod delete_public_message;
-pub mod delete_public_message_like;
+pub mod delete_public_message_reaction;
 pub mod delete_public_message_report;
+pub mod delete_public_message_subscription;
+pub mod flush_public_message_subscriptions;
+pub mod get_moderation_queue;
+pub mod get_public_message_reactions;
+pub mod get_public_message_revisions;
+pub mod pin_public_message;
+pub mod record_public_message_subscription_activity;
+pub mod resolve_public_message_reports;
+pub mod sync_public_message_mentions;
+pub mod unpin_public_message;
 pub mod update_public_message;
+pub mod update_public_message_subscription;
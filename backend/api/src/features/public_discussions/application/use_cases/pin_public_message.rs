@@ -0,0 +1,121 @@
+// Pin public message use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl;
+use crate::features::public_discussions::domain::entities::public_message::{
+    PublicMessage, PUBLIC_MESSAGE_MAX_PINS_PER_CHANNEL,
+};
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use uuid::Uuid;
+
+pub struct PinPublicMessageUseCase {
+    message_repo: PublicMessageRepositoryImpl,
+    challenge_repo: ChallengeRepositoryImpl,
+}
+
+impl PinPublicMessageUseCase {
+    pub fn new(
+        message_repo: PublicMessageRepositoryImpl,
+        challenge_repo: ChallengeRepositoryImpl,
+    ) -> Self {
+        Self {
+            message_repo,
+            challenge_repo,
+        }
+    }
+
+    // Returns the pinned message, and whether it just became an announcement
+    pub async fn execute(
+        &self,
+        message_id: Uuid,
+        user_id: Uuid,
+        can_moderate: bool,
+        is_announcement: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(PublicMessage, bool), AppError> {
+        let mut message = get_pinnable_message(
+            &self.message_repo,
+            &self.challenge_repo,
+            message_id,
+            user_id,
+            can_moderate,
+            transaction,
+        )
+        .await?;
+
+        // Only challenge channels have participants to tell
+        if is_announcement && message.challenge_id.is_none() {
+            return Err(AppError::PublicMessageAnnouncementOnlyInChallenges);
+        }
+
+        // Check the number of pins of the channel
+        if message.pinned_at.is_none() {
+            let pinned_messages = self
+                .message_repo
+                .get_pinned_with_executor(
+                    message.habit_id,
+                    message.challenge_id,
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+
+            if pinned_messages.len() >= PUBLIC_MESSAGE_MAX_PINS_PER_CHANNEL {
+                return Err(AppError::PublicMessagePinLimitReached);
+            }
+
+            message.pinned_at = Some(now());
+        }
+
+        let newly_announced = is_announcement && !message.is_announcement;
+        message.pinned_by = Some(user_id);
+        message.is_announcement = is_announcement;
+
+        self.message_repo
+            .update_pin_with_executor(&message, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageUpdate)?;
+
+        Ok((message, newly_announced))
+    }
+}
+
+// Challenge channels are managed by the challenge creator, moderators manage every channel.
+// Only visible messages starting a thread can be pinned.
+pub async fn get_pinnable_message(
+    message_repo: &PublicMessageRepositoryImpl,
+    challenge_repo: &ChallengeRepositoryImpl,
+    message_id: Uuid,
+    user_id: Uuid,
+    can_moderate: bool,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<PublicMessage, AppError> {
+    let message = message_repo
+        .get_by_id_with_executor(message_id, &mut **transaction)
+        .await
+        .map_err(|_| AppError::DatabaseQuery)?
+        .filter(|m| !m.deleted_by_creator && !m.deleted_by_admin && !m.hidden_by_moderation)
+        .ok_or(AppError::PublicMessageNotFound)?;
+
+    if !can_moderate {
+        let is_challenge_creator = match message.challenge_id {
+            Some(challenge_id) => challenge_repo
+                .get_by_id_with_executor(challenge_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+                .is_some_and(|challenge| challenge.creator == user_id),
+            None => false,
+        };
+
+        if !is_challenge_creator {
+            return Err(AppError::PublicMessagePinNotAllowed);
+        }
+    }
+
+    if message.replies_to.is_some() {
+        return Err(AppError::PublicMessagePinOnlyForRootMessages);
+    }
+
+    Ok(message)
+}
@@ -0,0 +1,70 @@
+// Record public message subscription activity use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::domain::entities::public_message_subscription::{
+    public_message_subscription_batch_window, PublicMessageSubscription,
+};
+use crate::features::public_discussions::infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl;
+use uuid::Uuid;
+
+pub struct RecordPublicMessageSubscriptionActivityUseCase {
+    subscription_repo: PublicMessageSubscriptionRepositoryImpl,
+}
+
+impl RecordPublicMessageSubscriptionActivityUseCase {
+    pub fn new(subscription_repo: PublicMessageSubscriptionRepositoryImpl) -> Self {
+        Self { subscription_repo }
+    }
+
+    // Counts the new message for every subscriber and returns the subscriptions to
+    // notify right away, with the number of messages their notification covers.
+    // Users already notified about this message (reply, mention) are skipped.
+    pub async fn execute(
+        &self,
+        message: &PublicMessage,
+        already_notified_user_ids: &[Uuid],
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<(PublicMessageSubscription, i32)>, AppError> {
+        let subscriptions = self
+            .subscription_repo
+            .get_for_message_with_executor(message, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        let now = now();
+        let mut to_notify = Vec::new();
+
+        for mut subscription in subscriptions {
+            if subscription.muted || already_notified_user_ids.contains(&subscription.user_id) {
+                continue;
+            }
+
+            let window_is_over = subscription
+                .last_notified_at
+                .is_none_or(|last_notified_at| {
+                    last_notified_at + public_message_subscription_batch_window() <= now
+                });
+
+            let count = subscription.pending_count + 1;
+            if window_is_over {
+                subscription.pending_count = 0;
+                subscription.last_notified_at = Some(now);
+            } else {
+                subscription.pending_count = count;
+            }
+
+            self.subscription_repo
+                .update_pending_count_with_executor(&subscription, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PublicMessageSubscriptionUpdate)?;
+
+            if window_is_over {
+                to_notify.push((subscription, count));
+            }
+        }
+
+        Ok(to_notify)
+    }
+}
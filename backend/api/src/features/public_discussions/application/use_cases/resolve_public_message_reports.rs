@@ -0,0 +1,185 @@
+// Resolve public message reports use case
+
+use std::collections::HashSet;
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::domain::entities::public_message_moderation::{
+    ModerationAction, PublicMessageModerationDecision, MODERATION_DEFAULT_SUSPENSION_IN_DAYS,
+    MODERATION_MAX_SUSPENSION_IN_DAYS, MODERATION_NOTE_MAX_LENGTH,
+};
+use crate::features::public_discussions::domain::entities::user_suspension::UserSuspension;
+use crate::features::public_discussions::infrastructure::repositories::public_message_moderation_decision_repository::PublicMessageModerationDecisionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_report_repository::PublicMessageReportRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::user_suspension_repository::UserSuspensionRepositoryImpl;
+
+// Checks what a moderator sent, shared by every kind of reported content.
+// Returns the trimmed note and the suspension duration.
+pub fn validate_moderation_decision(
+    action: ModerationAction,
+    note: Option<String>,
+    suspension_in_days: Option<i64>,
+    can_suspend: bool,
+) -> Result<(Option<String>, i64), AppError> {
+    if action == ModerationAction::SuspendAuthor && !can_suspend {
+        return Err(AppError::MissingPermission);
+    }
+
+    // Validate note
+    let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    if let Some(note) = &note {
+        if note.len() > MODERATION_NOTE_MAX_LENGTH {
+            return Err(AppError::ModerationNoteTooLong);
+        }
+    } else if matches!(
+        action,
+        ModerationAction::WarnAuthor | ModerationAction::SuspendAuthor
+    ) {
+        return Err(AppError::ModerationNoteEmpty);
+    }
+
+    let suspension_in_days = suspension_in_days.unwrap_or(MODERATION_DEFAULT_SUSPENSION_IN_DAYS);
+    if action == ModerationAction::SuspendAuthor
+        && !(1..=MODERATION_MAX_SUSPENSION_IN_DAYS).contains(&suspension_in_days)
+    {
+        return Err(AppError::ModerationSuspensionDurationInvalid);
+    }
+
+    Ok((note, suspension_in_days))
+}
+
+pub struct ResolvePublicMessageReportsUseCase {
+    report_repo: PublicMessageReportRepositoryImpl,
+    message_repo: PublicMessageRepositoryImpl,
+    decision_repo: PublicMessageModerationDecisionRepositoryImpl,
+    suspension_repo: UserSuspensionRepositoryImpl,
+}
+
+impl ResolvePublicMessageReportsUseCase {
+    pub fn new(
+        report_repo: PublicMessageReportRepositoryImpl,
+        message_repo: PublicMessageRepositoryImpl,
+        decision_repo: PublicMessageModerationDecisionRepositoryImpl,
+        suspension_repo: UserSuspensionRepositoryImpl,
+    ) -> Self {
+        Self {
+            report_repo,
+            message_repo,
+            decision_repo,
+            suspension_repo,
+        }
+    }
+
+    // Records the decision, applies it to the message and its author, and closes
+    // every pending report. Returns the decision, the message and the reporters.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute(
+        &self,
+        message_id: Uuid,
+        moderator: Uuid,
+        action: ModerationAction,
+        note: Option<String>,
+        suspension_in_days: Option<i64>,
+        can_suspend: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(PublicMessageModerationDecision, PublicMessage, Vec<Uuid>), AppError> {
+        let (note, suspension_in_days) =
+            validate_moderation_decision(action, note, suspension_in_days, can_suspend)?;
+
+        // Verify message exists
+        let mut message = self
+            .message_repo
+            .get_by_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::PublicMessageNotFound)?;
+
+        let reports = self
+            .report_repo
+            .get_pending_by_message_id_with_executor(message_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+        if reports.is_empty() {
+            return Err(AppError::PublicMessageHasNoPendingReports);
+        }
+
+        let mut reporters = Vec::new();
+        let mut seen = HashSet::new();
+        for report in &reports {
+            if seen.insert(report.reporter) {
+                reporters.push(report.reporter);
+            }
+        }
+
+        let resolved_at = now();
+        let decision = PublicMessageModerationDecision {
+            id: Uuid::new_v4(),
+            message_id: Some(message_id),
+            story_id: None,
+            moderator: Some(moderator),
+            action: action.to_string(),
+            note,
+            report_count: reports.len() as i32,
+            created_at: resolved_at,
+        };
+
+        self.decision_repo
+            .create_with_executor(&decision, &mut **transaction)
+            .await
+            .map_err(|_| AppError::ModerationDecisionCreation)?;
+
+        // Apply the decision to the message
+        self.message_repo
+            .update_hidden_by_moderation_with_executor(
+                message_id,
+                action.hides_message(),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::PublicMessageUpdate)?;
+        message.hidden_by_moderation = action.hides_message();
+
+        if action == ModerationAction::Delete {
+            message.deleted_by_admin = true;
+            self.message_repo
+                .delete_with_executor(&message, &mut **transaction)
+                .await
+                .map_err(|_| AppError::PublicMessageDeletion)?;
+            message.content = String::new();
+            message.attachment_ids = Vec::new();
+        }
+
+        // Apply the decision to the author
+        if action == ModerationAction::SuspendAuthor {
+            let suspension = UserSuspension {
+                id: Uuid::new_v4(),
+                user_id: message.creator,
+                decision_id: Some(decision.id),
+                created_at: resolved_at,
+                expires_at: resolved_at + Duration::days(suspension_in_days),
+            };
+
+            self.suspension_repo
+                .create_with_executor(&suspension, &mut **transaction)
+                .await
+                .map_err(|_| AppError::ModerationDecisionCreation)?;
+        }
+
+        self.report_repo
+            .resolve_by_message_id_with_executor(
+                message_id,
+                decision.id,
+                resolved_at,
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        Ok((decision, message, reporters))
+    }
+}
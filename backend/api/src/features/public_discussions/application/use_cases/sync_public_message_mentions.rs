@@ -0,0 +1,87 @@
+// Sync public message mentions, shared by the create and update use cases
+
+use std::collections::HashMap;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::domain::entities::public_message_mention::{
+    PublicMessageMention, PUBLIC_MESSAGE_MAX_MENTIONS,
+};
+use crate::features::public_discussions::infrastructure::repositories::public_message_mention_repository::PublicMessageMentionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::services::mention_service::MentionService;
+use uuid::Uuid;
+
+// Replaces the stored mentions of the message with the ones found in its content.
+// Returns the users that were not mentioned before and should be notified.
+pub async fn sync_public_message_mentions(
+    mention_repo: &PublicMessageMentionRepositoryImpl,
+    message: &PublicMessage,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<Vec<Uuid>, AppError> {
+    let parsed_mentions = MentionService::new().extract_mentions(&message.content);
+
+    let previously_mentioned: Vec<Uuid> = mention_repo
+        .get_by_message_ids_with_executor(vec![message.id], &mut **transaction)
+        .await
+        .map_err(|_| AppError::DatabaseQuery)?
+        .into_iter()
+        .map(|mention| mention.user_id)
+        .collect();
+
+    mention_repo
+        .delete_by_message_id_with_executor(message.id, &mut **transaction)
+        .await
+        .map_err(|_| AppError::PublicMessageMentionCreation)?;
+
+    if parsed_mentions.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut usernames: Vec<String> = Vec::new();
+    for mention in &parsed_mentions {
+        if !usernames.contains(&mention.username) {
+            usernames.push(mention.username.to_owned());
+        }
+    }
+    usernames.truncate(PUBLIC_MESSAGE_MAX_MENTIONS);
+
+    let users: HashMap<String, Uuid> = mention_repo
+        .get_mentionable_users_with_executor(usernames, message.creator, &mut **transaction)
+        .await
+        .map_err(|_| AppError::DatabaseQuery)?
+        .into_iter()
+        .map(|(id, username)| (username, id))
+        .collect();
+
+    let mut newly_mentioned = Vec::new();
+    for parsed_mention in parsed_mentions {
+        let Some(user_id) = users.get(&parsed_mention.username) else {
+            continue;
+        };
+
+        mention_repo
+            .create_with_executor(
+                &PublicMessageMention {
+                    id: Uuid::new_v4(),
+                    message_id: message.id,
+                    user_id: *user_id,
+                    start_index: parsed_mention.start_index as i32,
+                    length: parsed_mention.length as i32,
+                    created_at: now(),
+                },
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::PublicMessageMentionCreation)?;
+
+        if *user_id != message.creator
+            && !previously_mentioned.contains(user_id)
+            && !newly_mentioned.contains(user_id)
+        {
+            newly_mentioned.push(*user_id);
+        }
+    }
+
+    Ok(newly_mentioned)
+}
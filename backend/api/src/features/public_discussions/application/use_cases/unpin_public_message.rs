@@ -0,0 +1,54 @@
+// Unpin public message use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl;
+use crate::features::public_discussions::application::use_cases::pin_public_message::get_pinnable_message;
+use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use uuid::Uuid;
+
+pub struct UnpinPublicMessageUseCase {
+    message_repo: PublicMessageRepositoryImpl,
+    challenge_repo: ChallengeRepositoryImpl,
+}
+
+impl UnpinPublicMessageUseCase {
+    pub fn new(
+        message_repo: PublicMessageRepositoryImpl,
+        challenge_repo: ChallengeRepositoryImpl,
+    ) -> Self {
+        Self {
+            message_repo,
+            challenge_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        message_id: Uuid,
+        user_id: Uuid,
+        can_moderate: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<PublicMessage, AppError> {
+        let mut message = get_pinnable_message(
+            &self.message_repo,
+            &self.challenge_repo,
+            message_id,
+            user_id,
+            can_moderate,
+            transaction,
+        )
+        .await?;
+
+        message.pinned_at = None;
+        message.pinned_by = None;
+        message.is_announcement = false;
+
+        self.message_repo
+            .update_pin_with_executor(&message, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageUpdate)?;
+
+        Ok(message)
+    }
+}
@@ -1,25 +1,45 @@
 // Update public message use case
 
 use crate::core::constants::errors::AppError;
+use crate::features::content_filters::application::use_cases::apply_content_filter::ApplyContentFilterUseCase;
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
 use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::domain::entities::public_message_revision::PublicMessageRevision;
+use crate::features::public_discussions::application::use_cases::sync_public_message_mentions::sync_public_message_mentions;
+use crate::features::public_discussions::infrastructure::repositories::public_message_mention_repository::PublicMessageMentionRepositoryImpl;
 use crate::features::public_discussions::infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_revision_repository::PublicMessageRevisionRepositoryImpl;
 use uuid::Uuid;
 
 pub struct UpdatePublicMessageUseCase {
     message_repo: PublicMessageRepositoryImpl,
+    revision_repo: PublicMessageRevisionRepositoryImpl,
+    mention_repo: PublicMessageMentionRepositoryImpl,
+    content_filter: ApplyContentFilterUseCase,
 }
 
 impl UpdatePublicMessageUseCase {
-    pub fn new(message_repo: PublicMessageRepositoryImpl) -> Self {
-        Self { message_repo }
+    pub fn new(
+        message_repo: PublicMessageRepositoryImpl,
+        revision_repo: PublicMessageRevisionRepositoryImpl,
+        mention_repo: PublicMessageMentionRepositoryImpl,
+        content_filter: ApplyContentFilterUseCase,
+    ) -> Self {
+        Self {
+            message_repo,
+            revision_repo,
+            mention_repo,
+            content_filter,
+        }
     }
 
     pub async fn execute(
         &self,
-        message: &PublicMessage,
+        message: &mut PublicMessage,
         creator_id: Uuid,
+        content_filter_locales: &[String],
         transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<Uuid>, AppError> {
         // Verify message exists
         let existing_message = self
             .message_repo
@@ -41,12 +61,39 @@ impl UpdatePublicMessageUseCase {
             return Err(AppError::PublicMessageContentTooLong);
         }
 
+        // Rejected contents stop here, masked ones are stored masked
+        message.content = self
+            .content_filter
+            .execute(
+                &message.content,
+                content_filter_locales,
+                ContentFilterTarget::PublicMessage,
+                message.id,
+                transaction,
+            )
+            .await?;
+
         // Update message
         self.message_repo
             .update_with_executor(message, &mut **transaction)
             .await
             .map_err(|_| AppError::PublicMessageUpdate)?;
 
-        Ok(())
+        // Keep the new content in the message history
+        self.revision_repo
+            .create_with_executor(
+                &PublicMessageRevision {
+                    id: Uuid::new_v4(),
+                    message_id: message.id,
+                    content: message.content.to_owned(),
+                    created_at: message.updated_at.unwrap_or(message.created_at),
+                },
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::PublicMessageUpdate)?;
+
+        // Returns the users mentioned for the first time in this message
+        sync_public_message_mentions(&self.mention_repo, message, transaction).await
     }
 }
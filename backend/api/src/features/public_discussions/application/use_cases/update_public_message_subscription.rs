@@ -0,0 +1,41 @@
+// Update public message subscription use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::public_discussions::domain::entities::public_message_subscription::PublicMessageSubscription;
+use crate::features::public_discussions::infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl;
+use uuid::Uuid;
+
+pub struct UpdatePublicMessageSubscriptionUseCase {
+    subscription_repo: PublicMessageSubscriptionRepositoryImpl,
+}
+
+impl UpdatePublicMessageSubscriptionUseCase {
+    pub fn new(subscription_repo: PublicMessageSubscriptionRepositoryImpl) -> Self {
+        Self { subscription_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        subscription_id: Uuid,
+        user_id: Uuid,
+        muted: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<PublicMessageSubscription, AppError> {
+        // Subscriptions of other users are hidden
+        let mut subscription = self
+            .subscription_repo
+            .get_by_id_with_executor(subscription_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .filter(|s| s.user_id == user_id)
+            .ok_or(AppError::PublicMessageSubscriptionNotFound)?;
+
+        subscription.muted = muted;
+        self.subscription_repo
+            .update_with_executor(&subscription, &mut **transaction)
+            .await
+            .map_err(|_| AppError::PublicMessageSubscriptionUpdate)?;
+
+        Ok(subscription)
+    }
+}
@@ -1,5 +1,10 @@
 // Public discussions domain entities
 
 pub mod public_message;
-pub mod public_message_like;
+pub mod public_message_mention;
+pub mod public_message_moderation;
+pub mod public_message_reaction;
 pub mod public_message_report;
+pub mod public_message_revision;
+pub mod public_message_subscription;
+pub mod user_suspension;
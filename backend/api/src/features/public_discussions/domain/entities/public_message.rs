@@ -1,9 +1,17 @@
-use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 use uuid::Uuid;
 
+use super::public_message_reaction::PublicMessageReactionKind;
+
 pub const PUBLIC_MESSAGE_CONTENT_MAX_LENGTH: usize = 2000;
+pub const PUBLIC_MESSAGES_PAGE_SIZE: i64 = 20;
+pub const PUBLIC_MESSAGES_MAX_PAGE_SIZE: i64 = 50;
+pub const PUBLIC_MESSAGE_MAX_PINS_PER_CHANNEL: usize = 3;
+pub const PUBLIC_MESSAGE_MAX_ATTACHMENTS: usize = 4;
 
 #[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
 pub struct PublicMessage {
@@ -21,6 +29,17 @@ pub struct PublicMessage {
     pub deleted_by_creator: bool,
     pub deleted_by_admin: bool,
     pub language_code: Option<String>,
+    pub hidden_by_moderation: bool,
+    // Computed by the database from the counters and the creation date
+    pub hot_score: f64,
+    pub pinned_at: Option<DateTime<Utc>>,
+    pub pinned_by: Option<Uuid>,
+    // Announcements are pinned messages whose pinning notified the challenge participants
+    pub is_announcement: bool,
+    // Number of reactions by kind, like_count mirrors the "like" entry
+    pub reaction_counts: serde_json::Value,
+    // Images uploaded through the attachments feature, in display order
+    pub attachment_ids: Vec<Uuid>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -39,6 +58,11 @@ pub struct PublicMessageData {
     pub deleted_by_creator: bool,
     pub deleted_by_admin: bool,
     pub language_code: Option<String>,
+    pub hidden_by_moderation: bool,
+    pub pinned_at: Option<DateTime<Utc>>,
+    pub is_announcement: bool,
+    pub reaction_counts: BTreeMap<String, i32>,
+    pub attachment_ids: Vec<Uuid>,
 }
 
 impl PublicMessage {
@@ -58,6 +82,158 @@ impl PublicMessage {
             deleted_by_creator: self.deleted_by_creator,
             deleted_by_admin: self.deleted_by_admin,
             language_code: self.language_code.to_owned(),
+            hidden_by_moderation: self.hidden_by_moderation,
+            pinned_at: self.pinned_at,
+            is_announcement: self.is_announcement,
+            reaction_counts: self.get_reaction_counts(),
+            attachment_ids: self.attachment_ids.to_owned(),
+        }
+    }
+
+    pub fn get_reaction_counts(&self) -> BTreeMap<String, i32> {
+        serde_json::from_value(self.reaction_counts.clone()).unwrap_or_default()
+    }
+
+    pub fn add_reactions(&mut self, reaction: PublicMessageReactionKind, delta: i32) {
+        let mut reaction_counts = self.get_reaction_counts();
+        let count = reaction_counts.entry(reaction.to_string()).or_insert(0);
+        *count = (*count + delta).max(0);
+
+        if reaction == PublicMessageReactionKind::Like {
+            self.like_count = *count;
+        }
+
+        reaction_counts.retain(|_, count| *count > 0);
+        self.reaction_counts = serde_json::json!(reaction_counts);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PublicMessageSort {
+    #[default]
+    Newest,
+    Top,
+    MostReplied,
+    Hot,
+}
+
+impl PublicMessageSort {
+    pub fn column(&self) -> &'static str {
+        match self {
+            PublicMessageSort::Newest => "created_at",
+            PublicMessageSort::Top => "like_count",
+            PublicMessageSort::MostReplied => "reply_count",
+            PublicMessageSort::Hot => "hot_score",
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PublicMessageCursorValue {
+    CreatedAt(DateTime<Utc>),
+    Count(i32),
+    Score(f64),
+}
+
+// Position of the last message of a page: its sort value, with the id to break ties
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PublicMessageCursor {
+    pub value: PublicMessageCursorValue,
+    pub id: Uuid,
+}
+
+impl PublicMessageCursor {
+    pub fn from_message(message: &PublicMessage, sort: PublicMessageSort) -> Self {
+        let value = match sort {
+            PublicMessageSort::Newest => PublicMessageCursorValue::CreatedAt(message.created_at),
+            PublicMessageSort::Top => PublicMessageCursorValue::Count(message.like_count),
+            PublicMessageSort::MostReplied => PublicMessageCursorValue::Count(message.reply_count),
+            PublicMessageSort::Hot => PublicMessageCursorValue::Score(message.hot_score),
+        };
+
+        Self {
+            value,
+            id: message.id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let value = match self.value {
+            PublicMessageCursorValue::CreatedAt(created_at) => {
+                created_at.to_rfc3339_opts(SecondsFormat::Micros, true)
+            }
+            PublicMessageCursorValue::Count(count) => count.to_string(),
+            PublicMessageCursorValue::Score(score) => score.to_string(),
+        };
+
+        format!("{}_{}", value, self.id)
+    }
+
+    pub fn decode(cursor: &str, sort: PublicMessageSort) -> Option<Self> {
+        let (value, id) = cursor.rsplit_once('_')?;
+
+        let value = match sort {
+            PublicMessageSort::Newest => PublicMessageCursorValue::CreatedAt(
+                DateTime::parse_from_rfc3339(value)
+                    .ok()?
+                    .with_timezone(&Utc),
+            ),
+            PublicMessageSort::Top | PublicMessageSort::MostReplied => {
+                PublicMessageCursorValue::Count(value.parse().ok()?)
+            }
+            PublicMessageSort::Hot => {
+                PublicMessageCursorValue::Score(value.parse().ok().filter(|s: &f64| s.is_finite())?)
+            }
+        };
+
+        Some(Self {
+            value,
+            id: Uuid::parse_str(id).ok()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PublicMessagePage {
+    pub sort: PublicMessageSort,
+    pub cursor: Option<PublicMessageCursor>,
+    pub limit: i64,
+}
+
+impl PublicMessagePage {
+    // Returns None when the cursor was not produced for this sort
+    pub fn new(
+        sort: Option<PublicMessageSort>,
+        cursor: Option<&str>,
+        limit: Option<i64>,
+    ) -> Option<Self> {
+        let sort = sort.unwrap_or_default();
+        let cursor = match cursor {
+            Some(cursor) => Some(PublicMessageCursor::decode(cursor, sort)?),
+            None => None,
+        };
+
+        Some(Self {
+            sort,
+            cursor,
+            limit: limit
+                .unwrap_or(PUBLIC_MESSAGES_PAGE_SIZE)
+                .clamp(1, PUBLIC_MESSAGES_MAX_PAGE_SIZE),
+        })
+    }
+
+    // Repositories fetch one extra message to know whether another page exists
+    pub fn split(&self, mut messages: Vec<PublicMessage>) -> (Vec<PublicMessage>, Option<String>) {
+        if messages.len() as i64 <= self.limit {
+            return (messages, None);
+        }
+
+        messages.truncate(self.limit as usize);
+        let next_cursor = messages
+            .last()
+            .map(|m| PublicMessageCursor::from_message(m, self.sort).encode());
+
+        (messages, next_cursor)
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+pub const PUBLIC_MESSAGE_MAX_MENTIONS: usize = 10;
+
+// A user mentioned in a message. The span locates the `@username` in the content,
+// so clients can display the current username even after a rename.
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct PublicMessageMention {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub start_index: i32,
+    pub length: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicMessageMentionData {
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub start_index: i32,
+    pub length: i32,
+}
+
+impl PublicMessageMention {
+    pub fn to_public_message_mention_data(&self) -> PublicMessageMentionData {
+        PublicMessageMentionData {
+            message_id: self.message_id,
+            user_id: self.user_id,
+            start_index: self.start_index,
+            length: self.length,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+use crate::features::stories::domain::entities::story::StoryData;
+
+use super::public_message::PublicMessageData;
+
+pub const MODERATION_NOTE_MAX_LENGTH: usize = 2000;
+pub const MODERATION_DEFAULT_SUSPENSION_IN_DAYS: i64 = 7;
+pub const MODERATION_MAX_SUSPENSION_IN_DAYS: i64 = 365;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationAction {
+    Dismiss,
+    Hide,
+    Delete,
+    WarnAuthor,
+    SuspendAuthor,
+}
+
+impl ModerationAction {
+    pub const ALL: [ModerationAction; 5] = [
+        ModerationAction::Dismiss,
+        ModerationAction::Hide,
+        ModerationAction::Delete,
+        ModerationAction::WarnAuthor,
+        ModerationAction::SuspendAuthor,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModerationAction::Dismiss => "dismiss",
+            ModerationAction::Hide => "hide",
+            ModerationAction::Delete => "delete",
+            ModerationAction::WarnAuthor => "warn_author",
+            ModerationAction::SuspendAuthor => "suspend_author",
+        }
+    }
+
+    // Every action except a dismissal keeps the message out of public threads
+    pub fn hides_message(&self) -> bool {
+        !matches!(self, ModerationAction::Dismiss)
+    }
+}
+
+impl fmt::Display for ModerationAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ModerationAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ModerationAction::ALL
+            .into_iter()
+            .find(|action| action.as_str() == s)
+            .ok_or_else(|| format!("Unknown moderation action: {}", s))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct PublicMessageModerationDecision {
+    pub id: Uuid,
+    pub message_id: Option<Uuid>,
+    pub story_id: Option<Uuid>,
+    pub moderator: Option<Uuid>,
+    pub action: String,
+    pub note: Option<String>,
+    pub report_count: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicMessageModerationDecisionData {
+    pub id: Uuid,
+    pub message_id: Option<Uuid>,
+    pub story_id: Option<Uuid>,
+    pub moderator: Option<Uuid>,
+    pub action: String,
+    pub note: Option<String>,
+    pub report_count: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PublicMessageModerationDecision {
+    pub fn to_public_message_moderation_decision_data(
+        &self,
+    ) -> PublicMessageModerationDecisionData {
+        PublicMessageModerationDecisionData {
+            id: self.id,
+            message_id: self.message_id,
+            story_id: self.story_id,
+            moderator: self.moderator,
+            action: self.action.to_owned(),
+            note: self.note.to_owned(),
+            report_count: self.report_count,
+            created_at: self.created_at,
+        }
+    }
+}
+
+// A reported message or story with its pending reports aggregated
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ModerationQueueEntry {
+    pub message: Option<PublicMessageData>,
+    pub story: Option<StoryData>,
+    pub report_count: usize,
+    pub reporter_count: usize,
+    pub reasons: Vec<String>,
+    pub reported_revision_ids: Vec<Uuid>,
+    pub first_reported_at: DateTime<Utc>,
+    pub last_reported_at: DateTime<Utc>,
+}
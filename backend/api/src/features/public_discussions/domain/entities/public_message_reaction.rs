@@ -0,0 +1,98 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PublicMessageReactionKind {
+    // Existing likes were migrated into this reaction
+    Like,
+    Love,
+    Laugh,
+    Wow,
+    Celebrate,
+    Strong,
+}
+
+impl PublicMessageReactionKind {
+    pub const ALL: [PublicMessageReactionKind; 6] = [
+        PublicMessageReactionKind::Like,
+        PublicMessageReactionKind::Love,
+        PublicMessageReactionKind::Laugh,
+        PublicMessageReactionKind::Wow,
+        PublicMessageReactionKind::Celebrate,
+        PublicMessageReactionKind::Strong,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PublicMessageReactionKind::Like => "like",
+            PublicMessageReactionKind::Love => "love",
+            PublicMessageReactionKind::Laugh => "laugh",
+            PublicMessageReactionKind::Wow => "wow",
+            PublicMessageReactionKind::Celebrate => "celebrate",
+            PublicMessageReactionKind::Strong => "strong",
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            PublicMessageReactionKind::Like => "👍",
+            PublicMessageReactionKind::Love => "❤️",
+            PublicMessageReactionKind::Laugh => "😂",
+            PublicMessageReactionKind::Wow => "😮",
+            PublicMessageReactionKind::Celebrate => "🎉",
+            PublicMessageReactionKind::Strong => "💪",
+        }
+    }
+}
+
+impl fmt::Display for PublicMessageReactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PublicMessageReactionKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PublicMessageReactionKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| format!("Unknown public message reaction: {}", s))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct PublicMessageReaction {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub reaction: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicMessageReactionData {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub reaction: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PublicMessageReaction {
+    pub fn to_public_message_reaction_data(&self) -> PublicMessageReactionData {
+        PublicMessageReactionData {
+            id: self.id,
+            message_id: self.message_id,
+            user_id: self.user_id,
+            reaction: self.reaction.to_owned(),
+            created_at: self.created_at,
+        }
+    }
+}
@@ -5,22 +5,31 @@ use uuid::Uuid;
 
 pub const PUBLIC_MESSAGE_REPORT_CONTENT_MAX_LENGTH: usize = 2000;
 
+// A report targets either a public message or a story
 #[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
 pub struct PublicMessageReport {
     pub id: Uuid,
-    pub message_id: Uuid,
+    pub message_id: Option<Uuid>,
+    pub story_id: Option<Uuid>,
     pub reporter: Uuid,
     pub created_at: DateTime<Utc>,
     pub reason: String,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub decision_id: Option<Uuid>,
+    pub revision_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PublicMessageReportData {
     pub id: Uuid,
-    pub message_id: Uuid,
+    pub message_id: Option<Uuid>,
+    pub story_id: Option<Uuid>,
     pub reporter: Uuid,
     pub created_at: DateTime<Utc>,
     pub reason: String,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub decision_id: Option<Uuid>,
+    pub revision_id: Option<Uuid>,
 }
 
 impl PublicMessageReport {
@@ -28,9 +37,13 @@ impl PublicMessageReport {
         PublicMessageReportData {
             id: self.id,
             message_id: self.message_id,
+            story_id: self.story_id,
             reporter: self.reporter,
             created_at: self.created_at,
             reason: self.reason.to_owned(),
+            resolved_at: self.resolved_at,
+            decision_id: self.decision_id,
+            revision_id: self.revision_id,
         }
     }
 }
@@ -3,28 +3,29 @@ use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 use uuid::Uuid;
 
+// One version of a public message content, the latest one being the current content
 #[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
-pub struct PublicMessageLike {
+pub struct PublicMessageRevision {
     pub id: Uuid,
     pub message_id: Uuid,
-    pub user_id: Uuid,
+    pub content: String,
     pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct PublicMessageLikeData {
+pub struct PublicMessageRevisionData {
     pub id: Uuid,
     pub message_id: Uuid,
-    pub user_id: Uuid,
+    pub content: String,
     pub created_at: DateTime<Utc>,
 }
 
-impl PublicMessageLike {
-    pub fn to_public_message_like_data(&self) -> PublicMessageLikeData {
-        PublicMessageLikeData {
+impl PublicMessageRevision {
+    pub fn to_public_message_revision_data(&self) -> PublicMessageRevisionData {
+        PublicMessageRevisionData {
             id: self.id,
             message_id: self.message_id,
-            user_id: self.user_id,
+            content: self.content.to_owned(),
             created_at: self.created_at,
         }
     }
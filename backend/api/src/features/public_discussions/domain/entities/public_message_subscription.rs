@@ -0,0 +1,65 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+// Once a subscriber was notified, new messages are only counted during this window
+// and sent together afterwards, so busy threads don't flood devices
+pub const PUBLIC_MESSAGE_SUBSCRIPTION_BATCH_WINDOW_IN_MINUTES: i64 = 15;
+
+pub fn public_message_subscription_batch_window() -> Duration {
+    Duration::minutes(PUBLIC_MESSAGE_SUBSCRIPTION_BATCH_WINDOW_IN_MINUTES)
+}
+
+// Follows a habit/challenge channel, or a single thread of it when thread_id is set
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct PublicMessageSubscription {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub habit_id: Option<Uuid>,
+    pub challenge_id: Option<Uuid>,
+    pub thread_id: Option<Uuid>,
+    pub muted: bool,
+    // Messages not notified yet because of the batch window
+    pub pending_count: i32,
+    pub last_notified_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicMessageSubscriptionData {
+    pub id: Uuid,
+    pub habit_id: Option<Uuid>,
+    pub challenge_id: Option<Uuid>,
+    pub thread_id: Option<Uuid>,
+    pub muted: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PublicMessageSubscription {
+    pub fn to_public_message_subscription_data(&self) -> PublicMessageSubscriptionData {
+        PublicMessageSubscriptionData {
+            id: self.id,
+            habit_id: self.habit_id,
+            challenge_id: self.challenge_id,
+            thread_id: self.thread_id,
+            muted: self.muted,
+            created_at: self.created_at,
+        }
+    }
+
+    // Where the notification leads: the thread, or the channel itself
+    pub fn url(&self) -> String {
+        let mut url = if let Some(challenge_id) = self.challenge_id {
+            format!("/challenges/{}/null", challenge_id)
+        } else {
+            format!("/habits/{}", self.habit_id.unwrap_or_default())
+        };
+
+        if let Some(thread_id) = self.thread_id {
+            url.push_str(&format!("/threads/{}", thread_id));
+        }
+
+        url
+    }
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+// Suspended users can not write in public or private discussions until the suspension expires
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct UserSuspension {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub decision_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
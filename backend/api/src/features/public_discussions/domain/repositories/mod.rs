@@ -1,5 +1,10 @@
 // Public discussions domain repository traits
 
-pub mod public_message_like_repository;
+pub mod public_message_mention_repository;
+pub mod public_message_moderation_decision_repository;
+pub mod public_message_reaction_repository;
 pub mod public_message_report_repository;
 pub mod public_message_repository;
+pub mod public_message_revision_repository;
+pub mod public_message_subscription_repository;
+pub mod user_suspension_repository;
@@ -0,0 +1,21 @@
+// PublicMessageMention repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message_mention::PublicMessageMention;
+
+#[async_trait]
+pub trait PublicMessageMentionRepository: Send + Sync {
+    async fn create(&self, mention: &PublicMessageMention) -> Result<(), String>;
+    async fn delete_by_message_id(&self, message_id: Uuid) -> Result<(), String>;
+    async fn get_by_message_ids(
+        &self,
+        message_ids: Vec<Uuid>,
+    ) -> Result<Vec<PublicMessageMention>, String>;
+    async fn get_mentionable_users(
+        &self,
+        usernames: Vec<String>,
+        author: Uuid,
+    ) -> Result<Vec<(Uuid, String)>, String>;
+}
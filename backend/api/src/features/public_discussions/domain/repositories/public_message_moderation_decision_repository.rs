@@ -0,0 +1,15 @@
+// PublicMessageModerationDecision repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message_moderation::PublicMessageModerationDecision;
+
+#[async_trait]
+pub trait PublicMessageModerationDecisionRepository: Send + Sync {
+    async fn create(&self, decision: &PublicMessageModerationDecision) -> Result<(), String>;
+    async fn get_by_message_id(
+        &self,
+        message_id: Uuid,
+    ) -> Result<Vec<PublicMessageModerationDecision>, String>;
+}
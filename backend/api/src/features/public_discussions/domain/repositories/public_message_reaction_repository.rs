@@ -0,0 +1,34 @@
+// PublicMessageReaction repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message::{
+    PublicMessage, PublicMessagePage,
+};
+use crate::features::public_discussions::domain::entities::public_message_reaction::PublicMessageReaction;
+
+#[async_trait]
+pub trait PublicMessageReactionRepository: Send + Sync {
+    async fn create(&self, reaction: &PublicMessageReaction) -> Result<(), String>;
+    async fn delete(&self, reaction_id: Uuid) -> Result<(), String>;
+    async fn get_by_message_user_and_reaction(
+        &self,
+        message_id: Uuid,
+        user_id: Uuid,
+        reaction: &str,
+    ) -> Result<Option<PublicMessageReaction>, String>;
+    async fn get_by_message_id(
+        &self,
+        message_id: Uuid,
+        reaction: Option<&str>,
+    ) -> Result<Vec<PublicMessageReaction>, String>;
+    async fn get_messages_by_user_and_reaction(
+        &self,
+        user_id: Uuid,
+        reaction: &str,
+        page: &PublicMessagePage,
+    ) -> Result<Vec<PublicMessage>, String>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
+    async fn count_by_reaction(&self, reaction: &str) -> Result<i64, String>;
+}
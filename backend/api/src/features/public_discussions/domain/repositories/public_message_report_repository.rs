@@ -1,6 +1,7 @@
 // PublicMessageReport repository trait
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::features::public_discussions::domain::entities::public_message_report::PublicMessageReport;
@@ -12,6 +13,29 @@ pub trait PublicMessageReportRepository: Send + Sync {
     async fn get_by_id(&self, report_id: Uuid) -> Result<Option<PublicMessageReport>, String>;
     async fn get_all(&self) -> Result<Vec<PublicMessageReport>, String>;
     async fn get_by_reporter(&self, user_id: Uuid) -> Result<Vec<PublicMessageReport>, String>;
+    async fn get_pending(&self) -> Result<Vec<PublicMessageReport>, String>;
+    async fn get_pending_by_message_id(
+        &self,
+        message_id: Uuid,
+    ) -> Result<Vec<PublicMessageReport>, String>;
+    async fn count_pending_reporters(&self, message_id: Uuid) -> Result<i64, String>;
+    async fn resolve_by_message_id(
+        &self,
+        message_id: Uuid,
+        decision_id: Uuid,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), String>;
+    async fn get_pending_by_story_id(
+        &self,
+        story_id: Uuid,
+    ) -> Result<Vec<PublicMessageReport>, String>;
+    async fn count_pending_story_reporters(&self, story_id: Uuid) -> Result<i64, String>;
+    async fn resolve_by_story_id(
+        &self,
+        story_id: Uuid,
+        decision_id: Uuid,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), String>;
     async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
     async fn count(&self) -> Result<i64, String>;
 }
@@ -3,21 +3,55 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::domain::entities::public_message::{
+    PublicMessage, PublicMessagePage,
+};
 
 #[async_trait]
 pub trait PublicMessageRepository: Send + Sync {
     async fn create(&self, message: &PublicMessage) -> Result<(), String>;
     async fn update(&self, message: &PublicMessage) -> Result<(), String>;
-    async fn update_like_count(&self, message: &PublicMessage) -> Result<(), String>;
+    async fn update_reaction_counts(&self, message: &PublicMessage) -> Result<(), String>;
     async fn update_reply_count(&self, message: &PublicMessage) -> Result<(), String>;
+    async fn update_pin(&self, message: &PublicMessage) -> Result<(), String>;
     async fn get_by_id(&self, message_id: Uuid) -> Result<Option<PublicMessage>, String>;
-    async fn get_by_habit_id(&self, habit_id: Uuid) -> Result<Vec<PublicMessage>, String>;
-    async fn get_by_challenge_id(&self, challenge_id: Uuid) -> Result<Vec<PublicMessage>, String>;
-    async fn get_replies(&self, message_id: Uuid) -> Result<Vec<PublicMessage>, String>;
-    async fn get_by_creator(&self, user_id: Uuid) -> Result<Vec<PublicMessage>, String>;
+    async fn get_by_habit_id(
+        &self,
+        habit_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
+        languages: Option<&[String]>,
+    ) -> Result<Vec<PublicMessage>, String>;
+    async fn get_by_challenge_id(
+        &self,
+        challenge_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
+        languages: Option<&[String]>,
+    ) -> Result<Vec<PublicMessage>, String>;
+    async fn get_pinned(
+        &self,
+        habit_id: Option<Uuid>,
+        challenge_id: Option<Uuid>,
+    ) -> Result<Vec<PublicMessage>, String>;
+    async fn get_replies(
+        &self,
+        message_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
+    ) -> Result<Vec<PublicMessage>, String>;
+    async fn get_by_creator(
+        &self,
+        user_id: Uuid,
+        page: &PublicMessagePage,
+    ) -> Result<Vec<PublicMessage>, String>;
     async fn get_reported(&self) -> Result<Vec<PublicMessage>, String>;
     async fn get_reported_by_user(&self, user_id: Uuid) -> Result<Vec<PublicMessage>, String>;
+    async fn update_hidden_by_moderation(
+        &self,
+        message_id: Uuid,
+        hidden_by_moderation: bool,
+    ) -> Result<(), String>;
     async fn delete(&self, message: &PublicMessage) -> Result<(), String>;
     async fn mark_as_deleted_for_user(&self, user_id: Uuid) -> Result<(), String>;
     async fn count(&self) -> Result<i64, String>;
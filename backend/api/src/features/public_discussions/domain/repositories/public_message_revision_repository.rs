@@ -0,0 +1,20 @@
+// PublicMessageRevision repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message_revision::PublicMessageRevision;
+
+#[async_trait]
+pub trait PublicMessageRevisionRepository: Send + Sync {
+    async fn create(&self, revision: &PublicMessageRevision) -> Result<(), String>;
+    async fn get_by_message_id(
+        &self,
+        message_id: Uuid,
+    ) -> Result<Vec<PublicMessageRevision>, String>;
+    async fn get_latest_by_message_id(
+        &self,
+        message_id: Uuid,
+    ) -> Result<Option<PublicMessageRevision>, String>;
+    async fn delete_by_creator(&self, user_id: Uuid) -> Result<(), String>;
+}
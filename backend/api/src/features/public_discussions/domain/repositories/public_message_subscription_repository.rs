@@ -0,0 +1,42 @@
+// PublicMessageSubscription repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::{
+    public_message::PublicMessage, public_message_subscription::PublicMessageSubscription,
+};
+
+#[async_trait]
+pub trait PublicMessageSubscriptionRepository: Send + Sync {
+    async fn create(&self, subscription: &PublicMessageSubscription) -> Result<(), String>;
+    async fn update(&self, subscription: &PublicMessageSubscription) -> Result<(), String>;
+    async fn delete(&self, subscription_id: Uuid) -> Result<(), String>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
+    async fn get_by_id(
+        &self,
+        subscription_id: Uuid,
+    ) -> Result<Option<PublicMessageSubscription>, String>;
+    async fn get_by_user_id(&self, user_id: Uuid)
+        -> Result<Vec<PublicMessageSubscription>, String>;
+    async fn get_by_user_and_target(
+        &self,
+        user_id: Uuid,
+        habit_id: Option<Uuid>,
+        challenge_id: Option<Uuid>,
+        thread_id: Option<Uuid>,
+    ) -> Result<Option<PublicMessageSubscription>, String>;
+    async fn get_for_message(
+        &self,
+        message: &PublicMessage,
+    ) -> Result<Vec<PublicMessageSubscription>, String>;
+    async fn get_pending(
+        &self,
+        notified_before: DateTime<Utc>,
+    ) -> Result<Vec<PublicMessageSubscription>, String>;
+    async fn update_pending_count(
+        &self,
+        subscription: &PublicMessageSubscription,
+    ) -> Result<(), String>;
+}
@@ -0,0 +1,18 @@
+// UserSuspension repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::user_suspension::UserSuspension;
+
+#[async_trait]
+pub trait UserSuspensionRepository: Send + Sync {
+    async fn create(&self, suspension: &UserSuspension) -> Result<(), String>;
+    async fn get_active_by_user_id(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Option<UserSuspension>, String>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
+}
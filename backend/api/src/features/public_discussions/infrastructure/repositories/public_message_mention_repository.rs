@@ -0,0 +1,161 @@
+// PublicMessageMentionRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message_mention::PublicMessageMention;
+use crate::features::public_discussions::domain::repositories::public_message_mention_repository::PublicMessageMentionRepository;
+
+pub struct PublicMessageMentionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PublicMessageMentionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        mention: &PublicMessageMention,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO public_message_mentions (
+                id,
+                message_id,
+                user_id,
+                start_index,
+                length,
+                created_at
+            )
+            VALUES ( $1, $2, $3, $4, $5, $6 )
+            "#,
+            mention.id,
+            mention.message_id,
+            mention.user_id,
+            mention.start_index,
+            mention.length,
+            mention.created_at
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_by_message_id_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM public_message_mentions
+            WHERE message_id = $1
+            "#,
+            message_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_message_ids_with_executor<'a, E>(
+        &self,
+        message_ids: Vec<Uuid>,
+        executor: E,
+    ) -> Result<Vec<PublicMessageMention>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageMention,
+            r#"
+            SELECT *
+            FROM public_message_mentions
+            WHERE message_id = ANY($1)
+            ORDER BY message_id, start_index
+            "#,
+            &message_ids
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Existing users that can be mentioned by the author: deleted users and users
+    // blocked in either direction are left out
+    pub async fn get_mentionable_users_with_executor<'a, E>(
+        &self,
+        usernames: Vec<String>,
+        author: Uuid,
+        executor: E,
+    ) -> Result<Vec<(Uuid, String)>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let rows = sqlx::query!(
+            r#"
+            SELECT u.id, u.username
+            FROM users u
+            WHERE u.username = ANY($1)
+            AND u.is_deleted = false
+            AND NOT EXISTS (
+                SELECT 1
+                FROM user_blocks b
+                WHERE (b.blocker = $2 AND b.blocked = u.id)
+                OR (b.blocker = u.id AND b.blocked = $2)
+            )
+            "#,
+            &usernames,
+            author
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| (row.id, row.username)).collect())
+    }
+}
+
+#[async_trait]
+impl PublicMessageMentionRepository for PublicMessageMentionRepositoryImpl {
+    async fn create(&self, mention: &PublicMessageMention) -> Result<(), String> {
+        self.create_with_executor(mention, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete_by_message_id(&self, message_id: Uuid) -> Result<(), String> {
+        self.delete_by_message_id_with_executor(message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_message_ids(
+        &self,
+        message_ids: Vec<Uuid>,
+    ) -> Result<Vec<PublicMessageMention>, String> {
+        self.get_by_message_ids_with_executor(message_ids, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_mentionable_users(
+        &self,
+        usernames: Vec<String>,
+        author: Uuid,
+    ) -> Result<Vec<(Uuid, String)>, String> {
+        self.get_mentionable_users_with_executor(usernames, author, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,96 @@
+// PublicMessageModerationDecisionRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message_moderation::PublicMessageModerationDecision;
+use crate::features::public_discussions::domain::repositories::public_message_moderation_decision_repository::PublicMessageModerationDecisionRepository;
+
+pub struct PublicMessageModerationDecisionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PublicMessageModerationDecisionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        decision: &PublicMessageModerationDecision,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO public_message_moderation_decisions (
+                id,
+                message_id,
+                story_id,
+                moderator,
+                action,
+                note,
+                report_count,
+                created_at
+            )
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8 )
+            "#,
+            decision.id,
+            decision.message_id,
+            decision.story_id,
+            decision.moderator,
+            decision.action,
+            decision.note,
+            decision.report_count,
+            decision.created_at
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_message_id_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<PublicMessageModerationDecision>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageModerationDecision,
+            r#"
+            SELECT *
+            FROM public_message_moderation_decisions
+            WHERE message_id = $1
+            ORDER BY created_at DESC
+            "#,
+            message_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl PublicMessageModerationDecisionRepository for PublicMessageModerationDecisionRepositoryImpl {
+    async fn create(&self, decision: &PublicMessageModerationDecision) -> Result<(), String> {
+        self.create_with_executor(decision, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_message_id(
+        &self,
+        message_id: Uuid,
+    ) -> Result<Vec<PublicMessageModerationDecision>, String> {
+        self.get_by_message_id_with_executor(message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,259 @@
+// PublicMessageReactionRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message::{
+    PublicMessage, PublicMessagePage,
+};
+use crate::features::public_discussions::domain::entities::public_message_reaction::PublicMessageReaction;
+use crate::features::public_discussions::domain::repositories::public_message_reaction_repository::PublicMessageReactionRepository;
+use crate::features::public_discussions::infrastructure::repositories::public_message_repository::push_public_message_page;
+
+pub struct PublicMessageReactionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PublicMessageReactionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        reaction: &PublicMessageReaction,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO public_message_reactions (
+                id,
+                message_id,
+                user_id,
+                reaction,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (message_id, user_id, reaction) DO NOTHING
+            "#,
+            reaction.id,
+            reaction.message_id,
+            reaction.user_id,
+            reaction.reaction,
+            reaction.created_at
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        reaction_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM public_message_reactions
+            WHERE id = $1
+            "#,
+            reaction_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_message_user_and_reaction_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        user_id: Uuid,
+        reaction: &str,
+        executor: E,
+    ) -> Result<Option<PublicMessageReaction>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageReaction,
+            r#"
+            SELECT *
+            FROM public_message_reactions
+            WHERE message_id = $1 AND user_id = $2 AND reaction = $3
+            "#,
+            message_id,
+            user_id,
+            reaction
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    // Reactions of users who deleted their account are left out
+    pub async fn get_by_message_id_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        reaction: Option<&str>,
+        executor: E,
+    ) -> Result<Vec<PublicMessageReaction>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageReaction,
+            r#"
+            SELECT pmr.*
+            FROM public_message_reactions pmr
+            JOIN users u ON u.id = pmr.user_id
+            WHERE pmr.message_id = $1
+                AND ($2::TEXT IS NULL OR pmr.reaction = $2)
+                AND u.is_deleted = FALSE
+            ORDER BY pmr.created_at DESC
+            "#,
+            message_id,
+            reaction
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_messages_by_user_and_reaction_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        reaction: &str,
+        page: &PublicMessagePage,
+        executor: E,
+    ) -> Result<Vec<PublicMessage>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let mut builder = QueryBuilder::new(
+            r#"
+            SELECT pm.*
+            FROM public_messages pm
+            JOIN public_message_reactions pmr ON pm.id = pmr.message_id
+            WHERE pmr.reaction = "#,
+        );
+        builder.push_bind(reaction.to_string());
+        builder.push(" AND pmr.user_id = ");
+        builder.push_bind(user_id);
+        push_public_message_page(&mut builder, page);
+
+        builder
+            .build_query_as::<PublicMessage>()
+            .fetch_all(executor)
+            .await
+    }
+
+    pub async fn delete_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM public_message_reactions
+            WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn count_by_reaction_with_executor<'a, E>(
+        &self,
+        reaction: &str,
+        executor: E,
+    ) -> Result<i64, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM public_message_reactions
+            WHERE reaction = $1
+            "#,
+            reaction
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.count.unwrap_or(0))
+    }
+}
+
+#[async_trait]
+impl PublicMessageReactionRepository for PublicMessageReactionRepositoryImpl {
+    async fn create(&self, reaction: &PublicMessageReaction) -> Result<(), String> {
+        self.create_with_executor(reaction, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete(&self, reaction_id: Uuid) -> Result<(), String> {
+        self.delete_with_executor(reaction_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_message_user_and_reaction(
+        &self,
+        message_id: Uuid,
+        user_id: Uuid,
+        reaction: &str,
+    ) -> Result<Option<PublicMessageReaction>, String> {
+        self.get_by_message_user_and_reaction_with_executor(
+            message_id, user_id, reaction, &self.pool,
+        )
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_message_id(
+        &self,
+        message_id: Uuid,
+        reaction: Option<&str>,
+    ) -> Result<Vec<PublicMessageReaction>, String> {
+        self.get_by_message_id_with_executor(message_id, reaction, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_messages_by_user_and_reaction(
+        &self,
+        user_id: Uuid,
+        reaction: &str,
+        page: &PublicMessagePage,
+    ) -> Result<Vec<PublicMessage>, String> {
+        self.get_messages_by_user_and_reaction_with_executor(user_id, reaction, page, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
+        self.delete_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn count_by_reaction(&self, reaction: &str) -> Result<i64, String> {
+        self.count_by_reaction_with_executor(reaction, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
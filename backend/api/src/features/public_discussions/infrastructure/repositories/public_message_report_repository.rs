@@ -2,6 +2,7 @@
 // Supports both PgPool and transactions via Executor trait
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
 use uuid::Uuid;
 
@@ -32,16 +33,20 @@ impl PublicMessageReportRepositoryImpl {
                 id,
                 reporter,
                 message_id,
+                story_id,
                 created_at,
-                reason
+                reason,
+                revision_id
             )
-            VALUES ( $1, $2, $3, $4, $5)
+            VALUES ( $1, $2, $3, $4, $5, $6, $7 )
             "#,
             report.id,
             report.reporter,
             report.message_id,
+            report.story_id,
             report.created_at,
-            report.reason
+            report.reason,
+            report.revision_id
         )
         .execute(executor)
         .await
@@ -127,6 +132,172 @@ impl PublicMessageReportRepositoryImpl {
         .await
     }
 
+    pub async fn get_pending_with_executor<'a, E>(
+        &self,
+        executor: E,
+    ) -> Result<Vec<PublicMessageReport>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageReport,
+            r#"
+            SELECT *
+            FROM public_message_reports
+            WHERE resolved_at IS NULL
+            ORDER BY created_at
+            "#
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_pending_by_message_id_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<PublicMessageReport>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageReport,
+            r#"
+            SELECT *
+            FROM public_message_reports
+            WHERE message_id = $1
+            AND resolved_at IS NULL
+            ORDER BY created_at
+            "#,
+            message_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn count_pending_reporters_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        executor: E,
+    ) -> Result<i64, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT COUNT(DISTINCT reporter) as count
+            FROM public_message_reports
+            WHERE message_id = $1
+            AND resolved_at IS NULL
+            "#,
+            message_id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.count.unwrap_or(0))
+    }
+
+    pub async fn resolve_by_message_id_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        decision_id: Uuid,
+        resolved_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE public_message_reports
+            SET
+                resolved_at = $1,
+                decision_id = $2
+            WHERE message_id = $3
+            AND resolved_at IS NULL
+            "#,
+            resolved_at,
+            decision_id,
+            message_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_pending_by_story_id_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<PublicMessageReport>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageReport,
+            r#"
+            SELECT *
+            FROM public_message_reports
+            WHERE story_id = $1
+            AND resolved_at IS NULL
+            ORDER BY created_at
+            "#,
+            story_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn count_pending_story_reporters_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        executor: E,
+    ) -> Result<i64, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT COUNT(DISTINCT reporter) as count
+            FROM public_message_reports
+            WHERE story_id = $1
+            AND resolved_at IS NULL
+            "#,
+            story_id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.count.unwrap_or(0))
+    }
+
+    pub async fn resolve_by_story_id_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        decision_id: Uuid,
+        resolved_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE public_message_reports
+            SET
+                resolved_at = $1,
+                decision_id = $2
+            WHERE story_id = $3
+            AND resolved_at IS NULL
+            "#,
+            resolved_at,
+            decision_id,
+            story_id
+        )
+        .execute(executor)
+        .await
+    }
+
     pub async fn delete_by_user_id_with_executor<'a, E>(
         &self,
         user_id: Uuid,
@@ -197,6 +368,66 @@ impl PublicMessageReportRepository for PublicMessageReportRepositoryImpl {
             .map_err(|e| e.to_string())
     }
 
+    async fn get_pending(&self) -> Result<Vec<PublicMessageReport>, String> {
+        self.get_pending_with_executor(&self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_pending_by_message_id(
+        &self,
+        message_id: Uuid,
+    ) -> Result<Vec<PublicMessageReport>, String> {
+        self.get_pending_by_message_id_with_executor(message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn count_pending_reporters(&self, message_id: Uuid) -> Result<i64, String> {
+        self.count_pending_reporters_with_executor(message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn resolve_by_message_id(
+        &self,
+        message_id: Uuid,
+        decision_id: Uuid,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), String> {
+        self.resolve_by_message_id_with_executor(message_id, decision_id, resolved_at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_pending_by_story_id(
+        &self,
+        story_id: Uuid,
+    ) -> Result<Vec<PublicMessageReport>, String> {
+        self.get_pending_by_story_id_with_executor(story_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn count_pending_story_reporters(&self, story_id: Uuid) -> Result<i64, String> {
+        self.count_pending_story_reporters_with_executor(story_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn resolve_by_story_id(
+        &self,
+        story_id: Uuid,
+        decision_id: Uuid,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), String> {
+        self.resolve_by_story_id_with_executor(story_id, decision_id, resolved_at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
     async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
         self.delete_by_user_id_with_executor(user_id, &self.pool)
             .await
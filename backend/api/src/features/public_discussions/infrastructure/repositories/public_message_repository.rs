@@ -2,12 +2,59 @@
 // Supports both PgPool and transactions via Executor trait
 
 use async_trait::async_trait;
-use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
-use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::domain::entities::public_message::{
+    PublicMessage, PublicMessageCursorValue, PublicMessagePage,
+};
 use crate::features::public_discussions::domain::repositories::public_message_repository::PublicMessageRepository;
 
+// Keeps the messages written in one of the languages, and those whose language is unknown
+pub fn push_public_message_languages(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    languages: Option<&[String]>,
+) {
+    if let Some(languages) = languages {
+        builder
+            .push(" AND (pm.language_code IS NULL OR pm.language_code = ANY(")
+            .push_bind(languages.to_vec())
+            .push("))");
+    }
+}
+
+// Leaves out messages whose creator was blocked by the viewer, before the page limit applies
+pub fn push_public_message_visible_to(builder: &mut QueryBuilder<'_, Postgres>, viewer: Uuid) {
+    builder
+        .push(
+            " AND NOT EXISTS (SELECT 1 FROM user_blocks ub WHERE ub.blocked = pm.creator AND ub.blocker = ",
+        )
+        .push_bind(viewer)
+        .push(")");
+}
+
+// Appends the cursor condition, the ordering and the limit of a page to a query on `public_messages pm`
+pub fn push_public_message_page(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    page: &PublicMessagePage,
+) {
+    let column = page.sort.column();
+
+    if let Some(cursor) = page.cursor {
+        builder.push(format!(" AND (pm.{}, pm.id) < (", column));
+        match cursor.value {
+            PublicMessageCursorValue::CreatedAt(created_at) => builder.push_bind(created_at),
+            PublicMessageCursorValue::Count(count) => builder.push_bind(count),
+            PublicMessageCursorValue::Score(score) => builder.push_bind(score),
+        };
+        builder.push(", ").push_bind(cursor.id).push(")");
+    }
+
+    builder
+        .push(format!(" ORDER BY pm.{} DESC, pm.id DESC LIMIT ", column))
+        .push_bind(page.limit + 1);
+}
+
 pub struct PublicMessageRepositoryImpl {
     pool: PgPool,
 }
@@ -41,9 +88,10 @@ impl PublicMessageRepositoryImpl {
                 like_count,
                 deleted_by_creator,
                 deleted_by_admin,
-                language_code
+                language_code,
+                attachment_ids
             )
-            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
             "#,
             message.id,
             message.habit_id,
@@ -57,7 +105,8 @@ impl PublicMessageRepositoryImpl {
             message.like_count,
             message.deleted_by_creator,
             message.deleted_by_admin,
-            message.language_code
+            message.language_code,
+            &message.attachment_ids
         )
         .execute(executor)
         .await
@@ -74,18 +123,19 @@ impl PublicMessageRepositoryImpl {
         sqlx::query!(
             r#"
             UPDATE public_messages
-            SET updated_at = $1, content = $2
-            WHERE id = $3
+            SET updated_at = $1, content = $2, language_code = $3
+            WHERE id = $4
             "#,
             message.updated_at,
             message.content,
+            message.language_code,
             message.id,
         )
         .execute(executor)
         .await
     }
 
-    pub async fn update_like_count_with_executor<'a, E>(
+    pub async fn update_reaction_counts_with_executor<'a, E>(
         &self,
         message: &PublicMessage,
         executor: E,
@@ -96,10 +146,11 @@ impl PublicMessageRepositoryImpl {
         sqlx::query!(
             r#"
             UPDATE public_messages
-            SET like_count = $1
-            WHERE id = $2
+            SET like_count = $1, reaction_counts = $2
+            WHERE id = $3
             "#,
             message.like_count,
+            message.reaction_counts,
             message.id,
         )
         .execute(executor)
@@ -127,6 +178,29 @@ impl PublicMessageRepositoryImpl {
         .await
     }
 
+    pub async fn update_pin_with_executor<'a, E>(
+        &self,
+        message: &PublicMessage,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE public_messages
+            SET pinned_at = $1, pinned_by = $2, is_announcement = $3
+            WHERE id = $4
+            "#,
+            message.pinned_at,
+            message.pinned_by,
+            message.is_announcement,
+            message.id,
+        )
+        .execute(executor)
+        .await
+    }
+
     pub async fn get_by_id_with_executor<'a, E>(
         &self,
         message_id: Uuid,
@@ -151,30 +225,78 @@ impl PublicMessageRepositoryImpl {
     pub async fn get_by_habit_id_with_executor<'a, E>(
         &self,
         habit_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
+        languages: Option<&[String]>,
         executor: E,
     ) -> Result<Vec<PublicMessage>, sqlx::Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        sqlx::query_as!(
-            PublicMessage,
+        let mut builder = QueryBuilder::new(
             r#"
-            SELECT *
-            FROM public_messages
-            WHERE habit_id = $1 
-              AND replies_to IS NULL 
-              AND deleted_by_admin = false 
-              AND deleted_by_creator = false
-            "#,
-            habit_id
-        )
-        .fetch_all(executor)
-        .await
+            SELECT pm.*
+            FROM public_messages pm
+            WHERE pm.habit_id = "#,
+        );
+        builder.push_bind(habit_id).push(
+            r#"
+              AND pm.replies_to IS NULL
+              AND pm.deleted_by_admin = false
+              AND pm.deleted_by_creator = false
+              AND pm.hidden_by_moderation = false
+              AND pm.pinned_at IS NULL"#,
+        );
+        push_public_message_languages(&mut builder, languages);
+        push_public_message_visible_to(&mut builder, viewer);
+        push_public_message_page(&mut builder, page);
+
+        builder
+            .build_query_as::<PublicMessage>()
+            .fetch_all(executor)
+            .await
     }
 
     pub async fn get_by_challenge_id_with_executor<'a, E>(
         &self,
         challenge_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
+        languages: Option<&[String]>,
+        executor: E,
+    ) -> Result<Vec<PublicMessage>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let mut builder = QueryBuilder::new(
+            r#"
+            SELECT pm.*
+            FROM public_messages pm
+            WHERE pm.challenge_id = "#,
+        );
+        builder.push_bind(challenge_id).push(
+            r#"
+              AND pm.replies_to IS NULL
+              AND pm.deleted_by_admin = false
+              AND pm.deleted_by_creator = false
+              AND pm.hidden_by_moderation = false
+              AND pm.pinned_at IS NULL"#,
+        );
+        push_public_message_languages(&mut builder, languages);
+        push_public_message_visible_to(&mut builder, viewer);
+        push_public_message_page(&mut builder, page);
+
+        builder
+            .build_query_as::<PublicMessage>()
+            .fetch_all(executor)
+            .await
+    }
+
+    // Pinned messages of a channel, most recently pinned first
+    pub async fn get_pinned_with_executor<'a, E>(
+        &self,
+        habit_id: Option<Uuid>,
+        challenge_id: Option<Uuid>,
         executor: E,
     ) -> Result<Vec<PublicMessage>, sqlx::Error>
     where
@@ -185,12 +307,15 @@ impl PublicMessageRepositoryImpl {
             r#"
             SELECT *
             FROM public_messages
-            WHERE challenge_id = $1 
-              AND replies_to IS NULL 
-              AND deleted_by_admin = false 
-              AND deleted_by_creator = false
+            WHERE (habit_id = $1 OR challenge_id = $2)
+            AND pinned_at IS NOT NULL
+            AND deleted_by_admin = false
+            AND deleted_by_creator = false
+            AND hidden_by_moderation = false
+            ORDER BY pinned_at DESC
             "#,
-            challenge_id
+            habit_id,
+            challenge_id,
         )
         .fetch_all(executor)
         .await
@@ -199,43 +324,53 @@ impl PublicMessageRepositoryImpl {
     pub async fn get_replies_with_executor<'a, E>(
         &self,
         message_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
         executor: E,
     ) -> Result<Vec<PublicMessage>, sqlx::Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        sqlx::query_as!(
-            PublicMessage,
+        let mut builder = QueryBuilder::new(
             r#"
-            SELECT *
-            FROM public_messages
-            WHERE replies_to = $1
-            "#,
-            message_id
-        )
-        .fetch_all(executor)
-        .await
+            SELECT pm.*
+            FROM public_messages pm
+            WHERE pm.replies_to = "#,
+        );
+        builder
+            .push_bind(message_id)
+            .push(" AND pm.hidden_by_moderation = false");
+        push_public_message_visible_to(&mut builder, viewer);
+        push_public_message_page(&mut builder, page);
+
+        builder
+            .build_query_as::<PublicMessage>()
+            .fetch_all(executor)
+            .await
     }
 
     pub async fn get_by_creator_with_executor<'a, E>(
         &self,
         user_id: Uuid,
+        page: &PublicMessagePage,
         executor: E,
     ) -> Result<Vec<PublicMessage>, sqlx::Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        sqlx::query_as!(
-            PublicMessage,
+        let mut builder = QueryBuilder::new(
             r#"
-            SELECT *
-            FROM public_messages
-            WHERE creator = $1
-            "#,
-            user_id
-        )
-        .fetch_all(executor)
-        .await
+            SELECT pm.*
+            FROM public_messages pm
+            WHERE pm.creator = "#,
+        );
+        builder.push_bind(user_id);
+        push_public_message_page(&mut builder, page);
+
+        builder
+            .build_query_as::<PublicMessage>()
+            .fetch_all(executor)
+            .await
     }
 
     pub async fn get_reported_with_executor<'a, E>(
@@ -279,6 +414,28 @@ impl PublicMessageRepositoryImpl {
         .await
     }
 
+    pub async fn update_hidden_by_moderation_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        hidden_by_moderation: bool,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE public_messages
+            SET hidden_by_moderation = $1
+            WHERE id = $2
+            "#,
+            hidden_by_moderation,
+            message_id,
+        )
+        .execute(executor)
+        .await
+    }
+
     pub async fn delete_with_executor<'a, E>(
         &self,
         message: &PublicMessage,
@@ -293,7 +450,8 @@ impl PublicMessageRepositoryImpl {
             SET
                 deleted_by_admin = $1,
                 deleted_by_creator = $2,
-                content = $3
+                content = $3,
+                attachment_ids = '{}'
             WHERE id = $4
             "#,
             message.deleted_by_admin,
@@ -362,8 +520,8 @@ impl PublicMessageRepository for PublicMessageRepositoryImpl {
         Ok(())
     }
 
-    async fn update_like_count(&self, message: &PublicMessage) -> Result<(), String> {
-        self.update_like_count_with_executor(message, &self.pool)
+    async fn update_reaction_counts(&self, message: &PublicMessage) -> Result<(), String> {
+        self.update_reaction_counts_with_executor(message, &self.pool)
             .await
             .map_err(|e| e.to_string())?;
         Ok(())
@@ -376,32 +534,70 @@ impl PublicMessageRepository for PublicMessageRepositoryImpl {
         Ok(())
     }
 
+    async fn update_pin(&self, message: &PublicMessage) -> Result<(), String> {
+        self.update_pin_with_executor(message, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
     async fn get_by_id(&self, message_id: Uuid) -> Result<Option<PublicMessage>, String> {
         self.get_by_id_with_executor(message_id, &self.pool)
             .await
             .map_err(|e| e.to_string())
     }
 
-    async fn get_by_habit_id(&self, habit_id: Uuid) -> Result<Vec<PublicMessage>, String> {
-        self.get_by_habit_id_with_executor(habit_id, &self.pool)
+    async fn get_by_habit_id(
+        &self,
+        habit_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
+        languages: Option<&[String]>,
+    ) -> Result<Vec<PublicMessage>, String> {
+        self.get_by_habit_id_with_executor(habit_id, viewer, page, languages, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_challenge_id(
+        &self,
+        challenge_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
+        languages: Option<&[String]>,
+    ) -> Result<Vec<PublicMessage>, String> {
+        self.get_by_challenge_id_with_executor(challenge_id, viewer, page, languages, &self.pool)
             .await
             .map_err(|e| e.to_string())
     }
 
-    async fn get_by_challenge_id(&self, challenge_id: Uuid) -> Result<Vec<PublicMessage>, String> {
-        self.get_by_challenge_id_with_executor(challenge_id, &self.pool)
+    async fn get_pinned(
+        &self,
+        habit_id: Option<Uuid>,
+        challenge_id: Option<Uuid>,
+    ) -> Result<Vec<PublicMessage>, String> {
+        self.get_pinned_with_executor(habit_id, challenge_id, &self.pool)
             .await
             .map_err(|e| e.to_string())
     }
 
-    async fn get_replies(&self, message_id: Uuid) -> Result<Vec<PublicMessage>, String> {
-        self.get_replies_with_executor(message_id, &self.pool)
+    async fn get_replies(
+        &self,
+        message_id: Uuid,
+        viewer: Uuid,
+        page: &PublicMessagePage,
+    ) -> Result<Vec<PublicMessage>, String> {
+        self.get_replies_with_executor(message_id, viewer, page, &self.pool)
             .await
             .map_err(|e| e.to_string())
     }
 
-    async fn get_by_creator(&self, user_id: Uuid) -> Result<Vec<PublicMessage>, String> {
-        self.get_by_creator_with_executor(user_id, &self.pool)
+    async fn get_by_creator(
+        &self,
+        user_id: Uuid,
+        page: &PublicMessagePage,
+    ) -> Result<Vec<PublicMessage>, String> {
+        self.get_by_creator_with_executor(user_id, page, &self.pool)
             .await
             .map_err(|e| e.to_string())
     }
@@ -418,6 +614,21 @@ impl PublicMessageRepository for PublicMessageRepositoryImpl {
             .map_err(|e| e.to_string())
     }
 
+    async fn update_hidden_by_moderation(
+        &self,
+        message_id: Uuid,
+        hidden_by_moderation: bool,
+    ) -> Result<(), String> {
+        self.update_hidden_by_moderation_with_executor(
+            message_id,
+            hidden_by_moderation,
+            &self.pool,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
     async fn delete(&self, message: &PublicMessage) -> Result<(), String> {
         self.delete_with_executor(message, &self.pool)
             .await
@@ -0,0 +1,148 @@
+// PublicMessageRevisionRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::public_message_revision::PublicMessageRevision;
+use crate::features::public_discussions::domain::repositories::public_message_revision_repository::PublicMessageRevisionRepository;
+
+pub struct PublicMessageRevisionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PublicMessageRevisionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        revision: &PublicMessageRevision,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO public_message_revisions (
+                id,
+                message_id,
+                content,
+                created_at
+            )
+            VALUES ( $1, $2, $3, $4 )
+            "#,
+            revision.id,
+            revision.message_id,
+            revision.content,
+            revision.created_at
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_message_id_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<PublicMessageRevision>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageRevision,
+            r#"
+            SELECT *
+            FROM public_message_revisions
+            WHERE message_id = $1
+            ORDER BY created_at
+            "#,
+            message_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_latest_by_message_id_with_executor<'a, E>(
+        &self,
+        message_id: Uuid,
+        executor: E,
+    ) -> Result<Option<PublicMessageRevision>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageRevision,
+            r#"
+            SELECT *
+            FROM public_message_revisions
+            WHERE message_id = $1
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+            message_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn delete_by_creator_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM public_message_revisions
+            WHERE message_id IN (
+                SELECT id FROM public_messages WHERE creator = $1
+            )
+            "#,
+            user_id
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl PublicMessageRevisionRepository for PublicMessageRevisionRepositoryImpl {
+    async fn create(&self, revision: &PublicMessageRevision) -> Result<(), String> {
+        self.create_with_executor(revision, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_message_id(
+        &self,
+        message_id: Uuid,
+    ) -> Result<Vec<PublicMessageRevision>, String> {
+        self.get_by_message_id_with_executor(message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_latest_by_message_id(
+        &self,
+        message_id: Uuid,
+    ) -> Result<Option<PublicMessageRevision>, String> {
+        self.get_latest_by_message_id_with_executor(message_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_creator(&self, user_id: Uuid) -> Result<(), String> {
+        self.delete_by_creator_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,379 @@
+// PublicMessageSubscriptionRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::{
+    public_message::PublicMessage, public_message_subscription::PublicMessageSubscription,
+};
+use crate::features::public_discussions::domain::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepository;
+
+pub struct PublicMessageSubscriptionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PublicMessageSubscriptionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        subscription: &PublicMessageSubscription,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO public_message_subscriptions (
+                id,
+                user_id,
+                habit_id,
+                challenge_id,
+                thread_id,
+                muted,
+                pending_count,
+                last_notified_at,
+                created_at
+            )
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9 )
+            "#,
+            subscription.id,
+            subscription.user_id,
+            subscription.habit_id,
+            subscription.challenge_id,
+            subscription.thread_id,
+            subscription.muted,
+            subscription.pending_count,
+            subscription.last_notified_at,
+            subscription.created_at,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn update_with_executor<'a, E>(
+        &self,
+        subscription: &PublicMessageSubscription,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE public_message_subscriptions
+            SET muted = $1
+            WHERE id = $2
+            "#,
+            subscription.muted,
+            subscription.id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        subscription_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM public_message_subscriptions
+            WHERE id = $1
+            "#,
+            subscription_id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM public_message_subscriptions
+            WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        subscription_id: Uuid,
+        executor: E,
+    ) -> Result<Option<PublicMessageSubscription>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageSubscription,
+            r#"
+            SELECT *
+            FROM public_message_subscriptions
+            WHERE id = $1
+            "#,
+            subscription_id,
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn get_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<PublicMessageSubscription>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageSubscription,
+            r#"
+            SELECT *
+            FROM public_message_subscriptions
+            WHERE user_id = $1
+            ORDER BY created_at DESC
+            "#,
+            user_id,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // A thread subscription is found by its thread, a channel one by its habit or challenge
+    pub async fn get_by_user_and_target_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        habit_id: Option<Uuid>,
+        challenge_id: Option<Uuid>,
+        thread_id: Option<Uuid>,
+        executor: E,
+    ) -> Result<Option<PublicMessageSubscription>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageSubscription,
+            r#"
+            SELECT *
+            FROM public_message_subscriptions
+            WHERE user_id = $1
+            AND (
+                thread_id = $4
+                OR (
+                    $4::UUID IS NULL
+                    AND thread_id IS NULL
+                    AND (habit_id = $2 OR challenge_id = $3)
+                )
+            )
+            "#,
+            user_id,
+            habit_id,
+            challenge_id,
+            thread_id,
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    // At most one subscription per user interested in the message: the thread one wins
+    // over the channel one, so muting a thread silences it even in a followed channel.
+    // The author, deleted users and users blocked in either direction are left out.
+    pub async fn get_for_message_with_executor<'a, E>(
+        &self,
+        message: &PublicMessage,
+        executor: E,
+    ) -> Result<Vec<PublicMessageSubscription>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageSubscription,
+            r#"
+            SELECT DISTINCT ON (s.user_id) s.*
+            FROM public_message_subscriptions s
+            JOIN users u ON u.id = s.user_id
+            WHERE (
+                s.thread_id = $1
+                OR (s.thread_id IS NULL AND (s.habit_id = $2 OR s.challenge_id = $3))
+            )
+            AND s.user_id <> $4
+            AND u.is_deleted = false
+            AND NOT EXISTS (
+                SELECT 1
+                FROM user_blocks b
+                WHERE (b.blocker = $4 AND b.blocked = s.user_id)
+                OR (b.blocker = s.user_id AND b.blocked = $4)
+            )
+            ORDER BY s.user_id, s.thread_id NULLS LAST
+            "#,
+            message.thread_id,
+            message.habit_id,
+            message.challenge_id,
+            message.creator,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    // Batches whose window is over and which still have messages to tell about
+    pub async fn get_pending_with_executor<'a, E>(
+        &self,
+        notified_before: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<PublicMessageSubscription>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            PublicMessageSubscription,
+            r#"
+            SELECT *
+            FROM public_message_subscriptions
+            WHERE pending_count > 0
+            AND muted = false
+            AND last_notified_at <= $1
+            "#,
+            notified_before,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn update_pending_count_with_executor<'a, E>(
+        &self,
+        subscription: &PublicMessageSubscription,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE public_message_subscriptions
+            SET pending_count = $1, last_notified_at = $2
+            WHERE id = $3
+            "#,
+            subscription.pending_count,
+            subscription.last_notified_at,
+            subscription.id,
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl PublicMessageSubscriptionRepository for PublicMessageSubscriptionRepositoryImpl {
+    async fn create(&self, subscription: &PublicMessageSubscription) -> Result<(), String> {
+        self.create_with_executor(subscription, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn update(&self, subscription: &PublicMessageSubscription) -> Result<(), String> {
+        self.update_with_executor(subscription, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete(&self, subscription_id: Uuid) -> Result<(), String> {
+        self.delete_with_executor(subscription_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
+        self.delete_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_id(
+        &self,
+        subscription_id: Uuid,
+    ) -> Result<Option<PublicMessageSubscription>, String> {
+        self.get_by_id_with_executor(subscription_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<PublicMessageSubscription>, String> {
+        self.get_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_user_and_target(
+        &self,
+        user_id: Uuid,
+        habit_id: Option<Uuid>,
+        challenge_id: Option<Uuid>,
+        thread_id: Option<Uuid>,
+    ) -> Result<Option<PublicMessageSubscription>, String> {
+        self.get_by_user_and_target_with_executor(
+            user_id,
+            habit_id,
+            challenge_id,
+            thread_id,
+            &self.pool,
+        )
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_for_message(
+        &self,
+        message: &PublicMessage,
+    ) -> Result<Vec<PublicMessageSubscription>, String> {
+        self.get_for_message_with_executor(message, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_pending(
+        &self,
+        notified_before: DateTime<Utc>,
+    ) -> Result<Vec<PublicMessageSubscription>, String> {
+        self.get_pending_with_executor(notified_before, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn update_pending_count(
+        &self,
+        subscription: &PublicMessageSubscription,
+    ) -> Result<(), String> {
+        self.update_pending_count_with_executor(subscription, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+// UserSuspensionRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::public_discussions::domain::entities::user_suspension::UserSuspension;
+use crate::features::public_discussions::domain::repositories::user_suspension_repository::UserSuspensionRepository;
+
+pub struct UserSuspensionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl UserSuspensionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        suspension: &UserSuspension,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO user_suspensions (
+                id,
+                user_id,
+                decision_id,
+                created_at,
+                expires_at
+            )
+            VALUES ( $1, $2, $3, $4, $5 )
+            "#,
+            suspension.id,
+            suspension.user_id,
+            suspension.decision_id,
+            suspension.created_at,
+            suspension.expires_at
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_active_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Option<UserSuspension>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            UserSuspension,
+            r#"
+            SELECT *
+            FROM user_suspensions
+            WHERE user_id = $1
+            AND expires_at > $2
+            ORDER BY expires_at DESC
+            LIMIT 1
+            "#,
+            user_id,
+            at
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn delete_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM user_suspensions
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl UserSuspensionRepository for UserSuspensionRepositoryImpl {
+    async fn create(&self, suspension: &UserSuspension) -> Result<(), String> {
+        self.create_with_executor(suspension, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_active_by_user_id(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Option<UserSuspension>, String> {
+        self.get_active_by_user_id_with_executor(user_id, at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
+        self.delete_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+// Mention service - finds @username mentions in public message contents
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+// Same conventions as usernames, and the @ must not be glued to a previous word
+// (e-mail addresses are not mentions).
+static MENTION_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^\p{L}\p{N}._@-])(@[\p{L}\p{N}](?:[._-]?[\p{L}\p{N}]+)*)").unwrap()
+});
+
+// A mention as written in the content, positions are counted in characters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedMention {
+    pub username: String,
+    pub start_index: usize,
+    pub length: usize,
+}
+
+#[derive(Default)]
+pub struct MentionService;
+
+impl MentionService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn extract_mentions(&self, content: &str) -> Vec<ParsedMention> {
+        MENTION_PATTERN
+            .captures_iter(content)
+            .filter_map(|captures| captures.get(1))
+            .map(|mention| ParsedMention {
+                username: mention.as_str()[1..].to_lowercase(),
+                start_index: content[..mention.start()].chars().count(),
+                length: mention.as_str().chars().count(),
+            })
+            .collect()
+    }
+}
@@ -1 +1,7 @@
 // Infrastructure services
+
+pub mod language_service;
+pub mod mention_service;
+pub mod moderation_notification_service;
+pub mod reaction_notification_service;
+pub mod subscription_notification_service;
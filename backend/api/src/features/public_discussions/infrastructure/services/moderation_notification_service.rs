@@ -0,0 +1,127 @@
+// Moderation notification service - tells reporters and authors about a moderation decision
+
+use actix_web::web::Data;
+use fluent::FluentArgs;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::core::helpers::translation::Translator;
+use crate::features::notifications::infrastructure::services::notification_service::{
+    NotificationContent, NotificationService,
+};
+use crate::features::profile::domain::entities::UserPublicDataCache;
+use crate::features::public_discussions::domain::entities::public_message_moderation::{
+    ModerationAction, PublicMessageModerationDecision,
+};
+
+pub struct ModerationNotificationService {
+    pool: PgPool,
+    notification_service: NotificationService,
+}
+
+impl ModerationNotificationService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            notification_service: NotificationService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    // Reporters always learn the outcome, the author only when warned or suspended
+    #[allow(clippy::too_many_arguments)]
+    pub async fn notify(
+        &self,
+        decision: &PublicMessageModerationDecision,
+        action: ModerationAction,
+        suspension_in_days: i64,
+        author: Uuid,
+        reporters: &[Uuid],
+        redis_client: Data<Client>,
+        translator: &Translator,
+        user_public_data_cache: &UserPublicDataCache,
+    ) {
+        let mut notif_transaction = match self.pool.begin().await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Error: {}", e);
+                return;
+            }
+        };
+
+        let reporter_body_key = if action == ModerationAction::Dismiss {
+            "report-resolved-dismissed-body"
+        } else {
+            "report-resolved-action-taken-body"
+        };
+
+        for reporter in reporters {
+            if let Some(reporter_data) = user_public_data_cache
+                .get_value_for_key_or_insert_it(reporter, &mut notif_transaction)
+                .await
+            {
+                self.notification_service
+                    .generate_notification(
+                        &mut notif_transaction,
+                        None,
+                        *reporter,
+                        NotificationContent {
+                            title: &translator.translate(
+                                &reporter_data.locale,
+                                "report-resolved-title",
+                                None,
+                            ),
+                            body: &translator.translate(
+                                &reporter_data.locale,
+                                reporter_body_key,
+                                None,
+                            ),
+                            notification_type: "moderation_decision",
+                            url: None,
+                        },
+                        redis_client.clone(),
+                    )
+                    .await;
+            }
+        }
+
+        let author_keys = match action {
+            ModerationAction::WarnAuthor => {
+                Some(("moderation-warning-title", "moderation-warning-body"))
+            }
+            ModerationAction::SuspendAuthor => {
+                Some(("moderation-suspension-title", "moderation-suspension-body"))
+            }
+            _ => None,
+        };
+
+        if let Some((title_key, body_key)) = author_keys {
+            if let Some(author_data) = user_public_data_cache
+                .get_value_for_key_or_insert_it(&author, &mut notif_transaction)
+                .await
+            {
+                let mut args = FluentArgs::new();
+                args.set("note", decision.note.clone().unwrap_or_default());
+                args.set("days", suspension_in_days);
+
+                self.notification_service
+                    .generate_notification(
+                        &mut notif_transaction,
+                        None,
+                        author,
+                        NotificationContent {
+                            title: &translator.translate(&author_data.locale, title_key, None),
+                            body: &translator.translate(&author_data.locale, body_key, Some(args)),
+                            notification_type: "moderation_decision",
+                            url: None,
+                        },
+                        redis_client.clone(),
+                    )
+                    .await;
+            }
+        }
+
+        let _ = notif_transaction.commit().await;
+    }
+}
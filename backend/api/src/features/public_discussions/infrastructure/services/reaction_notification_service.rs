@@ -0,0 +1,110 @@
+// Reaction notification service - tells authors that someone reacted to their message
+
+use actix_web::web::Data;
+use fluent::FluentArgs;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::core::helpers::translation::Translator;
+use crate::features::notifications::infrastructure::services::notification_service::{
+    NotificationContent, NotificationService,
+};
+use crate::features::profile::domain::entities::UserPublicDataCache;
+use crate::features::public_discussions::domain::entities::public_message::PublicMessage;
+use crate::features::public_discussions::domain::entities::public_message_reaction::PublicMessageReactionKind;
+
+pub struct ReactionNotificationService {
+    pool: PgPool,
+    notification_service: NotificationService,
+}
+
+impl ReactionNotificationService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            notification_service: NotificationService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    // Every reaction shares the notification type of likes, so the same setting silences them
+    pub async fn notify(
+        &self,
+        message: &PublicMessage,
+        reaction: PublicMessageReactionKind,
+        sender_id: Uuid,
+        redis_client: Data<Client>,
+        translator: &Translator,
+        user_public_data_cache: &UserPublicDataCache,
+    ) {
+        if sender_id == message.creator {
+            return;
+        }
+
+        let mut notif_transaction = match self.pool.begin().await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Error: {}", e);
+                return;
+            }
+        };
+
+        let (Some(sender), Some(creator)) = (
+            user_public_data_cache
+                .get_value_for_key_or_insert_it(&sender_id, &mut notif_transaction)
+                .await,
+            user_public_data_cache
+                .get_value_for_key_or_insert_it(&message.creator, &mut notif_transaction)
+                .await,
+        ) else {
+            let _ = notif_transaction.rollback().await;
+            return;
+        };
+
+        let mut args = FluentArgs::new();
+        args.set("username", sender.username);
+
+        let (title_key, body_key) = if reaction == PublicMessageReactionKind::Like {
+            (
+                "user-liked-your-message-title",
+                "user-liked-your-message-body",
+            )
+        } else {
+            args.set("reaction", reaction.emoji());
+            (
+                "user-reacted-to-your-message-title",
+                "user-reacted-to-your-message-body",
+            )
+        };
+
+        let mut url = if let Some(challenge_id) = message.challenge_id {
+            format!("/challenges/{}/null", challenge_id)
+        } else {
+            format!("/habits/{}", message.habit_id.unwrap())
+        };
+
+        url.push_str(&format!("/threads/{}", message.thread_id));
+
+        if let Some(replies_to) = message.replies_to {
+            url.push_str(&format!("/reply/{}", replies_to));
+        }
+
+        self.notification_service
+            .generate_notification(
+                &mut notif_transaction,
+                Some(sender_id),
+                message.creator,
+                NotificationContent {
+                    title: &translator.translate(&creator.locale, title_key, None),
+                    body: &translator.translate(&creator.locale, body_key, Some(args)),
+                    notification_type: "public_message_liked",
+                    url: Some(url),
+                },
+                redis_client,
+            )
+            .await;
+
+        let _ = notif_transaction.commit().await;
+    }
+}
@@ -0,0 +1,93 @@
+// Subscription notification service - tells subscribers about new activity in what they follow
+
+use actix_web::web::Data;
+use fluent::FluentArgs;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::core::helpers::translation::Translator;
+use crate::features::notifications::infrastructure::services::notification_service::{
+    NotificationContent, NotificationService,
+};
+use crate::features::profile::domain::entities::UserPublicDataCache;
+use crate::features::public_discussions::domain::entities::public_message_subscription::PublicMessageSubscription;
+
+pub struct SubscriptionNotificationService {
+    pool: PgPool,
+    notification_service: NotificationService,
+}
+
+impl SubscriptionNotificationService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            notification_service: NotificationService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    // Sends one notification per subscription, covering `count` new messages.
+    // Batched notifications have no sender since their messages come from several users.
+    pub async fn notify(
+        &self,
+        subscriptions: Vec<(PublicMessageSubscription, i32)>,
+        sender_id: Option<Uuid>,
+        redis_client: Data<Client>,
+        translator: &Translator,
+        user_public_data_cache: &UserPublicDataCache,
+    ) {
+        if subscriptions.is_empty() {
+            return;
+        }
+
+        let mut notif_transaction = match self.pool.begin().await {
+            Ok(t) => t,
+            Err(e) => {
+                error!("Error: {}", e);
+                return;
+            }
+        };
+
+        for (subscription, count) in subscriptions {
+            let Some(subscriber) = user_public_data_cache
+                .get_value_for_key_or_insert_it(&subscription.user_id, &mut notif_transaction)
+                .await
+            else {
+                continue;
+            };
+
+            let (title_key, body_key) = if subscription.thread_id.is_some() {
+                (
+                    "new-replies-in-followed-thread-title",
+                    "new-replies-in-followed-thread-body",
+                )
+            } else {
+                (
+                    "new-messages-in-followed-discussion-title",
+                    "new-messages-in-followed-discussion-body",
+                )
+            };
+
+            let mut args = FluentArgs::new();
+            args.set("count", count);
+
+            self.notification_service
+                .generate_notification(
+                    &mut notif_transaction,
+                    sender_id,
+                    subscription.user_id,
+                    NotificationContent {
+                        title: &translator.translate(&subscriber.locale, title_key, None),
+                        body: &translator.translate(&subscriber.locale, body_key, Some(args)),
+                        notification_type: "public_message_subscription_activity",
+                        url: Some(subscription.url()),
+                    },
+                    redis_client.clone(),
+                )
+                .await;
+        }
+
+        let _ = notif_transaction.commit().await;
+    }
+}
@@ -6,19 +6,44 @@ use crate::{
         helpers::{mock_now::now, translation::Translator},
     },
     features::{
+        attachments::infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl,
         auth::domain::entities::Claims,
         challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl,
+        content_filters::{
+            application::use_cases::apply_content_filter::{
+                content_filter_locales, ApplyContentFilterUseCase,
+            },
+            infrastructure::repositories::{
+                content_filter_flag_repository::ContentFilterFlagRepositoryImpl,
+                content_filter_rule_repository::ContentFilterRuleRepositoryImpl,
+            },
+        },
         habits::infrastructure::repositories::habit_repository::HabitRepositoryImpl,
-        notifications::infrastructure::services::notification_service::NotificationService,
+        notifications::infrastructure::services::notification_service::{
+            NotificationContent, NotificationService,
+        },
         profile::domain::entities::UserPublicDataCache,
         public_discussions::{
             application::dto::{
                 requests::public_message::PublicMessageCreateRequest,
                 responses::public_message::PublicMessageResponse,
             },
-            application::use_cases::create_public_message::CreatePublicMessageUseCase,
+            application::use_cases::{
+                create_public_message::CreatePublicMessageUseCase,
+                record_public_message_subscription_activity::RecordPublicMessageSubscriptionActivityUseCase,
+            },
             domain::entities::public_message::PublicMessage,
-            infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl,
+            infrastructure::repositories::{
+                public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+                public_message_revision_repository::PublicMessageRevisionRepositoryImpl,
+                public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl,
+                user_suspension_repository::UserSuspensionRepositoryImpl,
+            },
+            infrastructure::services::{
+                language_service::LanguageService,
+                subscription_notification_service::SubscriptionNotificationService,
+            },
         },
     },
 };
@@ -56,7 +81,7 @@ pub async fn create_public_message(
     let new_message_id = Uuid::new_v4();
     let thread_id = body.thread_id.unwrap_or(new_message_id);
 
-    let new_public_message = PublicMessage {
+    let mut new_public_message = PublicMessage {
         id: new_message_id,
         habit_id: body.habit_id,
         challenge_id: body.challenge_id,
@@ -70,7 +95,14 @@ pub async fn create_public_message(
         reply_count: 0,
         deleted_by_creator: false,
         deleted_by_admin: false,
-        language_code: None,
+        language_code: LanguageService::new().detect_language(&body.content),
+        hidden_by_moderation: false,
+        hot_score: 0.0,
+        pinned_at: None,
+        pinned_by: None,
+        is_announcement: false,
+        reaction_counts: serde_json::json!({}),
+        attachment_ids: body.attachment_ids.to_owned(),
     };
 
     // Get parent message for notification (before use case modifies it)
@@ -91,97 +123,304 @@ pub async fn create_public_message(
     let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
     let habit_repo = HabitRepositoryImpl::new(pool_clone.clone());
     let challenge_repo = ChallengeRepositoryImpl::new(pool_clone.clone());
+    let suspension_repo = UserSuspensionRepositoryImpl::new(pool_clone.clone());
+    let revision_repo = PublicMessageRevisionRepositoryImpl::new(pool_clone.clone());
+    let mention_repo = PublicMessageMentionRepositoryImpl::new(pool_clone.clone());
+    let subscription_repo = PublicMessageSubscriptionRepositoryImpl::new(pool_clone.clone());
+    let attachment_repo = AttachmentRepositoryImpl::new(pool_clone.clone());
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool_clone.clone()),
+        ContentFilterFlagRepositoryImpl::new(pool_clone.clone()),
+    );
 
-    let use_case = CreatePublicMessageUseCase::new(message_repo, habit_repo, challenge_repo);
+    let author_locale = user_public_data_cache
+        .get_value_for_key_or_insert_it(&request_claims.user_id, &mut transaction)
+        .await
+        .map(|author| author.locale);
+    let locales = content_filter_locales(
+        author_locale.as_deref(),
+        new_public_message.language_code.as_deref(),
+    );
+
+    let use_case = CreatePublicMessageUseCase::new(
+        message_repo,
+        habit_repo,
+        challenge_repo,
+        suspension_repo,
+        revision_repo,
+        mention_repo,
+        subscription_repo,
+        attachment_repo,
+        content_filter,
+    );
     let result = use_case
-        .execute(&new_public_message, &mut transaction)
+        .execute(&mut new_public_message, &locales, &mut transaction)
         .await;
 
-    if let Err(e) = transaction.commit().await {
+    // Nothing is kept when the message is refused
+    if result.is_err() {
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+    } else if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
         return HttpResponse::InternalServerError()
             .json(AppError::DatabaseTransaction.to_response());
     }
 
     match result {
-        Ok(_) => {
+        Ok(mentioned_user_ids) => {
+            let mut mentioned_user_ids = mentioned_user_ids;
+            let mut notified_user_ids = Vec::new();
+
             // Handle notification for replies (use a new transaction since we already committed)
             if let Some(message) = parent_message {
                 if request_claims.user_id != message.creator {
-                    let mut notif_transaction = match pool.begin().await {
-                        Ok(t) => t,
-                        Err(_) => {
-                            return HttpResponse::Ok().json(PublicMessageResponse {
-                                code: "PUBLIC_MESSAGE_CREATED".to_string(),
-                                message: Some(new_public_message.to_public_message_data()),
-                            });
-                        }
-                    };
-
-                    if let (Some(person_who_replied), Some(creator)) = (
-                        user_public_data_cache
-                            .get_value_for_key_or_insert_it(
-                                &request_claims.user_id,
-                                &mut notif_transaction,
-                            )
-                            .await,
-                        user_public_data_cache
-                            .get_value_for_key_or_insert_it(
-                                &message.creator,
-                                &mut notif_transaction,
-                            )
-                            .await,
-                    ) {
-                        let mut args = FluentArgs::new();
-                        args.set("username", person_who_replied.username);
-
-                        let mut url = if let Some(challenge_id) = message.challenge_id {
-                            format!("/challenges/{}/null", challenge_id)
-                        } else {
-                            format!("/habits/{}", message.habit_id.unwrap())
-                        };
+                    // The replied user already hears about this message
+                    mentioned_user_ids.retain(|id| *id != message.creator);
+                    notified_user_ids.push(message.creator);
 
-                        url.push_str(&format!("/threads/{}", message.thread_id));
+                    if let Ok(mut notif_transaction) = pool.begin().await {
+                        if let (Some(person_who_replied), Some(creator)) = (
+                            user_public_data_cache
+                                .get_value_for_key_or_insert_it(
+                                    &request_claims.user_id,
+                                    &mut notif_transaction,
+                                )
+                                .await,
+                            user_public_data_cache
+                                .get_value_for_key_or_insert_it(
+                                    &message.creator,
+                                    &mut notif_transaction,
+                                )
+                                .await,
+                        ) {
+                            let mut args = FluentArgs::new();
+                            args.set("username", person_who_replied.username);
 
-                        if let Some(replies_to) = message.replies_to {
-                            url.push_str(&format!("/reply/{}", replies_to));
-                        }
+                            let mut url = if let Some(challenge_id) = message.challenge_id {
+                                format!("/challenges/{}/null", challenge_id)
+                            } else {
+                                format!("/habits/{}", message.habit_id.unwrap())
+                            };
 
-                        notification_service
-                            .generate_notification(
-                                &mut *notif_transaction,
-                                message.creator,
-                                &translator.translate(
-                                    &creator.locale,
-                                    "user-replied-to-your-message-title",
-                                    None,
-                                ),
-                                &translator.translate(
-                                    &creator.locale,
-                                    "user-replied-to-your-message-body",
-                                    Some(args),
-                                ),
-                                redis_client,
-                                "public_message_replied",
-                                Some(url),
-                            )
-                            .await;
-
-                        let _ = notif_transaction.commit().await;
-                    } else {
-                        let _ = notif_transaction.rollback().await;
+                            url.push_str(&format!("/threads/{}", message.thread_id));
+
+                            if let Some(replies_to) = message.replies_to {
+                                url.push_str(&format!("/reply/{}", replies_to));
+                            }
+
+                            notification_service
+                                .generate_notification(
+                                    &mut notif_transaction,
+                                    Some(request_claims.user_id),
+                                    message.creator,
+                                    NotificationContent {
+                                        title: &translator.translate(
+                                            &creator.locale,
+                                            "user-replied-to-your-message-title",
+                                            None,
+                                        ),
+                                        body: &translator.translate(
+                                            &creator.locale,
+                                            "user-replied-to-your-message-body",
+                                            Some(args),
+                                        ),
+                                        notification_type: "public_message_replied",
+                                        url: Some(url),
+                                    },
+                                    redis_client.clone(),
+                                )
+                                .await;
+
+                            let _ = notif_transaction.commit().await;
+                        } else {
+                            let _ = notif_transaction.rollback().await;
+                        }
                     }
                 }
             }
 
+            notified_user_ids.extend(mentioned_user_ids.iter().copied());
+
+            notify_mentioned_users(
+                &pool,
+                &new_public_message,
+                mentioned_user_ids,
+                redis_client.clone(),
+                &translator,
+                &user_public_data_cache,
+            )
+            .await;
+
+            notify_subscribers(
+                &pool,
+                &new_public_message,
+                &notified_user_ids,
+                redis_client,
+                &translator,
+                &user_public_data_cache,
+            )
+            .await;
+
+            let mentions = PublicMessageMentionRepositoryImpl::new(pool.get_ref().clone())
+                .get_by_message_ids_with_executor(vec![new_public_message.id], pool.get_ref())
+                .await
+                .unwrap_or_default();
+
             HttpResponse::Ok().json(PublicMessageResponse {
                 code: "PUBLIC_MESSAGE_CREATED".to_string(),
                 message: Some(new_public_message.to_public_message_data()),
+                mentions: mentions
+                    .iter()
+                    .map(|m| m.to_public_message_mention_data())
+                    .collect(),
             })
         }
+        Err(AppError::UserSuspended) => {
+            HttpResponse::Forbidden().json(AppError::UserSuspended.to_response())
+        }
+        Err(AppError::ContentRejectedByFilter) => {
+            HttpResponse::BadRequest().json(AppError::ContentRejectedByFilter.to_response())
+        }
+        Err(AppError::PublicMessageTooManyAttachments) => {
+            HttpResponse::BadRequest().json(AppError::PublicMessageTooManyAttachments.to_response())
+        }
+        Err(AppError::PublicMessageAttachmentNotFound) => {
+            HttpResponse::BadRequest().json(AppError::PublicMessageAttachmentNotFound.to_response())
+        }
         Err(e) => {
             error!("Error: {:?}", e);
             HttpResponse::InternalServerError().json(e.to_response())
         }
     }
 }
+
+// Tells users they were mentioned in a message, shared with the update route
+pub async fn notify_mentioned_users(
+    pool: &PgPool,
+    message: &PublicMessage,
+    mentioned_user_ids: Vec<Uuid>,
+    redis_client: Data<Client>,
+    translator: &Translator,
+    user_public_data_cache: &UserPublicDataCache,
+) {
+    if mentioned_user_ids.is_empty() {
+        return;
+    }
+
+    let notification_service = NotificationService::new(pool.clone());
+    let mut notif_transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return;
+        }
+    };
+
+    let Some(author) = user_public_data_cache
+        .get_value_for_key_or_insert_it(&message.creator, &mut notif_transaction)
+        .await
+    else {
+        let _ = notif_transaction.rollback().await;
+        return;
+    };
+
+    let mut url = if let Some(challenge_id) = message.challenge_id {
+        format!("/challenges/{}/null", challenge_id)
+    } else {
+        format!("/habits/{}", message.habit_id.unwrap())
+    };
+
+    url.push_str(&format!("/threads/{}", message.thread_id));
+
+    if let Some(replies_to) = message.replies_to {
+        url.push_str(&format!("/reply/{}", replies_to));
+    }
+
+    for user_id in mentioned_user_ids {
+        let Some(mentioned_user) = user_public_data_cache
+            .get_value_for_key_or_insert_it(&user_id, &mut notif_transaction)
+            .await
+        else {
+            continue;
+        };
+
+        let mut args = FluentArgs::new();
+        args.set("username", author.username.to_owned());
+
+        notification_service
+            .generate_notification(
+                &mut notif_transaction,
+                Some(message.creator),
+                user_id,
+                NotificationContent {
+                    title: &translator.translate(
+                        &mentioned_user.locale,
+                        "user-mentioned-you-title",
+                        None,
+                    ),
+                    body: &translator.translate(
+                        &mentioned_user.locale,
+                        "user-mentioned-you-body",
+                        Some(args),
+                    ),
+                    notification_type: "public_message_mentioned",
+                    url: Some(url.clone()),
+                },
+                redis_client.clone(),
+            )
+            .await;
+    }
+
+    let _ = notif_transaction.commit().await;
+}
+
+// Tells the followers of the thread and of the channel about the new message,
+// in batches when they were notified recently
+async fn notify_subscribers(
+    pool: &PgPool,
+    message: &PublicMessage,
+    already_notified_user_ids: &[Uuid],
+    redis_client: Data<Client>,
+    translator: &Translator,
+    user_public_data_cache: &UserPublicDataCache,
+) {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return;
+        }
+    };
+
+    let use_case = RecordPublicMessageSubscriptionActivityUseCase::new(
+        PublicMessageSubscriptionRepositoryImpl::new(pool.clone()),
+    );
+    let subscriptions = match use_case
+        .execute(message, already_notified_user_ids, &mut transaction)
+        .await
+    {
+        Ok(subscriptions) => subscriptions,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            let _ = transaction.rollback().await;
+            return;
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return;
+    }
+
+    SubscriptionNotificationService::new(pool.clone())
+        .notify(
+            subscriptions,
+            Some(message.creator),
+            redis_client,
+            translator,
+            user_public_data_cache,
+        )
+        .await;
+}
@@ -7,20 +7,25 @@ use crate::{
     },
     features::{
         auth::domain::entities::Claims,
-        notifications::infrastructure::services::notification_service::NotificationService,
         profile::domain::entities::UserPublicDataCache,
         public_discussions::{
             application::dto::{
                 requests::public_message_like::PublicMessageLikeCreateRequest,
                 responses::public_message_like::PublicMessageLikeResponse,
             },
-            application::use_cases::create_public_message_like::CreatePublicMessageLikeUseCase,
-            domain::entities::public_message_like::PublicMessageLike,
-            infrastructure::repositories::{
-                public_message_like_repository::PublicMessageLikeRepositoryImpl,
-                public_message_repository::PublicMessageRepositoryImpl,
+            application::use_cases::create_public_message_reaction::CreatePublicMessageReactionUseCase,
+            domain::entities::public_message_reaction::{
+                PublicMessageReaction, PublicMessageReactionKind,
+            },
+            infrastructure::{
+                repositories::{
+                    public_message_reaction_repository::PublicMessageReactionRepositoryImpl,
+                    public_message_repository::PublicMessageRepositoryImpl,
+                },
+                services::reaction_notification_service::ReactionNotificationService,
             },
         },
+        user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl,
     },
 };
 use actix_web::{
@@ -28,12 +33,12 @@ use actix_web::{
     web::{Data, Json, ReqData},
     HttpResponse, Responder,
 };
-use fluent::FluentArgs;
 use redis::Client;
 use sqlx::PgPool;
 use tracing::error;
 use uuid::Uuid;
 
+// Likes are stored as "like" reactions
 #[post("/")]
 pub async fn create_public_message_like(
     pool: Data<PgPool>,
@@ -43,7 +48,6 @@ pub async fn create_public_message_like(
     user_public_data_cache: Data<UserPublicDataCache>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
-    let notification_service = NotificationService::new(pool.get_ref().clone());
     let mut transaction = match pool.begin().await {
         Ok(t) => t,
         Err(e) => {
@@ -55,21 +59,24 @@ pub async fn create_public_message_like(
 
     let body = body.into_inner();
 
-    let public_message_like = PublicMessageLike {
+    let public_message_reaction = PublicMessageReaction {
         id: Uuid::new_v4(),
         message_id: body.message_id,
         user_id: request_claims.user_id,
+        reaction: PublicMessageReactionKind::Like.to_string(),
         created_at: now(),
     };
 
     // Create repositories and use case
     let pool_clone = pool.get_ref().clone();
-    let like_repo = PublicMessageLikeRepositoryImpl::new(pool_clone.clone());
+    let reaction_repo = PublicMessageReactionRepositoryImpl::new(pool_clone.clone());
     let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+    let user_block_repo = UserBlockRepositoryImpl::new(pool_clone.clone());
 
-    let use_case = CreatePublicMessageLikeUseCase::new(like_repo, message_repo);
+    let use_case =
+        CreatePublicMessageReactionUseCase::new(reaction_repo, message_repo, user_block_repo);
     let result = use_case
-        .execute(&public_message_like, &mut transaction)
+        .execute(&public_message_reaction, &mut transaction)
         .await;
 
     if let Err(e) = transaction.commit().await {
@@ -79,84 +86,27 @@ pub async fn create_public_message_like(
     }
 
     match result {
-        Ok(creator_id) => {
-            // Handle notification (use a new transaction since we already committed)
-            if request_claims.user_id != creator_id {
-                let mut notif_transaction = match pool.begin().await {
-                    Ok(t) => t,
-                    Err(_) => {
-                        return HttpResponse::Ok().json(PublicMessageLikeResponse {
-                            code: "PUBLIC_MESSAGE_LIKE_CREATED".to_string(),
-                        });
-                    }
-                };
-
-                // Get message for URL construction
-                let pool_clone2 = pool.get_ref().clone();
-                let message_repo2 = PublicMessageRepositoryImpl::new(pool_clone2);
-                if let Ok(Some(public_message)) = message_repo2
-                    .get_by_id_with_executor(body.message_id, &mut *notif_transaction)
-                    .await
-                {
-                    if let (Some(person_who_liked), Some(creator)) = (
-                        user_public_data_cache
-                            .get_value_for_key_or_insert_it(
-                                &request_claims.user_id,
-                                &mut notif_transaction,
-                            )
-                            .await,
-                        user_public_data_cache
-                            .get_value_for_key_or_insert_it(&creator_id, &mut notif_transaction)
-                            .await,
-                    ) {
-                        let mut args = FluentArgs::new();
-                        args.set("username", person_who_liked.username);
-
-                        let mut url = if let Some(challenge_id) = public_message.challenge_id {
-                            format!("/challenges/{}/null", challenge_id)
-                        } else {
-                            format!("/habits/{}", public_message.habit_id.unwrap())
-                        };
-
-                        url.push_str(&format!("/threads/{}", public_message.thread_id));
-
-                        if let Some(replies_to) = public_message.replies_to {
-                            url.push_str(&format!("/reply/{}", replies_to));
-                        }
-
-                        notification_service
-                            .generate_notification(
-                                &mut *notif_transaction,
-                                creator_id,
-                                &translator.translate(
-                                    &creator.locale,
-                                    "user-liked-your-message-title",
-                                    None,
-                                ),
-                                &translator.translate(
-                                    &creator.locale,
-                                    "user-liked-your-message-body",
-                                    Some(args),
-                                ),
-                                redis_client,
-                                "public_message_liked",
-                                Some(url),
-                            )
-                            .await;
-
-                        let _ = notif_transaction.commit().await;
-                    } else {
-                        let _ = notif_transaction.rollback().await;
-                    }
-                } else {
-                    let _ = notif_transaction.rollback().await;
-                }
+        Ok(message) => {
+            if let Some(message) = message {
+                ReactionNotificationService::new(pool.get_ref().clone())
+                    .notify(
+                        &message,
+                        PublicMessageReactionKind::Like,
+                        request_claims.user_id,
+                        redis_client,
+                        &translator,
+                        &user_public_data_cache,
+                    )
+                    .await;
             }
 
             HttpResponse::Ok().json(PublicMessageLikeResponse {
                 code: "PUBLIC_MESSAGE_LIKE_CREATED".to_string(),
             })
         }
+        Err(AppError::UserBlocked) => {
+            HttpResponse::Forbidden().json(AppError::UserBlocked.to_response())
+        }
         Err(e) => {
             error!("Error: {:?}", e);
             HttpResponse::InternalServerError().json(e.to_response())
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{
+        constants::errors::AppError,
+        helpers::{mock_now::now, translation::Translator},
+    },
+    features::{
+        auth::domain::entities::Claims,
+        profile::domain::entities::UserPublicDataCache,
+        public_discussions::{
+            application::dto::{
+                requests::public_message_reaction::PublicMessageReactionCreateRequest,
+                responses::public_message_reaction::PublicMessageReactionResponse,
+            },
+            application::use_cases::create_public_message_reaction::CreatePublicMessageReactionUseCase,
+            domain::entities::public_message_reaction::PublicMessageReaction,
+            infrastructure::{
+                repositories::{
+                    public_message_reaction_repository::PublicMessageReactionRepositoryImpl,
+                    public_message_repository::PublicMessageRepositoryImpl,
+                },
+                services::reaction_notification_service::ReactionNotificationService,
+            },
+        },
+        user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl,
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse, Responder,
+};
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+#[post("/")]
+pub async fn create_public_message_reaction(
+    pool: Data<PgPool>,
+    body: Json<PublicMessageReactionCreateRequest>,
+    redis_client: Data<Client>,
+    translator: Data<Arc<Translator>>,
+    user_public_data_cache: Data<UserPublicDataCache>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+
+    let public_message_reaction = PublicMessageReaction {
+        id: Uuid::new_v4(),
+        message_id: body.message_id,
+        user_id: request_claims.user_id,
+        reaction: body.reaction.to_string(),
+        created_at: now(),
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let reaction_repo = PublicMessageReactionRepositoryImpl::new(pool_clone.clone());
+    let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+    let user_block_repo = UserBlockRepositoryImpl::new(pool_clone.clone());
+
+    let use_case =
+        CreatePublicMessageReactionUseCase::new(reaction_repo, message_repo, user_block_repo);
+    let result = use_case
+        .execute(&public_message_reaction, &mut transaction)
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(message) => {
+            if let Some(message) = message {
+                ReactionNotificationService::new(pool.get_ref().clone())
+                    .notify(
+                        &message,
+                        body.reaction,
+                        request_claims.user_id,
+                        redis_client,
+                        &translator,
+                        &user_public_data_cache,
+                    )
+                    .await;
+            }
+
+            HttpResponse::Ok().json(PublicMessageReactionResponse {
+                code: "PUBLIC_MESSAGE_REACTION_CREATED".to_string(),
+            })
+        }
+        Err(AppError::PublicMessageNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageNotFound.to_response())
+        }
+        Err(AppError::UserBlocked) => {
+            HttpResponse::Forbidden().json(AppError::UserBlocked.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
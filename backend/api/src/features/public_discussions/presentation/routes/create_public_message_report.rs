@@ -1,7 +1,18 @@
 use crate::{
+    configuration::ModerationSettings,
     core::{constants::errors::AppError, helpers::mock_now::now},
     features::{
         auth::domain::entities::Claims,
+        content_filters::{
+            application::use_cases::apply_content_filter::{
+                content_filter_locales, ApplyContentFilterUseCase,
+            },
+            infrastructure::repositories::{
+                content_filter_flag_repository::ContentFilterFlagRepositoryImpl,
+                content_filter_rule_repository::ContentFilterRuleRepositoryImpl,
+            },
+        },
+        profile::domain::entities::UserPublicDataCache,
         public_discussions::{
             application::dto::{
                 requests::public_message_report::PublicMessageReportCreateRequest,
@@ -12,7 +23,9 @@ use crate::{
             infrastructure::repositories::{
                 public_message_report_repository::PublicMessageReportRepositoryImpl,
                 public_message_repository::PublicMessageRepositoryImpl,
+                public_message_revision_repository::PublicMessageRevisionRepositoryImpl,
             },
+            infrastructure::services::language_service::LanguageService,
         },
     },
 };
@@ -29,6 +42,8 @@ use uuid::Uuid;
 pub async fn create_public_message_report(
     pool: Data<PgPool>,
     body: Json<PublicMessageReportCreateRequest>,
+    moderation_settings: Data<ModerationSettings>,
+    user_public_data_cache: Data<UserPublicDataCache>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
     let mut transaction = match pool.begin().await {
@@ -42,12 +57,16 @@ pub async fn create_public_message_report(
 
     let body = body.into_inner();
 
-    let public_message_report = PublicMessageReport {
+    let mut public_message_report = PublicMessageReport {
         id: Uuid::new_v4(),
-        message_id: body.message_id,
+        message_id: Some(body.message_id),
+        story_id: None,
         reporter: request_claims.user_id,
         created_at: now(),
         reason: body.reason.to_owned(),
+        resolved_at: None,
+        decision_id: None,
+        revision_id: None,
     };
 
     // Create repositories and use case
@@ -55,9 +74,33 @@ pub async fn create_public_message_report(
     let report_repo = PublicMessageReportRepositoryImpl::new(pool_clone.clone());
     let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
 
-    let use_case = CreatePublicMessageReportUseCase::new(report_repo, message_repo);
+    let revision_repo = PublicMessageRevisionRepositoryImpl::new(pool_clone.clone());
+
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool_clone.clone()),
+        ContentFilterFlagRepositoryImpl::new(pool_clone.clone()),
+    );
+
+    let reporter_locale = user_public_data_cache
+        .get_value_for_key_or_insert_it(&request_claims.user_id, &mut transaction)
+        .await
+        .map(|reporter| reporter.locale);
+    let reason_language = LanguageService::new().detect_language(&public_message_report.reason);
+    let locales = content_filter_locales(reporter_locale.as_deref(), reason_language.as_deref());
+
+    let use_case = CreatePublicMessageReportUseCase::new(
+        report_repo,
+        message_repo,
+        revision_repo,
+        content_filter,
+    );
     let result = use_case
-        .execute(&public_message_report, &mut transaction)
+        .execute(
+            &mut public_message_report,
+            moderation_settings.auto_hide_reporter_threshold,
+            &locales,
+            &mut transaction,
+        )
         .await;
 
     if let Err(e) = transaction.commit().await {
@@ -71,6 +114,9 @@ pub async fn create_public_message_report(
             code: "PUBLIC_MESSAGE_REPORT_CREATED".to_string(),
             message_report: Some(public_message_report.to_public_message_report_data()),
         }),
+        Err(AppError::ContentRejectedByFilter) => {
+            HttpResponse::BadRequest().json(AppError::ContentRejectedByFilter.to_response())
+        }
         Err(e) => {
             error!("Error: {:?}", e);
             HttpResponse::InternalServerError().json(e.to_response())
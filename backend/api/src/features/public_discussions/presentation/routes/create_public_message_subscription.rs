@@ -0,0 +1,91 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl,
+        habits::infrastructure::repositories::habit_repository::HabitRepositoryImpl,
+        public_discussions::{
+            application::dto::{
+                requests::public_message_subscription::PublicMessageSubscriptionCreateRequest,
+                responses::public_message_subscription::PublicMessageSubscriptionResponse,
+            },
+            application::use_cases::create_public_message_subscription::CreatePublicMessageSubscriptionUseCase,
+            infrastructure::repositories::{
+                public_message_repository::PublicMessageRepositoryImpl,
+                public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl,
+            },
+        },
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[post("/")]
+pub async fn create_public_message_subscription(
+    pool: Data<PgPool>,
+    body: Json<PublicMessageSubscriptionCreateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let subscription_repo = PublicMessageSubscriptionRepositoryImpl::new(pool_clone.clone());
+    let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+    let habit_repo = HabitRepositoryImpl::new(pool_clone.clone());
+    let challenge_repo = ChallengeRepositoryImpl::new(pool_clone.clone());
+
+    let use_case = CreatePublicMessageSubscriptionUseCase::new(
+        subscription_repo,
+        message_repo,
+        habit_repo,
+        challenge_repo,
+    );
+    let result = use_case
+        .execute(request_claims.user_id, &body, &mut transaction)
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(subscription) => HttpResponse::Ok().json(PublicMessageSubscriptionResponse {
+            code: "PUBLIC_MESSAGE_SUBSCRIPTION_CREATED".to_string(),
+            subscription: Some(subscription.to_public_message_subscription_data()),
+        }),
+        Err(AppError::NoHabitNorChallengePassed) => {
+            HttpResponse::BadRequest().json(AppError::NoHabitNorChallengePassed.to_response())
+        }
+        Err(AppError::BothHabitAndChallengePassed) => {
+            HttpResponse::BadRequest().json(AppError::BothHabitAndChallengePassed.to_response())
+        }
+        Err(AppError::PublicMessageNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageNotFound.to_response())
+        }
+        Err(AppError::HabitNotFound) => {
+            HttpResponse::NotFound().json(AppError::HabitNotFound.to_response())
+        }
+        Err(AppError::ChallengeNotFound) => {
+            HttpResponse::NotFound().json(AppError::ChallengeNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
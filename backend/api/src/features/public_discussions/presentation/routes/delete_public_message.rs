@@ -1,6 +1,11 @@
 use crate::{
     core::constants::errors::AppError,
     features::{
+        attachments::{
+            application::use_cases::delete_public_message_attachments::DeletePublicMessageAttachmentsUseCase,
+            domain::services::storage_backend::{delete_attachment_files, StorageBackend},
+            infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl,
+        },
         auth::domain::entities::Claims,
         public_discussions::{
             application::dto::{
@@ -10,6 +15,7 @@ use crate::{
             application::use_cases::delete_public_message::DeletePublicMessageUseCase,
             infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl,
         },
+        roles::domain::entities::permission::Permission,
     },
 };
 use actix_web::{
@@ -23,6 +29,7 @@ use tracing::error;
 #[delete("/")]
 pub async fn delete_public_message(
     pool: Data<PgPool>,
+    storage_backend: Data<dyn StorageBackend>,
     query: Query<DeletePublicMessageParams>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
@@ -40,17 +47,26 @@ pub async fn delete_public_message(
     // Create repositories
     let pool_clone = pool.get_ref().clone();
     let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+    let attachment_repo = AttachmentRepositoryImpl::new(pool_clone.clone());
 
     let use_case = DeletePublicMessageUseCase::new(message_repo);
-    let result = use_case
+    let mut result = use_case
         .execute(
             params.message_id,
             request_claims.user_id,
-            request_claims.is_admin,
+            request_claims.has_permission(Permission::ModeratePublicMessages),
             params.deleted_by_admin,
             &mut transaction,
         )
-        .await;
+        .await
+        .map(|_| Vec::new());
+
+    // Images of a deleted message go with it
+    if result.is_ok() {
+        result = DeletePublicMessageAttachmentsUseCase::new(attachment_repo)
+            .execute(params.message_id, &mut transaction)
+            .await;
+    }
 
     if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
@@ -59,10 +75,15 @@ pub async fn delete_public_message(
     }
 
     match result {
-        Ok(_) => HttpResponse::Ok().json(PublicMessageResponse {
-            code: "PUBLIC_MESSAGE_DELETED".to_string(),
-            message: None,
-        }),
+        Ok(attachments) => {
+            delete_attachment_files(storage_backend.get_ref(), &attachments).await;
+
+            HttpResponse::Ok().json(PublicMessageResponse {
+                code: "PUBLIC_MESSAGE_DELETED".to_string(),
+                message: None,
+                mentions: Vec::new(),
+            })
+        }
         Err(e) => {
             error!("Error: {:?}", e);
             HttpResponse::InternalServerError().json(e.to_response())
@@ -7,9 +7,10 @@ use crate::{
                 requests::public_message_like::DeletePublicMessageLikeParams,
                 responses::public_message_like::PublicMessageLikeResponse,
             },
-            application::use_cases::delete_public_message_like::DeletePublicMessageLikeUseCase,
+            application::use_cases::delete_public_message_reaction::DeletePublicMessageReactionUseCase,
+            domain::entities::public_message_reaction::PublicMessageReactionKind,
             infrastructure::repositories::{
-                public_message_like_repository::PublicMessageLikeRepositoryImpl,
+                public_message_reaction_repository::PublicMessageReactionRepositoryImpl,
                 public_message_repository::PublicMessageRepositoryImpl,
             },
         },
@@ -23,6 +24,7 @@ use actix_web::{
 use sqlx::PgPool;
 use tracing::error;
 
+// Likes are stored as "like" reactions
 #[delete("/{message_id}")]
 pub async fn delete_public_message_like(
     pool: Data<PgPool>,
@@ -40,12 +42,17 @@ pub async fn delete_public_message_like(
 
     // Create repositories and use case
     let pool_clone = pool.get_ref().clone();
-    let like_repo = PublicMessageLikeRepositoryImpl::new(pool_clone.clone());
+    let reaction_repo = PublicMessageReactionRepositoryImpl::new(pool_clone.clone());
     let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
 
-    let use_case = DeletePublicMessageLikeUseCase::new(like_repo, message_repo);
+    let use_case = DeletePublicMessageReactionUseCase::new(reaction_repo, message_repo);
     let result = use_case
-        .execute(params.message_id, request_claims.user_id, &mut transaction)
+        .execute(
+            params.message_id,
+            request_claims.user_id,
+            PublicMessageReactionKind::Like,
+            &mut transaction,
+        )
         .await;
 
     if let Err(e) = transaction.commit().await {
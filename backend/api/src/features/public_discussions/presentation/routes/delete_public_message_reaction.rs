@@ -0,0 +1,77 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::dto::{
+                requests::public_message_reaction::DeletePublicMessageReactionParams,
+                responses::public_message_reaction::PublicMessageReactionResponse,
+            },
+            application::use_cases::delete_public_message_reaction::DeletePublicMessageReactionUseCase,
+            infrastructure::repositories::{
+                public_message_reaction_repository::PublicMessageReactionRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+            },
+        },
+    },
+};
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/{message_id}/{reaction}")]
+pub async fn delete_public_message_reaction(
+    pool: Data<PgPool>,
+    params: Path<DeletePublicMessageReactionParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let reaction_repo = PublicMessageReactionRepositoryImpl::new(pool_clone.clone());
+    let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+
+    let use_case = DeletePublicMessageReactionUseCase::new(reaction_repo, message_repo);
+    let result = use_case
+        .execute(
+            params.message_id,
+            request_claims.user_id,
+            params.reaction,
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(_) => HttpResponse::Ok().json(PublicMessageReactionResponse {
+            code: "PUBLIC_MESSAGE_REACTION_DELETED".to_string(),
+        }),
+        Err(AppError::PublicMessageNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageNotFound.to_response())
+        }
+        Err(AppError::PublicMessageReactionNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageReactionNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::dto::{
+                requests::public_message_subscription::PublicMessageSubscriptionParams,
+                responses::public_message_subscription::PublicMessageSubscriptionResponse,
+            },
+            application::use_cases::delete_public_message_subscription::DeletePublicMessageSubscriptionUseCase,
+            infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/{subscription_id}")]
+pub async fn delete_public_message_subscription(
+    pool: Data<PgPool>,
+    params: Path<PublicMessageSubscriptionParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let subscription_repo = PublicMessageSubscriptionRepositoryImpl::new(pool.get_ref().clone());
+
+    let use_case = DeletePublicMessageSubscriptionUseCase::new(subscription_repo);
+    let result = use_case
+        .execute(
+            params.subscription_id,
+            request_claims.user_id,
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(_) => HttpResponse::Ok().json(PublicMessageSubscriptionResponse {
+            code: "PUBLIC_MESSAGE_SUBSCRIPTION_DELETED".to_string(),
+            subscription: None,
+        }),
+        Err(AppError::PublicMessageSubscriptionNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageSubscriptionNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
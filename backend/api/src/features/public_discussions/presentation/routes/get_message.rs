@@ -5,7 +5,10 @@ use crate::{
             requests::public_message::GetPublicMessageParams,
             responses::public_message::PublicMessageResponse,
         },
-        infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl,
+        infrastructure::repositories::{
+            public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+            public_message_repository::PublicMessageRepositoryImpl,
+        },
     },
 };
 use actix_web::{
@@ -32,7 +35,8 @@ pub async fn get_message(
 
     // Create repository
     let pool_clone = pool.get_ref().clone();
-    let message_repo = PublicMessageRepositoryImpl::new(pool_clone);
+    let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+    let mention_repo = PublicMessageMentionRepositoryImpl::new(pool_clone);
 
     // Check if message exists
     let message = match message_repo
@@ -55,6 +59,20 @@ pub async fn get_message(
         }
     };
 
+    let mentions = match mention_repo
+        .get_by_message_ids_with_executor(vec![message.id], &mut *transaction)
+        .await
+    {
+        Ok(mentions) => mentions,
+        Err(e) => {
+            error!("Error: {}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+        }
+    };
+
     if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
         return HttpResponse::InternalServerError()
@@ -64,5 +82,9 @@ pub async fn get_message(
     HttpResponse::Ok().json(PublicMessageResponse {
         code: "PUBLIC_MESSAGE_FETCHED".to_string(),
         message: Some(message.to_public_message_data()),
+        mentions: mentions
+            .iter()
+            .map(|m| m.to_public_message_mention_data())
+            .collect(),
     })
 }
@@ -6,7 +6,10 @@ use crate::{
             responses::public_message::PublicMessagesResponse,
         },
         domain::entities::public_message::PublicMessage,
-        infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl,
+        infrastructure::repositories::{
+            public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+            public_message_repository::PublicMessageRepositoryImpl,
+        },
     },
 };
 use actix_web::{
@@ -33,7 +36,8 @@ pub async fn get_message_parents(
 
     // Create repository
     let pool_clone = pool.get_ref().clone();
-    let message_repo = PublicMessageRepositoryImpl::new(pool_clone);
+    let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+    let mention_repo = PublicMessageMentionRepositoryImpl::new(pool_clone);
 
     // Check if message exists
     let mut message = match message_repo
@@ -84,6 +88,20 @@ pub async fn get_message_parents(
         message = parent;
     }
 
+    let mentions = match mention_repo
+        .get_by_message_ids_with_executor(parents.iter().map(|m| m.id).collect(), &mut *transaction)
+        .await
+    {
+        Ok(mentions) => mentions,
+        Err(e) => {
+            error!("Error: {}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+        }
+    };
+
     if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
         return HttpResponse::InternalServerError()
@@ -92,6 +110,12 @@ pub async fn get_message_parents(
 
     HttpResponse::Ok().json(PublicMessagesResponse {
         code: "PUBLIC_MESSAGE_FETCHED".to_string(),
+        pinned_messages: vec![],
         messages: parents.iter().map(|m| m.to_public_message_data()).collect(),
+        mentions: mentions
+            .iter()
+            .map(|m| m.to_public_message_mention_data())
+            .collect(),
+        next_cursor: None,
     })
 }
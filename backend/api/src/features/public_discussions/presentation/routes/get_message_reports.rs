@@ -1,5 +1,5 @@
 use crate::{
-    core::constants::errors::AppError,
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
     features::{
         auth::domain::entities::Claims,
         public_discussions::{
@@ -9,6 +9,7 @@ use crate::{
                 public_message_repository::PublicMessageRepositoryImpl,
             },
         },
+        roles::domain::entities::permission::Permission,
     },
 };
 use actix_web::{
@@ -24,9 +25,8 @@ pub async fn get_message_reports(
     pool: Data<PgPool>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
-    // Check if user is admin
-    if !request_claims.is_admin {
-        return HttpResponse::Forbidden().json(AppError::NotAdmin.to_response());
+    if let Err(response) = require_permission(&request_claims, Permission::ModeratePublicMessages) {
+        return response;
     }
 
     let mut transaction = match pool.begin().await {
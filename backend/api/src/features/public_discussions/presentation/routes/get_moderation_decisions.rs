@@ -0,0 +1,52 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::dto::{
+                requests::public_message::GetPublicMessageParams,
+                responses::public_message_moderation::ModerationDecisionsResponse,
+            },
+            infrastructure::repositories::public_message_moderation_decision_repository::PublicMessageModerationDecisionRepositoryImpl,
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/{message_id}/decisions")]
+pub async fn get_moderation_decisions(
+    pool: Data<PgPool>,
+    params: Path<GetPublicMessageParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ModeratePublicMessages) {
+        return response;
+    }
+
+    // Create repository
+    let decision_repo = PublicMessageModerationDecisionRepositoryImpl::new(pool.get_ref().clone());
+
+    match decision_repo
+        .get_by_message_id_with_executor(params.message_id, pool.get_ref())
+        .await
+    {
+        Ok(decisions) => HttpResponse::Ok().json(ModerationDecisionsResponse {
+            code: "MODERATION_DECISIONS_FETCHED".to_string(),
+            decisions: decisions
+                .iter()
+                .map(|d| d.to_public_message_moderation_decision_data())
+                .collect(),
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response())
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::{
+                dto::responses::public_message_moderation::ModerationQueueResponse,
+                use_cases::get_moderation_queue::GetModerationQueueUseCase,
+            },
+            infrastructure::repositories::{
+                public_message_report_repository::PublicMessageReportRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+            },
+        },
+        roles::domain::entities::permission::Permission,
+        stories::infrastructure::repositories::story_repository::StoryRepositoryImpl,
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/")]
+pub async fn get_moderation_queue(
+    pool: Data<PgPool>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ModeratePublicMessages) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = GetModerationQueueUseCase::new(
+        PublicMessageReportRepositoryImpl::new(pool_clone.clone()),
+        PublicMessageRepositoryImpl::new(pool_clone.clone()),
+        StoryRepositoryImpl::new(pool_clone),
+    );
+    let result = use_case.execute(&mut transaction).await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(entries) => HttpResponse::Ok().json(ModerationQueueResponse {
+            code: "MODERATION_QUEUE_FETCHED".to_string(),
+            entries,
+        }),
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
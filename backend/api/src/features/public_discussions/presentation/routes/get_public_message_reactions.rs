@@ -0,0 +1,86 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::{
+                dto::{
+                    requests::{
+                        public_message::GetPublicMessageParams,
+                        public_message_reaction::GetPublicMessageReactionsQuery,
+                    },
+                    responses::public_message_reaction::PublicMessageReactionsResponse,
+                },
+                use_cases::get_public_message_reactions::GetPublicMessageReactionsUseCase,
+            },
+            infrastructure::repositories::{
+                public_message_reaction_repository::PublicMessageReactionRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+            },
+        },
+        user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl,
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, Path, Query, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/{message_id}/reactions")]
+pub async fn get_public_message_reactions(
+    pool: Data<PgPool>,
+    params: Path<GetPublicMessageParams>,
+    query: Query<GetPublicMessageReactionsQuery>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = GetPublicMessageReactionsUseCase::new(
+        PublicMessageReactionRepositoryImpl::new(pool_clone.clone()),
+        PublicMessageRepositoryImpl::new(pool_clone.clone()),
+        UserBlockRepositoryImpl::new(pool_clone),
+    );
+    let result = use_case
+        .execute(
+            params.message_id,
+            query.reaction,
+            request_claims.user_id,
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(reactions) => HttpResponse::Ok().json(PublicMessageReactionsResponse {
+            code: "PUBLIC_MESSAGE_REACTIONS_FETCHED".to_string(),
+            reactions: reactions
+                .iter()
+                .map(|r| r.to_public_message_reaction_data())
+                .collect(),
+        }),
+        Err(AppError::PublicMessageNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
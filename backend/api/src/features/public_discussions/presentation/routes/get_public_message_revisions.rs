@@ -0,0 +1,83 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::{
+                dto::{
+                    requests::public_message::GetPublicMessageParams,
+                    responses::public_message::PublicMessageRevisionsResponse,
+                },
+                use_cases::get_public_message_revisions::GetPublicMessageRevisionsUseCase,
+            },
+            infrastructure::repositories::{
+                public_message_repository::PublicMessageRepositoryImpl,
+                public_message_revision_repository::PublicMessageRevisionRepositoryImpl,
+            },
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/{message_id}/revisions")]
+pub async fn get_public_message_revisions(
+    pool: Data<PgPool>,
+    params: Path<GetPublicMessageParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = GetPublicMessageRevisionsUseCase::new(
+        PublicMessageRepositoryImpl::new(pool_clone.clone()),
+        PublicMessageRevisionRepositoryImpl::new(pool_clone),
+    );
+    let result = use_case
+        .execute(
+            params.message_id,
+            request_claims.user_id,
+            request_claims.has_permission(Permission::ModeratePublicMessages),
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(revisions) => HttpResponse::Ok().json(PublicMessageRevisionsResponse {
+            code: "PUBLIC_MESSAGE_REVISIONS_FETCHED".to_string(),
+            revisions: revisions
+                .iter()
+                .map(|r| r.to_public_message_revision_data())
+                .collect(),
+        }),
+        Err(AppError::PublicMessageNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageNotFound.to_response())
+        }
+        Err(AppError::PublicMessageHistoryNotAccessible) => HttpResponse::Forbidden()
+            .json(AppError::PublicMessageHistoryNotAccessible.to_response()),
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
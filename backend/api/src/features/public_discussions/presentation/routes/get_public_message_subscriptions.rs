@@ -0,0 +1,59 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::dto::responses::public_message_subscription::PublicMessageSubscriptionsResponse,
+            infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/")]
+pub async fn get_public_message_subscriptions(
+    pool: Data<PgPool>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories
+    let subscription_repo = PublicMessageSubscriptionRepositoryImpl::new(pool.get_ref().clone());
+
+    let result = subscription_repo
+        .get_by_user_id_with_executor(request_claims.user_id, &mut *transaction)
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(subscriptions) => HttpResponse::Ok().json(PublicMessageSubscriptionsResponse {
+            code: "PUBLIC_MESSAGE_SUBSCRIPTIONS_FETCHED".to_string(),
+            subscriptions: subscriptions
+                .iter()
+                .map(|s| s.to_public_message_subscription_data())
+                .collect(),
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response())
+        }
+    }
+}
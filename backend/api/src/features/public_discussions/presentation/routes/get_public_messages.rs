@@ -1,20 +1,28 @@
 use crate::{
     core::constants::errors::AppError,
     features::{
+        auth::domain::entities::Claims,
         challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl,
         habits::infrastructure::repositories::habit_repository::HabitRepositoryImpl,
+        profile::infrastructure::repositories::user_repository::UserRepositoryImpl,
         public_discussions::{
             application::dto::{
                 requests::public_message::GetPublicMessagesParams,
                 responses::public_message::PublicMessagesResponse,
             },
-            infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl,
+            domain::entities::public_message::PublicMessagePage,
+            domain::repositories::public_message_mention_repository::PublicMessageMentionRepository,
+            infrastructure::repositories::{
+                public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+            },
         },
+        user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl,
     },
 };
 use actix_web::{
     get,
-    web::{Data, Query},
+    web::{Data, Query, ReqData},
     HttpResponse, Responder,
 };
 use sqlx::PgPool;
@@ -24,6 +32,7 @@ use tracing::error;
 pub async fn get_public_messages(
     pool: Data<PgPool>,
     query: Query<GetPublicMessagesParams>,
+    request_claims: ReqData<Claims>,
 ) -> impl Responder {
     let params = query.into_inner();
 
@@ -47,11 +56,21 @@ pub async fn get_public_messages(
             .json(AppError::BothHabitAndChallengePassed.to_response());
     }
 
+    let page = match PublicMessagePage::new(params.sort, params.cursor.as_deref(), params.limit) {
+        Some(page) => page,
+        None => {
+            return HttpResponse::BadRequest()
+                .json(AppError::InvalidPublicMessageCursor.to_response());
+        }
+    };
+
     // Create repositories
     let pool_clone = pool.get_ref().clone();
     let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
     let habit_repo = HabitRepositoryImpl::new(pool_clone.clone());
     let challenge_repo = ChallengeRepositoryImpl::new(pool_clone.clone());
+    let user_block_repo = UserBlockRepositoryImpl::new(pool_clone.clone());
+    let user_repo = UserRepositoryImpl::new(pool_clone.clone());
 
     // Check if habit exists
     if let Some(habit_id) = params.habit_id {
@@ -101,34 +120,129 @@ pub async fn get_public_messages(
         }
     }
 
+    // Get the languages the user reads, if asked to filter by language
+    let languages = if params.filter_by_language.unwrap_or(false) {
+        match user_repo
+            .get_by_id_with_executor(request_claims.user_id, &mut *transaction)
+            .await
+        {
+            Ok(Some(user)) => Some(user.readable_public_message_languages()),
+            Ok(None) => {
+                if let Err(e) = transaction.rollback().await {
+                    error!("Error rolling back: {}", e);
+                }
+                return HttpResponse::NotFound().json(AppError::UserNotFound.to_response());
+            }
+            Err(e) => {
+                error!("Error: {}", e);
+                if let Err(e) = transaction.rollback().await {
+                    error!("Error rolling back: {}", e);
+                }
+                return HttpResponse::InternalServerError()
+                    .json(AppError::DatabaseQuery.to_response());
+            }
+        }
+    } else {
+        None
+    };
+
     // Get messages
     let get_messages_result = if let Some(challenge_id) = params.challenge_id {
         message_repo
-            .get_by_challenge_id_with_executor(challenge_id, &mut *transaction)
+            .get_by_challenge_id_with_executor(
+                challenge_id,
+                request_claims.user_id,
+                &page,
+                languages.as_deref(),
+                &mut *transaction,
+            )
             .await
     } else if let Some(habit_id) = params.habit_id {
         message_repo
-            .get_by_habit_id_with_executor(habit_id, &mut *transaction)
+            .get_by_habit_id_with_executor(
+                habit_id,
+                request_claims.user_id,
+                &page,
+                languages.as_deref(),
+                &mut *transaction,
+            )
+            .await
+    } else {
+        Ok(vec![])
+    };
+
+    // Pinned messages come first, above the first page
+    let get_pinned_messages_result = if page.cursor.is_none() {
+        message_repo
+            .get_pinned_with_executor(params.habit_id, params.challenge_id, &mut *transaction)
             .await
     } else {
         Ok(vec![])
     };
 
+    // Pinned messages are not paged, so blocked creators are left out here
+    let blocked_ids_result = user_block_repo
+        .get_blocked_ids_with_executor(request_claims.user_id, &mut *transaction)
+        .await;
+
     if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
         return HttpResponse::InternalServerError()
             .json(AppError::DatabaseTransaction.to_response());
     }
 
-    match get_messages_result {
-        Ok(messages) => HttpResponse::Ok().json(PublicMessagesResponse {
-            code: "PUBLIC_MESSAGE_FETCHED".to_string(),
-            messages: messages
-                .iter()
-                .map(|m| m.to_public_message_data())
-                .collect(),
-        }),
+    let blocked_ids = match blocked_ids_result {
+        Ok(blocked_ids) => blocked_ids,
         Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response());
+        }
+    };
+
+    match (get_messages_result, get_pinned_messages_result) {
+        (Ok(messages), Ok(pinned_messages)) => {
+            let (messages, next_cursor) = page.split(messages);
+            let pinned_messages: Vec<_> = pinned_messages
+                .into_iter()
+                .filter(|m| !blocked_ids.contains(&m.creator))
+                .collect();
+
+            let mentions = match PublicMessageMentionRepositoryImpl::new(pool.get_ref().clone())
+                .get_by_message_ids(
+                    pinned_messages
+                        .iter()
+                        .chain(messages.iter())
+                        .map(|m| m.id)
+                        .collect(),
+                )
+                .await
+            {
+                Ok(mentions) => mentions,
+                Err(e) => {
+                    error!("Error: {}", e);
+                    return HttpResponse::InternalServerError()
+                        .json(AppError::DatabaseQuery.to_response());
+                }
+            };
+
+            HttpResponse::Ok().json(PublicMessagesResponse {
+                code: "PUBLIC_MESSAGE_FETCHED".to_string(),
+                pinned_messages: pinned_messages
+                    .iter()
+                    .map(|m| m.to_public_message_data())
+                    .collect(),
+                messages: messages
+                    .iter()
+                    .map(|m| m.to_public_message_data())
+                    .collect(),
+                mentions: mentions
+                    .iter()
+                    .map(|m| m.to_public_message_mention_data())
+                    .collect(),
+                next_cursor,
+            })
+        }
+        (Err(e), _) | (_, Err(e)) => {
             error!("Error: {}", e);
             HttpResponse::InternalServerError().json(AppError::PublicMessageCreation.to_response())
         }
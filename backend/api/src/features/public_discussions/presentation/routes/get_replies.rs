@@ -1,16 +1,26 @@
 use crate::{
     core::constants::errors::AppError,
-    features::public_discussions::{
-        application::dto::{
-            requests::public_message::GetPublicMessageRepliesParams,
-            responses::public_message::PublicMessagesResponse,
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::dto::{
+                requests::public_message::{
+                    GetPublicMessageRepliesParams, PublicMessagePageParams,
+                },
+                responses::public_message::PublicMessagesResponse,
+            },
+            domain::entities::public_message::PublicMessagePage,
+            domain::repositories::public_message_mention_repository::PublicMessageMentionRepository,
+            infrastructure::repositories::{
+                public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+            },
         },
-        infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl,
     },
 };
 use actix_web::{
     get,
-    web::{Data, Path},
+    web::{Data, Path, Query, ReqData},
     HttpResponse, Responder,
 };
 use sqlx::PgPool;
@@ -20,7 +30,17 @@ use tracing::error;
 pub async fn get_replies(
     pool: Data<PgPool>,
     params: Path<GetPublicMessageRepliesParams>,
+    query: Query<PublicMessagePageParams>,
+    request_claims: ReqData<Claims>,
 ) -> impl Responder {
+    let page = match PublicMessagePage::new(query.sort, query.cursor.as_deref(), query.limit) {
+        Some(page) => page,
+        None => {
+            return HttpResponse::BadRequest()
+                .json(AppError::InvalidPublicMessageCursor.to_response());
+        }
+    };
+
     let mut transaction = match pool.begin().await {
         Ok(t) => t,
         Err(e) => {
@@ -30,7 +50,7 @@ pub async fn get_replies(
         }
     };
 
-    // Create repository
+    // Create repositories
     let pool_clone = pool.get_ref().clone();
     let message_repo = PublicMessageRepositoryImpl::new(pool_clone);
 
@@ -57,7 +77,12 @@ pub async fn get_replies(
 
     // Get replies
     let get_messages_result = message_repo
-        .get_replies_with_executor(params.message_id, &mut *transaction)
+        .get_replies_with_executor(
+            params.message_id,
+            request_claims.user_id,
+            &page,
+            &mut *transaction,
+        )
         .await;
 
     if let Err(e) = transaction.commit().await {
@@ -67,13 +92,35 @@ pub async fn get_replies(
     }
 
     match get_messages_result {
-        Ok(messages) => HttpResponse::Ok().json(PublicMessagesResponse {
-            code: "PUBLIC_MESSAGE_FETCHED".to_string(),
-            messages: messages
-                .iter()
-                .map(|m| m.to_public_message_data())
-                .collect(),
-        }),
+        Ok(messages) => {
+            let (messages, next_cursor) = page.split(messages);
+
+            let mentions = match PublicMessageMentionRepositoryImpl::new(pool.get_ref().clone())
+                .get_by_message_ids(messages.iter().map(|m| m.id).collect())
+                .await
+            {
+                Ok(mentions) => mentions,
+                Err(e) => {
+                    error!("Error: {}", e);
+                    return HttpResponse::InternalServerError()
+                        .json(AppError::DatabaseQuery.to_response());
+                }
+            };
+
+            HttpResponse::Ok().json(PublicMessagesResponse {
+                code: "PUBLIC_MESSAGE_FETCHED".to_string(),
+                pinned_messages: vec![],
+                messages: messages
+                    .iter()
+                    .map(|m| m.to_public_message_data())
+                    .collect(),
+                mentions: mentions
+                    .iter()
+                    .map(|m| m.to_public_message_mention_data())
+                    .collect(),
+                next_cursor,
+            })
+        }
         Err(e) => {
             error!("Error: {}", e);
             HttpResponse::InternalServerError().json(AppError::PublicMessageCreation.to_response())
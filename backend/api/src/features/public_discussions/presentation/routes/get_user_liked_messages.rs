@@ -3,15 +3,28 @@ use crate::{
     features::{
         auth::domain::entities::Claims,
         public_discussions::{
-            application::dto::responses::public_message::PublicMessagesResponse,
-            domain::repositories::public_message_like_repository::PublicMessageLikeRepository,
-            infrastructure::repositories::public_message_like_repository::PublicMessageLikeRepositoryImpl,
+            application::dto::{
+                requests::public_message::PublicMessagePageParams,
+                responses::public_message::PublicMessagesResponse,
+            },
+            domain::entities::{
+                public_message::PublicMessagePage,
+                public_message_reaction::PublicMessageReactionKind,
+            },
+            domain::repositories::{
+                public_message_mention_repository::PublicMessageMentionRepository,
+                public_message_reaction_repository::PublicMessageReactionRepository,
+            },
+            infrastructure::repositories::{
+                public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+                public_message_reaction_repository::PublicMessageReactionRepositoryImpl,
+            },
         },
     },
 };
 use actix_web::{
     get,
-    web::{Data, ReqData},
+    web::{Data, Query, ReqData},
     HttpResponse, Responder,
 };
 use sqlx::PgPool;
@@ -20,21 +33,58 @@ use tracing::error;
 #[get("/liked/")]
 pub async fn get_user_liked_messages(
     pool: Data<PgPool>,
+    query: Query<PublicMessagePageParams>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
+    let page = match PublicMessagePage::new(query.sort, query.cursor.as_deref(), query.limit) {
+        Some(page) => page,
+        None => {
+            return HttpResponse::BadRequest()
+                .json(AppError::InvalidPublicMessageCursor.to_response());
+        }
+    };
+
     let pool_clone = pool.get_ref().clone();
-    let like_repo = PublicMessageLikeRepositoryImpl::new(pool_clone);
+    let reaction_repo = PublicMessageReactionRepositoryImpl::new(pool_clone);
 
-    let get_messages_result = like_repo.get_messages_by_user(request_claims.user_id).await;
+    let get_messages_result = reaction_repo
+        .get_messages_by_user_and_reaction(
+            request_claims.user_id,
+            PublicMessageReactionKind::Like.as_str(),
+            &page,
+        )
+        .await;
 
     match get_messages_result {
-        Ok(messages) => HttpResponse::Ok().json(PublicMessagesResponse {
-            code: "PUBLIC_MESSAGE_FETCHED".to_string(),
-            messages: messages
-                .iter()
-                .map(|m| m.to_public_message_data())
-                .collect(),
-        }),
+        Ok(messages) => {
+            let (messages, next_cursor) = page.split(messages);
+
+            let mentions = match PublicMessageMentionRepositoryImpl::new(pool.get_ref().clone())
+                .get_by_message_ids(messages.iter().map(|m| m.id).collect())
+                .await
+            {
+                Ok(mentions) => mentions,
+                Err(e) => {
+                    error!("Error: {}", e);
+                    return HttpResponse::InternalServerError()
+                        .json(AppError::DatabaseQuery.to_response());
+                }
+            };
+
+            HttpResponse::Ok().json(PublicMessagesResponse {
+                code: "PUBLIC_MESSAGE_FETCHED".to_string(),
+                pinned_messages: vec![],
+                messages: messages
+                    .iter()
+                    .map(|m| m.to_public_message_data())
+                    .collect(),
+                mentions: mentions
+                    .iter()
+                    .map(|m| m.to_public_message_mention_data())
+                    .collect(),
+                next_cursor,
+            })
+        }
         Err(e) => {
             error!("Error: {}", e);
             HttpResponse::InternalServerError().json(AppError::PublicMessageCreation.to_response())
@@ -2,16 +2,29 @@
 
 pub mod create_public_message;
 pub mod create_public_message_like;
+pub mod create_public_message_reaction;
 pub mod create_public_message_report;
+pub mod create_public_message_subscription;
 pub mod delete_public_message;
 pub mod delete_public_message_like;
+pub mod delete_public_message_reaction;
 pub mod delete_public_message_report;
+pub mod delete_public_message_subscription;
 pub mod get_message;
 pub mod get_message_parents;
 pub mod get_message_reports;
+pub mod get_moderation_decisions;
+pub mod get_moderation_queue;
+pub mod get_public_message_reactions;
+pub mod get_public_message_revisions;
+pub mod get_public_message_subscriptions;
 pub mod get_public_messages;
 pub mod get_replies;
 pub mod get_user_liked_messages;
 pub mod get_user_message_reports;
 pub mod get_user_written_messages;
+pub mod pin_public_message;
+pub mod resolve_public_message_reports;
+pub mod unpin_public_message;
 pub mod update_public_message;
+pub mod update_public_message_subscription;
@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{constants::errors::AppError, helpers::translation::Translator},
+    features::{
+        auth::domain::entities::Claims,
+        challenges::infrastructure::repositories::{
+            challenge_participation_repository::ChallengeParticipationRepositoryImpl,
+            challenge_repository::ChallengeRepositoryImpl,
+        },
+        notifications::infrastructure::services::notification_service::{
+            NotificationContent, NotificationService,
+        },
+        profile::domain::entities::UserPublicDataCache,
+        public_discussions::{
+            application::dto::{
+                requests::public_message::{GetPublicMessageParams, PublicMessagePinRequest},
+                responses::public_message::PublicMessageResponse,
+            },
+            application::use_cases::pin_public_message::PinPublicMessageUseCase,
+            domain::entities::public_message::PublicMessage,
+            infrastructure::repositories::{
+                public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+            },
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, Path, ReqData},
+    HttpResponse, Responder,
+};
+use fluent::FluentArgs;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[post("/{message_id}/pin")]
+pub async fn pin_public_message(
+    pool: Data<PgPool>,
+    params: Path<GetPublicMessageParams>,
+    body: Json<PublicMessagePinRequest>,
+    redis_client: Data<Client>,
+    translator: Data<Arc<Translator>>,
+    user_public_data_cache: Data<UserPublicDataCache>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+    let challenge_repo = ChallengeRepositoryImpl::new(pool_clone.clone());
+
+    let use_case = PinPublicMessageUseCase::new(message_repo, challenge_repo);
+    let result = use_case
+        .execute(
+            params.message_id,
+            request_claims.user_id,
+            request_claims.has_permission(Permission::ModeratePublicMessages),
+            body.is_announcement,
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok((message, newly_announced)) => {
+            if newly_announced {
+                notify_challenge_participants(
+                    &pool,
+                    &message,
+                    &request_claims,
+                    redis_client,
+                    &translator,
+                    &user_public_data_cache,
+                )
+                .await;
+            }
+
+            let mentions = PublicMessageMentionRepositoryImpl::new(pool.get_ref().clone())
+                .get_by_message_ids_with_executor(vec![message.id], pool.get_ref())
+                .await
+                .unwrap_or_default();
+
+            HttpResponse::Ok().json(PublicMessageResponse {
+                code: "PUBLIC_MESSAGE_PINNED".to_string(),
+                message: Some(message.to_public_message_data()),
+                mentions: mentions
+                    .iter()
+                    .map(|m| m.to_public_message_mention_data())
+                    .collect(),
+            })
+        }
+        Err(AppError::PublicMessageNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageNotFound.to_response())
+        }
+        Err(AppError::PublicMessagePinNotAllowed) => {
+            HttpResponse::Forbidden().json(AppError::PublicMessagePinNotAllowed.to_response())
+        }
+        Err(AppError::PublicMessagePinLimitReached) => {
+            HttpResponse::BadRequest().json(AppError::PublicMessagePinLimitReached.to_response())
+        }
+        Err(AppError::PublicMessagePinOnlyForRootMessages) => HttpResponse::BadRequest()
+            .json(AppError::PublicMessagePinOnlyForRootMessages.to_response()),
+        Err(AppError::PublicMessageAnnouncementOnlyInChallenges) => HttpResponse::BadRequest()
+            .json(AppError::PublicMessageAnnouncementOnlyInChallenges.to_response()),
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
+
+// Tells every participant of the challenge about the announcement
+async fn notify_challenge_participants(
+    pool: &PgPool,
+    message: &PublicMessage,
+    request_claims: &Claims,
+    redis_client: Data<Client>,
+    translator: &Translator,
+    user_public_data_cache: &UserPublicDataCache,
+) {
+    let Some(challenge_id) = message.challenge_id else {
+        return;
+    };
+
+    let notification_service = NotificationService::new(pool.clone());
+    let mut notif_transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return;
+        }
+    };
+
+    let participations = match ChallengeParticipationRepositoryImpl::new(pool.clone())
+        .get_by_challenge_id_with_executor(challenge_id, &mut *notif_transaction)
+        .await
+    {
+        Ok(participations) => participations,
+        Err(e) => {
+            error!("Error: {}", e);
+            let _ = notif_transaction.rollback().await;
+            return;
+        }
+    };
+
+    let Some(author) = user_public_data_cache
+        .get_value_for_key_or_insert_it(&message.creator, &mut notif_transaction)
+        .await
+    else {
+        let _ = notif_transaction.rollback().await;
+        return;
+    };
+
+    let url = format!("/challenges/{}/null/threads/{}", challenge_id, message.id);
+
+    // A user can take part several times in the same challenge
+    let mut user_ids: Vec<_> = participations.iter().map(|p| p.user_id).collect();
+    user_ids.sort();
+    user_ids.dedup();
+
+    for user_id in user_ids {
+        if user_id == request_claims.user_id {
+            continue;
+        }
+
+        let Some(participant) = user_public_data_cache
+            .get_value_for_key_or_insert_it(&user_id, &mut notif_transaction)
+            .await
+        else {
+            continue;
+        };
+
+        let mut args = FluentArgs::new();
+        args.set("username", author.username.to_owned());
+
+        notification_service
+            .generate_notification(
+                &mut notif_transaction,
+                Some(message.creator),
+                user_id,
+                NotificationContent {
+                    title: &translator.translate(
+                        &participant.locale,
+                        "challenge-announcement-title",
+                        None,
+                    ),
+                    body: &translator.translate(
+                        &participant.locale,
+                        "challenge-announcement-body",
+                        Some(args),
+                    ),
+                    notification_type: "public_message_announcement",
+                    url: Some(url.clone()),
+                },
+                redis_client.clone(),
+            )
+            .await;
+    }
+
+    let _ = notif_transaction.commit().await;
+}
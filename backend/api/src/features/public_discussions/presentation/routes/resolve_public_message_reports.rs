@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{
+        constants::errors::AppError, helpers::translation::Translator,
+        presentation::guards::permission::require_permission,
+    },
+    features::{
+        attachments::{
+            application::use_cases::delete_public_message_attachments::DeletePublicMessageAttachmentsUseCase,
+            domain::services::storage_backend::{delete_attachment_files, StorageBackend},
+            infrastructure::repositories::attachment_repository::AttachmentRepositoryImpl,
+        },
+        auth::domain::entities::Claims,
+        profile::domain::entities::UserPublicDataCache,
+        public_discussions::{
+            application::{
+                dto::{
+                    requests::{
+                        public_message::GetPublicMessageParams,
+                        public_message_moderation::ResolvePublicMessageReportsRequest,
+                    },
+                    responses::public_message_moderation::ModerationDecisionResponse,
+                },
+                use_cases::resolve_public_message_reports::ResolvePublicMessageReportsUseCase,
+            },
+            domain::entities::public_message_moderation::{
+                ModerationAction, MODERATION_DEFAULT_SUSPENSION_IN_DAYS,
+            },
+            infrastructure::repositories::{
+                public_message_moderation_decision_repository::PublicMessageModerationDecisionRepositoryImpl,
+                public_message_report_repository::PublicMessageReportRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+                user_suspension_repository::UserSuspensionRepositoryImpl,
+            },
+            infrastructure::services::moderation_notification_service::ModerationNotificationService,
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, Path, ReqData},
+    HttpResponse, Responder,
+};
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[allow(clippy::too_many_arguments)]
+#[post("/{message_id}/resolve")]
+pub async fn resolve_public_message_reports(
+    pool: Data<PgPool>,
+    storage_backend: Data<dyn StorageBackend>,
+    params: Path<GetPublicMessageParams>,
+    body: Json<ResolvePublicMessageReportsRequest>,
+    redis_client: Data<Client>,
+    translator: Data<Arc<Translator>>,
+    user_public_data_cache: Data<UserPublicDataCache>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ModeratePublicMessages) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = ResolvePublicMessageReportsUseCase::new(
+        PublicMessageReportRepositoryImpl::new(pool_clone.clone()),
+        PublicMessageRepositoryImpl::new(pool_clone.clone()),
+        PublicMessageModerationDecisionRepositoryImpl::new(pool_clone.clone()),
+        UserSuspensionRepositoryImpl::new(pool_clone.clone()),
+    );
+
+    let (decision, message, reporters) = match use_case
+        .execute(
+            params.message_id,
+            request_claims.user_id,
+            body.action,
+            body.note,
+            body.suspension_in_days,
+            request_claims.has_permission(Permission::BlockReportedUsers),
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::MissingPermission => HttpResponse::Forbidden().json(e.to_response()),
+                AppError::PublicMessageNotFound | AppError::PublicMessageHasNoPendingReports => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                AppError::ModerationNoteEmpty
+                | AppError::ModerationNoteTooLong
+                | AppError::ModerationSuspensionDurationInvalid => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    // Images of a deleted message go with it
+    let attachments = if body.action == ModerationAction::Delete {
+        match DeletePublicMessageAttachmentsUseCase::new(AttachmentRepositoryImpl::new(pool_clone))
+            .execute(params.message_id, &mut transaction)
+            .await
+        {
+            Ok(attachments) => attachments,
+            Err(e) => {
+                error!("Error: {:?}", e);
+                if let Err(e) = transaction.rollback().await {
+                    error!("Error rolling back: {}", e);
+                }
+                return HttpResponse::InternalServerError().json(e.to_response());
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    delete_attachment_files(storage_backend.get_ref(), &attachments).await;
+
+    // Tell reporters and, when warned or suspended, the author about the outcome
+    ModerationNotificationService::new(pool.get_ref().clone())
+        .notify(
+            &decision,
+            body.action,
+            body.suspension_in_days
+                .unwrap_or(MODERATION_DEFAULT_SUSPENSION_IN_DAYS),
+            message.creator,
+            &reporters,
+            redis_client,
+            &translator,
+            &user_public_data_cache,
+        )
+        .await;
+
+    HttpResponse::Ok().json(ModerationDecisionResponse {
+        code: "MODERATION_DECISION_CREATED".to_string(),
+        decision: Some(decision.to_public_message_moderation_decision_data()),
+        message: Some(message.to_public_message_data()),
+    })
+}
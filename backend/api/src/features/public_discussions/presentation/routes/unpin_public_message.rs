@@ -0,0 +1,93 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        challenges::infrastructure::repositories::challenge_repository::ChallengeRepositoryImpl,
+        public_discussions::{
+            application::dto::{
+                requests::public_message::GetPublicMessageParams,
+                responses::public_message::PublicMessageResponse,
+            },
+            application::use_cases::unpin_public_message::UnpinPublicMessageUseCase,
+            infrastructure::repositories::{
+                public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+            },
+        },
+        roles::domain::entities::permission::Permission,
+    },
+};
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/{message_id}/pin")]
+pub async fn unpin_public_message(
+    pool: Data<PgPool>,
+    params: Path<GetPublicMessageParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let message_repo = PublicMessageRepositoryImpl::new(pool_clone.clone());
+    let challenge_repo = ChallengeRepositoryImpl::new(pool_clone.clone());
+
+    let use_case = UnpinPublicMessageUseCase::new(message_repo, challenge_repo);
+    let result = use_case
+        .execute(
+            params.message_id,
+            request_claims.user_id,
+            request_claims.has_permission(Permission::ModeratePublicMessages),
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(message) => {
+            let mentions = PublicMessageMentionRepositoryImpl::new(pool.get_ref().clone())
+                .get_by_message_ids_with_executor(vec![message.id], pool.get_ref())
+                .await
+                .unwrap_or_default();
+
+            HttpResponse::Ok().json(PublicMessageResponse {
+                code: "PUBLIC_MESSAGE_UNPINNED".to_string(),
+                message: Some(message.to_public_message_data()),
+                mentions: mentions
+                    .iter()
+                    .map(|m| m.to_public_message_mention_data())
+                    .collect(),
+            })
+        }
+        Err(AppError::PublicMessageNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageNotFound.to_response())
+        }
+        Err(AppError::PublicMessagePinNotAllowed) => {
+            HttpResponse::Forbidden().json(AppError::PublicMessagePinNotAllowed.to_response())
+        }
+        Err(AppError::PublicMessagePinOnlyForRootMessages) => HttpResponse::BadRequest()
+            .json(AppError::PublicMessagePinOnlyForRootMessages.to_response()),
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
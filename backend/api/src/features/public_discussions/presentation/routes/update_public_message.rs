@@ -1,14 +1,35 @@
+use std::sync::Arc;
+
 use crate::{
-    core::{constants::errors::AppError, helpers::mock_now::now},
+    core::{
+        constants::errors::AppError,
+        helpers::{mock_now::now, translation::Translator},
+    },
     features::{
         auth::domain::entities::Claims,
+        content_filters::{
+            application::use_cases::apply_content_filter::{
+                content_filter_locales, ApplyContentFilterUseCase,
+            },
+            infrastructure::repositories::{
+                content_filter_flag_repository::ContentFilterFlagRepositoryImpl,
+                content_filter_rule_repository::ContentFilterRuleRepositoryImpl,
+            },
+        },
+        profile::domain::entities::UserPublicDataCache,
         public_discussions::{
             application::dto::{
                 requests::public_message::{PublicMessageUpdateRequest, UpdatePublicMessageParams},
                 responses::public_message::PublicMessageResponse,
             },
             application::use_cases::update_public_message::UpdatePublicMessageUseCase,
-            infrastructure::repositories::public_message_repository::PublicMessageRepositoryImpl,
+            infrastructure::repositories::{
+                public_message_mention_repository::PublicMessageMentionRepositoryImpl,
+                public_message_repository::PublicMessageRepositoryImpl,
+                public_message_revision_repository::PublicMessageRevisionRepositoryImpl,
+            },
+            infrastructure::services::language_service::LanguageService,
+            presentation::routes::create_public_message::notify_mentioned_users,
         },
     },
 };
@@ -17,6 +38,7 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
     HttpResponse, Responder,
 };
+use redis::Client;
 use sqlx::PgPool;
 use tracing::error;
 
@@ -25,6 +47,9 @@ pub async fn update_public_message(
     pool: Data<PgPool>,
     params: Path<UpdatePublicMessageParams>,
     body: Json<PublicMessageUpdateRequest>,
+    redis_client: Data<Client>,
+    translator: Data<Arc<Translator>>,
+    user_public_data_cache: Data<UserPublicDataCache>,
     request_claims: ReqData<Claims>,
 ) -> impl Responder {
     let mut transaction = match pool.begin().await {
@@ -64,24 +89,78 @@ pub async fn update_public_message(
     };
 
     public_message.content = body.content.to_owned();
+    public_message.language_code = LanguageService::new().detect_language(&body.content);
     public_message.updated_at = Some(now());
 
-    let use_case = UpdatePublicMessageUseCase::new(message_repo);
+    let revision_repo = PublicMessageRevisionRepositoryImpl::new(pool_clone.clone());
+
+    let mention_repo = PublicMessageMentionRepositoryImpl::new(pool_clone.clone());
+
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool_clone.clone()),
+        ContentFilterFlagRepositoryImpl::new(pool_clone.clone()),
+    );
+
+    let author_locale = user_public_data_cache
+        .get_value_for_key_or_insert_it(&request_claims.user_id, &mut transaction)
+        .await
+        .map(|author| author.locale);
+    let locales = content_filter_locales(
+        author_locale.as_deref(),
+        public_message.language_code.as_deref(),
+    );
+
+    let use_case =
+        UpdatePublicMessageUseCase::new(message_repo, revision_repo, mention_repo, content_filter);
     let result = use_case
-        .execute(&public_message, request_claims.user_id, &mut transaction)
+        .execute(
+            &mut public_message,
+            request_claims.user_id,
+            &locales,
+            &mut transaction,
+        )
         .await;
 
-    if let Err(e) = transaction.commit().await {
+    // Nothing is kept when the update is refused
+    if result.is_err() {
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+    } else if let Err(e) = transaction.commit().await {
         error!("Error: {}", e);
         return HttpResponse::InternalServerError()
             .json(AppError::DatabaseTransaction.to_response());
     }
 
     match result {
-        Ok(_) => HttpResponse::Ok().json(PublicMessageResponse {
-            code: "PUBLIC_MESSAGE_UPDATED".to_string(),
-            message: Some(public_message.to_public_message_data()),
-        }),
+        Ok(mentioned_user_ids) => {
+            notify_mentioned_users(
+                &pool,
+                &public_message,
+                mentioned_user_ids,
+                redis_client,
+                &translator,
+                &user_public_data_cache,
+            )
+            .await;
+
+            let mentions = PublicMessageMentionRepositoryImpl::new(pool.get_ref().clone())
+                .get_by_message_ids_with_executor(vec![public_message.id], pool.get_ref())
+                .await
+                .unwrap_or_default();
+
+            HttpResponse::Ok().json(PublicMessageResponse {
+                code: "PUBLIC_MESSAGE_UPDATED".to_string(),
+                message: Some(public_message.to_public_message_data()),
+                mentions: mentions
+                    .iter()
+                    .map(|m| m.to_public_message_mention_data())
+                    .collect(),
+            })
+        }
+        Err(AppError::ContentRejectedByFilter) => {
+            HttpResponse::BadRequest().json(AppError::ContentRejectedByFilter.to_response())
+        }
         Err(e) => {
             error!("Error: {:?}", e);
             HttpResponse::InternalServerError().json(e.to_response())
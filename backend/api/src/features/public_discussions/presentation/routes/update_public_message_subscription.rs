@@ -0,0 +1,73 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        public_discussions::{
+            application::dto::{
+                requests::public_message_subscription::{
+                    PublicMessageSubscriptionParams, PublicMessageSubscriptionUpdateRequest,
+                },
+                responses::public_message_subscription::PublicMessageSubscriptionResponse,
+            },
+            application::use_cases::update_public_message_subscription::UpdatePublicMessageSubscriptionUseCase,
+            infrastructure::repositories::public_message_subscription_repository::PublicMessageSubscriptionRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    put,
+    web::{Data, Json, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[put("/{subscription_id}")]
+pub async fn update_public_message_subscription(
+    pool: Data<PgPool>,
+    params: Path<PublicMessageSubscriptionParams>,
+    body: Json<PublicMessageSubscriptionUpdateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let subscription_repo = PublicMessageSubscriptionRepositoryImpl::new(pool.get_ref().clone());
+
+    let use_case = UpdatePublicMessageSubscriptionUseCase::new(subscription_repo);
+    let result = use_case
+        .execute(
+            params.subscription_id,
+            request_claims.user_id,
+            body.muted,
+            &mut transaction,
+        )
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(subscription) => HttpResponse::Ok().json(PublicMessageSubscriptionResponse {
+            code: "PUBLIC_MESSAGE_SUBSCRIPTION_UPDATED".to_string(),
+            subscription: Some(subscription.to_public_message_subscription_data()),
+        }),
+        Err(AppError::PublicMessageSubscriptionNotFound) => {
+            HttpResponse::NotFound().json(AppError::PublicMessageSubscriptionNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
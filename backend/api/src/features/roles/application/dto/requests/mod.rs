@@ -0,0 +1,3 @@
+// Roles request DTOs
+
+pub mod role_grant;
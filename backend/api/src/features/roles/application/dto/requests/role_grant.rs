@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize)]
+pub struct RoleGrantCreateRequest {
+    pub user_id: Uuid,
+    pub role_id: Uuid,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+pub struct DeleteRoleGrantParams {
+    pub grant_id: Uuid,
+}
+
+#[derive(Deserialize)]
+pub struct GetUserRoleGrantsParams {
+    pub user_id: Uuid,
+}
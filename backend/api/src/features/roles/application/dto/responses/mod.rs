@@ -0,0 +1,4 @@
+// Roles response DTOs
+
+pub mod role;
+pub mod role_grant;
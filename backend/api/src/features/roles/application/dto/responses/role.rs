@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::roles::domain::entities::role::RoleData;
+
+#[derive(Serialize, Deserialize)]
+pub struct RolesResponse {
+    pub code: String,
+    pub roles: Vec<RoleData>,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::roles::domain::entities::role_grant::RoleGrantData;
+
+#[derive(Serialize, Deserialize)]
+pub struct RoleGrantResponse {
+    pub code: String,
+    pub role_grant: Option<RoleGrantData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RoleGrantsResponse {
+    pub code: String,
+    pub role_grants: Vec<RoleGrantData>,
+}
@@ -0,0 +1,60 @@
+// Create role grant use case
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::roles::domain::entities::role_grant::RoleGrant;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
+use crate::features::roles::infrastructure::repositories::role_repository::RoleRepositoryImpl;
+
+pub struct CreateRoleGrantUseCase {
+    role_grant_repo: RoleGrantRepositoryImpl,
+    role_repo: RoleRepositoryImpl,
+    user_repo: UserRepositoryImpl,
+}
+
+impl CreateRoleGrantUseCase {
+    pub fn new(
+        role_grant_repo: RoleGrantRepositoryImpl,
+        role_repo: RoleRepositoryImpl,
+        user_repo: UserRepositoryImpl,
+    ) -> Self {
+        Self {
+            role_grant_repo,
+            role_repo,
+            user_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        grant: &RoleGrant,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<RoleGrant, AppError> {
+        if grant
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= now())
+        {
+            return Err(AppError::RoleGrantExpirationInPast);
+        }
+
+        // Verify user exists
+        self.user_repo
+            .get_by_id_with_executor(grant.user_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::UserNotFound)?;
+
+        // Verify role exists
+        self.role_repo
+            .get_by_id_with_executor(grant.role_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::RoleNotFound)?;
+
+        self.role_grant_repo
+            .create_with_executor(grant, &mut **transaction)
+            .await
+            .map_err(|_| AppError::RoleGrantCreation)
+    }
+}
@@ -0,0 +1,35 @@
+// Delete role grant use case
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
+
+pub struct DeleteRoleGrantUseCase {
+    role_grant_repo: RoleGrantRepositoryImpl,
+}
+
+impl DeleteRoleGrantUseCase {
+    pub fn new(role_grant_repo: RoleGrantRepositoryImpl) -> Self {
+        Self { role_grant_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        grant_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        self.role_grant_repo
+            .get_by_id_with_executor(grant_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::RoleGrantNotFound)?;
+
+        self.role_grant_repo
+            .delete_with_executor(grant_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::RoleGrantDeletion)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+// Get user permissions use case - resolves the permissions embedded in the user's tokens
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::profile::domain::entities::User;
+use crate::features::roles::domain::entities::permission::Permission;
+use crate::features::roles::infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl;
+
+pub struct GetUserPermissionsUseCase {
+    role_grant_repo: RoleGrantRepositoryImpl,
+}
+
+impl GetUserPermissionsUseCase {
+    pub fn new(role_grant_repo: RoleGrantRepositoryImpl) -> Self {
+        Self { role_grant_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        user: &User,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<Permission>, AppError> {
+        // Administrators implicitly hold every permission
+        if user.is_admin {
+            return Ok(Permission::ALL.to_vec());
+        }
+
+        let permissions = self
+            .role_grant_repo
+            .get_active_permissions_by_user_id_with_executor(user.id, now(), &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        Ok(permissions
+            .iter()
+            .filter_map(|p| p.parse::<Permission>().ok())
+            .collect())
+    }
+}
@@ -0,0 +1,5 @@
+// Roles use cases
+
+pub mod create_role_grant;
+pub mod delete_role_grant;
+pub mod get_user_permissions;
@@ -0,0 +1,5 @@
+// Roles domain entities
+
+pub mod permission;
+pub mod role;
+pub mod role_grant;
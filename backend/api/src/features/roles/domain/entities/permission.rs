@@ -0,0 +1,57 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    BlockReportedUsers,
+    ManageChallenges,
+    ManageContentFilters,
+    ManageHabits,
+    ManageRoles,
+    ModeratePublicMessages,
+    // Gives access to the private messages disclosed by reporters
+    ReviewPrivateReports,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 7] = [
+        Permission::BlockReportedUsers,
+        Permission::ManageChallenges,
+        Permission::ManageContentFilters,
+        Permission::ManageHabits,
+        Permission::ManageRoles,
+        Permission::ModeratePublicMessages,
+        Permission::ReviewPrivateReports,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::BlockReportedUsers => "block_reported_users",
+            Permission::ManageChallenges => "manage_challenges",
+            Permission::ManageContentFilters => "manage_content_filters",
+            Permission::ManageHabits => "manage_habits",
+            Permission::ManageRoles => "manage_roles",
+            Permission::ModeratePublicMessages => "moderate_public_messages",
+            Permission::ReviewPrivateReports => "review_private_reports",
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Permission {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Permission::ALL
+            .into_iter()
+            .find(|permission| permission.as_str() == s)
+            .ok_or_else(|| format!("Unknown permission: {}", s))
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+use super::permission::Permission;
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct Role {
+    pub id: Uuid,
+    pub name: String,
+    pub permissions: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RoleData {
+    pub id: Uuid,
+    pub name: String,
+    pub permissions: Vec<Permission>,
+}
+
+impl Role {
+    // Unknown permission names (e.g. removed from the code but still stored) are ignored.
+    pub fn get_permissions(&self) -> Vec<Permission> {
+        self.permissions
+            .iter()
+            .filter_map(|p| p.parse::<Permission>().ok())
+            .collect()
+    }
+
+    pub fn to_role_data(&self) -> RoleData {
+        RoleData {
+            id: self.id,
+            name: self.name.clone(),
+            permissions: self.get_permissions(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct RoleGrant {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub role_id: Uuid,
+    pub granted_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RoleGrantData {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub role_id: Uuid,
+    pub granted_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl RoleGrant {
+    pub fn is_active(&self, at: DateTime<Utc>) -> bool {
+        self.expires_at.is_none_or(|expires_at| expires_at > at)
+    }
+
+    pub fn to_role_grant_data(&self) -> RoleGrantData {
+        RoleGrantData {
+            id: self.id,
+            user_id: self.user_id,
+            role_id: self.role_id,
+            granted_by: self.granted_by,
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+// Roles domain repository traits
+
+pub mod role_grant_repository;
+pub mod role_repository;
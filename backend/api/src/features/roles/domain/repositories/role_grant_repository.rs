@@ -0,0 +1,21 @@
+// RoleGrant repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::features::roles::domain::entities::role_grant::RoleGrant;
+
+#[async_trait]
+pub trait RoleGrantRepository: Send + Sync {
+    async fn create(&self, grant: &RoleGrant) -> Result<(), String>;
+    async fn delete(&self, grant_id: Uuid) -> Result<(), String>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
+    async fn get_by_id(&self, grant_id: Uuid) -> Result<Option<RoleGrant>, String>;
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<RoleGrant>, String>;
+    async fn get_active_permissions_by_user_id(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<String>, String>;
+}
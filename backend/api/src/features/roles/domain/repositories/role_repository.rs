@@ -0,0 +1,18 @@
+// Role repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::features::roles::domain::entities::role::Role;
+
+#[async_trait]
+pub trait RoleRepository: Send + Sync {
+    async fn get_all(&self) -> Result<Vec<Role>, String>;
+    async fn get_by_id(&self, role_id: Uuid) -> Result<Option<Role>, String>;
+    async fn get_active_by_user_id(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<Role>, String>;
+}
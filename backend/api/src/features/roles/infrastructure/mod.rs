@@ -0,0 +1,2 @@
+// Infrastructure layer - repository implementations
+pub mod repositories;
@@ -0,0 +1,4 @@
+// Roles infrastructure repository implementations
+
+pub mod role_grant_repository;
+pub mod role_repository;
@@ -0,0 +1,210 @@
+// RoleGrantRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::roles::domain::entities::role_grant::RoleGrant;
+use crate::features::roles::domain::repositories::role_grant_repository::RoleGrantRepository;
+
+pub struct RoleGrantRepositoryImpl {
+    pool: PgPool,
+}
+
+impl RoleGrantRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    // Granting a role the user already has refreshes the grant instead of duplicating it.
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        grant: &RoleGrant,
+        executor: E,
+    ) -> Result<RoleGrant, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            RoleGrant,
+            r#"
+            INSERT INTO user_role_grants (
+                id,
+                user_id,
+                role_id,
+                granted_by,
+                created_at,
+                expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (user_id, role_id) DO UPDATE
+            SET granted_by = EXCLUDED.granted_by,
+                created_at = EXCLUDED.created_at,
+                expires_at = EXCLUDED.expires_at
+            RETURNING *
+            "#,
+            grant.id,
+            grant.user_id,
+            grant.role_id,
+            grant.granted_by,
+            grant.created_at,
+            grant.expires_at
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        grant_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM user_role_grants
+            WHERE id = $1
+            "#,
+            grant_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM user_role_grants
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        grant_id: Uuid,
+        executor: E,
+    ) -> Result<Option<RoleGrant>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            RoleGrant,
+            r#"
+            SELECT *
+            FROM user_role_grants
+            WHERE id = $1
+            "#,
+            grant_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn get_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<RoleGrant>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            RoleGrant,
+            r#"
+            SELECT *
+            FROM user_role_grants
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_active_permissions_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<String>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_scalar!(
+            r#"
+            SELECT DISTINCT unnest(r.permissions) as "permission!"
+            FROM user_role_grants g
+            JOIN roles r ON r.id = g.role_id
+            WHERE g.user_id = $1
+            AND (g.expires_at IS NULL OR g.expires_at > $2)
+            "#,
+            user_id,
+            at
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl RoleGrantRepository for RoleGrantRepositoryImpl {
+    async fn create(&self, grant: &RoleGrant) -> Result<(), String> {
+        self.create_with_executor(grant, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete(&self, grant_id: Uuid) -> Result<(), String> {
+        self.delete_with_executor(grant_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
+        self.delete_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_id(&self, grant_id: Uuid) -> Result<Option<RoleGrant>, String> {
+        self.get_by_id_with_executor(grant_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<RoleGrant>, String> {
+        self.get_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_active_permissions_by_user_id(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<String>, String> {
+        self.get_active_permissions_by_user_id_with_executor(user_id, at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
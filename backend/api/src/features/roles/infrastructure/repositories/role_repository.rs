@@ -0,0 +1,109 @@
+// RoleRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::roles::domain::entities::role::Role;
+use crate::features::roles::domain::repositories::role_repository::RoleRepository;
+
+pub struct RoleRepositoryImpl {
+    pool: PgPool,
+}
+
+impl RoleRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn get_all_with_executor<'a, E>(&self, executor: E) -> Result<Vec<Role>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            Role,
+            r#"
+            SELECT *
+            FROM roles
+            ORDER BY name
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        role_id: Uuid,
+        executor: E,
+    ) -> Result<Option<Role>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            Role,
+            r#"
+            SELECT *
+            FROM roles
+            WHERE id = $1
+            "#,
+            role_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn get_active_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<Role>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            Role,
+            r#"
+            SELECT r.*
+            FROM roles r
+            JOIN user_role_grants g ON g.role_id = r.id
+            WHERE g.user_id = $1
+            AND (g.expires_at IS NULL OR g.expires_at > $2)
+            ORDER BY r.name
+            "#,
+            user_id,
+            at
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl RoleRepository for RoleRepositoryImpl {
+    async fn get_all(&self) -> Result<Vec<Role>, String> {
+        self.get_all_with_executor(&self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_id(&self, role_id: Uuid) -> Result<Option<Role>, String> {
+        self.get_by_id_with_executor(role_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_active_by_user_id(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<Role>, String> {
+        self.get_active_by_user_id_with_executor(user_id, at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    core::{
+        constants::errors::AppError, helpers::mock_now::now,
+        presentation::guards::permission::require_permission,
+    },
+    features::{
+        auth::domain::entities::Claims,
+        profile::infrastructure::repositories::user_repository::UserRepositoryImpl,
+        roles::{
+            application::{
+                dto::{
+                    requests::role_grant::RoleGrantCreateRequest,
+                    responses::role_grant::RoleGrantResponse,
+                },
+                use_cases::create_role_grant::CreateRoleGrantUseCase,
+            },
+            domain::entities::{permission::Permission, role_grant::RoleGrant},
+            infrastructure::repositories::{
+                role_grant_repository::RoleGrantRepositoryImpl, role_repository::RoleRepositoryImpl,
+            },
+        },
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+#[post("/grants/")]
+pub async fn create_role_grant(
+    pool: Data<PgPool>,
+    body: Json<RoleGrantCreateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageRoles) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let grant = RoleGrant {
+        id: Uuid::new_v4(),
+        user_id: body.user_id,
+        role_id: body.role_id,
+        granted_by: Some(request_claims.user_id),
+        created_at: now(),
+        expires_at: body.expires_at,
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let role_grant_repo = RoleGrantRepositoryImpl::new(pool_clone.clone());
+    let role_repo = RoleRepositoryImpl::new(pool_clone.clone());
+    let user_repo = UserRepositoryImpl::new(pool_clone.clone());
+
+    let use_case = CreateRoleGrantUseCase::new(role_grant_repo, role_repo, user_repo);
+    let result = use_case.execute(&grant, &mut transaction).await;
+
+    let grant = match result {
+        Ok(grant) => grant,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::UserNotFound | AppError::RoleNotFound => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                AppError::RoleGrantExpirationInPast => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(RoleGrantResponse {
+        code: "ROLE_GRANT_CREATED".to_string(),
+        role_grant: Some(grant.to_role_grant_data()),
+    })
+}
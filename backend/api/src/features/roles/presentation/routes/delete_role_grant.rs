@@ -0,0 +1,68 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        roles::{
+            application::{
+                dto::{
+                    requests::role_grant::DeleteRoleGrantParams,
+                    responses::role_grant::RoleGrantResponse,
+                },
+                use_cases::delete_role_grant::DeleteRoleGrantUseCase,
+            },
+            domain::entities::permission::Permission,
+            infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/grants/{grant_id}")]
+pub async fn delete_role_grant(
+    pool: Data<PgPool>,
+    params: Path<DeleteRoleGrantParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageRoles) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let role_grant_repo = RoleGrantRepositoryImpl::new(pool.get_ref().clone());
+    let use_case = DeleteRoleGrantUseCase::new(role_grant_repo);
+    let result = use_case.execute(params.grant_id, &mut transaction).await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(_) => HttpResponse::Ok().json(RoleGrantResponse {
+            code: "ROLE_GRANT_DELETED".to_string(),
+            role_grant: None,
+        }),
+        Err(AppError::RoleGrantNotFound) => {
+            HttpResponse::NotFound().json(AppError::RoleGrantNotFound.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        roles::{
+            application::dto::responses::role::RolesResponse,
+            domain::entities::permission::Permission,
+            infrastructure::repositories::role_repository::RoleRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/")]
+pub async fn get_roles(pool: Data<PgPool>, request_claims: ReqData<Claims>) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageRoles) {
+        return response;
+    }
+
+    let role_repo = RoleRepositoryImpl::new(pool.get_ref().clone());
+
+    match role_repo.get_all_with_executor(&**pool).await {
+        Ok(roles) => HttpResponse::Ok().json(RolesResponse {
+            code: "ROLES_FETCHED".to_string(),
+            roles: roles.iter().map(|r| r.to_role_data()).collect(),
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response())
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{
+    core::{constants::errors::AppError, presentation::guards::permission::require_permission},
+    features::{
+        auth::domain::entities::Claims,
+        roles::{
+            application::dto::{
+                requests::role_grant::GetUserRoleGrantsParams,
+                responses::role_grant::RoleGrantsResponse,
+            },
+            domain::entities::permission::Permission,
+            infrastructure::repositories::role_grant_repository::RoleGrantRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/users/{user_id}")]
+pub async fn get_user_role_grants(
+    pool: Data<PgPool>,
+    params: Path<GetUserRoleGrantsParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ManageRoles) {
+        return response;
+    }
+
+    let role_grant_repo = RoleGrantRepositoryImpl::new(pool.get_ref().clone());
+
+    match role_grant_repo
+        .get_by_user_id_with_executor(params.user_id, &**pool)
+        .await
+    {
+        Ok(grants) => HttpResponse::Ok().json(RoleGrantsResponse {
+            code: "ROLE_GRANTS_FETCHED".to_string(),
+            role_grants: grants.iter().map(|g| g.to_role_grant_data()).collect(),
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response())
+        }
+    }
+}
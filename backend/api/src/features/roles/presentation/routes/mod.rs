@@ -0,0 +1,6 @@
+// Roles presentation routes
+
+pub mod create_role_grant;
+pub mod delete_role_grant;
+pub mod get_roles;
+pub mod get_user_role_grants;
@@ -0,0 +1,4 @@
+// Stories DTOs - request/response models for the application layer
+
+pub mod requests;
+pub mod responses;
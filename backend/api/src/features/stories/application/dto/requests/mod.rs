@@ -0,0 +1,4 @@
+// Stories request DTOs
+
+pub mod story;
+pub mod story_report;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+pub struct StoryParams {
+    pub story_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct StoryCreateRequest {
+    pub habit_id: Option<Uuid>,
+    pub challenge_id: Option<Uuid>,
+    pub content: String,
+    pub media: Option<String>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize)]
+pub struct StoryReportCreateRequest {
+    pub story_id: Uuid,
+    pub reason: String,
+}
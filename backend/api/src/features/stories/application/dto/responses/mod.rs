@@ -0,0 +1,4 @@
+// Stories response DTOs
+
+pub mod story;
+pub mod story_report;
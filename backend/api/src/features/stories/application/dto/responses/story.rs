@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::stories::domain::entities::{story::StoryData, story_view::StoryViewData};
+
+#[derive(Serialize, Deserialize)]
+pub struct StoryResponse {
+    pub code: String,
+    pub story: Option<StoryData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StoriesResponse {
+    pub code: String,
+    pub stories: Vec<StoryData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StoryViewsResponse {
+    pub code: String,
+    pub views: Vec<StoryViewData>,
+}
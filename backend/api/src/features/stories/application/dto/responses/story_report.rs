@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::{
+    public_discussions::domain::entities::{
+        public_message_moderation::PublicMessageModerationDecisionData,
+        public_message_report::PublicMessageReportData,
+    },
+    stories::domain::entities::story::StoryData,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct StoryReportResponse {
+    pub code: String,
+    pub story_report: Option<PublicMessageReportData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StoryModerationDecisionResponse {
+    pub code: String,
+    pub decision: Option<PublicMessageModerationDecisionData>,
+    pub story: Option<StoryData>,
+}
@@ -0,0 +1,89 @@
+// Create story use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::challenges::infrastructure::repositories::challenge_participation_repository::ChallengeParticipationRepositoryImpl;
+use crate::features::habits::infrastructure::repositories::habit_participation_repository::HabitParticipationRepositoryImpl;
+use crate::features::stories::domain::entities::story::{
+    Story, STORY_CONTENT_MAX_LENGTH, STORY_MEDIA_MAX_LENGTH,
+};
+use crate::features::stories::infrastructure::repositories::story_repository::StoryRepositoryImpl;
+use sqlx::Postgres;
+
+pub struct CreateStoryUseCase {
+    story_repo: StoryRepositoryImpl,
+    habit_participation_repo: HabitParticipationRepositoryImpl,
+    challenge_participation_repo: ChallengeParticipationRepositoryImpl,
+}
+
+impl CreateStoryUseCase {
+    pub fn new(
+        story_repo: StoryRepositoryImpl,
+        habit_participation_repo: HabitParticipationRepositoryImpl,
+        challenge_participation_repo: ChallengeParticipationRepositoryImpl,
+    ) -> Self {
+        Self {
+            story_repo,
+            habit_participation_repo,
+            challenge_participation_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        story: &Story,
+        transaction: &mut sqlx::Transaction<'_, Postgres>,
+    ) -> Result<(), AppError> {
+        // Check if a habit or a challenge is given
+        if story.habit_id.is_none() && story.challenge_id.is_none() {
+            return Err(AppError::NoHabitNorChallengePassed);
+        }
+
+        // Check if a habit and a challenge were given
+        if story.habit_id.is_some() && story.challenge_id.is_some() {
+            return Err(AppError::BothHabitAndChallengePassed);
+        }
+
+        // Only participants can tell a story about a habit
+        if let Some(habit_id) = story.habit_id {
+            self.habit_participation_repo
+                .get_by_user_and_habit_id_with_executor(story.creator, habit_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+                .ok_or(AppError::HabitParticipationNotFound)?;
+        }
+
+        // Only ongoing participants can tell a story about a challenge
+        if let Some(challenge_id) = story.challenge_id {
+            self.challenge_participation_repo
+                .get_ongoing_by_user_and_challenge_with_executor(
+                    story.creator,
+                    challenge_id,
+                    &mut **transaction,
+                )
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+                .ok_or(AppError::ChallengeParticipationNotFound)?;
+        }
+
+        // Check content size
+        if story.content.len() > STORY_CONTENT_MAX_LENGTH {
+            return Err(AppError::StoryContentTooLong);
+        } else if story.content.is_empty() {
+            return Err(AppError::StoryContentEmpty);
+        }
+
+        if let Some(media) = &story.media {
+            if media.len() > STORY_MEDIA_MAX_LENGTH {
+                return Err(AppError::StoryMediaTooLong);
+            }
+        }
+
+        // Create story
+        self.story_repo
+            .create_with_executor(story, &mut **transaction)
+            .await
+            .map_err(|_| AppError::StoryCreation)?;
+
+        Ok(())
+    }
+}
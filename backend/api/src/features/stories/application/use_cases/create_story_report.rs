@@ -0,0 +1,93 @@
+// Create story report use case - story reports go through the public message moderation flow
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::content_filters::application::use_cases::apply_content_filter::ApplyContentFilterUseCase;
+use crate::features::content_filters::domain::entities::content_filter_flag::ContentFilterTarget;
+use crate::features::public_discussions::domain::entities::public_message_report::{
+    PublicMessageReport, PUBLIC_MESSAGE_REPORT_CONTENT_MAX_LENGTH,
+};
+use crate::features::public_discussions::infrastructure::repositories::public_message_report_repository::PublicMessageReportRepositoryImpl;
+use crate::features::stories::infrastructure::repositories::story_repository::StoryRepositoryImpl;
+
+pub struct CreateStoryReportUseCase {
+    report_repo: PublicMessageReportRepositoryImpl,
+    story_repo: StoryRepositoryImpl,
+    content_filter: ApplyContentFilterUseCase,
+}
+
+impl CreateStoryReportUseCase {
+    pub fn new(
+        report_repo: PublicMessageReportRepositoryImpl,
+        story_repo: StoryRepositoryImpl,
+        content_filter: ApplyContentFilterUseCase,
+    ) -> Self {
+        Self {
+            report_repo,
+            story_repo,
+            content_filter,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        report: &mut PublicMessageReport,
+        auto_hide_reporter_threshold: i64,
+        content_filter_locales: &[String],
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        // Stories the user is not allowed to see are reported as missing
+        let story_id = report.story_id.ok_or(AppError::StoryNotFound)?;
+        let story = self
+            .story_repo
+            .get_visible_for_user_with_executor(report.reporter, now(), &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .into_iter()
+            .find(|s| s.id == story_id)
+            .ok_or(AppError::StoryNotFound)?;
+
+        // Validate reason
+        if report.reason.is_empty() {
+            return Err(AppError::PublicMessageReportReasonEmpty);
+        }
+        if report.reason.len() > PUBLIC_MESSAGE_REPORT_CONTENT_MAX_LENGTH {
+            return Err(AppError::PublicMessageReportReasonTooLong);
+        }
+
+        report.reason = self
+            .content_filter
+            .execute(
+                &report.reason,
+                content_filter_locales,
+                ContentFilterTarget::PublicMessageReport,
+                report.id,
+                transaction,
+            )
+            .await?;
+
+        // Create report
+        self.report_repo
+            .create_with_executor(report, &mut **transaction)
+            .await
+            .map_err(|_| AppError::StoryReportCreation)?;
+
+        // Hide the story pending review once enough distinct users reported it
+        if !story.hidden_by_moderation {
+            let reporter_count = self
+                .report_repo
+                .count_pending_story_reporters_with_executor(story_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+
+            if reporter_count >= auto_hide_reporter_threshold {
+                self.story_repo
+                    .update_hidden_by_moderation_with_executor(story_id, true, &mut **transaction)
+                    .await
+                    .map_err(|_| AppError::StoryUpdate)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+// Delete story use case
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::features::stories::infrastructure::repositories::story_repository::StoryRepositoryImpl;
+
+pub struct DeleteStoryUseCase {
+    story_repo: StoryRepositoryImpl,
+}
+
+impl DeleteStoryUseCase {
+    pub fn new(story_repo: StoryRepositoryImpl) -> Self {
+        Self { story_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        story_id: Uuid,
+        user_id: Uuid,
+        can_moderate: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        let story = self
+            .story_repo
+            .get_by_id_with_executor(story_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::StoryNotFound)?;
+
+        if story.creator != user_id && !can_moderate {
+            return Err(AppError::StoryDeletionNotDoneByCreator);
+        }
+
+        self.story_repo
+            .delete_with_executor(story_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::StoryDeletion)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+// Get story views use case - only the creator can see who viewed a story
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::features::stories::domain::entities::story_view::StoryView;
+use crate::features::stories::infrastructure::repositories::story_repository::StoryRepositoryImpl;
+use crate::features::stories::infrastructure::repositories::story_view_repository::StoryViewRepositoryImpl;
+
+pub struct GetStoryViewsUseCase {
+    story_repo: StoryRepositoryImpl,
+    story_view_repo: StoryViewRepositoryImpl,
+}
+
+impl GetStoryViewsUseCase {
+    pub fn new(story_repo: StoryRepositoryImpl, story_view_repo: StoryViewRepositoryImpl) -> Self {
+        Self {
+            story_repo,
+            story_view_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        story_id: Uuid,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<StoryView>, AppError> {
+        let story = self
+            .story_repo
+            .get_by_id_with_executor(story_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::StoryNotFound)?;
+
+        if story.creator != user_id {
+            return Err(AppError::StoryViewsNotAccessible);
+        }
+
+        self.story_view_repo
+            .get_by_story_id_with_executor(story_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)
+    }
+}
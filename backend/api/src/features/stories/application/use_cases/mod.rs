@@ -0,0 +1,8 @@
+// Stories use cases
+
+pub mod create_story;
+pub mod create_story_report;
+pub mod delete_story;
+pub mod get_story_views;
+pub mod resolve_story_reports;
+pub mod view_story;
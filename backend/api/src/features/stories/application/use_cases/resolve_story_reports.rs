@@ -0,0 +1,149 @@
+// Resolve story reports use case - same decisions as for public messages
+
+use std::collections::HashSet;
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::public_discussions::application::use_cases::resolve_public_message_reports::validate_moderation_decision;
+use crate::features::public_discussions::domain::entities::public_message_moderation::{
+    ModerationAction, PublicMessageModerationDecision,
+};
+use crate::features::public_discussions::domain::entities::user_suspension::UserSuspension;
+use crate::features::public_discussions::infrastructure::repositories::public_message_moderation_decision_repository::PublicMessageModerationDecisionRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::public_message_report_repository::PublicMessageReportRepositoryImpl;
+use crate::features::public_discussions::infrastructure::repositories::user_suspension_repository::UserSuspensionRepositoryImpl;
+use crate::features::stories::domain::entities::story::Story;
+use crate::features::stories::infrastructure::repositories::story_repository::StoryRepositoryImpl;
+
+pub struct ResolveStoryReportsUseCase {
+    report_repo: PublicMessageReportRepositoryImpl,
+    story_repo: StoryRepositoryImpl,
+    decision_repo: PublicMessageModerationDecisionRepositoryImpl,
+    suspension_repo: UserSuspensionRepositoryImpl,
+}
+
+impl ResolveStoryReportsUseCase {
+    pub fn new(
+        report_repo: PublicMessageReportRepositoryImpl,
+        story_repo: StoryRepositoryImpl,
+        decision_repo: PublicMessageModerationDecisionRepositoryImpl,
+        suspension_repo: UserSuspensionRepositoryImpl,
+    ) -> Self {
+        Self {
+            report_repo,
+            story_repo,
+            decision_repo,
+            suspension_repo,
+        }
+    }
+
+    // Records the decision, applies it to the story and its creator, and closes
+    // every pending report. Returns the decision, the story and the reporters.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute(
+        &self,
+        story_id: Uuid,
+        moderator: Uuid,
+        action: ModerationAction,
+        note: Option<String>,
+        suspension_in_days: Option<i64>,
+        can_suspend: bool,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(PublicMessageModerationDecision, Story, Vec<Uuid>), AppError> {
+        let (note, suspension_in_days) =
+            validate_moderation_decision(action, note, suspension_in_days, can_suspend)?;
+
+        // Reported stories outlive their expiry, so expired ones can still be resolved
+        let mut story = self
+            .story_repo
+            .get_by_id_with_executor(story_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::StoryNotFound)?;
+
+        let reports = self
+            .report_repo
+            .get_pending_by_story_id_with_executor(story_id, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+        if reports.is_empty() {
+            return Err(AppError::StoryHasNoPendingReports);
+        }
+
+        let mut reporters = Vec::new();
+        let mut seen = HashSet::new();
+        for report in &reports {
+            if seen.insert(report.reporter) {
+                reporters.push(report.reporter);
+            }
+        }
+
+        let resolved_at = now();
+        let decision = PublicMessageModerationDecision {
+            id: Uuid::new_v4(),
+            message_id: None,
+            story_id: Some(story_id),
+            moderator: Some(moderator),
+            action: action.to_string(),
+            note,
+            report_count: reports.len() as i32,
+            created_at: resolved_at,
+        };
+
+        self.decision_repo
+            .create_with_executor(&decision, &mut **transaction)
+            .await
+            .map_err(|_| AppError::ModerationDecisionCreation)?;
+
+        // Apply the decision to the story
+        self.story_repo
+            .update_hidden_by_moderation_with_executor(
+                story_id,
+                action.hides_message(),
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::StoryUpdate)?;
+        story.hidden_by_moderation = action.hides_message();
+
+        if action == ModerationAction::Delete {
+            self.story_repo
+                .clear_content_with_executor(story_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::StoryDeletion)?;
+            story.content = String::new();
+            story.media = None;
+        }
+
+        // Apply the decision to the creator
+        if action == ModerationAction::SuspendAuthor {
+            let suspension = UserSuspension {
+                id: Uuid::new_v4(),
+                user_id: story.creator,
+                decision_id: Some(decision.id),
+                created_at: resolved_at,
+                expires_at: resolved_at + Duration::days(suspension_in_days),
+            };
+
+            self.suspension_repo
+                .create_with_executor(&suspension, &mut **transaction)
+                .await
+                .map_err(|_| AppError::ModerationDecisionCreation)?;
+        }
+
+        self.report_repo
+            .resolve_by_story_id_with_executor(
+                story_id,
+                decision.id,
+                resolved_at,
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?;
+
+        Ok((decision, story, reporters))
+    }
+}
@@ -0,0 +1,71 @@
+// View story use case - records that a user saw a story
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::core::helpers::mock_now::now;
+use crate::features::stories::domain::entities::story::Story;
+use crate::features::stories::domain::entities::story_view::StoryView;
+use crate::features::stories::infrastructure::repositories::story_repository::StoryRepositoryImpl;
+use crate::features::stories::infrastructure::repositories::story_view_repository::StoryViewRepositoryImpl;
+
+pub struct ViewStoryUseCase {
+    story_repo: StoryRepositoryImpl,
+    story_view_repo: StoryViewRepositoryImpl,
+}
+
+impl ViewStoryUseCase {
+    pub fn new(story_repo: StoryRepositoryImpl, story_view_repo: StoryViewRepositoryImpl) -> Self {
+        Self {
+            story_repo,
+            story_view_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        story_id: Uuid,
+        user_id: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Story, AppError> {
+        let at = now();
+
+        // Stories the user is not allowed to see are reported as missing
+        let mut story = self
+            .story_repo
+            .get_visible_for_user_with_executor(user_id, at, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .into_iter()
+            .find(|s| s.id == story_id)
+            .ok_or(AppError::StoryNotFound)?;
+
+        // The creator looking at its own story does not count
+        if story.creator == user_id {
+            return Ok(story);
+        }
+
+        let is_new_view = self
+            .story_view_repo
+            .create_with_executor(
+                &StoryView {
+                    story_id,
+                    user_id,
+                    viewed_at: at,
+                },
+                &mut **transaction,
+            )
+            .await
+            .map_err(|_| AppError::StoryViewCreation)?;
+
+        if is_new_view {
+            self.story_repo
+                .increment_view_count_with_executor(story_id, &mut **transaction)
+                .await
+                .map_err(|_| AppError::StoryViewCreation)?;
+            story.view_count += 1;
+        }
+
+        Ok(story)
+    }
+}
@@ -0,0 +1,4 @@
+// Stories domain entities
+
+pub mod story;
+pub mod story_view;
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+pub const STORY_CONTENT_MAX_LENGTH: usize = 500;
+pub const STORY_MEDIA_MAX_LENGTH: usize = 2048;
+pub const STORY_DURATION_IN_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct Story {
+    pub id: Uuid,
+    pub creator: Uuid,
+    pub habit_id: Option<Uuid>,
+    pub challenge_id: Option<Uuid>,
+    pub content: String,
+    pub media: Option<String>,
+    pub view_count: i32,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub hidden_by_moderation: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StoryData {
+    pub id: Uuid,
+    pub creator: Uuid,
+    pub habit_id: Option<Uuid>,
+    pub challenge_id: Option<Uuid>,
+    pub content: String,
+    pub media: Option<String>,
+    pub view_count: i32,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub hidden_by_moderation: bool,
+}
+
+impl Story {
+    pub fn is_expired(&self, at: DateTime<Utc>) -> bool {
+        self.expires_at <= at
+    }
+
+    pub fn to_story_data(&self) -> StoryData {
+        StoryData {
+            id: self.id,
+            creator: self.creator,
+            habit_id: self.habit_id,
+            challenge_id: self.challenge_id,
+            content: self.content.to_owned(),
+            media: self.media.to_owned(),
+            view_count: self.view_count,
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+            hidden_by_moderation: self.hidden_by_moderation,
+        }
+    }
+}
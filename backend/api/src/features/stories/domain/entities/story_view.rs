@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct StoryView {
+    pub story_id: Uuid,
+    pub user_id: Uuid,
+    pub viewed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StoryViewData {
+    pub story_id: Uuid,
+    pub user_id: Uuid,
+    pub viewed_at: DateTime<Utc>,
+}
+
+impl StoryView {
+    pub fn to_story_view_data(&self) -> StoryViewData {
+        StoryViewData {
+            story_id: self.story_id,
+            user_id: self.user_id,
+            viewed_at: self.viewed_at,
+        }
+    }
+}
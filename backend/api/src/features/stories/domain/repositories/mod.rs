@@ -0,0 +1,4 @@
+// Stories domain repository traits
+
+pub mod story_repository;
+pub mod story_view_repository;
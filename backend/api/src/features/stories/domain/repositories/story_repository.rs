@@ -0,0 +1,27 @@
+// Story repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::features::stories::domain::entities::story::Story;
+
+#[async_trait]
+pub trait StoryRepository: Send + Sync {
+    async fn create(&self, story: &Story) -> Result<(), String>;
+    async fn delete(&self, story_id: Uuid) -> Result<(), String>;
+    async fn get_by_id(&self, story_id: Uuid) -> Result<Option<Story>, String>;
+    async fn get_visible_for_user(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<Story>, String>;
+    async fn update_hidden_by_moderation(
+        &self,
+        story_id: Uuid,
+        hidden_by_moderation: bool,
+    ) -> Result<(), String>;
+    async fn clear_content(&self, story_id: Uuid) -> Result<(), String>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
+    async fn delete_expired(&self, at: DateTime<Utc>) -> Result<u64, String>;
+}
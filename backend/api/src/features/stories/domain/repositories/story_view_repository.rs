@@ -0,0 +1,13 @@
+// StoryView repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::stories::domain::entities::story_view::StoryView;
+
+#[async_trait]
+pub trait StoryViewRepository: Send + Sync {
+    async fn create(&self, view: &StoryView) -> Result<bool, String>;
+    async fn get_by_story_id(&self, story_id: Uuid) -> Result<Vec<StoryView>, String>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
+}
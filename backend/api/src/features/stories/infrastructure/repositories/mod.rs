@@ -0,0 +1,4 @@
+// Stories infrastructure repository implementations
+
+pub mod story_repository;
+pub mod story_view_repository;
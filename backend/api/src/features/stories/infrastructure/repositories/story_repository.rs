@@ -0,0 +1,320 @@
+// StoryRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::stories::domain::entities::story::Story;
+use crate::features::stories::domain::repositories::story_repository::StoryRepository;
+
+pub struct StoryRepositoryImpl {
+    pool: PgPool,
+}
+
+impl StoryRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        story: &Story,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO stories (
+                id,
+                creator,
+                habit_id,
+                challenge_id,
+                content,
+                media,
+                view_count,
+                created_at,
+                expires_at
+            )
+            VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9 )
+            "#,
+            story.id,
+            story.creator,
+            story.habit_id,
+            story.challenge_id,
+            story.content,
+            story.media,
+            story.view_count,
+            story.created_at,
+            story.expires_at
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM stories
+            WHERE id = $1
+            "#,
+            story_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_id_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        executor: E,
+    ) -> Result<Option<Story>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            Story,
+            r#"
+            SELECT *
+            FROM stories
+            WHERE id = $1
+            "#,
+            story_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    // A story is visible to its creator and to its creator's challenge co-participants:
+    // the participants of the challenge it is about, or for a habit story, the people
+    // taking part in a challenge together with the creator.
+    pub async fn get_visible_for_user_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<Story>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            Story,
+            r#"
+            SELECT s.*
+            FROM stories s
+            WHERE s.expires_at > $2
+            AND (s.creator = $1 OR NOT s.hidden_by_moderation)
+            AND (
+                s.creator = $1
+                OR EXISTS (
+                    SELECT 1
+                    FROM challenge_participations mine
+                    WHERE mine.user_id = $1
+                    AND mine.challenge_id = s.challenge_id
+                )
+                OR (
+                    s.habit_id IS NOT NULL
+                    AND EXISTS (
+                        SELECT 1
+                        FROM challenge_participations mine
+                        JOIN challenge_participations theirs ON theirs.challenge_id = mine.challenge_id
+                        WHERE mine.user_id = $1
+                        AND theirs.user_id = s.creator
+                    )
+                )
+            )
+            ORDER BY s.created_at DESC
+            "#,
+            user_id,
+            at
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn increment_view_count_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE stories
+            SET view_count = view_count + 1
+            WHERE id = $1
+            "#,
+            story_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn update_hidden_by_moderation_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        hidden_by_moderation: bool,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE stories
+            SET hidden_by_moderation = $1
+            WHERE id = $2
+            "#,
+            hidden_by_moderation,
+            story_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    // The row stays until the purge so the reports and the decision keep their target
+    pub async fn clear_content_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE stories
+            SET
+                content = '',
+                media = NULL
+            WHERE id = $1
+            "#,
+            story_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM stories
+            WHERE creator = $1
+            "#,
+            user_id
+        )
+        .execute(executor)
+        .await
+    }
+
+    // Stories with pending reports are kept until a moderator looked at them
+    pub async fn delete_expired_with_executor<'a, E>(
+        &self,
+        at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM stories s
+            WHERE s.expires_at <= $1
+            AND NOT EXISTS (
+                SELECT 1
+                FROM public_message_reports r
+                WHERE r.story_id = s.id
+                AND r.resolved_at IS NULL
+            )
+            "#,
+            at
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl StoryRepository for StoryRepositoryImpl {
+    async fn create(&self, story: &Story) -> Result<(), String> {
+        self.create_with_executor(story, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete(&self, story_id: Uuid) -> Result<(), String> {
+        self.delete_with_executor(story_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_by_id(&self, story_id: Uuid) -> Result<Option<Story>, String> {
+        self.get_by_id_with_executor(story_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_visible_for_user(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<Story>, String> {
+        self.get_visible_for_user_with_executor(user_id, at, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn update_hidden_by_moderation(
+        &self,
+        story_id: Uuid,
+        hidden_by_moderation: bool,
+    ) -> Result<(), String> {
+        self.update_hidden_by_moderation_with_executor(story_id, hidden_by_moderation, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn clear_content(&self, story_id: Uuid) -> Result<(), String> {
+        self.clear_content_with_executor(story_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
+        self.delete_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete_expired(&self, at: DateTime<Utc>) -> Result<u64, String> {
+        self.delete_expired_with_executor(at, &self.pool)
+            .await
+            .map(|r| r.rows_affected())
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,112 @@
+// StoryViewRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::stories::domain::entities::story_view::StoryView;
+use crate::features::stories::domain::repositories::story_view_repository::StoryViewRepository;
+
+pub struct StoryViewRepositoryImpl {
+    pool: PgPool,
+}
+
+impl StoryViewRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    // Returns whether the view was new, a user only counts once per story
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        view: &StoryView,
+        executor: E,
+    ) -> Result<bool, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO story_views (
+                story_id,
+                user_id,
+                viewed_at
+            )
+            VALUES ( $1, $2, $3 )
+            ON CONFLICT (story_id, user_id) DO NOTHING
+            "#,
+            view.story_id,
+            view.user_id,
+            view.viewed_at
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    pub async fn get_by_story_id_with_executor<'a, E>(
+        &self,
+        story_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<StoryView>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            StoryView,
+            r#"
+            SELECT *
+            FROM story_views
+            WHERE story_id = $1
+            ORDER BY viewed_at DESC
+            "#,
+            story_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn delete_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM story_views
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl StoryViewRepository for StoryViewRepositoryImpl {
+    async fn create(&self, view: &StoryView) -> Result<bool, String> {
+        self.create_with_executor(view, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_story_id(&self, story_id: Uuid) -> Result<Vec<StoryView>, String> {
+        self.get_by_story_id_with_executor(story_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
+        self.delete_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    core::{constants::errors::AppError, helpers::mock_now::now},
+    features::{
+        auth::domain::entities::Claims,
+        challenges::infrastructure::repositories::challenge_participation_repository::ChallengeParticipationRepositoryImpl,
+        habits::infrastructure::repositories::habit_participation_repository::HabitParticipationRepositoryImpl,
+        stories::{
+            application::{
+                dto::{requests::story::StoryCreateRequest, responses::story::StoryResponse},
+                use_cases::create_story::CreateStoryUseCase,
+            },
+            domain::entities::story::{Story, STORY_DURATION_IN_HOURS},
+            infrastructure::repositories::story_repository::StoryRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse, Responder,
+};
+use chrono::Duration;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+#[post("/")]
+pub async fn create_story(
+    pool: Data<PgPool>,
+    body: Json<StoryCreateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+    let created_at = now();
+
+    let story = Story {
+        id: Uuid::new_v4(),
+        creator: request_claims.user_id,
+        habit_id: body.habit_id,
+        challenge_id: body.challenge_id,
+        content: body.content,
+        media: body.media,
+        view_count: 0,
+        created_at,
+        expires_at: created_at + Duration::hours(STORY_DURATION_IN_HOURS),
+        hidden_by_moderation: false,
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = CreateStoryUseCase::new(
+        StoryRepositoryImpl::new(pool_clone.clone()),
+        HabitParticipationRepositoryImpl::new(pool_clone.clone()),
+        ChallengeParticipationRepositoryImpl::new(pool_clone.clone()),
+    );
+
+    if let Err(e) = use_case.execute(&story, &mut transaction).await {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::NoHabitNorChallengePassed
+            | AppError::BothHabitAndChallengePassed
+            | AppError::StoryContentEmpty
+            | AppError::StoryContentTooLong
+            | AppError::StoryMediaTooLong => HttpResponse::BadRequest().json(e.to_response()),
+            AppError::HabitParticipationNotFound | AppError::ChallengeParticipationNotFound => {
+                HttpResponse::Forbidden().json(e.to_response())
+            }
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(StoryResponse {
+        code: "STORY_CREATED".to_string(),
+        story: Some(story.to_story_data()),
+    })
+}
@@ -0,0 +1,126 @@
+use crate::{
+    configuration::ModerationSettings,
+    core::{constants::errors::AppError, helpers::mock_now::now},
+    features::{
+        auth::domain::entities::Claims,
+        content_filters::{
+            application::use_cases::apply_content_filter::{
+                content_filter_locales, ApplyContentFilterUseCase,
+            },
+            infrastructure::repositories::{
+                content_filter_flag_repository::ContentFilterFlagRepositoryImpl,
+                content_filter_rule_repository::ContentFilterRuleRepositoryImpl,
+            },
+        },
+        profile::domain::entities::UserPublicDataCache,
+        public_discussions::{
+            domain::entities::public_message_report::PublicMessageReport,
+            infrastructure::{
+                repositories::public_message_report_repository::PublicMessageReportRepositoryImpl,
+                services::language_service::LanguageService,
+            },
+        },
+        stories::{
+            application::{
+                dto::{
+                    requests::story_report::StoryReportCreateRequest,
+                    responses::story_report::StoryReportResponse,
+                },
+                use_cases::create_story_report::CreateStoryReportUseCase,
+            },
+            infrastructure::repositories::story_repository::StoryRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+#[post("/reports/")]
+pub async fn create_story_report(
+    pool: Data<PgPool>,
+    body: Json<StoryReportCreateRequest>,
+    moderation_settings: Data<ModerationSettings>,
+    user_public_data_cache: Data<UserPublicDataCache>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+
+    let mut story_report = PublicMessageReport {
+        id: Uuid::new_v4(),
+        message_id: None,
+        story_id: Some(body.story_id),
+        reporter: request_claims.user_id,
+        created_at: now(),
+        reason: body.reason,
+        resolved_at: None,
+        decision_id: None,
+        revision_id: None,
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let content_filter = ApplyContentFilterUseCase::new(
+        ContentFilterRuleRepositoryImpl::new(pool_clone.clone()),
+        ContentFilterFlagRepositoryImpl::new(pool_clone.clone()),
+    );
+    let use_case = CreateStoryReportUseCase::new(
+        PublicMessageReportRepositoryImpl::new(pool_clone.clone()),
+        StoryRepositoryImpl::new(pool_clone.clone()),
+        content_filter,
+    );
+
+    let reporter_locale = user_public_data_cache
+        .get_value_for_key_or_insert_it(&request_claims.user_id, &mut transaction)
+        .await
+        .map(|reporter| reporter.locale);
+    let reason_language = LanguageService::new().detect_language(&story_report.reason);
+    let locales = content_filter_locales(reporter_locale.as_deref(), reason_language.as_deref());
+
+    if let Err(e) = use_case
+        .execute(
+            &mut story_report,
+            moderation_settings.auto_hide_reporter_threshold,
+            &locales,
+            &mut transaction,
+        )
+        .await
+    {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::StoryNotFound => HttpResponse::NotFound().json(e.to_response()),
+            AppError::PublicMessageReportReasonEmpty
+            | AppError::PublicMessageReportReasonTooLong
+            | AppError::ContentRejectedByFilter => HttpResponse::BadRequest().json(e.to_response()),
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(StoryReportResponse {
+        code: "STORY_REPORT_CREATED".to_string(),
+        story_report: Some(story_report.to_public_message_report_data()),
+    })
+}
@@ -0,0 +1,74 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        roles::domain::entities::permission::Permission,
+        stories::{
+            application::{
+                dto::{requests::story::StoryParams, responses::story::StoryResponse},
+                use_cases::delete_story::DeleteStoryUseCase,
+            },
+            infrastructure::repositories::story_repository::StoryRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/{story_id}")]
+pub async fn delete_story(
+    pool: Data<PgPool>,
+    params: Path<StoryParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repository and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = DeleteStoryUseCase::new(StoryRepositoryImpl::new(pool_clone));
+
+    if let Err(e) = use_case
+        .execute(
+            params.story_id,
+            request_claims.user_id,
+            request_claims.has_permission(Permission::ModeratePublicMessages),
+            &mut transaction,
+        )
+        .await
+    {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::StoryNotFound => HttpResponse::NotFound().json(e.to_response()),
+            AppError::StoryDeletionNotDoneByCreator => {
+                HttpResponse::Forbidden().json(e.to_response())
+            }
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(StoryResponse {
+        code: "STORY_DELETED".to_string(),
+        story: None,
+    })
+}
@@ -0,0 +1,54 @@
+use crate::{
+    core::{constants::errors::AppError, helpers::mock_now::now},
+    features::{
+        auth::domain::entities::Claims,
+        stories::{
+            application::dto::responses::story::StoriesResponse,
+            infrastructure::repositories::story_repository::StoryRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/")]
+pub async fn get_stories(pool: Data<PgPool>, request_claims: ReqData<Claims>) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repository
+    let pool_clone = pool.get_ref().clone();
+    let story_repo = StoryRepositoryImpl::new(pool_clone);
+
+    let result = story_repo
+        .get_visible_for_user_with_executor(request_claims.user_id, now(), &mut *transaction)
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(stories) => HttpResponse::Ok().json(StoriesResponse {
+            code: "STORIES_FETCHED".to_string(),
+            stories: stories.iter().map(|s| s.to_story_data()).collect(),
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response())
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        stories::{
+            application::{
+                dto::{requests::story::StoryParams, responses::story::StoryViewsResponse},
+                use_cases::get_story_views::GetStoryViewsUseCase,
+            },
+            infrastructure::repositories::{
+                story_repository::StoryRepositoryImpl,
+                story_view_repository::StoryViewRepositoryImpl,
+            },
+        },
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/{story_id}/views")]
+pub async fn get_story_views(
+    pool: Data<PgPool>,
+    params: Path<StoryParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = GetStoryViewsUseCase::new(
+        StoryRepositoryImpl::new(pool_clone.clone()),
+        StoryViewRepositoryImpl::new(pool_clone.clone()),
+    );
+
+    let result = use_case
+        .execute(params.story_id, request_claims.user_id, &mut transaction)
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(views) => HttpResponse::Ok().json(StoryViewsResponse {
+            code: "STORY_VIEWS_FETCHED".to_string(),
+            views: views.iter().map(|v| v.to_story_view_data()).collect(),
+        }),
+        Err(AppError::StoryNotFound) => {
+            HttpResponse::NotFound().json(AppError::StoryNotFound.to_response())
+        }
+        Err(AppError::StoryViewsNotAccessible) => {
+            HttpResponse::Forbidden().json(AppError::StoryViewsNotAccessible.to_response())
+        }
+        Err(e) => {
+            error!("Error: {:?}", e);
+            HttpResponse::InternalServerError().json(e.to_response())
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// Stories presentation routes
+
+pub mod create_story;
+pub mod create_story_report;
+pub mod delete_story;
+pub mod get_stories;
+pub mod get_story_views;
+pub mod resolve_story_reports;
+pub mod view_story;
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{
+        constants::errors::AppError, helpers::translation::Translator,
+        presentation::guards::permission::require_permission,
+    },
+    features::{
+        auth::domain::entities::Claims,
+        profile::domain::entities::UserPublicDataCache,
+        public_discussions::{
+            application::dto::requests::public_message_moderation::ResolvePublicMessageReportsRequest,
+            domain::entities::public_message_moderation::MODERATION_DEFAULT_SUSPENSION_IN_DAYS,
+            infrastructure::{
+                repositories::{
+                    public_message_moderation_decision_repository::PublicMessageModerationDecisionRepositoryImpl,
+                    public_message_report_repository::PublicMessageReportRepositoryImpl,
+                    user_suspension_repository::UserSuspensionRepositoryImpl,
+                },
+                services::moderation_notification_service::ModerationNotificationService,
+            },
+        },
+        roles::domain::entities::permission::Permission,
+        stories::{
+            application::{
+                dto::{
+                    requests::story::StoryParams,
+                    responses::story_report::StoryModerationDecisionResponse,
+                },
+                use_cases::resolve_story_reports::ResolveStoryReportsUseCase,
+            },
+            infrastructure::repositories::story_repository::StoryRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, Path, ReqData},
+    HttpResponse, Responder,
+};
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+
+#[allow(clippy::too_many_arguments)]
+#[post("/stories/{story_id}/resolve")]
+pub async fn resolve_story_reports(
+    pool: Data<PgPool>,
+    params: Path<StoryParams>,
+    body: Json<ResolvePublicMessageReportsRequest>,
+    redis_client: Data<Client>,
+    translator: Data<Arc<Translator>>,
+    user_public_data_cache: Data<UserPublicDataCache>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    if let Err(response) = require_permission(&request_claims, Permission::ModeratePublicMessages) {
+        return response;
+    }
+
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    let body = body.into_inner();
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = ResolveStoryReportsUseCase::new(
+        PublicMessageReportRepositoryImpl::new(pool_clone.clone()),
+        StoryRepositoryImpl::new(pool_clone.clone()),
+        PublicMessageModerationDecisionRepositoryImpl::new(pool_clone.clone()),
+        UserSuspensionRepositoryImpl::new(pool_clone.clone()),
+    );
+
+    let (decision, story, reporters) = match use_case
+        .execute(
+            params.story_id,
+            request_claims.user_id,
+            body.action,
+            body.note,
+            body.suspension_in_days,
+            request_claims.has_permission(Permission::BlockReportedUsers),
+            &mut transaction,
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::MissingPermission => HttpResponse::Forbidden().json(e.to_response()),
+                AppError::StoryNotFound | AppError::StoryHasNoPendingReports => {
+                    HttpResponse::NotFound().json(e.to_response())
+                }
+                AppError::ModerationNoteEmpty
+                | AppError::ModerationNoteTooLong
+                | AppError::ModerationSuspensionDurationInvalid => {
+                    HttpResponse::BadRequest().json(e.to_response())
+                }
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    // Tell reporters and, when warned or suspended, the creator about the outcome
+    ModerationNotificationService::new(pool_clone)
+        .notify(
+            &decision,
+            body.action,
+            body.suspension_in_days
+                .unwrap_or(MODERATION_DEFAULT_SUSPENSION_IN_DAYS),
+            story.creator,
+            &reporters,
+            redis_client,
+            &translator,
+            &user_public_data_cache,
+        )
+        .await;
+
+    HttpResponse::Ok().json(StoryModerationDecisionResponse {
+        code: "MODERATION_DECISION_CREATED".to_string(),
+        decision: Some(decision.to_public_message_moderation_decision_data()),
+        story: Some(story.to_story_data()),
+    })
+}
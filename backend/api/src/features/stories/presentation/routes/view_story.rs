@@ -0,0 +1,74 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        stories::{
+            application::{
+                dto::{requests::story::StoryParams, responses::story::StoryResponse},
+                use_cases::view_story::ViewStoryUseCase,
+            },
+            infrastructure::repositories::{
+                story_repository::StoryRepositoryImpl,
+                story_view_repository::StoryViewRepositoryImpl,
+            },
+        },
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[post("/{story_id}/views")]
+pub async fn view_story(
+    pool: Data<PgPool>,
+    params: Path<StoryParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = ViewStoryUseCase::new(
+        StoryRepositoryImpl::new(pool_clone.clone()),
+        StoryViewRepositoryImpl::new(pool_clone.clone()),
+    );
+
+    let story = match use_case
+        .execute(params.story_id, request_claims.user_id, &mut transaction)
+        .await
+    {
+        Ok(story) => story,
+        Err(e) => {
+            error!("Error: {:?}", e);
+            if let Err(e) = transaction.rollback().await {
+                error!("Error rolling back: {}", e);
+            }
+            return match e {
+                AppError::StoryNotFound => HttpResponse::NotFound().json(e.to_response()),
+                _ => HttpResponse::InternalServerError().json(e.to_response()),
+            };
+        }
+    };
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(StoryResponse {
+        code: "STORY_VIEWED".to_string(),
+        story: Some(story.to_story_data()),
+    })
+}
@@ -0,0 +1,4 @@
+// User blocks DTOs - request/response models for the application layer
+
+pub mod requests;
+pub mod responses;
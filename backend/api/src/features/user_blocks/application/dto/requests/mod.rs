@@ -0,0 +1,3 @@
+// User blocks request DTOs
+
+pub mod user_block;
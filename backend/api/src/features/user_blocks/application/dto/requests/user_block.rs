@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+pub struct UserBlockParams {
+    pub user_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct UserBlockCreateRequest {
+    pub user_id: Uuid,
+}
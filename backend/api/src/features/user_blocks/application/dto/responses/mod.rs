@@ -0,0 +1,3 @@
+// User blocks response DTOs
+
+pub mod user_block;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::user_blocks::domain::entities::user_block::UserBlockData;
+
+#[derive(Serialize, Deserialize)]
+pub struct UserBlockResponse {
+    pub code: String,
+    pub user_block: Option<UserBlockData>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserBlocksResponse {
+    pub code: String,
+    pub user_blocks: Vec<UserBlockData>,
+}
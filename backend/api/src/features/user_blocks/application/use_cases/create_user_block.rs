@@ -0,0 +1,44 @@
+// Create user block use case
+
+use crate::core::constants::errors::AppError;
+use crate::features::profile::infrastructure::repositories::user_repository::UserRepositoryImpl;
+use crate::features::user_blocks::domain::entities::user_block::UserBlock;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+
+pub struct CreateUserBlockUseCase {
+    user_block_repo: UserBlockRepositoryImpl,
+    user_repo: UserRepositoryImpl,
+}
+
+impl CreateUserBlockUseCase {
+    pub fn new(user_block_repo: UserBlockRepositoryImpl, user_repo: UserRepositoryImpl) -> Self {
+        Self {
+            user_block_repo,
+            user_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        block: &UserBlock,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        if block.blocker == block.blocked {
+            return Err(AppError::UserBlockSelf);
+        }
+
+        self.user_repo
+            .get_by_id_with_executor(block.blocked, &mut **transaction)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+            .ok_or(AppError::UserNotFound)?;
+
+        // Blocking twice is a no-op
+        self.user_block_repo
+            .create_with_executor(block, &mut **transaction)
+            .await
+            .map_err(|_| AppError::UserBlockCreation)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+// Delete user block use case
+
+use uuid::Uuid;
+
+use crate::core::constants::errors::AppError;
+use crate::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+
+pub struct DeleteUserBlockUseCase {
+    user_block_repo: UserBlockRepositoryImpl,
+}
+
+impl DeleteUserBlockUseCase {
+    pub fn new(user_block_repo: UserBlockRepositoryImpl) -> Self {
+        Self { user_block_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        blocker: Uuid,
+        blocked: Uuid,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), AppError> {
+        let result = self
+            .user_block_repo
+            .delete_with_executor(blocker, blocked, &mut **transaction)
+            .await
+            .map_err(|_| AppError::UserBlockDeletion)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::UserBlockNotFound);
+        }
+
+        Ok(())
+    }
+}
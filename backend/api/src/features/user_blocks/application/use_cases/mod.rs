@@ -0,0 +1,4 @@
+// User blocks use cases
+
+pub mod create_user_block;
+pub mod delete_user_block;
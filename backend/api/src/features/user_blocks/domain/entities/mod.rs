@@ -0,0 +1,3 @@
+// User blocks domain entities
+
+pub mod user_block;
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
+pub struct UserBlock {
+    pub id: Uuid,
+    pub blocker: Uuid,
+    pub blocked: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UserBlockData {
+    pub id: Uuid,
+    pub blocked: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserBlock {
+    pub fn to_user_block_data(&self) -> UserBlockData {
+        UserBlockData {
+            id: self.id,
+            blocked: self.blocked,
+            created_at: self.created_at,
+        }
+    }
+}
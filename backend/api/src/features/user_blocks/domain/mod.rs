@@ -0,0 +1,3 @@
+// Domain layer - entities and repository traits
+pub mod entities;
+pub mod repositories;
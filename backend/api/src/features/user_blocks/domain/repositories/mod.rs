@@ -0,0 +1,3 @@
+// User blocks domain repository traits
+
+pub mod user_block_repository;
@@ -0,0 +1,16 @@
+// UserBlock repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::features::user_blocks::domain::entities::user_block::UserBlock;
+
+#[async_trait]
+pub trait UserBlockRepository: Send + Sync {
+    async fn create(&self, block: &UserBlock) -> Result<(), String>;
+    async fn delete(&self, blocker: Uuid, blocked: Uuid) -> Result<bool, String>;
+    async fn get_by_blocker(&self, blocker: Uuid) -> Result<Vec<UserBlock>, String>;
+    async fn get_blocked_ids(&self, blocker: Uuid) -> Result<Vec<Uuid>, String>;
+    async fn exists_between(&self, user_id: Uuid, other_user_id: Uuid) -> Result<bool, String>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String>;
+}
@@ -0,0 +1,3 @@
+// User blocks infrastructure repository implementations
+
+pub mod user_block_repository;
@@ -0,0 +1,202 @@
+// UserBlockRepository implementation using SQLx
+// Supports both PgPool and transactions via Executor trait
+
+use async_trait::async_trait;
+use sqlx::{postgres::PgQueryResult, Executor, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::features::user_blocks::domain::entities::user_block::UserBlock;
+use crate::features::user_blocks::domain::repositories::user_block_repository::UserBlockRepository;
+
+pub struct UserBlockRepositoryImpl {
+    pool: PgPool,
+}
+
+impl UserBlockRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Transaction-aware methods that accept Executor
+    pub async fn create_with_executor<'a, E>(
+        &self,
+        block: &UserBlock,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO user_blocks (
+                id,
+                blocker,
+                blocked,
+                created_at
+            )
+            VALUES ( $1, $2, $3, $4 )
+            ON CONFLICT (blocker, blocked) DO NOTHING
+            "#,
+            block.id,
+            block.blocker,
+            block.blocked,
+            block.created_at
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn delete_with_executor<'a, E>(
+        &self,
+        blocker: Uuid,
+        blocked: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM user_blocks
+            WHERE blocker = $1 AND blocked = $2
+            "#,
+            blocker,
+            blocked
+        )
+        .execute(executor)
+        .await
+    }
+
+    pub async fn get_by_blocker_with_executor<'a, E>(
+        &self,
+        blocker: Uuid,
+        executor: E,
+    ) -> Result<Vec<UserBlock>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            UserBlock,
+            r#"
+            SELECT *
+            FROM user_blocks
+            WHERE blocker = $1
+            ORDER BY created_at DESC
+            "#,
+            blocker
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_blocked_ids_with_executor<'a, E>(
+        &self,
+        blocker: Uuid,
+        executor: E,
+    ) -> Result<Vec<Uuid>, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let rows = sqlx::query!(
+            r#"
+            SELECT blocked
+            FROM user_blocks
+            WHERE blocker = $1
+            "#,
+            blocker
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.blocked).collect())
+    }
+
+    // True when either user blocked the other
+    pub async fn exists_between_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        other_user_id: Uuid,
+        executor: E,
+    ) -> Result<bool, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM user_blocks
+                WHERE (blocker = $1 AND blocked = $2)
+                OR (blocker = $2 AND blocked = $1)
+            ) AS "exists!"
+            "#,
+            user_id,
+            other_user_id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(row.exists)
+    }
+
+    pub async fn delete_by_user_id_with_executor<'a, E>(
+        &self,
+        user_id: Uuid,
+        executor: E,
+    ) -> Result<PgQueryResult, sqlx::Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            DELETE FROM user_blocks
+            WHERE blocker = $1 OR blocked = $1
+            "#,
+            user_id
+        )
+        .execute(executor)
+        .await
+    }
+}
+
+#[async_trait]
+impl UserBlockRepository for UserBlockRepositoryImpl {
+    async fn create(&self, block: &UserBlock) -> Result<(), String> {
+        self.create_with_executor(block, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete(&self, blocker: Uuid, blocked: Uuid) -> Result<bool, String> {
+        self.delete_with_executor(blocker, blocked, &self.pool)
+            .await
+            .map(|r| r.rows_affected() > 0)
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_by_blocker(&self, blocker: Uuid) -> Result<Vec<UserBlock>, String> {
+        self.get_by_blocker_with_executor(blocker, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn get_blocked_ids(&self, blocker: Uuid) -> Result<Vec<Uuid>, String> {
+        self.get_blocked_ids_with_executor(blocker, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn exists_between(&self, user_id: Uuid, other_user_id: Uuid) -> Result<bool, String> {
+        self.exists_between_with_executor(user_id, other_user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), String> {
+        self.delete_by_user_id_with_executor(user_id, &self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
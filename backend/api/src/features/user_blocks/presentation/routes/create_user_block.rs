@@ -0,0 +1,79 @@
+use crate::{
+    core::{constants::errors::AppError, helpers::mock_now::now},
+    features::{
+        auth::domain::entities::Claims,
+        profile::infrastructure::repositories::user_repository::UserRepositoryImpl,
+        user_blocks::{
+            application::{
+                dto::{
+                    requests::user_block::UserBlockCreateRequest,
+                    responses::user_block::UserBlockResponse,
+                },
+                use_cases::create_user_block::CreateUserBlockUseCase,
+            },
+            domain::entities::user_block::UserBlock,
+            infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+#[post("/")]
+pub async fn create_user_block(
+    pool: Data<PgPool>,
+    body: Json<UserBlockCreateRequest>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repositories and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = CreateUserBlockUseCase::new(
+        UserBlockRepositoryImpl::new(pool_clone.clone()),
+        UserRepositoryImpl::new(pool_clone),
+    );
+
+    let block = UserBlock {
+        id: Uuid::new_v4(),
+        blocker: request_claims.user_id,
+        blocked: body.user_id,
+        created_at: now(),
+    };
+
+    if let Err(e) = use_case.execute(&block, &mut transaction).await {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::UserNotFound => HttpResponse::NotFound().json(e.to_response()),
+            AppError::UserBlockSelf => HttpResponse::BadRequest().json(e.to_response()),
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(UserBlockResponse {
+        code: "USER_BLOCKED".to_string(),
+        user_block: Some(block.to_user_block_data()),
+    })
+}
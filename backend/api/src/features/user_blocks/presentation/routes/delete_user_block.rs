@@ -0,0 +1,67 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        user_blocks::{
+            application::{
+                dto::{
+                    requests::user_block::UserBlockParams, responses::user_block::UserBlockResponse,
+                },
+                use_cases::delete_user_block::DeleteUserBlockUseCase,
+            },
+            infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[delete("/{user_id}")]
+pub async fn delete_user_block(
+    pool: Data<PgPool>,
+    params: Path<UserBlockParams>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repository and use case
+    let pool_clone = pool.get_ref().clone();
+    let use_case = DeleteUserBlockUseCase::new(UserBlockRepositoryImpl::new(pool_clone));
+
+    if let Err(e) = use_case
+        .execute(request_claims.user_id, params.user_id, &mut transaction)
+        .await
+    {
+        error!("Error: {:?}", e);
+        if let Err(e) = transaction.rollback().await {
+            error!("Error rolling back: {}", e);
+        }
+        return match e {
+            AppError::UserBlockNotFound => HttpResponse::NotFound().json(e.to_response()),
+            _ => HttpResponse::InternalServerError().json(e.to_response()),
+        };
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    HttpResponse::Ok().json(UserBlockResponse {
+        code: "USER_UNBLOCKED".to_string(),
+        user_block: None,
+    })
+}
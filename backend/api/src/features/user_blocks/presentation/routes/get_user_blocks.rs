@@ -0,0 +1,57 @@
+use crate::{
+    core::constants::errors::AppError,
+    features::{
+        auth::domain::entities::Claims,
+        user_blocks::{
+            application::dto::responses::user_block::UserBlocksResponse,
+            infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl,
+        },
+    },
+};
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse, Responder,
+};
+use sqlx::PgPool;
+use tracing::error;
+
+#[get("/")]
+pub async fn get_user_blocks(
+    pool: Data<PgPool>,
+    request_claims: ReqData<Claims>,
+) -> impl Responder {
+    let mut transaction = match pool.begin().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Error: {}", e);
+            return HttpResponse::InternalServerError()
+                .json(AppError::DatabaseConnection.to_response());
+        }
+    };
+
+    // Create repository
+    let pool_clone = pool.get_ref().clone();
+    let user_block_repo = UserBlockRepositoryImpl::new(pool_clone);
+
+    let result = user_block_repo
+        .get_by_blocker_with_executor(request_claims.user_id, &mut *transaction)
+        .await;
+
+    if let Err(e) = transaction.commit().await {
+        error!("Error: {}", e);
+        return HttpResponse::InternalServerError()
+            .json(AppError::DatabaseTransaction.to_response());
+    }
+
+    match result {
+        Ok(blocks) => HttpResponse::Ok().json(UserBlocksResponse {
+            code: "USER_BLOCKS_FETCHED".to_string(),
+            user_blocks: blocks.iter().map(|b| b.to_user_block_data()).collect(),
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            HttpResponse::InternalServerError().json(AppError::DatabaseQuery.to_response())
+        }
+    }
+}
@@ -0,0 +1,5 @@
+// User blocks presentation routes
+
+pub mod create_user_block;
+pub mod delete_user_block;
+pub mod get_user_blocks;
@@ -7,7 +7,10 @@ pub mod core {
     }
 
     pub mod helpers {
+        pub mod attachments;
         pub mod mock_now;
+        pub mod private_message_expiration;
+        pub mod public_message_subscriptions;
         pub mod startup;
         pub mod translation;
         pub mod user_deletion;
@@ -79,4 +82,35 @@ pub mod features {
         pub mod infrastructure;
         pub mod presentation;
     }
+
+    pub mod roles {
+        pub mod application;
+        pub mod domain;
+        pub mod infrastructure;
+        pub mod presentation;
+    }
+    pub mod stories {
+        pub mod application;
+        pub mod domain;
+        pub mod infrastructure;
+        pub mod presentation;
+    }
+    pub mod user_blocks {
+        pub mod application;
+        pub mod domain;
+        pub mod infrastructure;
+        pub mod presentation;
+    }
+    pub mod content_filters {
+        pub mod application;
+        pub mod domain;
+        pub mod infrastructure;
+        pub mod presentation;
+    }
+    pub mod attachments {
+        pub mod application;
+        pub mod domain;
+        pub mod infrastructure;
+        pub mod presentation;
+    }
 }
@@ -4,11 +4,16 @@ use std::net::TcpListener;
 use std::sync::Arc;
 use std::time::Duration;
 
-use crate::configuration::{DatabaseSettings, Settings};
+use crate::configuration::{DatabaseSettings, ModerationSettings, Settings};
 use crate::core::helpers::translation::Translator;
 use crate::core::presentation::middlewares::token_validator::TokenValidator;
 use crate::core::presentation::routes::health_check::health_check;
 use crate::core::presentation::routes::version::version_check;
+use crate::features::attachments::domain::services::storage_backend::StorageBackend;
+use crate::features::attachments::infrastructure::services::local_storage_backend::LocalStorageBackend;
+use crate::features::attachments::presentation::routes::create_attachment::create_attachment;
+use crate::features::attachments::presentation::routes::get_attachment::get_attachment;
+use crate::features::attachments::presentation::routes::get_attachment_thumbnail::get_attachment_thumbnail;
 use crate::features::auth::presentation::routes::disable_otp::disable;
 use crate::features::auth::presentation::routes::generate_otp::generate;
 use crate::features::auth::presentation::routes::login::log_user_in;
@@ -41,6 +46,12 @@ use crate::features::challenges::presentation::routes::update_challenge::update_
 use crate::features::challenges::presentation::routes::update_challenge_daily_tracking::update_challenge_daily_tracking;
 use crate::features::challenges::presentation::routes::update_challenge_participation::update_challenge_participation;
 use crate::features::challenges::domain::entities::challenge_statistics::ChallengeStatisticsCache;
+use crate::features::content_filters::presentation::routes::create_content_filter_rule::create_content_filter_rule;
+use crate::features::content_filters::presentation::routes::delete_content_filter_flag::delete_content_filter_flag;
+use crate::features::content_filters::presentation::routes::delete_content_filter_rule::delete_content_filter_rule;
+use crate::features::content_filters::presentation::routes::get_content_filter_flags::get_content_filter_flags;
+use crate::features::content_filters::presentation::routes::get_content_filter_rules::get_content_filter_rules;
+use crate::features::content_filters::presentation::routes::update_content_filter_rule::update_content_filter_rule;
 use crate::features::habits::presentation::routes::create_habit::create_habit;
 use crate::features::habits::presentation::routes::create_habit_category::create_habit_category;
 use crate::features::habits::presentation::routes::create_habit_daily_tracking::create_habit_daily_tracking;
@@ -68,19 +79,39 @@ use crate::features::notifications::presentation::routes::delete_all_notificatio
 use crate::features::notifications::presentation::routes::delete_notification::delete_notification;
 use crate::features::notifications::presentation::routes::get_notifications::get_notifications;
 use crate::features::notifications::presentation::routes::mark_notification_as_seen::mark_notification_as_seen;
+use crate::features::private_discussions::presentation::routes::add_private_discussion_member::add_private_discussion_member;
+use crate::features::private_discussions::presentation::routes::answer_private_discussion_request::answer_private_discussion_request;
 use crate::features::private_discussions::presentation::routes::create_private_discussion::create_private_discussion;
+use crate::features::private_discussions::presentation::routes::create_private_discussion_group::create_private_discussion_group;
+use crate::features::private_discussions::presentation::routes::create_private_discussion_report::create_private_discussion_report;
 use crate::features::private_discussions::presentation::routes::create_private_message::create_private_message;
+use crate::features::private_discussions::presentation::routes::create_private_message_attachment::create_private_message_attachment;
 use crate::features::private_discussions::presentation::routes::delete_private_message::delete_private_message;
+use crate::features::private_discussions::presentation::routes::distribute_private_message_session_keys::distribute_private_message_session_keys;
+use crate::features::private_discussions::presentation::routes::get_private_discussion_device_keys::get_private_discussion_device_keys;
+use crate::features::private_discussions::presentation::routes::get_private_discussion_members::get_private_discussion_members;
 use crate::features::private_discussions::presentation::routes::get_private_discussion_messages::get_private_discussion_messages;
+use crate::features::private_discussions::presentation::routes::get_private_discussion_reports::get_private_discussion_reports;
 use crate::features::private_discussions::presentation::routes::get_private_discussions::get_private_discussions;
+use crate::features::private_discussions::presentation::routes::get_private_message_attachment::get_private_message_attachment;
+use crate::features::private_discussions::presentation::routes::mark_message_as_delivered::mark_message_as_delivered;
 use crate::features::private_discussions::presentation::routes::mark_message_as_seen::mark_message_as_seen;
+use crate::features::private_discussions::presentation::routes::mark_private_discussion_as_seen::mark_private_discussion_as_seen;
+use crate::features::private_discussions::presentation::routes::remove_private_discussion_member::remove_private_discussion_member;
+use crate::features::private_discussions::presentation::routes::resolve_private_discussion_report::resolve_private_discussion_report;
+use crate::features::private_discussions::presentation::routes::update_private_discussion_member::update_private_discussion_member;
 use crate::features::private_discussions::presentation::routes::update_private_discussion_participation::update_private_discussion_participation;
+use crate::features::private_discussions::presentation::routes::update_private_discussion_retention::update_private_discussion_retention;
+use crate::features::private_discussions::presentation::routes::update_private_discussion_settings::update_private_discussion_settings;
 use crate::features::private_discussions::presentation::routes::update_private_message::update_private_message;
 use crate::features::profile::helpers::redis_handler::handle_redis_messages;
 use crate::features::profile::presentation::routes::delete_account::delete_account;
 use crate::features::profile::presentation::routes::delete_device::delete_device;
 use crate::features::profile::presentation::routes::get_devices::get_devices;
+use crate::features::auth::presentation::routes::register_device_key::register_device_key;
 use crate::features::profile::presentation::routes::get_profile_information::get_profile_information;
+use crate::features::profile::presentation::routes::get_friend_matches::get_friend_matches;
+use crate::features::profile::presentation::routes::get_public_profile::get_public_profile;
 use crate::features::profile::presentation::routes::get_user_data_by_username::get_user_data_by_username;
 use crate::features::profile::presentation::routes::get_users_data_by_id::get_users_data_by_id;
 use crate::features::profile::presentation::routes::is_otp_enabled::is_otp_enabled;
@@ -91,19 +122,46 @@ use crate::features::profile::presentation::routes::update_password::update_pass
 use crate::features::profile::domain::entities::UserPublicDataCache;
 use crate::features::public_discussions::presentation::routes::create_public_message::create_public_message;
 use crate::features::public_discussions::presentation::routes::create_public_message_like::create_public_message_like;
+use crate::features::public_discussions::presentation::routes::create_public_message_reaction::create_public_message_reaction;
 use crate::features::public_discussions::presentation::routes::create_public_message_report::create_public_message_report;
+use crate::features::public_discussions::presentation::routes::create_public_message_subscription::create_public_message_subscription;
 use crate::features::public_discussions::presentation::routes::delete_public_message::delete_public_message;
 use crate::features::public_discussions::presentation::routes::delete_public_message_like::delete_public_message_like;
+use crate::features::public_discussions::presentation::routes::delete_public_message_reaction::delete_public_message_reaction;
 use crate::features::public_discussions::presentation::routes::delete_public_message_report::delete_public_message_report;
+use crate::features::public_discussions::presentation::routes::delete_public_message_subscription::delete_public_message_subscription;
 use crate::features::public_discussions::presentation::routes::get_message::get_message;
 use crate::features::public_discussions::presentation::routes::get_message_parents::get_message_parents;
 use crate::features::public_discussions::presentation::routes::get_message_reports::get_message_reports;
+use crate::features::public_discussions::presentation::routes::get_moderation_decisions::get_moderation_decisions;
+use crate::features::public_discussions::presentation::routes::get_moderation_queue::get_moderation_queue;
+use crate::features::public_discussions::presentation::routes::get_public_message_reactions::get_public_message_reactions;
+use crate::features::public_discussions::presentation::routes::get_public_message_revisions::get_public_message_revisions;
+use crate::features::public_discussions::presentation::routes::get_public_message_subscriptions::get_public_message_subscriptions;
 use crate::features::public_discussions::presentation::routes::get_public_messages::get_public_messages;
 use crate::features::public_discussions::presentation::routes::get_replies::get_replies;
 use crate::features::public_discussions::presentation::routes::get_user_liked_messages::get_user_liked_messages;
 use crate::features::public_discussions::presentation::routes::get_user_message_reports::get_user_message_reports;
 use crate::features::public_discussions::presentation::routes::get_user_written_messages::get_user_written_messages;
+use crate::features::public_discussions::presentation::routes::pin_public_message::pin_public_message;
+use crate::features::public_discussions::presentation::routes::resolve_public_message_reports::resolve_public_message_reports;
+use crate::features::public_discussions::presentation::routes::unpin_public_message::unpin_public_message;
 use crate::features::public_discussions::presentation::routes::update_public_message::update_public_message;
+use crate::features::public_discussions::presentation::routes::update_public_message_subscription::update_public_message_subscription;
+use crate::features::roles::presentation::routes::create_role_grant::create_role_grant;
+use crate::features::roles::presentation::routes::delete_role_grant::delete_role_grant;
+use crate::features::roles::presentation::routes::get_roles::get_roles;
+use crate::features::roles::presentation::routes::get_user_role_grants::get_user_role_grants;
+use crate::features::stories::presentation::routes::create_story::create_story;
+use crate::features::stories::presentation::routes::create_story_report::create_story_report;
+use crate::features::stories::presentation::routes::delete_story::delete_story;
+use crate::features::stories::presentation::routes::get_stories::get_stories;
+use crate::features::stories::presentation::routes::get_story_views::get_story_views;
+use crate::features::stories::presentation::routes::resolve_story_reports::resolve_story_reports;
+use crate::features::stories::presentation::routes::view_story::view_story;
+use crate::features::user_blocks::presentation::routes::create_user_block::create_user_block;
+use crate::features::user_blocks::presentation::routes::delete_user_block::delete_user_block;
+use crate::features::user_blocks::presentation::routes::get_user_blocks::get_user_blocks;
 use actix_cors::Cors;
 use actix_http::header::HeaderName;
 use actix_web::body::MessageBody;
@@ -120,6 +178,10 @@ use tokio::task;
 pub async fn run(listener: TcpListener, configuration: Settings) -> Result<Server, std::io::Error> {
     let connection_pool = get_connection_pool(&configuration.database).await.unwrap();
     let secret = configuration.application.secret;
+    let moderation_settings = configuration.moderation;
+    let storage_backend: Arc<dyn StorageBackend> = Arc::new(LocalStorageBackend::new(
+        configuration.storage.local_directory,
+    ));
     let habit_statistics_cache = HabitStatisticsCache::default();
     let challenge_statistics_cache = ChallengeStatisticsCache::default();
     let token_cache = TokenCache::default();
@@ -149,6 +211,8 @@ pub async fn run(listener: TcpListener, configuration: Settings) -> Result<Serve
             user_public_data_cache.clone(),
             redis_client.clone(),
             translator,
+            moderation_settings.clone(),
+            storage_backend.clone(),
         )
     })
     .listen(listener)?
@@ -167,6 +231,8 @@ struct AppConfig {
     user_public_data_cache: UserPublicDataCache,
     redis_client: Client,
     translator: Arc<Translator>,
+    moderation_settings: ModerationSettings,
+    storage_backend: Arc<dyn StorageBackend>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -179,6 +245,8 @@ pub fn create_app(
     user_public_data_cache: UserPublicDataCache,
     redis_client: Client,
     translator: Arc<Translator>,
+    moderation_settings: ModerationSettings,
+    storage_backend: Arc<dyn StorageBackend>,
 ) -> App<
     impl ServiceFactory<
         ServiceRequest,
@@ -197,6 +265,8 @@ pub fn create_app(
         user_public_data_cache,
         redis_client,
         translator,
+        moderation_settings,
+        storage_backend,
     };
 
     _create_app_internal(config)
@@ -270,6 +340,8 @@ fn _create_app_internal(
                             .service(get_profile_information)
                             .service(get_users_data_by_id)
                             .service(get_user_data_by_username)
+                            .service(get_public_profile)
+                            .service(get_friend_matches)
                             .service(post_profile_information)
                             .service(set_password)
                             .service(delete_account)
@@ -280,6 +352,7 @@ fn _create_app_internal(
                     web::scope("/devices")
                         .wrap(TokenValidator {})
                         .service(get_devices)
+                        .service(register_device_key)
                         .service(delete_device),
                 )
                 .service(
@@ -388,10 +461,14 @@ fn _create_app_internal(
                             .service(delete_public_message)
                             .service(get_message_parents)
                             .service(get_public_messages)
+                            .service(get_public_message_reactions)
+                            .service(get_public_message_revisions)
                             .service(get_replies)
                             .service(get_message)
                             .service(get_user_liked_messages)
                             .service(get_user_written_messages)
+                            .service(pin_public_message)
+                            .service(unpin_public_message)
                             .service(update_public_message),
                     ),
                 )
@@ -403,6 +480,23 @@ fn _create_app_internal(
                             .service(delete_public_message_like),
                     ),
                 )
+                .service(
+                    web::scope("/attachments").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(create_attachment)
+                            .service(get_attachment_thumbnail)
+                            .service(get_attachment),
+                    ),
+                )
+                .service(
+                    web::scope("/public-message-reactions").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(create_public_message_reaction)
+                            .service(delete_public_message_reaction),
+                    ),
+                )
                 .service(
                     web::scope("/public-message-reports").service(
                         web::scope("")
@@ -413,6 +507,26 @@ fn _create_app_internal(
                             .service(get_user_message_reports),
                     ),
                 )
+                .service(
+                    web::scope("/public-message-subscriptions").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(get_public_message_subscriptions)
+                            .service(create_public_message_subscription)
+                            .service(update_public_message_subscription)
+                            .service(delete_public_message_subscription),
+                    ),
+                )
+                .service(
+                    web::scope("/moderation-queue").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(get_moderation_queue)
+                            .service(get_moderation_decisions)
+                            .service(resolve_public_message_reports)
+                            .service(resolve_story_reports),
+                    ),
+                )
                 .service(
                     web::scope("/private-messages").service(
                         web::scope("")
@@ -421,9 +535,21 @@ fn _create_app_internal(
                             .service(delete_private_message)
                             .service(update_private_message)
                             .service(mark_message_as_seen)
+                            .service(mark_message_as_delivered)
+                            .service(create_private_message_attachment)
+                            .service(get_private_message_attachment)
                             .service(get_private_discussion_messages),
                     ),
                 )
+                .service(
+                    web::scope("/private-discussion-reports").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(create_private_discussion_report)
+                            .service(get_private_discussion_reports)
+                            .service(resolve_private_discussion_report),
+                    ),
+                )
                 .service(
                     web::scope("/private-discussion-participations").service(
                         web::scope("")
@@ -436,7 +562,18 @@ fn _create_app_internal(
                         web::scope("")
                             .wrap(TokenValidator {})
                             .service(create_private_discussion)
-                            .service(get_private_discussions),
+                            .service(create_private_discussion_group)
+                            .service(get_private_discussions)
+                            .service(get_private_discussion_members)
+                            .service(get_private_discussion_device_keys)
+                            .service(add_private_discussion_member)
+                            .service(update_private_discussion_member)
+                            .service(remove_private_discussion_member)
+                            .service(distribute_private_message_session_keys)
+                            .service(mark_private_discussion_as_seen)
+                            .service(update_private_discussion_retention)
+                            .service(update_private_discussion_settings)
+                            .service(answer_private_discussion_request),
                     ),
                 )
                 .service(
@@ -449,6 +586,49 @@ fn _create_app_internal(
                             .service(mark_notification_as_seen)
                             .service(set_fcm_token),
                     ),
+                )
+                .service(
+                    web::scope("/roles").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(get_roles)
+                            .service(get_user_role_grants)
+                            .service(create_role_grant)
+                            .service(delete_role_grant),
+                    ),
+                )
+                .service(
+                    web::scope("/stories").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(create_story_report)
+                            .service(get_stories)
+                            .service(create_story)
+                            .service(view_story)
+                            .service(get_story_views)
+                            .service(delete_story),
+                    ),
+                )
+                .service(
+                    web::scope("/user-blocks").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(get_user_blocks)
+                            .service(create_user_block)
+                            .service(delete_user_block),
+                    ),
+                )
+                .service(
+                    web::scope("/content-filters").service(
+                        web::scope("")
+                            .wrap(TokenValidator {})
+                            .service(get_content_filter_flags)
+                            .service(get_content_filter_rules)
+                            .service(create_content_filter_rule)
+                            .service(update_content_filter_rule)
+                            .service(delete_content_filter_flag)
+                            .service(delete_content_filter_rule),
+                    ),
                 ),
         )
         .wrap(cors)
@@ -461,6 +641,8 @@ fn _create_app_internal(
         .app_data(web::Data::new(config.user_public_data_cache))
         .app_data(web::Data::new(config.redis_client))
         .app_data(web::Data::new(config.translator))
+        .app_data(web::Data::new(config.moderation_settings))
+        .app_data(web::Data::from(config.storage_backend))
 }
 
 pub struct Application {
@@ -0,0 +1,345 @@
+use std::io::Cursor;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::features::{
+    attachments::{
+        application::dto::responses::attachment::AttachmentResponse,
+        domain::entities::attachment::{AttachmentData, ATTACHMENT_MAX_SIZE_IN_BYTES},
+    },
+    public_discussions::{
+        application::dto::{
+            requests::public_message::PublicMessageCreateRequest,
+            responses::public_message::PublicMessageResponse,
+        },
+        domain::entities::public_message::PublicMessageData,
+    },
+};
+use image::{DynamicImage, ImageFormat, RgbImage};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::challenge::user_creates_a_challenge,
+    helpers::spawn_app,
+    public_discussions::public_message::user_deletes_a_public_message,
+};
+
+const BOUNDARY: &str = "reallystick-test-boundary";
+
+pub fn an_image(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+    let image = DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, |x, y| {
+        image::Rgb([(x * 5) as u8, (y * 5) as u8, 128])
+    }));
+    let mut content = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut content), format)
+        .unwrap();
+    content
+}
+
+pub async fn user_uploads_an_attachment(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    content_type: &str,
+    content: &[u8],
+) -> (u16, Option<AttachmentData>) {
+    let mut body = format!(
+        "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"image\"\r\nContent-Type: {}\r\n\r\n",
+        BOUNDARY, content_type
+    )
+    .into_bytes();
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+
+    let req = test::TestRequest::post()
+        .uri("/api/attachments/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header((
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(body)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    let response: AttachmentResponse = serde_json::from_slice(&body).unwrap();
+
+    if status == 200 {
+        assert_eq!(response.code, "ATTACHMENT_CREATED");
+    }
+
+    (status, response.attachment)
+}
+
+pub async fn user_gets_an_attachment(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    attachment_id: Uuid,
+    thumbnail: bool,
+) -> (u16, Option<String>, Vec<u8>) {
+    let mut uri = format!("/api/attachments/{}", attachment_id);
+    if thumbnail {
+        uri.push_str("/thumbnail");
+    }
+
+    let req = test::TestRequest::get()
+        .uri(&uri)
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .map(|value| value.to_str().unwrap().to_string());
+
+    (
+        status,
+        content_type,
+        test::read_body(response).await.to_vec(),
+    )
+}
+
+pub async fn user_creates_a_public_message_with_attachments(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    challenge_id: Uuid,
+    content: &str,
+    attachment_ids: Vec<Uuid>,
+) -> (u16, Option<PublicMessageData>) {
+    let req = test::TestRequest::post()
+        .uri("/api/public-messages/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PublicMessageCreateRequest {
+            challenge_id: Some(challenge_id),
+            habit_id: None,
+            thread_id: None,
+            replies_to: None,
+            content: content.to_string(),
+            attachment_ids,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PublicMessageResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_MESSAGE_CREATED");
+
+    (status, response.message)
+}
+
+#[sqlx::test]
+pub async fn users_can_attach_images_to_a_public_message(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let (reader_token, _) = user_signs_up(&app, Some("testusername2")).await;
+
+    let (status, attachment) = user_uploads_an_attachment(
+        &app,
+        &author_token,
+        "image/png",
+        &an_image(640, 480, ImageFormat::Png),
+    )
+    .await;
+    assert_eq!(status, 200);
+    let attachment = attachment.unwrap();
+    assert_eq!(attachment.content_type, "image/png");
+    assert_eq!((attachment.width, attachment.height), (640, 480));
+    assert!(attachment.public_message_id.is_none());
+
+    let (status, content_type, content) =
+        user_gets_an_attachment(&app, &author_token, attachment.id, true).await;
+    assert_eq!(status, 200);
+    assert_eq!(content_type.as_deref(), Some("image/png"));
+    let thumbnail = image::load_from_memory(&content).unwrap();
+    assert_eq!((thumbnail.width(), thumbnail.height()), (320, 240));
+
+    // Until it is posted, only the uploader can see an attachment
+    let (status, _, _) = user_gets_an_attachment(&app, &reader_token, attachment.id, false).await;
+    assert_eq!(status, 404);
+
+    // A message with images does not need any text
+    let (status, message) = user_creates_a_public_message_with_attachments(
+        &app,
+        &author_token,
+        challenge_id,
+        "",
+        vec![attachment.id],
+    )
+    .await;
+    assert_eq!(status, 200);
+    let message = message.unwrap();
+    assert_eq!(message.attachment_ids, vec![attachment.id]);
+
+    let (status, content_type, _) =
+        user_gets_an_attachment(&app, &reader_token, attachment.id, false).await;
+    assert_eq!(status, 200);
+    assert_eq!(content_type.as_deref(), Some("image/png"));
+
+    // An attachment belongs to a single message
+    let (status, _) = user_creates_a_public_message_with_attachments(
+        &app,
+        &author_token,
+        challenge_id,
+        "Again",
+        vec![attachment.id],
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    user_deletes_a_public_message(&app, &author_token, message.id, false).await;
+
+    let (status, _, _) = user_gets_an_attachment(&app, &author_token, attachment.id, false).await;
+    assert_eq!(status, 404);
+}
+
+#[sqlx::test]
+pub async fn attachments_of_messages_deleted_by_an_admin_are_deleted(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+
+    let (_, attachment) = user_uploads_an_attachment(
+        &app,
+        &author_token,
+        "image/jpeg",
+        &an_image(64, 64, ImageFormat::Jpeg),
+    )
+    .await;
+    let attachment = attachment.unwrap();
+
+    let (_, message) = user_creates_a_public_message_with_attachments(
+        &app,
+        &author_token,
+        challenge_id,
+        "Look at this",
+        vec![attachment.id],
+    )
+    .await;
+
+    user_deletes_a_public_message(&app, &admin_token, message.unwrap().id, true).await;
+
+    let (status, _, _) = user_gets_an_attachment(&app, &author_token, attachment.id, true).await;
+    assert_eq!(status, 404);
+}
+
+#[sqlx::test]
+pub async fn attachment_uploads_are_validated(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (user_token, _) = user_signs_up(&app, None).await;
+    let png = an_image(16, 16, ImageFormat::Png);
+
+    // The declared type has to match the content
+    let (status, _) = user_uploads_an_attachment(&app, &user_token, "image/jpeg", &png).await;
+    assert_eq!(status, 415);
+
+    let (status, _) = user_uploads_an_attachment(&app, &user_token, "image/gif", &png).await;
+    assert_eq!(status, 415);
+
+    let (status, _) =
+        user_uploads_an_attachment(&app, &user_token, "image/png", b"not an image").await;
+    assert_eq!(status, 415);
+
+    // A valid signature is not enough
+    let (status, _) =
+        user_uploads_an_attachment(&app, &user_token, "image/png", &png[..png.len() / 2]).await;
+    assert_eq!(status, 400);
+
+    let (status, _) = user_uploads_an_attachment(
+        &app,
+        &user_token,
+        "image/png",
+        &vec![0; ATTACHMENT_MAX_SIZE_IN_BYTES + 1],
+    )
+    .await;
+    assert_eq!(status, 413);
+
+    // A message has a limited number of attachments
+    let mut attachment_ids = Vec::new();
+    for _ in 0..5 {
+        let (_, attachment) =
+            user_uploads_an_attachment(&app, &user_token, "image/png", &png).await;
+        attachment_ids.push(attachment.unwrap().id);
+    }
+    let (status, _) = user_creates_a_public_message_with_attachments(
+        &app,
+        &user_token,
+        challenge_id,
+        "Too many",
+        attachment_ids.clone(),
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    // Users can only post their own uploads
+    let (other_user_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    let (status, _) = user_creates_a_public_message_with_attachments(
+        &app,
+        &other_user_token,
+        challenge_id,
+        "Not mine",
+        vec![attachment_ids[0]],
+    )
+    .await;
+    assert_eq!(status, 400);
+}
+
+#[sqlx::test]
+pub async fn attachment_metadata_is_stripped(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (user_token, _) = user_signs_up(&app, None).await;
+
+    // EXIF segment rotating the image by 90 degrees, followed by a fake GPS position
+    let mut tiff = b"II*\0\x08\0\0\0\x01\0\x12\x01\x03\0\x01\0\0\0\x06\0\0\0\0\0\0\0".to_vec();
+    tiff.extend_from_slice(b"GPS 48.8584 N 2.2945 E");
+    let mut exif = b"Exif\0\0".to_vec();
+    exif.extend_from_slice(&tiff);
+
+    let jpeg = an_image(40, 20, ImageFormat::Jpeg);
+    let mut content = jpeg[..2].to_vec();
+    content.extend_from_slice(&[0xFF, 0xE1]);
+    content.extend_from_slice(&((exif.len() + 2) as u16).to_be_bytes());
+    content.extend_from_slice(&exif);
+    content.extend_from_slice(&jpeg[2..]);
+
+    let (status, attachment) =
+        user_uploads_an_attachment(&app, &user_token, "image/jpeg", &content).await;
+    assert_eq!(status, 200);
+    let attachment = attachment.unwrap();
+
+    // The orientation is applied before the metadata is dropped
+    assert_eq!((attachment.width, attachment.height), (20, 40));
+
+    let (_, _, stored) = user_gets_an_attachment(&app, &user_token, attachment.id, false).await;
+    let contains = |needle: &[u8]| stored.windows(needle.len()).any(|w| w == needle);
+    assert!(!contains(b"Exif"));
+    assert!(!contains(b"GPS"));
+    assert_eq!(
+        image::guess_format(&stored).unwrap(),
+        image::ImageFormat::Jpeg
+    );
+}
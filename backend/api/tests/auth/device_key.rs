@@ -0,0 +1,369 @@
+use std::collections::BTreeMap;
+
+use actix_http::Request;
+use actix_web::body::MessageBody;
+use actix_web::dev::{Service, ServiceResponse};
+use actix_web::http::header::{self, ContentType};
+use actix_web::{test, Error};
+use api::core::structs::responses::GenericResponse;
+use api::features::auth::application::dto::requests::RegisterDeviceKeyRequest;
+use api::features::auth::application::dto::responses::{DeviceKeyResponse, DeviceKeysResponse};
+use api::features::auth::domain::entities::DeviceKeyData;
+use api::features::private_discussions::application::dto::requests::private_message::PrivateMessageCreateRequest;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::login::user_logs_in;
+use crate::auth::signup::user_signs_up;
+use crate::auth::token::user_refreshes_token;
+use crate::helpers::spawn_app;
+use crate::private_discussions::private_discussion_group::user_creates_a_private_discussion_group;
+use crate::private_discussions::private_message::user_gets_private_messages_of_discussion;
+use crate::profile::devices::{user_gets_list_of_devices, user_removes_a_device};
+use crate::profile::profile::user_has_access_to_protected_route;
+
+pub async fn user_registers_a_device_key(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    public_key: &str,
+) -> DeviceKeyData {
+    let req = test::TestRequest::post()
+        .uri("/api/devices/keys")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(RegisterDeviceKeyRequest {
+            public_key: public_key.to_string(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: DeviceKeyResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "DEVICE_KEY_REGISTERED");
+    response.device_key.unwrap()
+}
+
+pub async fn user_gets_private_discussion_device_keys(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+) -> Vec<DeviceKeyData> {
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/private-discussions/{}/device-keys",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: DeviceKeysResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "DEVICE_KEYS_FETCHED");
+    response.device_keys
+}
+
+pub async fn user_creates_a_message_for_devices(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    members: &[Uuid],
+    device_keys: &[Uuid],
+) -> u16 {
+    let req = test::TestRequest::post()
+        .uri("/api/private-messages/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateMessageCreateRequest {
+            discussion_id,
+            content: "content".to_string(),
+            creator_encrypted_session_key: String::new(),
+            recipient_encrypted_session_key: String::new(),
+            encrypted_session_keys: members
+                .iter()
+                .map(|member| (*member, format!("session-key-for-{}", member)))
+                .collect(),
+            device_encrypted_session_keys: device_keys
+                .iter()
+                .map(|key| (*key, format!("session-key-for-device-{}", key)))
+                .collect::<BTreeMap<Uuid, String>>(),
+            attachment_ids: Vec::new(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+#[sqlx::test]
+async fn user_can_register_a_device_key(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+
+    let alice_key = user_registers_a_device_key(&app, &alice_token, "alice-public-key").await;
+    assert_eq!(alice_key.user_id, alice_id);
+
+    // Registering again replaces the key of the device
+    let alice_key = user_registers_a_device_key(&app, &alice_token, "alice-new-key").await;
+    let bob_key = user_registers_a_device_key(&app, &bob_token, "bob-public-key").await;
+
+    let discussion_id =
+        user_creates_a_private_discussion_group(&app, &alice_token, "Runners", vec![bob_id]).await;
+
+    let device_keys =
+        user_gets_private_discussion_device_keys(&app, &bob_token, discussion_id).await;
+    assert_eq!(device_keys.len(), 2);
+    assert!(device_keys
+        .iter()
+        .any(|k| k.id == alice_key.id && k.public_key == "alice-new-key"));
+    assert!(device_keys.iter().any(|k| k.id == bob_key.id));
+
+    // Non-members can not see the devices of the group
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/private-discussions/{}/device-keys",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", thomas_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[sqlx::test]
+async fn message_must_be_encrypted_for_every_registered_device(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+
+    let alice_key = user_registers_a_device_key(&app, &alice_token, "alice-public-key").await;
+    let bob_key = user_registers_a_device_key(&app, &bob_token, "bob-public-key").await;
+
+    let discussion_id =
+        user_creates_a_private_discussion_group(&app, &alice_token, "Runners", vec![bob_id]).await;
+    let members = [alice_id, bob_id];
+
+    // Once members registered device keys, messages must be encrypted for their devices
+    let status =
+        user_creates_a_message_for_devices(&app, &alice_token, discussion_id, &members, &[]).await;
+    assert_eq!(status, 400);
+
+    let status = user_creates_a_message_for_devices(
+        &app,
+        &alice_token,
+        discussion_id,
+        &members,
+        &[alice_key.id],
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    let status = user_creates_a_message_for_devices(
+        &app,
+        &alice_token,
+        discussion_id,
+        &members,
+        &[alice_key.id, bob_key.id, Uuid::new_v4()],
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    let status = user_creates_a_message_for_devices(
+        &app,
+        &alice_token,
+        discussion_id,
+        &members,
+        &[alice_key.id, bob_key.id],
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    let messages = user_gets_private_messages_of_discussion(&app, &bob_token, discussion_id).await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        messages[0].device_encrypted_session_keys[&bob_key.id],
+        format!("session-key-for-device-{}", bob_key.id)
+    );
+
+    // The session keys encrypted for their accounts are not kept
+    assert!(messages[0].encrypted_session_keys.is_empty());
+    assert!(messages[0].creator_encrypted_session_key.is_empty());
+}
+
+#[sqlx::test]
+async fn device_key_survives_token_refresh(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, alice_refresh_token) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+
+    let alice_key = user_registers_a_device_key(&app, &alice_token, "alice-public-key").await;
+    let discussion_id =
+        user_creates_a_private_discussion_group(&app, &alice_token, "Runners", vec![bob_id]).await;
+
+    let (alice_token, _) = user_refreshes_token(&app, &alice_refresh_token).await;
+
+    let device_keys =
+        user_gets_private_discussion_device_keys(&app, &alice_token, discussion_id).await;
+    assert_eq!(device_keys.len(), 1);
+    assert_eq!(device_keys[0].id, alice_key.id);
+
+    // The refreshed session still owns the key
+    let alice_new_key = user_registers_a_device_key(&app, &alice_token, "alice-new-key").await;
+    let device_keys =
+        user_gets_private_discussion_device_keys(&app, &alice_token, discussion_id).await;
+    assert_eq!(device_keys.len(), 1);
+    assert_eq!(device_keys[0].id, alice_new_key.id);
+}
+
+#[sqlx::test]
+async fn revoked_device_loses_its_key_and_session_keys(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (bob_phone_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_phone_token)
+        .await
+        .id;
+    let (bob_laptop_token, _) = user_logs_in(&app, "bob", "password1_").await;
+
+    let alice_key = user_registers_a_device_key(&app, &alice_token, "alice-public-key").await;
+    let bob_phone_key = user_registers_a_device_key(&app, &bob_phone_token, "phone-key").await;
+    let bob_laptop_key = user_registers_a_device_key(&app, &bob_laptop_token, "laptop-key").await;
+
+    let discussion_id =
+        user_creates_a_private_discussion_group(&app, &alice_token, "Runners", vec![bob_id]).await;
+    let members = [alice_id, bob_id];
+
+    let status = user_creates_a_message_for_devices(
+        &app,
+        &alice_token,
+        discussion_id,
+        &members,
+        &[alice_key.id, bob_phone_key.id, bob_laptop_key.id],
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    // Bob revokes his phone from his laptop
+    let devices = user_gets_list_of_devices(&app, &bob_laptop_token).await;
+    assert_eq!(devices.len(), 2);
+    let phone_device_id = devices.first().unwrap().token_id;
+    user_removes_a_device(&app, &bob_laptop_token, phone_device_id).await;
+
+    let device_keys =
+        user_gets_private_discussion_device_keys(&app, &alice_token, discussion_id).await;
+    assert_eq!(device_keys.len(), 2);
+    assert!(!device_keys.iter().any(|k| k.id == bob_phone_key.id));
+
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &bob_laptop_token, discussion_id).await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].device_encrypted_session_keys.len(), 2);
+    assert!(!messages[0]
+        .device_encrypted_session_keys
+        .contains_key(&bob_phone_key.id));
+
+    // Future messages must no longer include the revoked device
+    let status = user_creates_a_message_for_devices(
+        &app,
+        &alice_token,
+        discussion_id,
+        &members,
+        &[alice_key.id, bob_phone_key.id, bob_laptop_key.id],
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    let status = user_creates_a_message_for_devices(
+        &app,
+        &alice_token,
+        discussion_id,
+        &members,
+        &[alice_key.id, bob_laptop_key.id],
+    )
+    .await;
+    assert_eq!(status, 200);
+}
+
+#[sqlx::test]
+async fn revoked_device_can_not_open_new_messages_with_the_account_key(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (bob_phone_token, bob_phone_refresh_token) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_phone_token)
+        .await
+        .id;
+    let (bob_laptop_token, _) = user_logs_in(&app, "bob", "password1_").await;
+
+    // Only Bob's phone has a key of its own
+    user_registers_a_device_key(&app, &bob_phone_token, "phone-key").await;
+
+    let discussion_id =
+        user_creates_a_private_discussion_group(&app, &alice_token, "Runners", vec![bob_id]).await;
+    let members = [alice_id, bob_id];
+
+    let devices = user_gets_list_of_devices(&app, &bob_laptop_token).await;
+    let phone_device_id = devices.first().unwrap().token_id;
+    user_removes_a_device(&app, &bob_laptop_token, phone_device_id).await;
+
+    // The phone still holds the account key pair, new messages are not encrypted for it
+    let status =
+        user_creates_a_message_for_devices(&app, &alice_token, discussion_id, &members, &[]).await;
+    assert_eq!(status, 200);
+
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &alice_token, discussion_id).await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        messages[0]
+            .encrypted_session_keys
+            .keys()
+            .collect::<Vec<_>>(),
+        vec![&alice_id]
+    );
+
+    // A phone that was offline learns about its revocation when it comes back
+    let req = test::TestRequest::post()
+        .uri("/api/auth/refresh-token")
+        .insert_header(ContentType::json())
+        .set_json(serde_json::json!({
+            "refresh_token": bob_phone_refresh_token,
+        }))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(401, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "DEVICE_REVOKED");
+}
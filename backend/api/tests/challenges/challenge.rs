@@ -434,6 +434,7 @@ pub async fn user_can_get_challenge_statistics(pool: PgPool) {
             notifications_for_public_message_replies_enabled: true,
             notifications_user_joined_your_challenge_enabled: true,
             notifications_user_duplicated_your_challenge_enabled: true,
+            ..Default::default()
         })
         .to_request();
     let response = test::call_service(&app, req).await;
@@ -535,6 +536,7 @@ pub async fn user_can_get_challenge_statistics(pool: PgPool) {
             notifications_for_public_message_replies_enabled: true,
             notifications_user_joined_your_challenge_enabled: true,
             notifications_user_duplicated_your_challenge_enabled: true,
+            ..Default::default()
         })
         .to_request();
     let response = test::call_service(&app, req).await;
@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::{
+    core::structs::responses::GenericResponse,
+    features::{
+        auth::application::dto::requests::UserRegisterRequest,
+        challenges::application::dto::responses::challenge::ChallengeResponse,
+        content_filters::{
+            application::dto::{
+                requests::content_filter_rule::{
+                    ContentFilterRuleCreateRequest, ContentFilterRuleUpdateRequest,
+                },
+                responses::{
+                    content_filter_flag::ContentFilterFlagsResponse,
+                    content_filter_rule::{ContentFilterRuleResponse, ContentFilterRulesResponse},
+                },
+            },
+            domain::entities::{
+                content_filter_flag::ContentFilterFlagData,
+                content_filter_rule::{
+                    ContentFilterAction, ContentFilterRuleData, ContentFilterRuleKind,
+                },
+            },
+        },
+        public_discussions::application::dto::requests::public_message::PublicMessageCreateRequest,
+    },
+};
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::challenge::user_creates_a_challenge,
+    helpers::spawn_app,
+    public_discussions::public_message::{
+        user_creates_a_public_message, user_gets_a_public_message,
+    },
+};
+
+pub async fn user_creates_a_content_filter_rule(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    locale: Option<&str>,
+    kind: ContentFilterRuleKind,
+    pattern: &str,
+    action: ContentFilterAction,
+) -> (u16, Option<ContentFilterRuleData>) {
+    let req = test::TestRequest::post()
+        .uri("/api/content-filters/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(ContentFilterRuleCreateRequest {
+            locale: locale.map(str::to_string),
+            kind,
+            pattern: pattern.to_string(),
+            action,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    if status != 200 {
+        return (status, None);
+    }
+
+    let response: ContentFilterRuleResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response.code, "CONTENT_FILTER_RULE_CREATED");
+
+    (status, response.rule)
+}
+
+pub async fn user_gets_content_filter_rules(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<ContentFilterRuleData> {
+    let req = test::TestRequest::get()
+        .uri("/api/content-filters/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: ContentFilterRulesResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "CONTENT_FILTER_RULES_FETCHED");
+    response.rules
+}
+
+pub async fn user_gets_content_filter_flags(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<ContentFilterFlagData> {
+    let req = test::TestRequest::get()
+        .uri("/api/content-filters/flags")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: ContentFilterFlagsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "CONTENT_FILTER_FLAGS_FETCHED");
+    response.flags
+}
+
+async fn user_tries_to_create_a_public_message(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    challenge_id: Uuid,
+    content: &str,
+) -> (u16, String) {
+    let req = test::TestRequest::post()
+        .uri("/api/public-messages/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PublicMessageCreateRequest {
+            challenge_id: Some(challenge_id),
+            habit_id: None,
+            thread_id: None,
+            replies_to: None,
+            content: content.to_string(),
+            attachment_ids: Vec::new(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    let response: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    (status, response["code"].as_str().unwrap().to_string())
+}
+
+#[sqlx::test]
+async fn admin_can_manage_content_filter_rules(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let (user_token, _) = user_signs_up(&app, None).await;
+
+    let (status, _) = user_creates_a_content_filter_rule(
+        &app,
+        &user_token,
+        None,
+        ContentFilterRuleKind::Word,
+        "forbidden",
+        ContentFilterAction::Reject,
+    )
+    .await;
+    assert_eq!(status, 403);
+
+    // Invalid regexes and regexes matching everything are refused
+    for pattern in ["(unclosed", ".*"] {
+        let (status, _) = user_creates_a_content_filter_rule(
+            &app,
+            &admin_token,
+            None,
+            ContentFilterRuleKind::Regex,
+            pattern,
+            ContentFilterAction::Reject,
+        )
+        .await;
+        assert_eq!(status, 400);
+    }
+
+    let (status, rule) = user_creates_a_content_filter_rule(
+        &app,
+        &admin_token,
+        Some("fr"),
+        ContentFilterRuleKind::Word,
+        "interdit",
+        ContentFilterAction::Reject,
+    )
+    .await;
+    assert_eq!(status, 200);
+    let rule = rule.unwrap();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/content-filters/{}", rule.id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", admin_token)))
+        .insert_header(ContentType::json())
+        .set_json(ContentFilterRuleUpdateRequest {
+            locale: None,
+            kind: ContentFilterRuleKind::Word,
+            pattern: "interdit".to_string(),
+            action: ContentFilterAction::Mask,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(200, response.status().as_u16());
+
+    let rules = user_gets_content_filter_rules(&app, &admin_token).await;
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].locale, None);
+    assert_eq!(rules[0].action, "mask");
+
+    let req = test::TestRequest::get()
+        .uri("/api/content-filters/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", user_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(403, response.status().as_u16());
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/content-filters/{}", rule.id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", admin_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(200, response.status().as_u16());
+
+    assert!(user_gets_content_filter_rules(&app, &admin_token)
+        .await
+        .is_empty());
+}
+
+#[sqlx::test]
+async fn public_messages_are_rejected_masked_or_flagged(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let (user_token, _) = user_signs_up(&app, None).await;
+    let challenge_id = user_creates_a_challenge(&app, &user_token).await;
+
+    let (_, reject_rule) = user_creates_a_content_filter_rule(
+        &app,
+        &admin_token,
+        None,
+        ContentFilterRuleKind::Word,
+        "scam",
+        ContentFilterAction::Reject,
+    )
+    .await;
+    let (_, mask_rule) = user_creates_a_content_filter_rule(
+        &app,
+        &admin_token,
+        Some("en"),
+        ContentFilterRuleKind::Word,
+        "darn",
+        ContentFilterAction::Mask,
+    )
+    .await;
+    let (_, flag_rule) = user_creates_a_content_filter_rule(
+        &app,
+        &admin_token,
+        None,
+        ContentFilterRuleKind::Regex,
+        r"(?i)buy\s+now",
+        ContentFilterAction::Flag,
+    )
+    .await;
+    // Rules of other locales do not apply to an English speaker
+    user_creates_a_content_filter_rule(
+        &app,
+        &admin_token,
+        Some("fr"),
+        ContentFilterRuleKind::Word,
+        "running",
+        ContentFilterAction::Reject,
+    )
+    .await;
+
+    let (status, code) = user_tries_to_create_a_public_message(
+        &app,
+        &user_token,
+        challenge_id,
+        "This is a SCAM, don't trust it",
+    )
+    .await;
+    assert_eq!(status, 400);
+    assert_eq!(code, "CONTENT_REJECTED_BY_FILTER");
+
+    let masked_message_id = user_creates_a_public_message(
+        &app,
+        &user_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "I missed my darn running session".to_string(),
+    )
+    .await;
+    let (masked_message, _) =
+        user_gets_a_public_message(&app, &user_token, masked_message_id).await;
+    assert_eq!(masked_message.content, "I missed my **** running session");
+
+    let flagged_message_id = user_creates_a_public_message(
+        &app,
+        &user_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Buy now my running shoes".to_string(),
+    )
+    .await;
+    let (flagged_message, _) =
+        user_gets_a_public_message(&app, &user_token, flagged_message_id).await;
+    assert_eq!(flagged_message.content, "Buy now my running shoes");
+
+    let flags = user_gets_content_filter_flags(&app, &admin_token).await;
+    assert_eq!(flags.len(), 1);
+    assert_eq!(flags[0].target, "public_message");
+    assert_eq!(flags[0].target_id, flagged_message_id);
+    assert_eq!(flags[0].rule_id, Some(flag_rule.as_ref().unwrap().id));
+
+    // Rejected contents are counted too
+    let hit_counts: HashMap<Uuid, i64> = user_gets_content_filter_rules(&app, &admin_token)
+        .await
+        .iter()
+        .map(|rule| (rule.id, rule.hit_count))
+        .collect();
+    assert_eq!(hit_counts[&reject_rule.unwrap().id], 1);
+    assert_eq!(hit_counts[&mask_rule.unwrap().id], 1);
+    assert_eq!(hit_counts[&flag_rule.unwrap().id], 1);
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/content-filters/flags/{}", flags[0].id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", admin_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(200, response.status().as_u16());
+
+    assert!(user_gets_content_filter_flags(&app, &admin_token)
+        .await
+        .is_empty());
+}
+
+#[sqlx::test]
+async fn usernames_and_challenge_names_are_filtered(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+
+    user_creates_a_content_filter_rule(
+        &app,
+        &admin_token,
+        None,
+        ContentFilterRuleKind::Regex,
+        "(?i)stupid",
+        ContentFilterAction::Mask,
+    )
+    .await;
+
+    // A username can not be masked, so it is refused
+    let req = test::TestRequest::post()
+        .uri("/api/auth/signup")
+        .insert_header(ContentType::json())
+        .set_json(UserRegisterRequest {
+            username: "stupidname".to_string(),
+            password: "password1_".to_string(),
+            locale: "en".to_string(),
+            theme: "dark".to_string(),
+            timezone: "America/New_York".to_string(),
+            public_key: "public_key".to_string(),
+            private_key_encrypted: "private_key_encrypted".to_string(),
+            salt_used_to_derive_key_from_password: "salt".to_string(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(401, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response.code, "CONTENT_REJECTED_BY_FILTER");
+
+    let req = test::TestRequest::post()
+        .uri("/api/challenges/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", admin_token)))
+        .insert_header(ContentType::json())
+        .set_json(serde_json::json!({
+            "name": HashMap::from([("en", "Stop being stupid")]),
+            "description": HashMap::from([("en", "No stupid excuses for 30 days")]),
+            "icon": "icon".to_string(),
+            "start_date": Utc::now(),
+        }))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: ChallengeResponse = serde_json::from_slice(&body).unwrap();
+    let challenge = response.challenge.unwrap();
+    assert_eq!(challenge.name, r#"{"en":"Stop being ******"}"#);
+    assert_eq!(
+        challenge.description,
+        r#"{"en":"No ****** excuses for 30 days"}"#
+    );
+}
@@ -10,6 +10,7 @@ use actix_web::{
 use api::{
     configuration::get_configuration,
     core::helpers::translation::Translator,
+    features::attachments::infrastructure::services::local_storage_backend::LocalStorageBackend,
     features::profile::{
         domain::entities::User, domain::repositories::UserRepository,
         infrastructure::repositories::user_repository::UserRepositoryImpl,
@@ -51,6 +52,10 @@ pub async fn spawn_app(
     let user_public_data_cache = UserPublicDataCache::default();
     let redis_client = redis::Client::open("redis://redis:6379").unwrap();
     let translator = Arc::new(Translator::new());
+    // Each test stores its attachments in its own directory
+    let storage_backend = Arc::new(LocalStorageBackend::new(
+        std::env::temp_dir().join(format!("reallystick-uploads-{}", Uuid::new_v4())),
+    ));
 
     configure_database(&pool).await;
     let secret = configuration.application.secret;
@@ -64,6 +69,8 @@ pub async fn spawn_app(
         user_public_data_cache,
         redis_client,
         translator,
+        configuration.moderation.clone(),
+        storage_backend,
     ))
     .await
 }
@@ -112,7 +119,18 @@ pub async fn configure_database(pool: &PgPool) {
         notifications_for_private_messages_enabled: false,
         notifications_for_public_message_liked_enabled: false,
         notifications_for_public_message_replies_enabled: false,
+        notifications_for_public_message_mentions_enabled: false,
+        public_message_languages: vec![],
         notifications_user_duplicated_your_challenge_enabled: false,
+        bio: None,
+        avatar: None,
+        profile_shows_bio: false,
+        profile_shows_avatar: false,
+        profile_shows_habits: false,
+        profile_shows_finished_challenges: false,
+        profile_shows_badges: false,
+        open_to_meeting_people: false,
+        matching_uses_demographics: false,
         notifications_user_joined_your_challenge_enabled: true,
     };
 
@@ -151,7 +169,18 @@ pub async fn configure_database(pool: &PgPool) {
         notifications_for_private_messages_enabled: false,
         notifications_for_public_message_liked_enabled: false,
         notifications_for_public_message_replies_enabled: false,
+        notifications_for_public_message_mentions_enabled: false,
+        public_message_languages: vec![],
         notifications_user_duplicated_your_challenge_enabled: false,
+        bio: None,
+        avatar: None,
+        profile_shows_bio: false,
+        profile_shows_avatar: false,
+        profile_shows_habits: false,
+        profile_shows_finished_challenges: false,
+        profile_shows_badges: false,
+        open_to_meeting_people: false,
+        matching_uses_demographics: false,
         notifications_user_joined_your_challenge_enabled: true,
     };
 
@@ -1,4 +1,5 @@
 pub mod auth {
+    pub mod device_key;
     pub mod login;
     pub mod otp;
     pub mod recovery {
@@ -15,7 +16,9 @@ pub mod auth {
 #[allow(clippy::module_inception)]
 pub mod profile {
     pub mod devices;
+    pub mod friend_matching;
     pub mod profile;
+    pub mod public_profile;
     pub mod set_password;
     pub mod update_password;
     pub mod user_public_data;
@@ -40,15 +43,29 @@ pub mod challenges {
 }
 
 pub mod public_discussions {
+    pub mod language_service;
+    pub mod moderation;
     pub mod public_message;
     pub mod public_message_like;
+    pub mod public_message_pin;
+    pub mod public_message_reaction;
     pub mod public_message_report;
+    pub mod public_message_subscription;
 }
 
 pub mod private_discussions {
     pub mod private_discussion;
+    pub mod private_discussion_group;
     pub mod private_discussion_participation;
+    pub mod private_discussion_report;
+    pub mod private_discussion_request;
+    pub mod private_discussion_retention;
+    pub mod private_discussion_settings;
     pub mod private_message;
+    pub mod private_message_attachment;
+    pub mod private_message_page;
+    pub mod private_message_status;
+    pub mod socket_message;
 }
 
 #[allow(clippy::module_inception)]
@@ -56,4 +73,25 @@ pub mod notifications {
     pub mod notifications;
 }
 
+#[allow(clippy::module_inception)]
+pub mod roles {
+    pub mod roles;
+}
+
+pub mod stories {
+    pub mod story;
+}
+
+pub mod user_blocks {
+    pub mod user_block;
+}
+
+pub mod content_filters {
+    pub mod content_filter;
+}
+
+pub mod attachments {
+    pub mod attachment;
+}
+
 pub mod helpers;
@@ -0,0 +1,520 @@
+use std::collections::BTreeMap;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::features::private_discussions::{
+    application::dto::{
+        requests::{
+            private_discussion::PrivateDiscussionGroupCreateRequest,
+            private_discussion_participation::{
+                PrivateDiscussionMemberAddRequest, PrivateDiscussionMemberUpdateRequest,
+            },
+            private_message::{
+                PrivateMessageCreateRequest, PrivateMessageSessionKeysDistributeRequest,
+            },
+        },
+        responses::{
+            private_discussion::PrivateDiscussionResponse,
+            private_discussion_participation::PrivateDiscussionMembersResponse,
+            private_message::{PrivateMessageResponse, PrivateMessageSessionKeysResponse},
+        },
+    },
+    domain::entities::private_discussion_participation::PrivateDiscussionMemberData,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    helpers::spawn_app,
+    private_discussions::{
+        private_discussion_request::user_gets_private_discussion_requests,
+        private_message::user_gets_private_messages_of_discussion,
+    },
+    profile::profile::user_has_access_to_protected_route,
+};
+
+pub async fn user_creates_a_private_discussion_group(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    name: &str,
+    members: Vec<Uuid>,
+) -> Uuid {
+    let req = test::TestRequest::post()
+        .uri("/api/private-discussions/groups")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionGroupCreateRequest {
+            name: name.to_string(),
+            color: "blue".to_string(),
+            members,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSION_GROUP_CREATED");
+    assert!(response.discussion.is_some());
+
+    response.discussion.unwrap().id
+}
+
+pub async fn user_gets_private_discussion_members(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+) -> Vec<PrivateDiscussionMemberData> {
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/private-discussions/{}/members",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionMembersResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSION_MEMBERS_FETCHED");
+    response.members
+}
+
+// Returns the status and the response code
+pub async fn user_adds_a_private_discussion_member(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    user_id: Uuid,
+) -> (u16, String) {
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/api/private-discussions/{}/members",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionMemberAddRequest { user_id })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    let response: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    (status, response["code"].as_str().unwrap().to_string())
+}
+
+pub async fn user_updates_a_private_discussion_member(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    user_id: Uuid,
+    is_admin: bool,
+) -> (u16, String) {
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/private-discussions/{}/members/{}",
+            discussion_id, user_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionMemberUpdateRequest { is_admin })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    let response: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    (status, response["code"].as_str().unwrap().to_string())
+}
+
+pub async fn user_removes_a_private_discussion_member(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    user_id: Uuid,
+) -> (u16, String) {
+    let req = test::TestRequest::delete()
+        .uri(&format!(
+            "/api/private-discussions/{}/members/{}",
+            discussion_id, user_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    let response: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    (status, response["code"].as_str().unwrap().to_string())
+}
+
+pub async fn user_creates_a_group_message(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    members: &[Uuid],
+) -> (u16, Option<Uuid>) {
+    let encrypted_session_keys: BTreeMap<Uuid, String> = members
+        .iter()
+        .map(|member| (*member, format!("session-key-for-{}", member)))
+        .collect();
+
+    let req = test::TestRequest::post()
+        .uri("/api/private-messages/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateMessageCreateRequest {
+            discussion_id,
+            content: "content".to_string(),
+            creator_encrypted_session_key: String::new(),
+            recipient_encrypted_session_key: String::new(),
+            encrypted_session_keys,
+            device_encrypted_session_keys: BTreeMap::new(),
+            attachment_ids: Vec::new(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateMessageResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_MESSAGE_CREATED");
+    (status, response.message.map(|m| m.id))
+}
+
+pub async fn user_distributes_private_message_session_keys(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    user_id: Uuid,
+    session_keys: BTreeMap<Uuid, String>,
+) {
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/api/private-discussions/{}/session-keys",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateMessageSessionKeysDistributeRequest {
+            user_id,
+            session_keys,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PrivateMessageSessionKeysResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_MESSAGE_SESSION_KEYS_DISTRIBUTED");
+}
+
+#[sqlx::test]
+pub async fn user_can_create_a_private_discussion_group(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &thomas_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+
+    let discussion_id = user_creates_a_private_discussion_group(
+        &app,
+        &alice_token,
+        "Runners",
+        vec![thomas_id, bob_id, bob_id, alice_id],
+    )
+    .await;
+
+    let members = user_gets_private_discussion_members(&app, &bob_token, discussion_id).await;
+    assert_eq!(members.len(), 3);
+    assert!(members.iter().any(|m| m.user_id == alice_id && m.is_admin));
+    assert!(members.iter().any(|m| m.user_id == bob_id && !m.is_admin));
+    assert!(members
+        .iter()
+        .any(|m| m.user_id == thomas_id && !m.is_admin));
+
+    // Bob does not know alice, so the group waits in his message requests
+    let requests = user_gets_private_discussion_requests(&app, &bob_token).await;
+    let group = requests.iter().find(|d| d.id == discussion_id).unwrap();
+    assert!(group.is_group);
+    assert_eq!(group.name, Some("Runners".to_string()));
+    assert_eq!(group.recipient_id, None);
+    assert_eq!(group.requested_by, Some(alice_id));
+}
+
+#[sqlx::test]
+pub async fn group_message_needs_a_session_key_for_each_member(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &thomas_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+
+    let discussion_id =
+        user_creates_a_private_discussion_group(&app, &alice_token, "Runners", vec![bob_id]).await;
+
+    let (status, _) =
+        user_creates_a_group_message(&app, &alice_token, discussion_id, &[alice_id]).await;
+    assert_eq!(status, 400);
+
+    let (status, _) = user_creates_a_group_message(
+        &app,
+        &alice_token,
+        discussion_id,
+        &[alice_id, bob_id, thomas_id],
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    // Non-members can not write in the group
+    let (status, _) =
+        user_creates_a_group_message(&app, &thomas_token, discussion_id, &[alice_id, bob_id]).await;
+    assert_eq!(status, 404);
+
+    let (status, message_id) =
+        user_creates_a_group_message(&app, &alice_token, discussion_id, &[alice_id, bob_id]).await;
+    assert_eq!(status, 200);
+
+    let messages = user_gets_private_messages_of_discussion(&app, &bob_token, discussion_id).await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].id, message_id.unwrap());
+    assert_eq!(messages[0].encrypted_session_keys.len(), 2);
+    assert_eq!(
+        messages[0].encrypted_session_keys[&bob_id],
+        format!("session-key-for-{}", bob_id)
+    );
+    assert_eq!(
+        messages[0].creator_encrypted_session_key,
+        format!("session-key-for-{}", alice_id)
+    );
+}
+
+#[sqlx::test]
+pub async fn admin_can_add_and_remove_group_members(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &thomas_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+
+    let discussion_id =
+        user_creates_a_private_discussion_group(&app, &alice_token, "Runners", vec![bob_id]).await;
+    let (_, message_id) =
+        user_creates_a_group_message(&app, &alice_token, discussion_id, &[alice_id, bob_id]).await;
+    let message_id = message_id.unwrap();
+
+    // Only admins manage members
+    let (status, code) =
+        user_adds_a_private_discussion_member(&app, &bob_token, discussion_id, thomas_id).await;
+    assert_eq!(status, 403);
+    assert_eq!(code, "PRIVATE_DISCUSSION_MEMBER_NOT_ADMIN");
+
+    let (status, code) =
+        user_adds_a_private_discussion_member(&app, &alice_token, discussion_id, thomas_id).await;
+    assert_eq!(status, 200);
+    assert_eq!(code, "PRIVATE_DISCUSSION_MEMBER_ADDED");
+
+    let (status, code) =
+        user_adds_a_private_discussion_member(&app, &alice_token, discussion_id, thomas_id).await;
+    assert_eq!(status, 400);
+    assert_eq!(code, "PRIVATE_DISCUSSION_MEMBER_ALREADY_EXISTS");
+
+    // The new member can only read older messages once a member shares their keys
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &thomas_token, discussion_id).await;
+    assert!(!messages[0].encrypted_session_keys.contains_key(&thomas_id));
+
+    user_distributes_private_message_session_keys(
+        &app,
+        &alice_token,
+        discussion_id,
+        thomas_id,
+        BTreeMap::from([(message_id, "session-key-for-thomas".to_string())]),
+    )
+    .await;
+
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &thomas_token, discussion_id).await;
+    assert_eq!(
+        messages[0].encrypted_session_keys[&thomas_id],
+        "session-key-for-thomas"
+    );
+
+    // Keys a member already has can not be replaced by another member
+    user_distributes_private_message_session_keys(
+        &app,
+        &bob_token,
+        discussion_id,
+        thomas_id,
+        BTreeMap::from([(message_id, "garbage".to_string())]),
+    )
+    .await;
+    user_distributes_private_message_session_keys(
+        &app,
+        &bob_token,
+        discussion_id,
+        alice_id,
+        BTreeMap::from([(message_id, "garbage".to_string())]),
+    )
+    .await;
+
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &thomas_token, discussion_id).await;
+    assert_eq!(
+        messages[0].encrypted_session_keys[&thomas_id],
+        "session-key-for-thomas"
+    );
+    assert_eq!(
+        messages[0].encrypted_session_keys[&alice_id],
+        format!("session-key-for-{}", alice_id)
+    );
+
+    // Messages are now encrypted for the three members
+    let (status, _) = user_creates_a_group_message(
+        &app,
+        &alice_token,
+        discussion_id,
+        &[alice_id, bob_id, thomas_id],
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    let (status, code) =
+        user_removes_a_private_discussion_member(&app, &alice_token, discussion_id, thomas_id)
+            .await;
+    assert_eq!(status, 200);
+    assert_eq!(code, "PRIVATE_DISCUSSION_MEMBER_REMOVED");
+
+    // The removed member loses access and their session keys are dropped
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/private-messages/{}", discussion_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", thomas_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(404, response.status().as_u16());
+
+    let messages = user_gets_private_messages_of_discussion(&app, &bob_token, discussion_id).await;
+    assert_eq!(messages.len(), 2);
+    assert!(messages
+        .iter()
+        .all(|m| !m.encrypted_session_keys.contains_key(&thomas_id)));
+
+    let members = user_gets_private_discussion_members(&app, &alice_token, discussion_id).await;
+    assert_eq!(members.len(), 2);
+}
+
+#[sqlx::test]
+pub async fn group_always_keeps_an_admin(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &thomas_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+
+    let discussion_id = user_creates_a_private_discussion_group(
+        &app,
+        &alice_token,
+        "Runners",
+        vec![bob_id, thomas_id],
+    )
+    .await;
+
+    let (status, code) = user_updates_a_private_discussion_member(
+        &app,
+        &alice_token,
+        discussion_id,
+        alice_id,
+        false,
+    )
+    .await;
+    assert_eq!(status, 400);
+    assert_eq!(code, "PRIVATE_DISCUSSION_LAST_ADMIN");
+
+    // Members can leave, but not kick others
+    let (status, _) =
+        user_removes_a_private_discussion_member(&app, &bob_token, discussion_id, thomas_id).await;
+    assert_eq!(status, 403);
+
+    // When the last admin leaves, the oldest member takes over
+    let (status, _) =
+        user_removes_a_private_discussion_member(&app, &alice_token, discussion_id, alice_id).await;
+    assert_eq!(status, 200);
+
+    let members = user_gets_private_discussion_members(&app, &bob_token, discussion_id).await;
+    assert_eq!(members.len(), 2);
+    assert_eq!(members.iter().filter(|m| m.is_admin).count(), 1);
+
+    let admin_token = if members.iter().any(|m| m.user_id == bob_id && m.is_admin) {
+        &bob_token
+    } else {
+        &thomas_token
+    };
+    let other_id = if admin_token == &bob_token {
+        thomas_id
+    } else {
+        bob_id
+    };
+
+    let (status, code) =
+        user_updates_a_private_discussion_member(&app, admin_token, discussion_id, other_id, true)
+            .await;
+    assert_eq!(status, 200);
+    assert_eq!(code, "PRIVATE_DISCUSSION_MEMBER_UPDATED");
+
+    let members = user_gets_private_discussion_members(&app, &bob_token, discussion_id).await;
+    assert!(members.iter().all(|m| m.is_admin));
+}
@@ -0,0 +1,361 @@
+use std::collections::BTreeMap;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use aes_gcm::{
+    aead::{consts::U16, Aead, KeyInit},
+    aes::Aes256,
+    AesGcm, Nonce,
+};
+use api::{
+    core::structs::responses::GenericResponse,
+    features::private_discussions::{
+        application::dto::{
+            requests::{
+                private_discussion_report::{
+                    PrivateDiscussionReportCreateRequest, PrivateDiscussionReportMessageRequest,
+                    ResolvePrivateDiscussionReportRequest,
+                },
+                private_message::PrivateMessageCreateRequest,
+            },
+            responses::private_discussion_report::{
+                PrivateDiscussionReportResponse, PrivateDiscussionReportsResponse,
+            },
+        },
+        domain::entities::private_discussion_report::{
+            PrivateDiscussionReportAction, PrivateDiscussionReportData,
+            PrivateDiscussionReportEntry,
+        },
+    },
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    helpers::spawn_app,
+    notifications::notifications::user_gets_notifications,
+    private_discussions::{
+        private_discussion::user_creates_a_private_discussion,
+        private_message::user_creates_a_private_message,
+    },
+    profile::profile::user_has_access_to_protected_route,
+};
+
+// Encrypts like the clients do: a 16 bytes nonce followed by the AES-GCM ciphertext
+fn encrypt_private_message_content(session_key: &str, content: &str) -> String {
+    let key = STANDARD.decode(session_key).unwrap();
+    let cipher = AesGcm::<Aes256, U16>::new_from_slice(&key).unwrap();
+    let nonce = [7u8; 16];
+    let ciphertext = cipher
+        .encrypt(Nonce::<U16>::from_slice(&nonce), content.as_bytes())
+        .unwrap();
+
+    STANDARD.encode([nonce.to_vec(), ciphertext].concat())
+}
+
+pub async fn user_creates_a_private_discussion_report(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    request: PrivateDiscussionReportCreateRequest,
+) -> (u16, Option<PrivateDiscussionReportData>) {
+    let req = test::TestRequest::post()
+        .uri("/api/private-discussion-reports/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(request)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionReportResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSION_REPORT_CREATED");
+    (status, response.report)
+}
+
+pub async fn user_gets_private_discussion_reports(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> (u16, Vec<PrivateDiscussionReportEntry>) {
+    let req = test::TestRequest::get()
+        .uri("/api/private-discussion-reports/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, Vec::new());
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionReportsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSION_REPORTS_FETCHED");
+    (status, response.reports)
+}
+
+pub async fn user_resolves_a_private_discussion_report(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    report_id: Uuid,
+    request: ResolvePrivateDiscussionReportRequest,
+) -> (u16, String) {
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/api/private-discussion-reports/{}/resolve",
+            report_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(request)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    if status == 200 {
+        let response: PrivateDiscussionReportResponse = serde_json::from_slice(&body).unwrap();
+        assert!(response.report.unwrap().resolved_at.is_some());
+        (status, response.code)
+    } else {
+        let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+        (status, response.code)
+    }
+}
+
+#[sqlx::test]
+async fn reports_only_keep_messages_matching_the_ciphertext(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    let session_key = STANDARD.encode([42u8; 32]);
+    let message_id = user_creates_a_private_message(
+        &app,
+        &bob_token,
+        discussion_id,
+        encrypt_private_message_content(&session_key, "You will regret it"),
+        "nonce".to_string(),
+        "creator_encrypted_session_key".to_string(),
+        "recipient_encrypted_session_key".to_string(),
+    )
+    .await;
+
+    let report_request = |content: &str, session_key: &str, reported_user: Uuid| {
+        PrivateDiscussionReportCreateRequest {
+            discussion_id,
+            reported_user,
+            reason: "Threats".to_string(),
+            messages: vec![PrivateDiscussionReportMessageRequest {
+                message_id,
+                content: content.to_string(),
+                session_key: session_key.to_string(),
+            }],
+        }
+    };
+
+    // A disclosed content that is not what was sent is rejected
+    let (status, _) = user_creates_a_private_discussion_report(
+        &app,
+        &alice_token,
+        report_request("You will love it", &session_key, bob_id),
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    let (status, _) = user_creates_a_private_discussion_report(
+        &app,
+        &alice_token,
+        report_request("You will regret it", &STANDARD.encode([1u8; 32]), bob_id),
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    // Only the other member of the discussion can be reported
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (status, _) = user_creates_a_private_discussion_report(
+        &app,
+        &alice_token,
+        report_request("You will regret it", &session_key, alice_id),
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    let (status, _) = user_creates_a_private_discussion_report(
+        &app,
+        &thomas_token,
+        report_request("You will regret it", &session_key, bob_id),
+    )
+    .await;
+    assert_eq!(status, 404);
+
+    let (status, report) = user_creates_a_private_discussion_report(
+        &app,
+        &alice_token,
+        report_request("You will regret it", &session_key, bob_id),
+    )
+    .await;
+    assert_eq!(status, 200);
+    let report = report.unwrap();
+    assert_eq!(report.reported_user, bob_id);
+    assert!(report.resolved_at.is_none());
+
+    // Only moderators can read the disclosed messages
+    let (status, _) = user_gets_private_discussion_reports(&app, &alice_token).await;
+    assert_eq!(status, 403);
+
+    let (status, reports) = user_gets_private_discussion_reports(&app, &thomas_token).await;
+    assert_eq!(status, 200);
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].report.id, report.id);
+    assert_eq!(reports[0].messages.len(), 1);
+    assert_eq!(reports[0].messages[0].message_id, Some(message_id));
+    assert_eq!(reports[0].messages[0].creator, Some(bob_id));
+    assert_eq!(reports[0].messages[0].content, "You will regret it");
+}
+
+#[sqlx::test]
+async fn moderators_can_suspend_the_reported_user(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    let session_key = STANDARD.encode([42u8; 32]);
+    let message_id = user_creates_a_private_message(
+        &app,
+        &bob_token,
+        discussion_id,
+        encrypt_private_message_content(&session_key, "You will regret it"),
+        "nonce".to_string(),
+        "creator_encrypted_session_key".to_string(),
+        "recipient_encrypted_session_key".to_string(),
+    )
+    .await;
+
+    let (_, report) = user_creates_a_private_discussion_report(
+        &app,
+        &alice_token,
+        PrivateDiscussionReportCreateRequest {
+            discussion_id,
+            reported_user: bob_id,
+            reason: "Threats".to_string(),
+            messages: vec![PrivateDiscussionReportMessageRequest {
+                message_id,
+                content: "You will regret it".to_string(),
+                session_key: session_key.clone(),
+            }],
+        },
+    )
+    .await;
+    let report_id = report.unwrap().id;
+
+    let (status, _) = user_resolves_a_private_discussion_report(
+        &app,
+        &alice_token,
+        report_id,
+        ResolvePrivateDiscussionReportRequest {
+            action: PrivateDiscussionReportAction::Dismiss,
+            note: None,
+            suspension_in_days: None,
+        },
+    )
+    .await;
+    assert_eq!(status, 403);
+
+    let (status, code) = user_resolves_a_private_discussion_report(
+        &app,
+        &thomas_token,
+        report_id,
+        ResolvePrivateDiscussionReportRequest {
+            action: PrivateDiscussionReportAction::SuspendOffender,
+            note: None,
+            suspension_in_days: Some(3),
+        },
+    )
+    .await;
+    assert_eq!(status, 400);
+    assert_eq!(code, "MODERATION_NOTE_EMPTY");
+
+    let (status, code) = user_resolves_a_private_discussion_report(
+        &app,
+        &thomas_token,
+        report_id,
+        ResolvePrivateDiscussionReportRequest {
+            action: PrivateDiscussionReportAction::SuspendOffender,
+            note: Some("Threatening other members".to_string()),
+            suspension_in_days: Some(3),
+        },
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(code, "PRIVATE_DISCUSSION_REPORT_RESOLVED");
+
+    let (_, reports) = user_gets_private_discussion_reports(&app, &thomas_token).await;
+    assert!(reports.is_empty());
+
+    let (status, code) = user_resolves_a_private_discussion_report(
+        &app,
+        &thomas_token,
+        report_id,
+        ResolvePrivateDiscussionReportRequest {
+            action: PrivateDiscussionReportAction::Dismiss,
+            note: None,
+            suspension_in_days: None,
+        },
+    )
+    .await;
+    assert_eq!(status, 404);
+    assert_eq!(code, "PRIVATE_DISCUSSION_REPORT_NOT_FOUND");
+
+    // The suspended user can not write private messages anymore
+    let req = test::TestRequest::post()
+        .uri("/api/private-messages/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", bob_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateMessageCreateRequest {
+            discussion_id,
+            content: "hello".to_string(),
+            creator_encrypted_session_key: "creator_encrypted_session_key".to_string(),
+            recipient_encrypted_session_key: "recipient_encrypted_session_key".to_string(),
+            encrypted_session_keys: BTreeMap::new(),
+            device_encrypted_session_keys: BTreeMap::new(),
+            attachment_ids: Vec::new(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(response.status().as_u16(), 403);
+
+    let notifications = user_gets_notifications(&app, &alice_token).await;
+    assert!(notifications
+        .iter()
+        .any(|n| n.title == "Your report was reviewed"));
+    let notifications = user_gets_notifications(&app, &bob_token).await;
+    assert_eq!(notifications.len(), 1);
+}
@@ -0,0 +1,478 @@
+use std::collections::BTreeMap;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::core::helpers::mock_now::override_now;
+use api::features::private_discussions::{
+    application::dto::{
+        requests::private_discussion::{
+            PrivateDiscussionCreateRequest, PrivateDiscussionRequestAnswerRequest,
+        },
+        requests::private_message::PrivateMessageCreateRequest,
+        responses::private_discussion::{PrivateDiscussionResponse, PrivateDiscussionsResponse},
+    },
+    domain::entities::private_discussion::{
+        PrivateDiscussionData, PrivateDiscussionRequestAnswer,
+        PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_MAX_PER_DAY,
+    },
+};
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::{
+        challenge::user_creates_a_challenge,
+        challenge_participation::user_creates_a_challenge_participation,
+    },
+    helpers::spawn_app,
+    notifications::notifications::user_gets_notifications,
+    private_discussions::{
+        private_discussion::{user_creates_a_private_discussion, user_gets_private_discussions},
+        private_discussion_group::{
+            user_adds_a_private_discussion_member, user_creates_a_private_discussion_group,
+        },
+        private_message::user_creates_a_private_message,
+    },
+    profile::profile::user_has_access_to_protected_route,
+    user_blocks::user_block::user_gets_user_blocks,
+};
+
+pub async fn user_answers_a_private_discussion_request(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    answer: PrivateDiscussionRequestAnswer,
+) -> (u16, Option<PrivateDiscussionData>) {
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/private-discussions/{}/request",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionRequestAnswerRequest { answer })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSION_REQUEST_ANSWERED");
+    (status, response.discussion)
+}
+
+pub async fn user_gets_private_discussion_requests(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<PrivateDiscussionData> {
+    let req = test::TestRequest::get()
+        .uri("/api/private-discussions/?requests=true")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSIONS_FETCHED");
+    response.discussions
+}
+
+async fn user_tries_to_create_a_private_discussion(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    recipient: Uuid,
+) -> u16 {
+    let req = test::TestRequest::post()
+        .uri("/api/private-discussions/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionCreateRequest {
+            recipient,
+            color: "blue".to_string(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+async fn user_tries_to_send_a_private_message(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+) -> u16 {
+    let req = test::TestRequest::post()
+        .uri("/api/private-messages/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateMessageCreateRequest {
+            discussion_id,
+            content: "hello".to_string(),
+            creator_encrypted_session_key: "creator_encrypted_session_key".to_string(),
+            recipient_encrypted_session_key: "recipient_encrypted_session_key".to_string(),
+            encrypted_session_keys: BTreeMap::new(),
+            device_encrypted_session_keys: BTreeMap::new(),
+            attachment_ids: vec![],
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+#[sqlx::test]
+async fn discussion_with_a_stranger_is_a_message_request(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (carol_token, _) = user_signs_up(&app, Some("carol")).await;
+    let carol_id = user_has_access_to_protected_route(&app, &carol_token)
+        .await
+        .id;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    // Only the recipient sees the discussion as a request
+    let discussion = user_gets_private_discussions(&app, &alice_token)
+        .await
+        .into_iter()
+        .find(|d| d.id == discussion_id)
+        .unwrap();
+    assert_eq!(discussion.request_status, "accepted");
+
+    let discussions = user_gets_private_discussions(&app, &bob_token).await;
+    assert!(!discussions.iter().any(|d| d.id == discussion_id));
+    let requests = user_gets_private_discussion_requests(&app, &bob_token).await;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].id, discussion_id);
+    assert_eq!(requests[0].request_status, "pending");
+    assert_eq!(requests[0].requested_by, Some(alice_id));
+
+    // The requester can not answer its own request
+    let (status, _) = user_answers_a_private_discussion_request(
+        &app,
+        &alice_token,
+        discussion_id,
+        PrivateDiscussionRequestAnswer::Accept,
+    )
+    .await;
+    assert_eq!(status, 404);
+
+    let (status, discussion) = user_answers_a_private_discussion_request(
+        &app,
+        &bob_token,
+        discussion_id,
+        PrivateDiscussionRequestAnswer::Accept,
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(discussion.unwrap().request_status, "accepted");
+
+    let discussions = user_gets_private_discussions(&app, &bob_token).await;
+    assert!(discussions.iter().any(|d| d.id == discussion_id));
+    assert!(user_gets_private_discussion_requests(&app, &bob_token)
+        .await
+        .is_empty());
+
+    // Replying to a request accepts it
+    let discussion_id =
+        user_creates_a_private_discussion(&app, &carol_token, alice_id, "blue").await;
+    user_creates_a_private_message(
+        &app,
+        &alice_token,
+        discussion_id,
+        "hello".to_string(),
+        "nonce".to_string(),
+        "creator_encrypted_session_key".to_string(),
+        "recipient_encrypted_session_key".to_string(),
+    )
+    .await;
+    let discussions = user_gets_private_discussions(&app, &alice_token).await;
+    let discussion = discussions.iter().find(|d| d.id == discussion_id).unwrap();
+    assert_eq!(discussion.request_status, "accepted");
+
+    // Knowing the same person is not enough
+    let discussion_id = user_creates_a_private_discussion(&app, &bob_token, carol_id, "blue").await;
+    let requests = user_gets_private_discussion_requests(&app, &carol_token).await;
+    assert!(requests.iter().any(|d| d.id == discussion_id));
+
+    let (dave_token, _) = user_signs_up(&app, Some("dave")).await;
+    let dave_id = user_has_access_to_protected_route(&app, &dave_token)
+        .await
+        .id;
+    // Users who share a challenge are not strangers
+    let challenge_id = user_creates_a_challenge(&app, &carol_token).await;
+    user_creates_a_challenge_participation(&app, &dave_token, challenge_id).await;
+
+    let discussion_id =
+        user_creates_a_private_discussion(&app, &carol_token, dave_id, "blue").await;
+    assert!(user_gets_private_discussion_requests(&app, &dave_token)
+        .await
+        .is_empty());
+    let discussions = user_gets_private_discussions(&app, &dave_token).await;
+    let discussion = discussions.iter().find(|d| d.id == discussion_id).unwrap();
+    assert_eq!(discussion.request_status, "accepted");
+}
+
+#[sqlx::test]
+async fn recipient_can_decline_or_block_a_message_request(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (carol_token, _) = user_signs_up(&app, Some("carol")).await;
+    let carol_id = user_has_access_to_protected_route(&app, &carol_token)
+        .await
+        .id;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    let (status, discussion) = user_answers_a_private_discussion_request(
+        &app,
+        &bob_token,
+        discussion_id,
+        PrivateDiscussionRequestAnswer::Decline,
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(discussion.unwrap().request_status, "declined");
+
+    // The request disappears for bob and alice can not write anymore
+    assert!(!user_gets_private_discussions(&app, &bob_token)
+        .await
+        .iter()
+        .any(|d| d.id == discussion_id));
+    assert!(user_gets_private_discussion_requests(&app, &bob_token)
+        .await
+        .is_empty());
+
+    let status = user_tries_to_send_a_private_message(&app, &alice_token, discussion_id).await;
+    assert_eq!(status, 403);
+
+    // A declined request can still be accepted later
+    let (status, _) = user_answers_a_private_discussion_request(
+        &app,
+        &bob_token,
+        discussion_id,
+        PrivateDiscussionRequestAnswer::Accept,
+    )
+    .await;
+    assert_eq!(status, 200);
+    let status = user_tries_to_send_a_private_message(&app, &alice_token, discussion_id).await;
+    assert_eq!(status, 200);
+
+    // Blocking declines the request and blocks the requester
+    let discussion_id = user_creates_a_private_discussion(&app, &carol_token, bob_id, "blue").await;
+    let (status, _) = user_answers_a_private_discussion_request(
+        &app,
+        &bob_token,
+        discussion_id,
+        PrivateDiscussionRequestAnswer::Block,
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    let user_blocks = user_gets_user_blocks(&app, &bob_token).await;
+    assert_eq!(user_blocks.len(), 1);
+    assert_eq!(user_blocks[0].blocked, carol_id);
+
+    let status = user_tries_to_send_a_private_message(&app, &carol_token, discussion_id).await;
+    assert_eq!(status, 403);
+}
+
+#[sqlx::test]
+async fn accepting_a_request_sums_up_the_held_messages(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+    for _ in 0..2 {
+        user_creates_a_private_message(
+            &app,
+            &alice_token,
+            discussion_id,
+            "hello".to_string(),
+            "nonce".to_string(),
+            "creator_encrypted_session_key".to_string(),
+            "recipient_encrypted_session_key".to_string(),
+        )
+        .await;
+    }
+    let notifications_before = user_gets_notifications(&app, &bob_token).await.len();
+
+    let (status, _) = user_answers_a_private_discussion_request(
+        &app,
+        &bob_token,
+        discussion_id,
+        PrivateDiscussionRequestAnswer::Accept,
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    let notifications = user_gets_notifications(&app, &bob_token).await;
+    assert_eq!(notifications.len(), notifications_before + 1);
+    let summary = notifications
+        .iter()
+        .find(|n| n.url == Some(format!("/messages/{}", discussion_id)))
+        .unwrap();
+    assert!(summary.body.contains("alice"));
+    assert!(summary.body.contains('2'));
+}
+
+#[sqlx::test]
+async fn strangers_added_to_a_group_get_a_message_request(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (carol_token, _) = user_signs_up(&app, Some("carol")).await;
+    let carol_id = user_has_access_to_protected_route(&app, &carol_token)
+        .await
+        .id;
+    let (dave_token, _) = user_signs_up(&app, Some("dave")).await;
+    let dave_id = user_has_access_to_protected_route(&app, &dave_token)
+        .await
+        .id;
+
+    let challenge_id = user_creates_a_challenge(&app, &alice_token).await;
+    user_creates_a_challenge_participation(&app, &carol_token, challenge_id).await;
+
+    let group_id = user_creates_a_private_discussion_group(
+        &app,
+        &alice_token,
+        "Runners",
+        vec![bob_id, carol_id],
+    )
+    .await;
+
+    // Only the stranger has to accept the group
+    let requests = user_gets_private_discussion_requests(&app, &bob_token).await;
+    assert!(requests
+        .iter()
+        .any(|d| d.id == group_id && d.requested_by == Some(alice_id)));
+    assert!(user_gets_private_discussion_requests(&app, &carol_token)
+        .await
+        .is_empty());
+    assert!(user_gets_private_discussions(&app, &carol_token)
+        .await
+        .iter()
+        .any(|d| d.id == group_id));
+
+    let (status, _) =
+        user_adds_a_private_discussion_member(&app, &alice_token, group_id, dave_id).await;
+    assert_eq!(status, 200);
+    let requests = user_gets_private_discussion_requests(&app, &dave_token).await;
+    assert!(requests.iter().any(|d| d.id == group_id));
+
+    // A group waiting for an answer does not make its members acquaintances
+    let discussion_id = user_creates_a_private_discussion(&app, &bob_token, carol_id, "blue").await;
+    let requests = user_gets_private_discussion_requests(&app, &carol_token).await;
+    assert!(requests.iter().any(|d| d.id == discussion_id));
+
+    let (status, _) = user_answers_a_private_discussion_request(
+        &app,
+        &dave_token,
+        group_id,
+        PrivateDiscussionRequestAnswer::Accept,
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert!(user_gets_private_discussions(&app, &dave_token)
+        .await
+        .iter()
+        .any(|d| d.id == group_id));
+
+    let discussion_id =
+        user_creates_a_private_discussion(&app, &dave_token, carol_id, "blue").await;
+    assert!(!user_gets_private_discussion_requests(&app, &carol_token)
+        .await
+        .iter()
+        .any(|d| d.id == discussion_id));
+}
+
+#[sqlx::test]
+async fn new_accounts_can_only_send_a_few_requests_per_day(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+
+    let mut recipients = Vec::new();
+    let mut recipient_tokens = Vec::new();
+    for i in 0..=PRIVATE_DISCUSSION_REQUESTS_NEW_ACCOUNT_MAX_PER_DAY {
+        let (token, _) = user_signs_up(&app, Some(&format!("user{}", i))).await;
+        recipients.push(user_has_access_to_protected_route(&app, &token).await.id);
+        recipient_tokens.push(token);
+    }
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &thomas_token)
+        .await
+        .id;
+
+    let (last_recipient, recipients) = recipients.split_last().unwrap();
+    let mut discussion_ids = Vec::new();
+    for recipient in recipients {
+        discussion_ids
+            .push(user_creates_a_private_discussion(&app, &alice_token, *recipient, "blue").await);
+    }
+
+    // Answered requests still count
+    user_answers_a_private_discussion_request(
+        &app,
+        &recipient_tokens[0],
+        discussion_ids[0],
+        PrivateDiscussionRequestAnswer::Decline,
+    )
+    .await;
+    user_answers_a_private_discussion_request(
+        &app,
+        &recipient_tokens[1],
+        discussion_ids[1],
+        PrivateDiscussionRequestAnswer::Accept,
+    )
+    .await;
+
+    let status =
+        user_tries_to_create_a_private_discussion(&app, &alice_token, *last_recipient).await;
+    assert_eq!(status, 429);
+
+    // Once the account is old enough, the limit is gone
+    override_now(Some((Utc::now() + Duration::days(8)).fixed_offset()));
+
+    let (alice_token, _) = user_logs_in(&app, "alice", "password1_").await;
+    let status =
+        user_tries_to_create_a_private_discussion(&app, &alice_token, *last_recipient).await;
+    assert_eq!(status, 200);
+    let status = user_tries_to_create_a_private_discussion(&app, &alice_token, thomas_id).await;
+    assert_eq!(status, 200);
+}
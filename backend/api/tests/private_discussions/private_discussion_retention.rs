@@ -0,0 +1,203 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::core::helpers::{
+    mock_now::override_now, private_message_expiration::delete_expired_private_messages,
+};
+use api::features::private_discussions::{
+    application::dto::{
+        requests::private_discussion::PrivateDiscussionRetentionUpdateRequest,
+        responses::private_discussion::PrivateDiscussionResponse,
+    },
+    domain::entities::private_discussion::PrivateDiscussionData,
+};
+use chrono::{Duration, Utc};
+use redis::Client;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    helpers::spawn_app,
+    private_discussions::{
+        private_discussion::{user_creates_a_private_discussion, user_gets_private_discussions},
+        private_message::{
+            user_creates_a_private_message, user_gets_private_messages_of_discussion,
+        },
+    },
+    profile::profile::user_has_access_to_protected_route,
+};
+
+pub async fn user_updates_private_discussion_retention(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    message_retention: &str,
+) -> (u16, Option<PrivateDiscussionData>) {
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/private-discussions/{}/retention",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionRetentionUpdateRequest {
+            message_retention: message_retention.to_string(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSION_RETENTION_UPDATED");
+    (status, response.discussion)
+}
+
+async fn user_sends_a_message(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+) -> Uuid {
+    user_creates_a_private_message(
+        app,
+        access_token,
+        discussion_id,
+        "content".to_string(),
+        "nonce".to_string(),
+        "creator_encrypted_session_key".to_string(),
+        "recipient_encrypted_session_key".to_string(),
+    )
+    .await
+}
+
+#[sqlx::test]
+async fn retention_must_be_accepted_by_the_other_member(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (eve_token, _) = user_signs_up(&app, Some("eve")).await;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    let (status, _) =
+        user_updates_private_discussion_retention(&app, &alice_token, discussion_id, "1y").await;
+    assert_eq!(status, 400);
+
+    let (status, _) =
+        user_updates_private_discussion_retention(&app, &eve_token, discussion_id, "24h").await;
+    assert_eq!(status, 404);
+
+    // Alice's proposal is not applied until Bob accepts it
+    let (_, discussion) =
+        user_updates_private_discussion_retention(&app, &alice_token, discussion_id, "24h").await;
+    let discussion = discussion.unwrap();
+    assert_eq!(discussion.message_retention, "off");
+    assert_eq!(
+        discussion.proposed_message_retention,
+        Some("24h".to_string())
+    );
+    assert_eq!(discussion.message_retention_proposed_by, Some(alice_id));
+
+    let message_id = user_sends_a_message(&app, &alice_token, discussion_id).await;
+    let messages = user_gets_private_messages_of_discussion(&app, &bob_token, discussion_id).await;
+    assert_eq!(messages[0].id, message_id);
+    assert_eq!(messages[0].expires_at, None);
+
+    let (_, discussion) =
+        user_updates_private_discussion_retention(&app, &bob_token, discussion_id, "24h").await;
+    let discussion = discussion.unwrap();
+    assert_eq!(discussion.message_retention, "24h");
+    assert_eq!(discussion.proposed_message_retention, None);
+    assert_eq!(discussion.message_retention_proposed_by, None);
+
+    let discussions = user_gets_private_discussions(&app, &alice_token).await;
+    let discussion = discussions.iter().find(|d| d.id == discussion_id).unwrap();
+    assert_eq!(discussion.message_retention, "24h");
+
+    // Turning it off again needs both members too
+    let (_, discussion) =
+        user_updates_private_discussion_retention(&app, &bob_token, discussion_id, "off").await;
+    let discussion = discussion.unwrap();
+    assert_eq!(discussion.message_retention, "24h");
+    assert_eq!(
+        discussion.proposed_message_retention,
+        Some("off".to_string())
+    );
+}
+
+#[sqlx::test]
+async fn expired_messages_are_hidden_then_deleted(pool: PgPool) {
+    let app = spawn_app(pool.clone()).await;
+    let redis_client = Client::open("redis://redis:6379").unwrap();
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+    let kept_message_id = user_sends_a_message(&app, &alice_token, discussion_id).await;
+
+    user_updates_private_discussion_retention(&app, &alice_token, discussion_id, "24h").await;
+    user_updates_private_discussion_retention(&app, &bob_token, discussion_id, "24h").await;
+
+    let expiring_message_id = user_sends_a_message(&app, &bob_token, discussion_id).await;
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &alice_token, discussion_id).await;
+    assert_eq!(messages.len(), 2);
+    let expiring_message = messages
+        .iter()
+        .find(|m| m.id == expiring_message_id)
+        .unwrap();
+    assert_eq!(
+        expiring_message.expires_at,
+        Some(expiring_message.created_at + Duration::hours(24))
+    );
+
+    override_now(Some((Utc::now() + Duration::hours(25)).fixed_offset()));
+    let (alice_token, _) = user_logs_in(&app, "alice", "password1_").await;
+
+    // Expired messages are hidden even before the purge runs
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &alice_token, discussion_id).await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].id, kept_message_id);
+
+    let discussions = user_gets_private_discussions(&app, &alice_token).await;
+    let discussion = discussions.iter().find(|d| d.id == discussion_id).unwrap();
+    assert_eq!(
+        discussion.last_message.as_ref().unwrap().id,
+        kept_message_id
+    );
+    assert_eq!(discussion.unseen_messages, 0);
+
+    let count = delete_expired_private_messages(&pool, &redis_client)
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+
+    // Back to the present, the purged message does not come back
+    override_now(None);
+    let (alice_token, _) = user_logs_in(&app, "alice", "password1_").await;
+
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &alice_token, discussion_id).await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].id, kept_message_id);
+}
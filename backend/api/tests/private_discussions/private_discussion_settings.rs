@@ -0,0 +1,258 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::core::helpers::mock_now::override_now;
+use api::features::private_discussions::{
+    application::dto::{
+        requests::private_discussion_participation::PrivateDiscussionSettingsUpdateRequest,
+        responses::{
+            private_discussion::PrivateDiscussionsResponse,
+            private_discussion_participation::PrivateDiscussionSettingsResponse,
+        },
+    },
+    domain::entities::{
+        private_discussion::{PrivateDiscussionData, PrivateDiscussionRequestAnswer},
+        private_discussion_participation::PrivateDiscussionParticipationData,
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    helpers::spawn_app,
+    private_discussions::{
+        private_discussion::{user_creates_a_private_discussion, user_gets_private_discussions},
+        private_discussion_request::user_answers_a_private_discussion_request,
+        private_message::user_creates_a_private_message,
+    },
+    profile::profile::user_has_access_to_protected_route,
+};
+
+pub async fn user_updates_private_discussion_settings(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    muted_until: Option<DateTime<Utc>>,
+    is_archived: bool,
+    is_pinned: bool,
+) -> (u16, Option<PrivateDiscussionParticipationData>) {
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/private-discussions/{}/settings",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionSettingsUpdateRequest {
+            muted_until,
+            is_archived,
+            is_pinned,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionSettingsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSION_SETTINGS_UPDATED");
+    (status, response.participation)
+}
+
+pub async fn user_gets_archived_private_discussions(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<PrivateDiscussionData> {
+    let req = test::TestRequest::get()
+        .uri("/api/private-discussions/?archived=true")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PrivateDiscussionsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSIONS_FETCHED");
+    response.discussions
+}
+
+#[sqlx::test]
+async fn archived_discussion_comes_back_with_a_new_message(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    let (status, participation) = user_updates_private_discussion_settings(
+        &app,
+        &alice_token,
+        discussion_id,
+        None,
+        true,
+        false,
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert!(participation.unwrap().archived_at.is_some());
+
+    let discussions = user_gets_private_discussions(&app, &alice_token).await;
+    assert!(!discussions.iter().any(|d| d.id == discussion_id));
+    let discussions = user_gets_archived_private_discussions(&app, &alice_token).await;
+    assert_eq!(discussions.len(), 1);
+    assert_eq!(discussions[0].id, discussion_id);
+    assert!(discussions[0].is_archived);
+
+    // Archiving only concerns alice, once bob accepted the request
+    user_answers_a_private_discussion_request(
+        &app,
+        &bob_token,
+        discussion_id,
+        PrivateDiscussionRequestAnswer::Accept,
+    )
+    .await;
+    let discussions = user_gets_private_discussions(&app, &bob_token).await;
+    assert!(discussions.iter().any(|d| d.id == discussion_id));
+
+    // Non-members can not change the settings of the discussion
+    let (status, _) = user_updates_private_discussion_settings(
+        &app,
+        &thomas_token,
+        discussion_id,
+        None,
+        true,
+        false,
+    )
+    .await;
+    assert_eq!(status, 404);
+
+    override_now(Some((Utc::now() + Duration::minutes(1)).fixed_offset()));
+
+    user_creates_a_private_message(
+        &app,
+        &bob_token,
+        discussion_id,
+        "hello".to_string(),
+        "nonce".to_string(),
+        "creator_encrypted_session_key".to_string(),
+        "recipient_encrypted_session_key".to_string(),
+    )
+    .await;
+
+    let discussions = user_gets_private_discussions(&app, &alice_token).await;
+    let discussion = discussions.iter().find(|d| d.id == discussion_id).unwrap();
+    assert!(!discussion.is_archived);
+    assert!(user_gets_archived_private_discussions(&app, &alice_token)
+        .await
+        .is_empty());
+}
+
+#[sqlx::test]
+async fn user_can_pin_and_mute_discussions(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &thomas_token)
+        .await
+        .id;
+
+    let bob_discussion_id =
+        user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+    let thomas_discussion_id =
+        user_creates_a_private_discussion(&app, &alice_token, thomas_id, "blue").await;
+
+    let (_, participation) = user_updates_private_discussion_settings(
+        &app,
+        &alice_token,
+        bob_discussion_id,
+        None,
+        false,
+        true,
+    )
+    .await;
+    let pinned_at = participation.unwrap().pinned_at;
+    assert!(pinned_at.is_some());
+
+    let discussions = user_gets_private_discussions(&app, &alice_token).await;
+    assert_eq!(discussions[0].id, bob_discussion_id);
+    assert!(discussions[0].is_pinned);
+    assert!(discussions.iter().filter(|d| d.is_pinned).count() == 1);
+
+    override_now(Some((Utc::now() + Duration::minutes(1)).fixed_offset()));
+
+    // The last pinned discussion goes to the top, pinning again keeps the position
+    user_updates_private_discussion_settings(
+        &app,
+        &alice_token,
+        thomas_discussion_id,
+        None,
+        false,
+        true,
+    )
+    .await;
+    let muted_until = Utc::now() + Duration::hours(8);
+    let (_, participation) = user_updates_private_discussion_settings(
+        &app,
+        &alice_token,
+        bob_discussion_id,
+        Some(muted_until),
+        false,
+        true,
+    )
+    .await;
+    let participation = participation.unwrap();
+    // Postgres keeps microseconds
+    assert_eq!(
+        participation.pinned_at.map(|d| d.timestamp_micros()),
+        pinned_at.map(|d| d.timestamp_micros())
+    );
+    assert!(participation.muted_until.is_some());
+
+    let discussions = user_gets_private_discussions(&app, &alice_token).await;
+    assert_eq!(discussions[0].id, thomas_discussion_id);
+    assert_eq!(discussions[1].id, bob_discussion_id);
+    assert!(discussions[1].muted_until.is_some());
+
+    // A mute in the past unmutes the discussion
+    let (_, participation) = user_updates_private_discussion_settings(
+        &app,
+        &alice_token,
+        bob_discussion_id,
+        Some(Utc::now() - Duration::hours(1)),
+        false,
+        false,
+    )
+    .await;
+    let participation = participation.unwrap();
+    assert!(participation.muted_until.is_none());
+    assert!(participation.pinned_at.is_none());
+
+    let discussions = user_gets_private_discussions(&app, &alice_token).await;
+    assert_eq!(discussions[0].id, thomas_discussion_id);
+    let discussion = discussions
+        .iter()
+        .find(|d| d.id == bob_discussion_id)
+        .unwrap();
+    assert!(!discussion.is_pinned);
+    assert!(discussion.muted_until.is_none());
+}
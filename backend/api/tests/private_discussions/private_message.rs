@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use actix_http::{header, Request};
 use actix_web::{
     body::MessageBody,
@@ -55,6 +57,9 @@ pub async fn user_creates_a_private_message(
             content,
             creator_encrypted_session_key,
             recipient_encrypted_session_key,
+            encrypted_session_keys: BTreeMap::new(),
+            device_encrypted_session_keys: BTreeMap::new(),
+            attachment_ids: Vec::new(),
         })
         .to_request();
     let response = test::call_service(&app, req).await;
@@ -0,0 +1,264 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::core::helpers::mock_now::override_now;
+use api::features::private_discussions::{
+    application::dto::{
+        requests::private_message::PrivateMessageCreateRequest,
+        responses::private_message::{PrivateMessageAttachmentResponse, PrivateMessageResponse},
+    },
+    domain::entities::{
+        private_message::PRIVATE_MESSAGE_MAX_ATTACHMENTS,
+        private_message_attachment::PrivateMessageAttachmentData,
+    },
+};
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    helpers::spawn_app,
+    private_discussions::{
+        private_discussion::user_creates_a_private_discussion,
+        private_message::{
+            user_deletes_a_private_message, user_gets_private_messages_of_discussion,
+        },
+    },
+    profile::profile::{
+        delete_user_marked_as_deleted, user_deletes_its_account, user_has_access_to_protected_route,
+    },
+};
+
+const BOUNDARY: &str = "reallystick-test-boundary";
+
+pub async fn user_uploads_a_private_message_attachment(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    content: &[u8],
+) -> (u16, Option<PrivateMessageAttachmentData>) {
+    let mut body = format!(
+        "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"file\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+        BOUNDARY
+    )
+    .into_bytes();
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+
+    let req = test::TestRequest::post()
+        .uri("/api/private-messages/attachments/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header((
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(body)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    let response: PrivateMessageAttachmentResponse = serde_json::from_slice(&body).unwrap();
+
+    if status == 200 {
+        assert_eq!(response.code, "PRIVATE_MESSAGE_ATTACHMENT_CREATED");
+    }
+
+    (status, response.attachment)
+}
+
+pub async fn user_gets_a_private_message_attachment(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    attachment_id: Uuid,
+) -> (u16, Vec<u8>) {
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/private-messages/attachments/{}",
+            attachment_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    (status, test::read_body(response).await.to_vec())
+}
+
+pub async fn user_creates_a_private_message_with_attachments(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    attachment_ids: Vec<Uuid>,
+) -> (u16, Option<Uuid>) {
+    let req = test::TestRequest::post()
+        .uri("/api/private-messages/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateMessageCreateRequest {
+            discussion_id,
+            content: String::new(),
+            creator_encrypted_session_key: "creator_encrypted_session_key".to_string(),
+            recipient_encrypted_session_key: "recipient_encrypted_session_key".to_string(),
+            encrypted_session_keys: BTreeMap::new(),
+            device_encrypted_session_keys: BTreeMap::new(),
+            attachment_ids,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateMessageResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_MESSAGE_CREATED");
+    (status, response.message.map(|m| m.id))
+}
+
+#[sqlx::test]
+async fn members_can_download_the_attachments_of_a_message(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    let (status, attachment) =
+        user_uploads_a_private_message_attachment(&app, &alice_token, b"encrypted bytes").await;
+    assert_eq!(status, 200);
+    let attachment = attachment.unwrap();
+    assert_eq!(attachment.size_in_bytes, 15);
+    assert!(attachment.private_message_id.is_none());
+
+    // Until it is sent, only the uploader can see the file
+    let (status, _) = user_gets_a_private_message_attachment(&app, &bob_token, attachment.id).await;
+    assert_eq!(status, 404);
+    let (status, content) =
+        user_gets_a_private_message_attachment(&app, &alice_token, attachment.id).await;
+    assert_eq!(status, 200);
+    assert_eq!(content, b"encrypted bytes");
+
+    // Unknown attachments are rejected
+    let (status, _) = user_creates_a_private_message_with_attachments(
+        &app,
+        &alice_token,
+        discussion_id,
+        vec![Uuid::new_v4()],
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    let (status, _) = user_creates_a_private_message_with_attachments(
+        &app,
+        &alice_token,
+        discussion_id,
+        (0..=PRIVATE_MESSAGE_MAX_ATTACHMENTS)
+            .map(|_| Uuid::new_v4())
+            .collect(),
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    // Someone else can not send the attachment of alice
+    let (status, _) = user_creates_a_private_message_with_attachments(
+        &app,
+        &bob_token,
+        discussion_id,
+        vec![attachment.id],
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    let (status, message_id) = user_creates_a_private_message_with_attachments(
+        &app,
+        &alice_token,
+        discussion_id,
+        vec![attachment.id],
+    )
+    .await;
+    assert_eq!(status, 200);
+    let message_id = message_id.unwrap();
+
+    let messages = user_gets_private_messages_of_discussion(&app, &bob_token, discussion_id).await;
+    let message = messages.iter().find(|m| m.id == message_id).unwrap();
+    assert_eq!(message.attachment_ids, vec![attachment.id]);
+
+    let (status, content) =
+        user_gets_a_private_message_attachment(&app, &bob_token, attachment.id).await;
+    assert_eq!(status, 200);
+    assert_eq!(content, b"encrypted bytes");
+
+    let (status, _) =
+        user_gets_a_private_message_attachment(&app, &thomas_token, attachment.id).await;
+    assert_eq!(status, 404);
+
+    // An attachment can only be sent once
+    let (status, _) = user_creates_a_private_message_with_attachments(
+        &app,
+        &alice_token,
+        discussion_id,
+        vec![attachment.id],
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    user_deletes_a_private_message(&app, &alice_token, message_id).await;
+
+    let (status, _) = user_gets_a_private_message_attachment(&app, &bob_token, attachment.id).await;
+    assert_eq!(status, 404);
+    let (status, _) =
+        user_gets_a_private_message_attachment(&app, &alice_token, attachment.id).await;
+    assert_eq!(status, 404);
+}
+
+#[sqlx::test]
+async fn attachments_are_removed_with_their_uploader(pool: PgPool) {
+    let app = spawn_app(pool.clone()).await;
+    let redis_client = redis::Client::open("redis://redis:6379").unwrap();
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    let (_, attachment) =
+        user_uploads_a_private_message_attachment(&app, &alice_token, b"encrypted bytes").await;
+    let attachment = attachment.unwrap();
+    let (status, _) = user_creates_a_private_message_with_attachments(
+        &app,
+        &alice_token,
+        discussion_id,
+        vec![attachment.id],
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    user_deletes_its_account(&app, &alice_token).await;
+
+    override_now(Some(
+        (Utc::now() + Duration::from_secs(7 * 60 * 60 * 24)).fixed_offset(),
+    ));
+
+    delete_user_marked_as_deleted(&pool, &redis_client).await;
+
+    let (bob_token, _) = user_logs_in(&app, "bob", "password1_").await;
+    let (status, _) = user_gets_a_private_message_attachment(&app, &bob_token, attachment.id).await;
+    assert_eq!(status, 404);
+}
@@ -0,0 +1,211 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    test, Error,
+};
+use api::core::helpers::mock_now::override_now;
+use api::features::private_discussions::application::dto::responses::private_message::PrivateMessagesResponse;
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::signup::user_signs_up,
+    helpers::spawn_app,
+    private_discussions::{
+        private_discussion::user_creates_a_private_discussion,
+        private_message::user_creates_a_private_message,
+    },
+    profile::profile::user_has_access_to_protected_route,
+};
+
+pub async fn user_gets_a_page_of_private_messages(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    query: &str,
+) -> (u16, Option<PrivateMessagesResponse>) {
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/private-messages/{}?{}",
+            discussion_id, query
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateMessagesResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_MESSAGE_FETCHED");
+    (status, Some(response))
+}
+
+// Returns the ids of the messages, oldest first
+async fn users_exchange_messages(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    count: usize,
+) -> (String, Uuid, Vec<Uuid>) {
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+
+    let discussion_id = user_creates_a_private_discussion(&app, &alice_token, bob_id, "blue").await;
+
+    let mut message_ids = Vec::new();
+    for i in 0..count {
+        message_ids.push(
+            user_creates_a_private_message(
+                &app,
+                &alice_token,
+                discussion_id,
+                format!("message {}", i),
+                "nonce".to_string(),
+                "creator_encrypted_session_key".to_string(),
+                "recipient_encrypted_session_key".to_string(),
+            )
+            .await,
+        );
+    }
+
+    (alice_token, discussion_id, message_ids)
+}
+
+#[sqlx::test]
+async fn user_can_page_through_private_messages_in_both_directions(pool: PgPool) {
+    let app = spawn_app(pool).await;
+
+    // Messages created at the same instant must not be skipped
+    override_now(Some(Utc::now().fixed_offset()));
+    let (token, discussion_id, message_ids) = users_exchange_messages(&app, 5).await;
+    override_now(None);
+
+    let mut older_ids = Vec::new();
+    let mut query = "limit=2".to_string();
+    loop {
+        let (_, page) =
+            user_gets_a_page_of_private_messages(&app, &token, discussion_id, &query).await;
+        let page = page.unwrap();
+        assert!(page.messages.len() <= 2);
+        assert_eq!(page.newer_cursor, None);
+        older_ids.extend(page.messages.iter().map(|m| m.id));
+
+        match page.older_cursor {
+            Some(cursor) => query = format!("limit=2&before={}", cursor),
+            None => break,
+        }
+    }
+    older_ids.reverse();
+    assert_eq!(older_ids.len(), 5);
+
+    // Older clients can still page with a date
+    let (_, page) = user_gets_a_page_of_private_messages(
+        &app,
+        &token,
+        discussion_id,
+        "limit=1&before_date=2100-01-01T00:00:00Z",
+    )
+    .await;
+    assert_eq!(page.unwrap().messages[0].id, older_ids[4]);
+
+    // Paging forward from the oldest message gives them back in the same order
+    let (_, page) = user_gets_a_page_of_private_messages(
+        &app,
+        &token,
+        discussion_id,
+        &format!("limit=4&around={}", older_ids[0]),
+    )
+    .await;
+    let page = page.unwrap();
+    assert_eq!(page.older_cursor, None);
+    let mut newer_ids = page.messages.iter().rev().map(|m| m.id).collect::<Vec<_>>();
+    let mut newer_cursor = page.newer_cursor;
+    while let Some(cursor) = newer_cursor {
+        let (_, page) = user_gets_a_page_of_private_messages(
+            &app,
+            &token,
+            discussion_id,
+            &format!("limit=2&after={}", cursor),
+        )
+        .await;
+        let page = page.unwrap();
+        assert_eq!(page.older_cursor, None);
+        newer_ids.extend(page.messages.iter().rev().map(|m| m.id));
+        newer_cursor = page.newer_cursor;
+    }
+    assert_eq!(newer_ids, older_ids);
+
+    let mut sorted_ids = message_ids.clone();
+    sorted_ids.sort();
+    let mut paged_ids = older_ids.clone();
+    paged_ids.sort();
+    assert_eq!(paged_ids, sorted_ids);
+}
+
+#[sqlx::test]
+async fn user_can_open_a_discussion_around_a_message(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (token, discussion_id, message_ids) = users_exchange_messages(&app, 7).await;
+
+    let (_, page) = user_gets_a_page_of_private_messages(
+        &app,
+        &token,
+        discussion_id,
+        &format!("limit=3&around={}", message_ids[3]),
+    )
+    .await;
+    let page = page.unwrap();
+    assert_eq!(
+        page.messages.iter().map(|m| m.id).collect::<Vec<_>>(),
+        vec![message_ids[4], message_ids[3], message_ids[2]]
+    );
+    assert!(page.older_cursor.is_some());
+    assert!(page.newer_cursor.is_some());
+
+    let (_, page) = user_gets_a_page_of_private_messages(
+        &app,
+        &token,
+        discussion_id,
+        &format!("limit=10&before={}", page.older_cursor.unwrap()),
+    )
+    .await;
+    assert_eq!(
+        page.unwrap()
+            .messages
+            .iter()
+            .map(|m| m.id)
+            .collect::<Vec<_>>(),
+        vec![message_ids[1], message_ids[0]]
+    );
+
+    let (status, _) = user_gets_a_page_of_private_messages(
+        &app,
+        &token,
+        discussion_id,
+        &format!("around={}", Uuid::new_v4()),
+    )
+    .await;
+    assert_eq!(status, 404);
+
+    let (status, _) =
+        user_gets_a_page_of_private_messages(&app, &token, discussion_id, "before=not-a-cursor")
+            .await;
+    assert_eq!(status, 400);
+
+    let (status, _) = user_gets_a_page_of_private_messages(
+        &app,
+        &token,
+        discussion_id,
+        &format!("around={}&before_date=2100-01-01T00:00:00Z", message_ids[0]),
+    )
+    .await;
+    assert_eq!(status, 400);
+}
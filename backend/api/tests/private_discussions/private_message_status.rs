@@ -0,0 +1,293 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::features::private_discussions::{
+    application::dto::{
+        requests::private_discussion::PrivateDiscussionMarkAsSeenRequest,
+        responses::private_message::{PrivateMessageResponse, PrivateMessagesResponse},
+    },
+    domain::entities::{
+        private_discussion::{PrivateDiscussionData, PrivateDiscussionRequestAnswer},
+        private_message::{PrivateMessageData, PrivateMessageStatus},
+    },
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    helpers::spawn_app,
+    private_discussions::{
+        private_discussion::{user_creates_a_private_discussion, user_gets_private_discussions},
+        private_discussion_group::{
+            user_creates_a_group_message, user_creates_a_private_discussion_group,
+        },
+        private_discussion_request::{
+            user_answers_a_private_discussion_request, user_gets_private_discussion_requests,
+        },
+        private_message::{
+            user_creates_a_private_message, user_gets_private_messages_of_discussion,
+            user_marks_a_private_message_as_seen,
+        },
+    },
+    profile::profile::user_has_access_to_protected_route,
+};
+
+pub async fn user_marks_a_private_message_as_delivered(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    private_message_id: Uuid,
+) -> (u16, Option<PrivateMessageData>) {
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/private-messages/mark-as-delivered/{}",
+            private_message_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PrivateMessageResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_MESSAGE_UPDATED");
+    (status, response.message)
+}
+
+pub async fn user_marks_a_private_discussion_as_seen(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    message_id: Uuid,
+) -> Vec<PrivateMessageData> {
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/api/private-discussions/{}/mark-as-seen",
+            discussion_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionMarkAsSeenRequest { message_id })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PrivateMessagesResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PRIVATE_DISCUSSION_MARKED_AS_SEEN");
+    response.messages
+}
+
+async fn user_sends_messages(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    discussion_id: Uuid,
+    count: usize,
+) -> Vec<Uuid> {
+    let mut message_ids = vec![];
+    for i in 0..count {
+        message_ids.push(
+            user_creates_a_private_message(
+                &app,
+                access_token,
+                discussion_id,
+                format!("content {}", i),
+                String::from("nonce"),
+                String::new(),
+                String::new(),
+            )
+            .await,
+        );
+    }
+    message_ids
+}
+
+#[sqlx::test]
+pub async fn private_message_goes_through_sent_delivered_and_seen(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &thomas_token)
+        .await
+        .id;
+    let (access_token, _) = user_signs_up(&app, None).await;
+
+    let discussion_id =
+        user_creates_a_private_discussion(&app, &access_token, thomas_id, "blue").await;
+    let message_ids = user_sends_messages(&app, &access_token, discussion_id, 1).await;
+
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &thomas_token, discussion_id).await;
+    assert_eq!(messages[0].status, PrivateMessageStatus::Sent);
+    assert!(messages[0].delivered_at.is_none());
+
+    // Only the recipient can acknowledge the delivery
+    let (status, _) =
+        user_marks_a_private_message_as_delivered(&app, &access_token, message_ids[0]).await;
+    assert_eq!(status, 401);
+
+    let (status, message) =
+        user_marks_a_private_message_as_delivered(&app, &thomas_token, message_ids[0]).await;
+    assert_eq!(status, 200);
+    assert_eq!(message.unwrap().status, PrivateMessageStatus::Delivered);
+
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &access_token, discussion_id).await;
+    assert_eq!(messages[0].status, PrivateMessageStatus::Delivered);
+    let delivered_at = messages[0].delivered_at.unwrap();
+
+    // A second device acknowledging keeps the first delivery date
+    let (_, message) =
+        user_marks_a_private_message_as_delivered(&app, &thomas_token, message_ids[0]).await;
+    assert_eq!(message.unwrap().delivered_at, Some(delivered_at));
+
+    user_marks_a_private_message_as_seen(&app, &thomas_token, message_ids[0]).await;
+
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &access_token, discussion_id).await;
+    assert_eq!(messages[0].status, PrivateMessageStatus::Seen);
+    assert_eq!(messages[0].delivered_at, Some(delivered_at));
+    assert!(messages[0].seen_at.is_some());
+}
+
+#[sqlx::test]
+pub async fn user_can_mark_a_discussion_as_seen_up_to_a_message(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (thomas_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &thomas_token)
+        .await
+        .id;
+    let (access_token, _) = user_signs_up(&app, None).await;
+
+    let discussion_id =
+        user_creates_a_private_discussion(&app, &access_token, thomas_id, "blue").await;
+    let message_ids = user_sends_messages(&app, &access_token, discussion_id, 3).await;
+    user_answers_a_private_discussion_request(
+        &app,
+        &thomas_token,
+        discussion_id,
+        PrivateDiscussionRequestAnswer::Accept,
+    )
+    .await;
+
+    let unseen_messages = |discussions: Vec<PrivateDiscussionData>| {
+        discussions
+            .iter()
+            .find(|d| d.id == discussion_id)
+            .unwrap()
+            .unseen_messages
+    };
+
+    assert_eq!(
+        unseen_messages(user_gets_private_discussions(&app, &thomas_token).await),
+        3
+    );
+    // Messages sent by the user are never unseen for them
+    assert_eq!(
+        unseen_messages(user_gets_private_discussions(&app, &access_token).await),
+        0
+    );
+
+    let seen_messages =
+        user_marks_a_private_discussion_as_seen(&app, &thomas_token, discussion_id, message_ids[1])
+            .await;
+    assert_eq!(seen_messages.len(), 2);
+    assert!(seen_messages
+        .iter()
+        .all(|m| m.status == PrivateMessageStatus::Seen && m.delivered_at.is_some()));
+
+    assert_eq!(
+        unseen_messages(user_gets_private_discussions(&app, &thomas_token).await),
+        1
+    );
+
+    // Marking again only updates the messages that were not seen yet
+    let seen_messages =
+        user_marks_a_private_discussion_as_seen(&app, &thomas_token, discussion_id, message_ids[2])
+            .await;
+    assert_eq!(seen_messages.len(), 1);
+    assert_eq!(seen_messages[0].id, message_ids[2]);
+
+    assert_eq!(
+        unseen_messages(user_gets_private_discussions(&app, &thomas_token).await),
+        0
+    );
+}
+
+#[sqlx::test]
+pub async fn group_members_see_and_receive_messages_on_their_own(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (alice_token, _) = user_signs_up(&app, Some("alice")).await;
+    let alice_id = user_has_access_to_protected_route(&app, &alice_token)
+        .await
+        .id;
+    let (bob_token, _) = user_signs_up(&app, Some("bob")).await;
+    let bob_id = user_has_access_to_protected_route(&app, &bob_token)
+        .await
+        .id;
+    let (carol_token, _) = user_signs_up(&app, Some("carol")).await;
+    let carol_id = user_has_access_to_protected_route(&app, &carol_token)
+        .await
+        .id;
+
+    let members = vec![alice_id, bob_id, carol_id];
+    let discussion_id =
+        user_creates_a_private_discussion_group(&app, &alice_token, "Runners", members.clone())
+            .await;
+    let (_, message_id) =
+        user_creates_a_group_message(&app, &alice_token, discussion_id, &members).await;
+    let message_id = message_id.unwrap();
+
+    let unseen_messages = |discussions: Vec<PrivateDiscussionData>| {
+        discussions
+            .iter()
+            .find(|d| d.id == discussion_id)
+            .unwrap()
+            .unseen_messages
+    };
+
+    // The message is delivered once it reached every member
+    let (_, message) =
+        user_marks_a_private_message_as_delivered(&app, &bob_token, message_id).await;
+    assert_eq!(message.unwrap().status, PrivateMessageStatus::Sent);
+    let (_, message) =
+        user_marks_a_private_message_as_delivered(&app, &carol_token, message_id).await;
+    assert_eq!(message.unwrap().status, PrivateMessageStatus::Delivered);
+
+    // Bob reading it does not read it for carol
+    let seen_messages =
+        user_marks_a_private_discussion_as_seen(&app, &bob_token, discussion_id, message_id).await;
+    assert_eq!(seen_messages.len(), 1);
+    assert_eq!(seen_messages[0].status, PrivateMessageStatus::Delivered);
+    let messages =
+        user_gets_private_messages_of_discussion(&app, &alice_token, discussion_id).await;
+    assert_eq!(messages[0].status, PrivateMessageStatus::Delivered);
+    assert_eq!(
+        unseen_messages(user_gets_private_discussion_requests(&app, &bob_token).await),
+        0
+    );
+    assert_eq!(
+        unseen_messages(user_gets_private_discussion_requests(&app, &carol_token).await),
+        1
+    );
+
+    let seen_messages =
+        user_marks_a_private_discussion_as_seen(&app, &carol_token, discussion_id, message_id)
+            .await;
+    assert_eq!(seen_messages.len(), 1);
+    assert_eq!(seen_messages[0].status, PrivateMessageStatus::Seen);
+    assert_eq!(
+        unseen_messages(user_gets_private_discussion_requests(&app, &carol_token).await),
+        0
+    );
+}
@@ -0,0 +1,56 @@
+use api::core::constants::errors::AppError;
+use api::core::helpers::mock_now::now;
+use api::features::private_discussions::domain::entities::socket_message::{
+    SocketClientFrame, SocketClientMessage, SocketRateLimiter, SOCKET_RATE_LIMIT_MAX_MESSAGES,
+    SOCKET_RATE_LIMIT_WINDOW_IN_SECONDS,
+};
+use chrono::Duration;
+use uuid::Uuid;
+
+#[test]
+fn client_frames_follow_the_socket_protocol() {
+    let discussion_id = Uuid::new_v4();
+
+    assert_eq!(
+        SocketClientFrame::parse(&format!(
+            r#"{{"version": 1, "type": "typing_started", "discussion_id": "{}"}}"#,
+            discussion_id
+        ))
+        .unwrap(),
+        SocketClientMessage::TypingStarted { discussion_id }
+    );
+    assert_eq!(
+        SocketClientFrame::parse(r#"{"version": 1, "type": "presence_heartbeat"}"#).unwrap(),
+        SocketClientMessage::PresenceHeartbeat
+    );
+
+    assert!(matches!(
+        SocketClientFrame::parse(r#"{"version": 2, "type": "presence_heartbeat"}"#),
+        Err(AppError::SocketProtocolVersionUnsupported)
+    ));
+    assert!(matches!(
+        SocketClientFrame::parse(r#"{"type": "presence_heartbeat"}"#),
+        Err(AppError::SocketMessageInvalid)
+    ));
+    assert!(matches!(
+        SocketClientFrame::parse(r#"{"version": 1, "type": "message_read"}"#),
+        Err(AppError::SocketMessageInvalid)
+    ));
+    assert!(matches!(
+        SocketClientFrame::parse("hello"),
+        Err(AppError::SocketMessageInvalid)
+    ));
+}
+
+#[test]
+fn socket_rate_limiter_frees_up_once_the_window_passed() {
+    let mut rate_limiter = SocketRateLimiter::default();
+    let start = now();
+
+    for _ in 0..SOCKET_RATE_LIMIT_MAX_MESSAGES {
+        assert!(rate_limiter.allow(start));
+    }
+    assert!(!rate_limiter.allow(start + Duration::seconds(1)));
+
+    assert!(rate_limiter.allow(start + Duration::seconds(SOCKET_RATE_LIMIT_WINDOW_IN_SECONDS)));
+}
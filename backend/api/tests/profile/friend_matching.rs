@@ -0,0 +1,154 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    test, Error,
+};
+use api::{
+    core::structs::responses::GenericResponse,
+    features::profile::{
+        application::dto::responses::FriendMatchesResponse,
+        domain::entities::friend_match::{FriendMatch, FRIEND_MATCH_SEARCHES_PER_DAY},
+    },
+};
+use sqlx::PgPool;
+
+use crate::{
+    auth::signup::user_signs_up,
+    challenges::{
+        challenge::user_creates_a_challenge,
+        challenge_participation::user_creates_a_challenge_participation,
+    },
+    helpers::spawn_app,
+    profile::public_profile::{profile_update_request, user_updates_its_profile},
+};
+
+pub async fn user_opens_up_to_meeting_people(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) {
+    let mut request = profile_update_request(None, false);
+    request.open_to_meeting_people = Some(true);
+    request.matching_uses_demographics = Some(true);
+    request.age_category = Some("20-25".to_string());
+
+    assert_eq!(
+        200,
+        user_updates_its_profile(&app, access_token, request).await
+    );
+}
+
+pub async fn user_searches_for_friends(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> (u16, Vec<u8>) {
+    let req = test::TestRequest::get()
+        .uri("/api/users/matches/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+    let body = test::read_body(response).await;
+
+    (status, body.to_vec())
+}
+
+pub async fn user_gets_friend_matches(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<FriendMatch> {
+    let (status, body) = user_searches_for_friends(&app, access_token).await;
+
+    assert_eq!(200, status);
+
+    let response: FriendMatchesResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "FRIEND_MATCHES_FETCHED");
+
+    response.matches
+}
+
+#[sqlx::test]
+async fn user_must_opt_in_to_search_for_friends(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+
+    let (status, body) = user_searches_for_friends(&app, &access_token).await;
+
+    assert_eq!(403, status);
+
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response.code, "FRIEND_MATCHING_NOT_ENABLED");
+}
+
+#[sqlx::test]
+async fn updates_without_matching_settings_keep_them(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    user_opens_up_to_meeting_people(&app, &access_token).await;
+
+    // Older clients do not know about friend matching
+    assert_eq!(
+        200,
+        user_updates_its_profile(&app, &access_token, profile_update_request(None, false)).await
+    );
+
+    let (status, _) = user_searches_for_friends(&app, &access_token).await;
+    assert_eq!(200, status);
+}
+
+#[sqlx::test]
+async fn friend_matches_are_ranked_by_similarity(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let (close_access_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    let (far_access_token, _) = user_signs_up(&app, Some("testusername3")).await;
+    let (not_looking_access_token, _) = user_signs_up(&app, Some("testusername4")).await;
+
+    user_opens_up_to_meeting_people(&app, &access_token).await;
+    user_opens_up_to_meeting_people(&app, &close_access_token).await;
+    user_opens_up_to_meeting_people(&app, &far_access_token).await;
+
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+    user_creates_a_challenge_participation(&app, &access_token, challenge_id).await;
+    user_creates_a_challenge_participation(&app, &close_access_token, challenge_id).await;
+    user_creates_a_challenge_participation(&app, &not_looking_access_token, challenge_id).await;
+
+    let (status, body) = user_searches_for_friends(&app, &access_token).await;
+    assert_eq!(200, status);
+
+    // Demographic answers are used for scoring but never sent back
+    let raw_body = String::from_utf8(body.clone()).unwrap();
+    assert!(!raw_body.contains("age_category"));
+    assert!(!raw_body.contains("20-25"));
+
+    let response: FriendMatchesResponse = serde_json::from_slice(&body).unwrap();
+    let matches = response.matches;
+
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].username, "testusername2");
+    assert_eq!(matches[0].shared_challenge_count, 1);
+    assert_eq!(matches[1].username, "testusername3");
+    assert_eq!(matches[1].shared_challenge_count, 0);
+
+    // Only the ordering is sent back, not the score behind it
+    assert!(!raw_body.contains("score"));
+}
+
+#[sqlx::test]
+async fn friend_search_is_rate_limited_per_day(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    user_opens_up_to_meeting_people(&app, &access_token).await;
+
+    for _ in 0..FRIEND_MATCH_SEARCHES_PER_DAY {
+        user_gets_friend_matches(&app, &access_token).await;
+    }
+
+    let (status, body) = user_searches_for_friends(&app, &access_token).await;
+
+    assert_eq!(429, status);
+
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response.code, "FRIEND_MATCHING_RATE_LIMITED");
+}
@@ -16,6 +16,7 @@ use api::{
         structs::responses::GenericResponse,
     },
     features::{
+        attachments::infrastructure::services::local_storage_backend::LocalStorageBackend,
         auth::structs::models::TokenCache,
         challenges::domain::entities::challenge_statistics::ChallengeStatisticsCache,
         habits::domain::entities::habit_statistics::HabitStatisticsCache,
@@ -85,8 +86,11 @@ pub async fn delete_user_marked_as_deleted(
     connection_pool: &Pool<Postgres>,
     redis_client: &Client,
 ) {
+    let storage_backend = LocalStorageBackend::new(
+        std::env::temp_dir().join(format!("reallystick-uploads-{}", uuid::Uuid::new_v4())),
+    );
     assert!(
-        remove_users_marked_as_deleted(connection_pool, redis_client)
+        remove_users_marked_as_deleted(connection_pool, redis_client, &storage_backend)
             .await
             .is_ok()
     );
@@ -125,6 +129,7 @@ pub async fn user_can_update_profile(pool: PgPool) {
             notifications_for_public_message_replies_enabled: false,
             notifications_user_joined_your_challenge_enabled: false,
             notifications_user_duplicated_your_challenge_enabled: false,
+            ..Default::default()
         })
         .to_request();
     let response = test::call_service(&app, req).await;
@@ -139,6 +144,44 @@ pub async fn user_can_update_profile(pool: PgPool) {
     assert_eq!(response.user.theme, "light");
 }
 
+#[sqlx::test]
+pub async fn profile_updates_without_mention_notifications_keep_them_on(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+
+    // Older clients do not send the setting
+    let mut request = serde_json::to_value(UserUpdateRequest {
+        locale: "en".to_string(),
+        theme: "dark".to_string(),
+        timezone: "Europe/Paris".to_string(),
+        ..Default::default()
+    })
+    .unwrap();
+    request
+        .as_object_mut()
+        .unwrap()
+        .remove("notifications_for_public_message_mentions_enabled");
+
+    let req = test::TestRequest::post()
+        .uri("/api/users/me")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(request)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: UserResponse = serde_json::from_slice(&body).unwrap();
+
+    assert!(
+        response
+            .user
+            .notifications_for_public_message_mentions_enabled
+    );
+}
+
 #[sqlx::test]
 pub async fn user_can_delete_account(pool: PgPool) {
     configure_database(&pool).await;
@@ -163,6 +206,10 @@ pub async fn user_can_delete_account(pool: PgPool) {
         user_public_data_cache,
         redis_client.clone(),
         translator,
+        configuration.moderation.clone(),
+        Arc::new(LocalStorageBackend::new(
+            std::env::temp_dir().join(format!("reallystick-uploads-{}", uuid::Uuid::new_v4())),
+        )),
     ))
     .await;
 
@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::{
+    core::structs::responses::GenericResponse,
+    features::profile::{
+        application::dto::{requests::UserUpdateRequest, responses::UserPublicProfileResponse},
+        domain::entities::UserPublicProfile,
+    },
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    habits::{
+        habit::{user_creates_a_habit, user_updates_a_habit},
+        habit_category::user_creates_a_habit_category,
+        habit_daily_tracking::user_creates_a_habit_daily_tracking,
+        habit_participation::user_creates_a_habit_participation,
+        unit::user_creates_a_unit,
+    },
+    helpers::spawn_app,
+    profile::profile::user_has_access_to_protected_route,
+    roles::roles::{user_gets_roles, user_grants_a_role},
+};
+
+pub fn profile_update_request(bio: Option<String>, shows_everything: bool) -> UserUpdateRequest {
+    UserUpdateRequest {
+        locale: "en".to_string(),
+        theme: "dark".to_string(),
+        timezone: "Europe/Paris".to_string(),
+        age_category: None,
+        gender: None,
+        continent: None,
+        country: None,
+        region: None,
+        activity: None,
+        financial_situation: None,
+        lives_in_urban_area: None,
+        relationship_status: None,
+        level_of_education: None,
+        has_children: None,
+        has_seen_questions: false,
+        notifications_enabled: false,
+        notifications_for_private_messages_enabled: false,
+        notifications_for_public_message_liked_enabled: false,
+        notifications_for_public_message_replies_enabled: false,
+        notifications_for_public_message_mentions_enabled: false,
+        public_message_languages: None,
+        notifications_user_joined_your_challenge_enabled: false,
+        notifications_user_duplicated_your_challenge_enabled: false,
+        bio,
+        avatar: Some("avatar_1".to_string()),
+        profile_shows_bio: Some(shows_everything),
+        profile_shows_avatar: Some(shows_everything),
+        profile_shows_habits: Some(shows_everything),
+        profile_shows_finished_challenges: Some(shows_everything),
+        profile_shows_badges: Some(shows_everything),
+        open_to_meeting_people: None,
+        matching_uses_demographics: None,
+    }
+}
+
+pub async fn user_updates_its_profile(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    request: UserUpdateRequest,
+) -> u16 {
+    let req = test::TestRequest::post()
+        .uri("/api/users/me")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(request)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+pub async fn user_gets_a_public_profile(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    user_id: Uuid,
+) -> UserPublicProfile {
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/users/{}/profile", user_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: UserPublicProfileResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_PROFILE_FETCHED");
+
+    response.profile
+}
+
+#[sqlx::test]
+async fn public_profile_hides_everything_by_default(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let user = user_has_access_to_protected_route(&app, &access_token).await;
+
+    assert_eq!(
+        200,
+        user_updates_its_profile(
+            &app,
+            &access_token,
+            profile_update_request(Some("Hello".to_string()), false),
+        )
+        .await
+    );
+
+    let (other_access_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    let profile = user_gets_a_public_profile(&app, &other_access_token, user.id).await;
+
+    assert_eq!(profile.username, "testusername");
+    assert!(profile.bio.is_none());
+    assert!(profile.avatar.is_none());
+    assert!(profile.habits.is_none());
+    assert!(profile.finished_challenges.is_none());
+    assert!(profile.badges.is_none());
+}
+
+#[sqlx::test]
+async fn public_profile_shows_opted_in_sections(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let habit_category_id = user_creates_a_habit_category(&app, &admin_access_token).await;
+    let unit_id = user_creates_a_unit(&app, &admin_access_token).await;
+    let habit_id = user_creates_a_habit(
+        &app,
+        &admin_access_token,
+        habit_category_id,
+        HashSet::from([unit_id]),
+    )
+    .await;
+    user_updates_a_habit(
+        &app,
+        &admin_access_token,
+        habit_category_id,
+        habit_id,
+        HashSet::from([unit_id]),
+    )
+    .await;
+
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let user = user_has_access_to_protected_route(&app, &access_token).await;
+
+    user_creates_a_habit_participation(&app, &access_token, habit_id).await;
+    user_creates_a_habit_daily_tracking(&app, &access_token, habit_id, unit_id).await;
+
+    let roles = user_gets_roles(&app, &admin_access_token).await;
+    let moderator_role_id = roles.iter().find(|r| r.name == "moderator").unwrap().id;
+    user_grants_a_role(&app, &admin_access_token, user.id, moderator_role_id, None).await;
+
+    assert_eq!(
+        200,
+        user_updates_its_profile(
+            &app,
+            &access_token,
+            profile_update_request(Some("Hello".to_string()), true),
+        )
+        .await
+    );
+
+    let (other_access_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    let profile = user_gets_a_public_profile(&app, &other_access_token, user.id).await;
+
+    assert_eq!(profile.bio, Some("Hello".to_string()));
+    assert_eq!(profile.avatar, Some("avatar_1".to_string()));
+
+    let habits = profile.habits.unwrap();
+    assert_eq!(habits.len(), 1);
+    assert_eq!(habits[0].habit_id, habit_id);
+    assert_eq!(habits[0].current_streak, 1);
+    assert_eq!(habits[0].longest_streak, 1);
+
+    assert!(profile.finished_challenges.unwrap().is_empty());
+    assert_eq!(profile.badges.unwrap(), vec!["moderator".to_string()]);
+}
+
+#[sqlx::test]
+async fn updates_without_profile_settings_keep_them(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let user = user_has_access_to_protected_route(&app, &access_token).await;
+
+    assert_eq!(
+        200,
+        user_updates_its_profile(
+            &app,
+            &access_token,
+            profile_update_request(Some("Hello".to_string()), true),
+        )
+        .await
+    );
+
+    // Older clients do not know about the profile settings
+    let request = UserUpdateRequest {
+        profile_shows_bio: None,
+        profile_shows_avatar: None,
+        profile_shows_habits: None,
+        profile_shows_finished_challenges: None,
+        profile_shows_badges: None,
+        ..profile_update_request(Some("Hello".to_string()), false)
+    };
+    assert_eq!(
+        200,
+        user_updates_its_profile(&app, &access_token, request).await
+    );
+
+    let (other_access_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    let profile = user_gets_a_public_profile(&app, &other_access_token, user.id).await;
+
+    assert_eq!(profile.bio, Some("Hello".to_string()));
+    assert_eq!(profile.avatar, Some("avatar_1".to_string()));
+}
+
+#[sqlx::test]
+async fn user_cannot_set_a_bio_that_is_too_long(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/users/me")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(profile_update_request(Some("a".repeat(501)), true))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(400, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "USER_BIO_TOO_LONG");
+}
+
+#[sqlx::test]
+async fn public_profile_of_unknown_user_is_not_found(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/users/{}/profile", Uuid::new_v4()))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(404, response.status().as_u16());
+}
@@ -0,0 +1,22 @@
+use api::features::public_discussions::infrastructure::services::language_service::LanguageService;
+
+#[test]
+fn detects_the_language_of_long_enough_contents() {
+    let language_service = LanguageService::new();
+
+    assert_eq!(
+        language_service.detect_language(
+            "Je suis allé courir ce matin avant le travail et je me sens vraiment bien"
+        ),
+        Some("fr".to_string())
+    );
+}
+
+#[test]
+fn unreliable_detections_are_not_kept() {
+    let language_service = LanguageService::new();
+
+    // Whatlang leans towards French here but does not mark it as reliable
+    assert_eq!(language_service.detect_language("Bonjour à tous"), None);
+    assert_eq!(language_service.detect_language("👍"), None);
+}
@@ -0,0 +1,291 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::{
+    core::structs::responses::GenericResponse,
+    features::public_discussions::{
+        application::dto::{
+            requests::{
+                public_message::PublicMessageCreateRequest,
+                public_message_moderation::ResolvePublicMessageReportsRequest,
+            },
+            responses::public_message_moderation::{
+                ModerationDecisionResponse, ModerationDecisionsResponse, ModerationQueueResponse,
+            },
+        },
+        domain::entities::public_message_moderation::{
+            ModerationAction, ModerationQueueEntry, PublicMessageModerationDecisionData,
+        },
+    },
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::challenge::user_creates_a_challenge,
+    helpers::spawn_app,
+    notifications::notifications::user_gets_notifications,
+};
+
+use super::{
+    public_message::{user_creates_a_public_message, user_gets_public_messages},
+    public_message_report::user_creates_a_public_message_report,
+};
+
+pub async fn user_gets_the_moderation_queue(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<ModerationQueueEntry> {
+    let req = test::TestRequest::get()
+        .uri("/api/moderation-queue/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: ModerationQueueResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "MODERATION_QUEUE_FETCHED");
+    response.entries
+}
+
+pub async fn user_resolves_public_message_reports(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    message_id: Uuid,
+    request: ResolvePublicMessageReportsRequest,
+) -> (u16, String) {
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/moderation-queue/{}/resolve", message_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(request)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    if status == 200 {
+        let response: ModerationDecisionResponse = serde_json::from_slice(&body).unwrap();
+        assert!(response.decision.is_some());
+        (status, response.code)
+    } else {
+        let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+        (status, response.code)
+    }
+}
+
+pub async fn user_gets_moderation_decisions(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    message_id: Uuid,
+) -> Vec<PublicMessageModerationDecisionData> {
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/moderation-queue/{}/decisions", message_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: ModerationDecisionsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "MODERATION_DECISIONS_FETCHED");
+    response.decisions
+}
+
+#[sqlx::test]
+pub async fn message_is_hidden_once_enough_users_reported_it(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let message_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Hello".to_string(),
+    )
+    .await;
+
+    // The same reporter only counts once towards the threshold
+    let (first_reporter_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    user_creates_a_public_message_report(&app, &first_reporter_token, message_id, "Spam".into())
+        .await;
+    user_creates_a_public_message_report(&app, &first_reporter_token, message_id, "Rude".into())
+        .await;
+
+    let (second_reporter_token, _) = user_signs_up(&app, Some("testusername3")).await;
+    user_creates_a_public_message_report(&app, &second_reporter_token, message_id, "Spam".into())
+        .await;
+
+    let messages = user_gets_public_messages(&app, &author_token, Some(challenge_id), None).await;
+    assert_eq!(messages.len(), 1);
+
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    user_creates_a_public_message_report(&app, &admin_token, message_id, "Spam".into()).await;
+
+    let messages = user_gets_public_messages(&app, &admin_token, Some(challenge_id), None).await;
+    assert!(messages.is_empty());
+
+    let queue = user_gets_the_moderation_queue(&app, &admin_token).await;
+    assert_eq!(queue.len(), 1);
+    let message = queue[0].message.as_ref().unwrap();
+    assert_eq!(message.id, message_id);
+    assert!(message.hidden_by_moderation);
+    assert_eq!(queue[0].report_count, 4);
+    assert_eq!(queue[0].reporter_count, 3);
+    assert_eq!(queue[0].reasons, vec!["Spam", "Rude", "Spam", "Spam"]);
+
+    // Dismissing the reports puts the message back and tells reporters
+    let (status, code) = user_resolves_public_message_reports(
+        &app,
+        &admin_token,
+        message_id,
+        ResolvePublicMessageReportsRequest {
+            action: ModerationAction::Dismiss,
+            note: None,
+            suspension_in_days: None,
+        },
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(code, "MODERATION_DECISION_CREATED");
+
+    let messages = user_gets_public_messages(&app, &admin_token, Some(challenge_id), None).await;
+    assert_eq!(messages.len(), 1);
+    assert!(user_gets_the_moderation_queue(&app, &admin_token)
+        .await
+        .is_empty());
+
+    let decisions = user_gets_moderation_decisions(&app, &admin_token, message_id).await;
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].action, "dismiss");
+    assert_eq!(decisions[0].report_count, 4);
+
+    let (status, code) = user_resolves_public_message_reports(
+        &app,
+        &admin_token,
+        message_id,
+        ResolvePublicMessageReportsRequest {
+            action: ModerationAction::Hide,
+            note: None,
+            suspension_in_days: None,
+        },
+    )
+    .await;
+    assert_eq!(status, 404);
+    assert_eq!(code, "PUBLIC_MESSAGE_HAS_NO_PENDING_REPORTS");
+
+    let (first_reporter_token, _) = user_logs_in(&app, "testusername2", "password1_").await;
+    let notifications = user_gets_notifications(&app, &first_reporter_token).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].title, "Your report was reviewed");
+
+    let (author_token, _) = user_logs_in(&app, "testusername", "password1_").await;
+    assert!(user_gets_notifications(&app, &author_token)
+        .await
+        .is_empty());
+}
+
+#[sqlx::test]
+pub async fn moderator_can_suspend_the_author_of_a_reported_message(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let message_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Hello".to_string(),
+    )
+    .await;
+
+    let (reporter_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    user_creates_a_public_message_report(&app, &reporter_token, message_id, "Insults".into()).await;
+
+    // Only moderators can read the queue
+    let req = test::TestRequest::get()
+        .uri("/api/moderation-queue/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", reporter_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(403, response.status().as_u16());
+
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+
+    let (status, code) = user_resolves_public_message_reports(
+        &app,
+        &admin_token,
+        message_id,
+        ResolvePublicMessageReportsRequest {
+            action: ModerationAction::SuspendAuthor,
+            note: None,
+            suspension_in_days: Some(3),
+        },
+    )
+    .await;
+    assert_eq!(status, 400);
+    assert_eq!(code, "MODERATION_NOTE_EMPTY");
+
+    let (status, code) = user_resolves_public_message_reports(
+        &app,
+        &admin_token,
+        message_id,
+        ResolvePublicMessageReportsRequest {
+            action: ModerationAction::SuspendAuthor,
+            note: Some("Insulting other users".to_string()),
+            suspension_in_days: Some(3),
+        },
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(code, "MODERATION_DECISION_CREATED");
+
+    let messages = user_gets_public_messages(&app, &admin_token, Some(challenge_id), None).await;
+    assert!(messages.is_empty());
+
+    // The author is told why and can not post until the suspension expires
+    let (author_token, _) = user_logs_in(&app, "testusername", "password1_").await;
+    let notifications = user_gets_notifications(&app, &author_token).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].title, "Your account was suspended");
+    assert!(notifications[0].body.contains("Insulting other users"));
+
+    let req = test::TestRequest::post()
+        .uri("/api/public-messages/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", author_token)))
+        .insert_header(ContentType::json())
+        .set_json(PublicMessageCreateRequest {
+            challenge_id: Some(challenge_id),
+            habit_id: None,
+            thread_id: None,
+            replies_to: None,
+            content: "Hello again".to_string(),
+            attachment_ids: Vec::new(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    assert_eq!(403, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response.code, "USER_SUSPENDED");
+}
@@ -11,9 +11,14 @@ use actix_web::{
 use api::features::public_discussions::{
     application::dto::{
         requests::public_message::{PublicMessageCreateRequest, PublicMessageUpdateRequest},
-        responses::public_message::{PublicMessageResponse, PublicMessagesResponse},
+        responses::public_message::{
+            PublicMessageResponse, PublicMessageRevisionsResponse, PublicMessagesResponse,
+        },
+    },
+    domain::entities::{
+        public_message::PublicMessageData, public_message_mention::PublicMessageMentionData,
+        public_message_revision::PublicMessageRevisionData,
     },
-    domain::entities::public_message::PublicMessageData,
 };
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -26,6 +31,16 @@ use crate::{
         unit::user_creates_a_unit,
     },
     helpers::spawn_app,
+    notifications::notifications::user_gets_notifications,
+    profile::{
+        profile::user_has_access_to_protected_route,
+        public_profile::{profile_update_request, user_updates_its_profile},
+    },
+    public_discussions::{
+        public_message_like::user_creates_a_public_message_like,
+        public_message_report::{user_creates_a_public_message_report, user_gets_message_reports},
+    },
+    user_blocks::user_block::user_blocks_a_user,
 };
 
 pub async fn user_creates_a_public_message(
@@ -47,6 +62,7 @@ pub async fn user_creates_a_public_message(
             thread_id,
             replies_to,
             content,
+            attachment_ids: Vec::new(),
         })
         .to_request();
     let response = test::call_service(&app, req).await;
@@ -88,6 +104,32 @@ pub async fn user_updates_a_public_message(
     assert_eq!(response.message.unwrap().content, content);
 }
 
+pub async fn user_gets_public_message_revisions(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    public_message_id: Uuid,
+) -> (u16, Vec<PublicMessageRevisionData>) {
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/public-messages/{}/revisions",
+            public_message_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, Vec::new());
+    }
+
+    let body = test::read_body(response).await;
+    let response: PublicMessageRevisionsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_MESSAGE_REVISIONS_FETCHED");
+    (status, response.revisions)
+}
+
 pub async fn user_deletes_a_public_message(
     app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
     access_token: &str,
@@ -141,6 +183,53 @@ pub async fn user_gets_public_messages(
     response.messages
 }
 
+pub async fn user_gets_a_page_of_public_messages(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    challenge_id: Uuid,
+    page_query: &str,
+) -> (u16, Option<PublicMessagesResponse>) {
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/public-messages/?challenge_id={}&{}",
+            challenge_id, page_query
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PublicMessagesResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_MESSAGE_FETCHED");
+    (status, Some(response))
+}
+
+pub async fn user_gets_a_public_message(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    public_message_id: Uuid,
+) -> (PublicMessageData, Vec<PublicMessageMentionData>) {
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/public-messages/{}", public_message_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PublicMessageResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_MESSAGE_FETCHED");
+    (response.message.unwrap(), response.mentions)
+}
+
 pub async fn user_gets_liked_messages(
     app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
     access_token: &str,
@@ -367,6 +456,134 @@ pub async fn creator_can_update_a_public_message(pool: PgPool) {
         .await;
 }
 
+#[sqlx::test]
+pub async fn author_and_moderators_can_see_the_edit_history(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let public_message_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Something rude".to_string(),
+    )
+    .await;
+
+    let (reporter_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    user_creates_a_public_message_report(&app, &reporter_token, public_message_id, "Rude".into())
+        .await;
+
+    user_updates_a_public_message(
+        &app,
+        &author_token,
+        public_message_id,
+        "Something nice".to_string(),
+    )
+    .await;
+
+    let (status, revisions) =
+        user_gets_public_message_revisions(&app, &author_token, public_message_id).await;
+    assert_eq!(status, 200);
+    assert_eq!(
+        revisions
+            .iter()
+            .map(|r| r.content.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Something rude", "Something nice"]
+    );
+
+    // Other users only see the current content
+    let (status, _) =
+        user_gets_public_message_revisions(&app, &reporter_token, public_message_id).await;
+    assert_eq!(status, 403);
+
+    // The report points to the content as it was when reported
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let (status, moderator_revisions) =
+        user_gets_public_message_revisions(&app, &admin_token, public_message_id).await;
+    assert_eq!(status, 200);
+    assert_eq!(moderator_revisions.len(), 2);
+
+    let (_, reports) = user_gets_message_reports(&app, &admin_token).await;
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].revision_id, Some(revisions[0].id));
+}
+
+#[sqlx::test]
+pub async fn mentioned_users_are_notified(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+    let admin_id = user_has_access_to_protected_route(&app, &admin_token)
+        .await
+        .id;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let author_id = user_has_access_to_protected_route(&app, &author_token)
+        .await
+        .id;
+    let (mentioned_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    let mentioned_id = user_has_access_to_protected_route(&app, &mentioned_token)
+        .await
+        .id;
+    let (blocker_token, _) = user_signs_up(&app, Some("testusername3")).await;
+    assert_eq!(
+        user_blocks_a_user(&app, &blocker_token, author_id).await,
+        200
+    );
+
+    // Unknown users and e-mail addresses are not mentions
+    let public_message_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Hi @TestUsername2, @nobody and mail@testusername2.com".to_string(),
+    )
+    .await;
+
+    let (_, mentions) = user_gets_a_public_message(&app, &author_token, public_message_id).await;
+    assert_eq!(mentions.len(), 1);
+    assert_eq!(mentions[0].user_id, mentioned_id);
+    assert_eq!(mentions[0].start_index, 3);
+    assert_eq!(mentions[0].length, 14);
+
+    let notifications = user_gets_notifications(&app, &mentioned_token).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].title, "New mention");
+
+    // Only newly mentioned users are notified on edit, and blocked users are never mentioned
+    user_updates_a_public_message(
+        &app,
+        &author_token,
+        public_message_id,
+        "Hi @testusername2, @thomas and @testusername3".to_string(),
+    )
+    .await;
+
+    let (_, mentions) = user_gets_a_public_message(&app, &author_token, public_message_id).await;
+    assert_eq!(
+        mentions.iter().map(|m| m.user_id).collect::<Vec<_>>(),
+        vec![mentioned_id, admin_id]
+    );
+
+    assert_eq!(
+        user_gets_notifications(&app, &mentioned_token).await.len(),
+        1
+    );
+    assert_eq!(user_gets_notifications(&app, &admin_token).await.len(), 1);
+    assert!(user_gets_notifications(&app, &blocker_token)
+        .await
+        .is_empty());
+}
+
 #[sqlx::test]
 pub async fn creator_can_delete_a_public_message(pool: PgPool) {
     let app = spawn_app(pool).await;
@@ -436,3 +653,223 @@ pub async fn admin_can_delete_a_public_message(pool: PgPool) {
         user_gets_public_messages(&app, &access_token, Some(challenge_id), None).await;
     assert_eq!(public_messages.len(), 0);
 }
+
+#[sqlx::test]
+pub async fn user_can_page_through_public_messages(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+
+    let mut message_ids = vec![];
+    for content in ["First", "Second", "Third"] {
+        message_ids.push(
+            user_creates_a_public_message(
+                &app,
+                &access_token,
+                Some(challenge_id),
+                None,
+                None,
+                None,
+                content.to_string(),
+            )
+            .await,
+        );
+    }
+    user_creates_a_public_message_like(&app, &access_token, message_ids[0]).await;
+
+    let (_, first_page) =
+        user_gets_a_page_of_public_messages(&app, &access_token, challenge_id, "limit=2").await;
+    let first_page = first_page.unwrap();
+    assert_eq!(
+        first_page.messages.iter().map(|m| m.id).collect::<Vec<_>>(),
+        vec![message_ids[2], message_ids[1]]
+    );
+    assert!(first_page.next_cursor.is_some());
+
+    let (_, second_page) = user_gets_a_page_of_public_messages(
+        &app,
+        &access_token,
+        challenge_id,
+        &format!("limit=2&cursor={}", first_page.next_cursor.unwrap()),
+    )
+    .await;
+    let second_page = second_page.unwrap();
+    assert_eq!(
+        second_page
+            .messages
+            .iter()
+            .map(|m| m.id)
+            .collect::<Vec<_>>(),
+        vec![message_ids[0]]
+    );
+    assert!(second_page.next_cursor.is_none());
+
+    for sort in ["top", "hot"] {
+        let (_, page) = user_gets_a_page_of_public_messages(
+            &app,
+            &access_token,
+            challenge_id,
+            &format!("sort={}&limit=1", sort),
+        )
+        .await;
+        let page = page.unwrap();
+        assert_eq!(page.messages[0].id, message_ids[0]);
+
+        let (_, page) = user_gets_a_page_of_public_messages(
+            &app,
+            &access_token,
+            challenge_id,
+            &format!("sort={}&cursor={}", sort, page.next_cursor.unwrap()),
+        )
+        .await;
+        let page = page.unwrap();
+        assert_eq!(page.messages.len(), 2);
+        assert!(!page.messages.iter().any(|m| m.id == message_ids[0]));
+    }
+
+    // A cursor only makes sense for the sort it was produced with
+    let (status, _) = user_gets_a_page_of_public_messages(
+        &app,
+        &access_token,
+        challenge_id,
+        &format!(
+            "sort=top&cursor={}_{}",
+            "2026-01-01T00:00:00Z", message_ids[0]
+        ),
+    )
+    .await;
+    assert_eq!(status, 400);
+}
+
+#[sqlx::test]
+pub async fn public_messages_can_be_filtered_by_detected_language(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+
+    // The profile locale is English, without extra languages
+    assert_eq!(
+        user_updates_its_profile(&app, &access_token, profile_update_request(None, false)).await,
+        200
+    );
+
+    let english_message_id = user_creates_a_public_message(
+        &app,
+        &access_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "This morning I went for a long run in the park before going to work, and I feel really great about it"
+            .to_string(),
+    )
+    .await;
+    let french_message_id = user_creates_a_public_message(
+        &app,
+        &access_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Je suis allé courir ce matin avant le travail et je me sens vraiment bien".to_string(),
+    )
+    .await;
+    let unknown_language_message_id = user_creates_a_public_message(
+        &app,
+        &access_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "👍".to_string(),
+    )
+    .await;
+
+    let messages = user_gets_public_messages(&app, &access_token, Some(challenge_id), None).await;
+    let language_of = |message_id: Uuid| {
+        messages
+            .iter()
+            .find(|m| m.id == message_id)
+            .unwrap()
+            .language_code
+            .clone()
+    };
+    assert_eq!(language_of(english_message_id), Some("en".to_string()));
+    assert_eq!(language_of(french_message_id), Some("fr".to_string()));
+    assert_eq!(language_of(unknown_language_message_id), None);
+
+    // Messages in unknown languages are always kept
+    let (_, page) = user_gets_a_page_of_public_messages(
+        &app,
+        &access_token,
+        challenge_id,
+        "filter_by_language=true",
+    )
+    .await;
+    let message_ids: HashSet<Uuid> = page.unwrap().messages.iter().map(|m| m.id).collect();
+    assert_eq!(
+        message_ids,
+        HashSet::from([english_message_id, unknown_language_message_id])
+    );
+
+    // The user opts into French
+    let mut request = profile_update_request(None, false);
+    request.public_message_languages = Some(vec!["fr".to_string()]);
+    assert_eq!(
+        user_updates_its_profile(&app, &access_token, request).await,
+        200
+    );
+
+    let (_, page) = user_gets_a_page_of_public_messages(
+        &app,
+        &access_token,
+        challenge_id,
+        "filter_by_language=true",
+    )
+    .await;
+    assert_eq!(page.unwrap().messages.len(), 3);
+
+    // Older clients do not send the languages, the stored ones are kept
+    assert_eq!(
+        user_updates_its_profile(&app, &access_token, profile_update_request(None, false)).await,
+        200
+    );
+    let (_, page) = user_gets_a_page_of_public_messages(
+        &app,
+        &access_token,
+        challenge_id,
+        "filter_by_language=true",
+    )
+    .await;
+    assert_eq!(page.unwrap().messages.len(), 3);
+
+    // Editing a message detects its language again
+    user_updates_a_public_message(
+        &app,
+        &access_token,
+        english_message_id,
+        "Heute Morgen bin ich vor der Arbeit laufen gegangen und es war wirklich schön".to_string(),
+    )
+    .await;
+
+    let (_, page) = user_gets_a_page_of_public_messages(
+        &app,
+        &access_token,
+        challenge_id,
+        "filter_by_language=true",
+    )
+    .await;
+    let message_ids: HashSet<Uuid> = page.unwrap().messages.iter().map(|m| m.id).collect();
+    assert_eq!(
+        message_ids,
+        HashSet::from([french_message_id, unknown_language_message_id])
+    );
+
+    // Extra languages must be ISO 639-1 codes
+    let mut request = profile_update_request(None, false);
+    request.public_message_languages = Some(vec!["french".to_string()]);
+    assert_eq!(
+        user_updates_its_profile(&app, &access_token, request).await,
+        400
+    );
+}
@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+
+use api::{
+    core::structs::responses::GenericResponse,
+    features::public_discussions::application::dto::{
+        requests::public_message::PublicMessagePinRequest,
+        responses::public_message::PublicMessageResponse,
+    },
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::{
+        challenge::user_creates_a_challenge,
+        challenge_participation::user_creates_a_challenge_participation,
+    },
+    habits::{
+        habit::user_creates_a_habit, habit_category::user_creates_a_habit_category,
+        unit::user_creates_a_unit,
+    },
+    helpers::spawn_app,
+    notifications::notifications::user_gets_notifications,
+};
+
+use super::public_message::{user_creates_a_public_message, user_gets_a_page_of_public_messages};
+
+pub async fn user_pins_a_public_message(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    message_id: Uuid,
+    is_announcement: bool,
+) -> (u16, String) {
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/public-messages/{}/pin", message_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PublicMessagePinRequest { is_announcement })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    let body = test::read_body(response).await;
+    if status != 200 {
+        let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+        return (status, response.code);
+    }
+
+    let response: PublicMessageResponse = serde_json::from_slice(&body).unwrap();
+    let message = response.message.unwrap();
+
+    assert!(message.pinned_at.is_some());
+    assert_eq!(message.is_announcement, is_announcement);
+
+    (status, response.code)
+}
+
+pub async fn user_unpins_a_public_message(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    message_id: Uuid,
+) -> u16 {
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/public-messages/{}/pin", message_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+#[sqlx::test]
+pub async fn challenge_creator_can_pin_announcements(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (creator_token, _) = user_signs_up(&app, None).await;
+    let challenge_id = user_creates_a_challenge(&app, &creator_token).await;
+
+    let (participant_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    user_creates_a_challenge_participation(&app, &participant_token, challenge_id).await;
+
+    let (author_token, _) = user_signs_up(&app, Some("testusername3")).await;
+    let mut message_ids = Vec::new();
+    for content in ["Rules", "Second", "Third", "Fourth"] {
+        message_ids.push(
+            user_creates_a_public_message(
+                &app,
+                &author_token,
+                Some(challenge_id),
+                None,
+                None,
+                None,
+                content.to_string(),
+            )
+            .await,
+        );
+    }
+    let reply_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        Some(message_ids[0]),
+        Some(message_ids[0]),
+        "A reply".to_string(),
+    )
+    .await;
+
+    assert_eq!(
+        user_pins_a_public_message(&app, &author_token, message_ids[0], false).await,
+        (403, "PUBLIC_MESSAGE_PIN_NOT_ALLOWED".to_string())
+    );
+    assert_eq!(
+        user_pins_a_public_message(&app, &creator_token, reply_id, false).await,
+        (400, "PUBLIC_MESSAGE_PIN_ONLY_FOR_ROOT_MESSAGES".to_string())
+    );
+
+    // Announcements reach the participants of the challenge
+    assert_eq!(
+        user_pins_a_public_message(&app, &creator_token, message_ids[0], true).await,
+        (200, "PUBLIC_MESSAGE_PINNED".to_string())
+    );
+
+    let notifications = user_gets_notifications(&app, &participant_token).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].title, "New announcement");
+    assert!(!user_gets_notifications(&app, &author_token)
+        .await
+        .iter()
+        .any(|n| n.title == "New announcement"));
+
+    // Pinned messages are listed apart, on the first page only
+    let (_, response) =
+        user_gets_a_page_of_public_messages(&app, &participant_token, challenge_id, "limit=2")
+            .await;
+    let response = response.unwrap();
+    assert_eq!(
+        response
+            .pinned_messages
+            .iter()
+            .map(|m| m.id)
+            .collect::<Vec<_>>(),
+        vec![message_ids[0]]
+    );
+    assert!(!response.messages.iter().any(|m| m.id == message_ids[0]));
+
+    let (_, response) = user_gets_a_page_of_public_messages(
+        &app,
+        &participant_token,
+        challenge_id,
+        &format!("limit=2&cursor={}", response.next_cursor.unwrap()),
+    )
+    .await;
+    let response = response.unwrap();
+    assert!(response.pinned_messages.is_empty());
+    assert_eq!(response.messages.len(), 1);
+
+    // Channels have a limited number of pins
+    for message_id in &message_ids[1..3] {
+        assert_eq!(
+            user_pins_a_public_message(&app, &creator_token, *message_id, false)
+                .await
+                .0,
+            200
+        );
+    }
+    assert_eq!(
+        user_pins_a_public_message(&app, &creator_token, message_ids[3], false).await,
+        (400, "PUBLIC_MESSAGE_PIN_LIMIT_REACHED".to_string())
+    );
+
+    assert_eq!(
+        user_unpins_a_public_message(&app, &author_token, message_ids[0]).await,
+        403
+    );
+    assert_eq!(
+        user_unpins_a_public_message(&app, &creator_token, message_ids[0]).await,
+        200
+    );
+    assert_eq!(
+        user_pins_a_public_message(&app, &creator_token, message_ids[3], false)
+            .await
+            .0,
+        200
+    );
+}
+
+#[sqlx::test]
+pub async fn moderators_can_pin_messages_in_habit_channels(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let habit_category_id = user_creates_a_habit_category(&app, &admin_token).await;
+    let unit_id = user_creates_a_unit(&app, &admin_token).await;
+    let habit_id = user_creates_a_habit(
+        &app,
+        &admin_token,
+        habit_category_id,
+        HashSet::from([unit_id]),
+    )
+    .await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let message_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        None,
+        Some(habit_id),
+        None,
+        None,
+        "Hello".to_string(),
+    )
+    .await;
+
+    assert_eq!(
+        user_pins_a_public_message(&app, &author_token, message_id, false)
+            .await
+            .0,
+        403
+    );
+    assert_eq!(
+        user_pins_a_public_message(&app, &admin_token, message_id, true).await,
+        (
+            400,
+            "PUBLIC_MESSAGE_ANNOUNCEMENT_ONLY_IN_CHALLENGES".to_string()
+        )
+    );
+    assert_eq!(
+        user_pins_a_public_message(&app, &admin_token, message_id, false)
+            .await
+            .0,
+        200
+    );
+}
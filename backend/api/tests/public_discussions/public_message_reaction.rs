@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+
+use api::features::public_discussions::{
+    application::dto::{
+        requests::public_message_reaction::PublicMessageReactionCreateRequest,
+        responses::public_message_reaction::{
+            PublicMessageReactionResponse, PublicMessageReactionsResponse,
+        },
+    },
+    domain::entities::public_message_reaction::{
+        PublicMessageReactionData, PublicMessageReactionKind,
+    },
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::challenge::user_creates_a_challenge,
+    helpers::spawn_app,
+    notifications::notifications::user_gets_notifications,
+};
+
+use super::{
+    public_message::{user_creates_a_public_message, user_gets_a_public_message},
+    public_message_like::{user_creates_a_public_message_like, user_deletes_a_public_message_like},
+};
+
+pub async fn user_reacts_to_a_public_message(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    message_id: Uuid,
+    reaction: PublicMessageReactionKind,
+) {
+    let req = test::TestRequest::post()
+        .uri("/api/public-message-reactions/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PublicMessageReactionCreateRequest {
+            message_id,
+            reaction,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PublicMessageReactionResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_MESSAGE_REACTION_CREATED");
+}
+
+pub async fn user_deletes_a_public_message_reaction(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    message_id: Uuid,
+    reaction: PublicMessageReactionKind,
+) -> u16 {
+    let req = test::TestRequest::delete()
+        .uri(&format!(
+            "/api/public-message-reactions/{}/{}",
+            message_id, reaction
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+pub async fn user_gets_public_message_reactions(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    message_id: Uuid,
+    reaction: Option<PublicMessageReactionKind>,
+) -> Vec<PublicMessageReactionData> {
+    let mut uri = format!("/api/public-messages/{}/reactions", message_id);
+    if let Some(reaction) = reaction {
+        uri.push_str(&format!("?reaction={}", reaction));
+    }
+
+    let req = test::TestRequest::get()
+        .uri(&uri)
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PublicMessageReactionsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_MESSAGE_REACTIONS_FETCHED");
+
+    response.reactions
+}
+
+#[sqlx::test]
+pub async fn users_can_react_to_a_public_message(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let (reactor_token, _) = user_signs_up(&app, Some("testusername2")).await;
+
+    let message_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Hello".to_string(),
+    )
+    .await;
+
+    // A user can leave several reactions, each of them only once
+    for reaction in [
+        PublicMessageReactionKind::Celebrate,
+        PublicMessageReactionKind::Strong,
+        PublicMessageReactionKind::Celebrate,
+    ] {
+        user_reacts_to_a_public_message(&app, &reactor_token, message_id, reaction).await;
+    }
+    user_reacts_to_a_public_message(
+        &app,
+        &admin_token,
+        message_id,
+        PublicMessageReactionKind::Celebrate,
+    )
+    .await;
+
+    let (message, _) = user_gets_a_public_message(&app, &author_token, message_id).await;
+    assert_eq!(
+        message.reaction_counts,
+        BTreeMap::from([("celebrate".to_string(), 2), ("strong".to_string(), 1)])
+    );
+    assert_eq!(message.like_count, 0);
+
+    let notifications = user_gets_notifications(&app, &author_token).await;
+    assert_eq!(notifications.len(), 3);
+    assert_eq!(notifications[0].title, "New reaction");
+    assert!(notifications[0].body.contains("🎉"));
+
+    // Everyone can see who reacted
+    let reactions = user_gets_public_message_reactions(&app, &author_token, message_id, None).await;
+    assert_eq!(reactions.len(), 3);
+
+    let reactions = user_gets_public_message_reactions(
+        &app,
+        &author_token,
+        message_id,
+        Some(PublicMessageReactionKind::Strong),
+    )
+    .await;
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].reaction, "strong");
+
+    assert_eq!(
+        user_deletes_a_public_message_reaction(
+            &app,
+            &reactor_token,
+            message_id,
+            PublicMessageReactionKind::Strong
+        )
+        .await,
+        200
+    );
+    assert_eq!(
+        user_deletes_a_public_message_reaction(
+            &app,
+            &reactor_token,
+            message_id,
+            PublicMessageReactionKind::Strong
+        )
+        .await,
+        404
+    );
+
+    let (message, _) = user_gets_a_public_message(&app, &author_token, message_id).await;
+    assert_eq!(
+        message.reaction_counts,
+        BTreeMap::from([("celebrate".to_string(), 2)])
+    );
+}
+
+#[sqlx::test]
+pub async fn likes_are_like_reactions(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let (reactor_token, _) = user_signs_up(&app, Some("testusername2")).await;
+
+    let message_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Hello".to_string(),
+    )
+    .await;
+
+    user_creates_a_public_message_like(&app, &reactor_token, message_id).await;
+    user_reacts_to_a_public_message(
+        &app,
+        &reactor_token,
+        message_id,
+        PublicMessageReactionKind::Like,
+    )
+    .await;
+
+    let (message, _) = user_gets_a_public_message(&app, &author_token, message_id).await;
+    assert_eq!(message.like_count, 1);
+    assert_eq!(
+        message.reaction_counts,
+        BTreeMap::from([("like".to_string(), 1)])
+    );
+
+    let notifications = user_gets_notifications(&app, &author_token).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].title, "New like");
+
+    assert_eq!(
+        user_deletes_a_public_message_reaction(
+            &app,
+            &reactor_token,
+            message_id,
+            PublicMessageReactionKind::Like
+        )
+        .await,
+        200
+    );
+
+    let (message, _) = user_gets_a_public_message(&app, &author_token, message_id).await;
+    assert_eq!(message.like_count, 0);
+    assert!(message.reaction_counts.is_empty());
+
+    user_creates_a_public_message_like(&app, &reactor_token, message_id).await;
+    user_deletes_a_public_message_like(&app, &reactor_token, message_id).await;
+    assert!(
+        user_gets_public_message_reactions(&app, &author_token, message_id, None)
+            .await
+            .is_empty()
+    );
+}
@@ -0,0 +1,360 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+
+use api::{
+    core::helpers::{
+        mock_now::override_now,
+        public_message_subscriptions::send_pending_public_message_subscription_notifications,
+    },
+    features::public_discussions::{
+        application::dto::{
+            requests::public_message_subscription::{
+                PublicMessageSubscriptionCreateRequest, PublicMessageSubscriptionUpdateRequest,
+            },
+            responses::public_message_subscription::{
+                PublicMessageSubscriptionResponse, PublicMessageSubscriptionsResponse,
+            },
+        },
+        domain::entities::public_message_subscription::PublicMessageSubscriptionData,
+    },
+};
+use chrono::{Duration, Utc};
+use redis::Client;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::challenge::user_creates_a_challenge,
+    helpers::spawn_app,
+    notifications::notifications::user_gets_notifications,
+};
+
+use super::public_message::user_creates_a_public_message;
+
+pub async fn user_subscribes_to_public_messages(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    request: PublicMessageSubscriptionCreateRequest,
+) -> (u16, Option<PublicMessageSubscriptionData>) {
+    let req = test::TestRequest::post()
+        .uri("/api/public-message-subscriptions/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(request)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: PublicMessageSubscriptionResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_MESSAGE_SUBSCRIPTION_CREATED");
+
+    (status, response.subscription)
+}
+
+pub async fn user_gets_public_message_subscriptions(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<PublicMessageSubscriptionData> {
+    let req = test::TestRequest::get()
+        .uri("/api/public-message-subscriptions/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: PublicMessageSubscriptionsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "PUBLIC_MESSAGE_SUBSCRIPTIONS_FETCHED");
+
+    response.subscriptions
+}
+
+pub async fn user_updates_a_public_message_subscription(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    subscription_id: Uuid,
+    muted: bool,
+) -> u16 {
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/public-message-subscriptions/{}",
+            subscription_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PublicMessageSubscriptionUpdateRequest { muted })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+pub async fn user_deletes_a_public_message_subscription(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    subscription_id: Uuid,
+) -> u16 {
+    let req = test::TestRequest::delete()
+        .uri(&format!(
+            "/api/public-message-subscriptions/{}",
+            subscription_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+fn thread_subscription_request(
+    thread_id: Uuid,
+    muted: bool,
+) -> PublicMessageSubscriptionCreateRequest {
+    PublicMessageSubscriptionCreateRequest {
+        habit_id: None,
+        challenge_id: None,
+        thread_id: Some(thread_id),
+        muted,
+    }
+}
+
+#[sqlx::test]
+pub async fn thread_followers_are_notified_in_batches(pool: PgPool) {
+    let app = spawn_app(pool.clone()).await;
+    let redis_client = Client::open("redis://redis:6379").unwrap();
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let (follower_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    let (replier_token, _) = user_signs_up(&app, Some("testusername3")).await;
+
+    // Writing a message follows its thread
+    let thread_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Hello".to_string(),
+    )
+    .await;
+
+    let subscriptions = user_gets_public_message_subscriptions(&app, &author_token).await;
+    assert_eq!(subscriptions.len(), 1);
+    assert_eq!(subscriptions[0].thread_id, Some(thread_id));
+    assert_eq!(subscriptions[0].challenge_id, Some(challenge_id));
+    assert!(!subscriptions[0].muted);
+
+    let (status, subscription) = user_subscribes_to_public_messages(
+        &app,
+        &follower_token,
+        thread_subscription_request(thread_id, false),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(subscription.unwrap().thread_id, Some(thread_id));
+
+    // The author hears about the reply only once
+    user_creates_a_public_message(
+        &app,
+        &replier_token,
+        Some(challenge_id),
+        None,
+        Some(thread_id),
+        Some(thread_id),
+        "First reply".to_string(),
+    )
+    .await;
+
+    let notifications = user_gets_notifications(&app, &author_token).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].title, "New reply");
+
+    let notifications = user_gets_notifications(&app, &follower_token).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].title, "New replies");
+
+    // Busy threads are batched
+    for content in ["Second reply", "Third reply"] {
+        user_creates_a_public_message(
+            &app,
+            &replier_token,
+            Some(challenge_id),
+            None,
+            Some(thread_id),
+            Some(thread_id),
+            content.to_string(),
+        )
+        .await;
+    }
+
+    send_pending_public_message_subscription_notifications(&pool, &redis_client)
+        .await
+        .unwrap();
+    assert_eq!(
+        user_gets_notifications(&app, &follower_token).await.len(),
+        1
+    );
+
+    override_now(Some((Utc::now() + Duration::minutes(16)).fixed_offset()));
+
+    send_pending_public_message_subscription_notifications(&pool, &redis_client)
+        .await
+        .unwrap();
+
+    let (follower_token, _) = user_logs_in(&app, "testusername2", "password1_").await;
+    let notifications = user_gets_notifications(&app, &follower_token).await;
+    assert_eq!(notifications.len(), 2);
+    assert_eq!(notifications[0].title, "New replies");
+    assert!(notifications[0].body.contains('2'));
+
+    // Nothing is left to send
+    send_pending_public_message_subscription_notifications(&pool, &redis_client)
+        .await
+        .unwrap();
+    assert_eq!(
+        user_gets_notifications(&app, &follower_token).await.len(),
+        2
+    );
+}
+
+#[sqlx::test]
+pub async fn muted_threads_are_silent_in_followed_channels(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_token, _) = user_logs_in(&app, "thomas", "").await;
+    let challenge_id = user_creates_a_challenge(&app, &admin_token).await;
+
+    let (author_token, _) = user_signs_up(&app, None).await;
+    let (follower_token, _) = user_signs_up(&app, Some("testusername2")).await;
+
+    let (status, _) = user_subscribes_to_public_messages(
+        &app,
+        &follower_token,
+        PublicMessageSubscriptionCreateRequest {
+            habit_id: None,
+            challenge_id: None,
+            thread_id: None,
+            muted: false,
+        },
+    )
+    .await;
+    assert_eq!(status, 400);
+
+    let (status, channel_subscription) = user_subscribes_to_public_messages(
+        &app,
+        &follower_token,
+        PublicMessageSubscriptionCreateRequest {
+            habit_id: None,
+            challenge_id: Some(challenge_id),
+            thread_id: None,
+            muted: false,
+        },
+    )
+    .await;
+    assert_eq!(status, 200);
+    let channel_subscription = channel_subscription.unwrap();
+    assert_eq!(channel_subscription.thread_id, None);
+
+    let thread_id = user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Hello".to_string(),
+    )
+    .await;
+
+    let notifications = user_gets_notifications(&app, &follower_token).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].title, "New messages");
+
+    let (status, thread_subscription) = user_subscribes_to_public_messages(
+        &app,
+        &follower_token,
+        thread_subscription_request(thread_id, true),
+    )
+    .await;
+    assert_eq!(status, 200);
+    let thread_subscription = thread_subscription.unwrap();
+    assert!(thread_subscription.muted);
+
+    override_now(Some((Utc::now() + Duration::minutes(16)).fixed_offset()));
+    let (author_token, _) = user_logs_in(&app, "testusername", "password1_").await;
+    let (follower_token, _) = user_logs_in(&app, "testusername2", "password1_").await;
+
+    user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        Some(thread_id),
+        None,
+        "Anyone there?".to_string(),
+    )
+    .await;
+    assert_eq!(
+        user_gets_notifications(&app, &follower_token).await.len(),
+        1
+    );
+
+    // The rest of the channel is still followed
+    user_creates_a_public_message(
+        &app,
+        &author_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Another topic".to_string(),
+    )
+    .await;
+    assert_eq!(
+        user_gets_notifications(&app, &follower_token).await.len(),
+        2
+    );
+
+    // Subscriptions belong to their user
+    assert_eq!(
+        user_deletes_a_public_message_subscription(&app, &author_token, channel_subscription.id)
+            .await,
+        404
+    );
+    assert_eq!(
+        user_updates_a_public_message_subscription(
+            &app,
+            &follower_token,
+            thread_subscription.id,
+            false
+        )
+        .await,
+        200
+    );
+    assert_eq!(
+        user_deletes_a_public_message_subscription(&app, &follower_token, channel_subscription.id)
+            .await,
+        200
+    );
+
+    let subscriptions = user_gets_public_message_subscriptions(&app, &follower_token).await;
+    assert_eq!(subscriptions.len(), 1);
+    assert_eq!(subscriptions[0].id, thread_subscription.id);
+    assert!(!subscriptions[0].muted);
+}
@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::{
+    core::{
+        helpers::mock_now::{now, override_now},
+        structs::responses::GenericResponse,
+    },
+    features::roles::{
+        application::dto::{
+            requests::role_grant::RoleGrantCreateRequest,
+            responses::{
+                role::RolesResponse,
+                role_grant::{RoleGrantResponse, RoleGrantsResponse},
+            },
+        },
+        domain::entities::{permission::Permission, role::RoleData, role_grant::RoleGrantData},
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up, token::user_refreshes_token},
+    helpers::spawn_app,
+    profile::profile::user_has_access_to_protected_route,
+    public_discussions::public_message_report::user_gets_message_reports,
+};
+
+pub async fn user_gets_roles(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<RoleData> {
+    let req = test::TestRequest::get()
+        .uri("/api/roles/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: RolesResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "ROLES_FETCHED");
+
+    response.roles
+}
+
+pub async fn user_grants_a_role(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    user_id: Uuid,
+    role_id: Uuid,
+    expires_at: Option<DateTime<Utc>>,
+) -> RoleGrantData {
+    let req = test::TestRequest::post()
+        .uri("/api/roles/grants/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(RoleGrantCreateRequest {
+            user_id,
+            role_id,
+            expires_at,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: RoleGrantResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "ROLE_GRANT_CREATED");
+    assert!(response.role_grant.is_some());
+
+    response.role_grant.unwrap()
+}
+
+pub async fn user_revokes_a_role_grant(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    grant_id: Uuid,
+) {
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/roles/grants/{}", grant_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: RoleGrantResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "ROLE_GRANT_DELETED");
+}
+
+pub async fn user_gets_user_role_grants(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    user_id: Uuid,
+) -> Vec<RoleGrantData> {
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/roles/users/{}", user_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: RoleGrantsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "ROLE_GRANTS_FETCHED");
+
+    response.role_grants
+}
+
+pub async fn user_tries_to_create_a_habit_category(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> u16 {
+    let req = test::TestRequest::post()
+        .uri("/api/habit-categories/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(serde_json::json!({
+            "name": HashMap::from([("en", "Learning languages")]),
+            "icon": "english_icon".to_string(),
+        }))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+fn get_role_id(roles: &[RoleData], name: &str) -> Uuid {
+    roles.iter().find(|r| r.name == name).unwrap().id
+}
+
+#[sqlx::test]
+async fn admin_can_get_roles(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_logs_in(&app, "thomas", "").await;
+
+    let roles = user_gets_roles(&app, &access_token).await;
+
+    let moderator = roles.iter().find(|r| r.name == "moderator").unwrap();
+    assert!(moderator
+        .permissions
+        .contains(&Permission::ModeratePublicMessages));
+    assert!(!moderator.permissions.contains(&Permission::ManageRoles));
+}
+
+#[sqlx::test]
+async fn normal_user_cannot_manage_roles(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let user = user_has_access_to_protected_route(&app, &access_token).await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/roles/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(403, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "MISSING_PERMISSION");
+
+    let req = test::TestRequest::post()
+        .uri("/api/roles/grants/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(RoleGrantCreateRequest {
+            user_id: user.id,
+            role_id: Uuid::new_v4(),
+            expires_at: None,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[sqlx::test]
+async fn granted_role_gives_permissions_until_revoked(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let (access_token, refresh_token) = user_signs_up(&app, None).await;
+    let user = user_has_access_to_protected_route(&app, &access_token).await;
+
+    assert_eq!(
+        403,
+        user_tries_to_create_a_habit_category(&app, &access_token).await
+    );
+
+    let roles = user_gets_roles(&app, &admin_access_token).await;
+    let grant = user_grants_a_role(
+        &app,
+        &admin_access_token,
+        user.id,
+        get_role_id(&roles, "habit_editor"),
+        None,
+    )
+    .await;
+
+    let grants = user_gets_user_role_grants(&app, &admin_access_token, user.id).await;
+    assert_eq!(grants.len(), 1);
+    assert_eq!(grants[0].id, grant.id);
+
+    // Permissions are embedded in the tokens, so they apply once the token is refreshed.
+    let (access_token, refresh_token) = user_refreshes_token(&app, &refresh_token).await;
+    assert_eq!(
+        200,
+        user_tries_to_create_a_habit_category(&app, &access_token).await
+    );
+
+    user_revokes_a_role_grant(&app, &admin_access_token, grant.id).await;
+
+    let (access_token, _) = user_refreshes_token(&app, &refresh_token).await;
+    assert_eq!(
+        403,
+        user_tries_to_create_a_habit_category(&app, &access_token).await
+    );
+}
+
+#[sqlx::test]
+async fn moderator_can_get_message_reports(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let user = user_has_access_to_protected_route(&app, &access_token).await;
+
+    let roles = user_gets_roles(&app, &admin_access_token).await;
+    user_grants_a_role(
+        &app,
+        &admin_access_token,
+        user.id,
+        get_role_id(&roles, "moderator"),
+        Some(now() + Duration::days(30)),
+    )
+    .await;
+
+    let (access_token, _) = user_logs_in(&app, "testusername", "password1_").await;
+    let (messages, reports) = user_gets_message_reports(&app, &access_token).await;
+
+    assert!(messages.is_empty());
+    assert!(reports.is_empty());
+}
+
+#[sqlx::test]
+async fn role_grant_stops_giving_permissions_once_expired(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let user = user_has_access_to_protected_route(&app, &access_token).await;
+
+    let roles = user_gets_roles(&app, &admin_access_token).await;
+    user_grants_a_role(
+        &app,
+        &admin_access_token,
+        user.id,
+        get_role_id(&roles, "habit_editor"),
+        Some(now() + Duration::days(1)),
+    )
+    .await;
+
+    let (access_token, _) = user_logs_in(&app, "testusername", "password1_").await;
+    assert_eq!(
+        200,
+        user_tries_to_create_a_habit_category(&app, &access_token).await
+    );
+
+    override_now(Some((Utc::now() + Duration::days(2)).fixed_offset()));
+
+    let (access_token, _) = user_logs_in(&app, "testusername", "password1_").await;
+    assert_eq!(
+        403,
+        user_tries_to_create_a_habit_category(&app, &access_token).await
+    );
+}
+
+#[sqlx::test]
+async fn role_grant_cannot_expire_in_the_past(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (admin_access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let user = user_has_access_to_protected_route(&app, &access_token).await;
+
+    let roles = user_gets_roles(&app, &admin_access_token).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/roles/grants/")
+        .insert_header((
+            header::AUTHORIZATION,
+            format!("Bearer {}", admin_access_token),
+        ))
+        .insert_header(ContentType::json())
+        .set_json(RoleGrantCreateRequest {
+            user_id: user.id,
+            role_id: get_role_id(&roles, "habit_editor"),
+            expires_at: Some(now() - Duration::days(1)),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(400, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "ROLE_GRANT_EXPIRATION_IN_PAST");
+}
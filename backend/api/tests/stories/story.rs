@@ -0,0 +1,425 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::{
+    core::{
+        helpers::mock_now::{now, override_now},
+        structs::responses::GenericResponse,
+    },
+    features::public_discussions::{
+        application::dto::requests::public_message_moderation::ResolvePublicMessageReportsRequest,
+        domain::entities::public_message_moderation::ModerationAction,
+    },
+    features::stories::{
+        application::dto::{
+            requests::{story::StoryCreateRequest, story_report::StoryReportCreateRequest},
+            responses::{
+                story::{StoriesResponse, StoryResponse, StoryViewsResponse},
+                story_report::{StoryModerationDecisionResponse, StoryReportResponse},
+            },
+        },
+        domain::{entities::story::StoryData, repositories::story_repository::StoryRepository},
+        infrastructure::repositories::story_repository::StoryRepositoryImpl,
+    },
+};
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::{
+        challenge::user_creates_a_challenge,
+        challenge_participation::user_creates_a_challenge_participation,
+    },
+    helpers::spawn_app,
+    profile::profile::user_has_access_to_protected_route,
+    public_discussions::moderation::user_gets_the_moderation_queue,
+};
+
+pub async fn user_creates_a_story(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    challenge_id: Uuid,
+) -> StoryData {
+    let req = test::TestRequest::post()
+        .uri("/api/stories/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(StoryCreateRequest {
+            habit_id: None,
+            challenge_id: Some(challenge_id),
+            content: "Day 3, still going!".to_string(),
+            media: None,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: StoryResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "STORY_CREATED");
+    assert!(response.story.is_some());
+
+    response.story.unwrap()
+}
+
+pub async fn user_gets_stories(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<StoryData> {
+    let req = test::TestRequest::get()
+        .uri("/api/stories/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: StoriesResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "STORIES_FETCHED");
+
+    response.stories
+}
+
+pub async fn user_views_a_story(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    story_id: Uuid,
+) -> u16 {
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/stories/{}/views", story_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    response.status().as_u16()
+}
+
+#[sqlx::test]
+async fn challenge_co_participants_can_see_and_view_stories(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+    user_creates_a_challenge_participation(&app, &access_token, challenge_id).await;
+
+    let (other_access_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    let other_user = user_has_access_to_protected_route(&app, &other_access_token).await;
+    let (outsider_access_token, _) = user_signs_up(&app, Some("testusername3")).await;
+
+    user_creates_a_challenge_participation(&app, &other_access_token, challenge_id).await;
+
+    // Sharing another challenge with the creator is not enough
+    let (access_token, _) = user_logs_in(&app, "testusername", "password1_").await;
+    let other_challenge_id = user_creates_a_challenge(&app, &access_token).await;
+    user_creates_a_challenge_participation(&app, &access_token, other_challenge_id).await;
+    user_creates_a_challenge_participation(&app, &outsider_access_token, other_challenge_id).await;
+
+    let story = user_creates_a_story(&app, &access_token, challenge_id).await;
+    assert_eq!(story.expires_at - story.created_at, Duration::hours(24),);
+
+    let stories = user_gets_stories(&app, &other_access_token).await;
+    assert_eq!(stories.len(), 1);
+    assert_eq!(stories[0].id, story.id);
+
+    assert!(user_gets_stories(&app, &outsider_access_token)
+        .await
+        .is_empty());
+    assert_eq!(
+        404,
+        user_views_a_story(&app, &outsider_access_token, story.id).await
+    );
+
+    // A user viewing twice only counts once
+    assert_eq!(
+        200,
+        user_views_a_story(&app, &other_access_token, story.id).await
+    );
+    assert_eq!(
+        200,
+        user_views_a_story(&app, &other_access_token, story.id).await
+    );
+
+    let stories = user_gets_stories(&app, &access_token).await;
+    assert_eq!(stories[0].view_count, 1);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/stories/{}/views", story.id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: StoryViewsResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "STORY_VIEWS_FETCHED");
+    assert_eq!(response.views.len(), 1);
+    assert_eq!(response.views[0].user_id, other_user.id);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/stories/{}/views", story.id))
+        .insert_header((
+            header::AUTHORIZATION,
+            format!("Bearer {}", other_access_token),
+        ))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[sqlx::test]
+async fn user_cannot_create_a_story_about_a_challenge_it_does_not_take_part_in(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/stories/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(StoryCreateRequest {
+            habit_id: None,
+            challenge_id: Some(challenge_id),
+            content: "Hello".to_string(),
+            media: None,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(403, response.status().as_u16());
+
+    user_creates_a_challenge_participation(&app, &access_token, challenge_id).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/stories/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(StoryCreateRequest {
+            habit_id: None,
+            challenge_id: Some(challenge_id),
+            content: "".to_string(),
+            media: None,
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(400, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "STORY_CONTENT_EMPTY");
+}
+
+#[sqlx::test]
+async fn stories_expire_and_are_purged(pool: PgPool) {
+    let story_repo = StoryRepositoryImpl::new(pool.clone());
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+    user_creates_a_challenge_participation(&app, &access_token, challenge_id).await;
+
+    let story = user_creates_a_story(&app, &access_token, challenge_id).await;
+    assert_eq!(story_repo.delete_expired(now()).await.unwrap(), 0);
+
+    override_now(Some((Utc::now() + Duration::hours(25)).fixed_offset()));
+
+    let (access_token, _) = user_logs_in(&app, "testusername", "password1_").await;
+    assert!(user_gets_stories(&app, &access_token).await.is_empty());
+    assert_eq!(story_repo.delete_expired(now()).await.unwrap(), 1);
+    assert!(story_repo.get_by_id(story.id).await.unwrap().is_none());
+}
+
+pub async fn user_reports_a_story(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    story_id: Uuid,
+) -> u16 {
+    let req = test::TestRequest::post()
+        .uri("/api/stories/reports/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(StoryReportCreateRequest {
+            story_id,
+            reason: "Spam".to_string(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status == 200 {
+        let body = test::read_body(response).await;
+        let response: StoryReportResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.code, "STORY_REPORT_CREATED");
+        assert_eq!(response.story_report.unwrap().story_id, Some(story_id));
+    }
+
+    status
+}
+
+pub async fn user_resolves_story_reports(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    story_id: Uuid,
+    request: ResolvePublicMessageReportsRequest,
+) -> (u16, Option<StoryData>) {
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/api/moderation-queue/stories/{}/resolve",
+            story_id
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(request)
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status != 200 {
+        return (status, None);
+    }
+
+    let body = test::read_body(response).await;
+    let response: StoryModerationDecisionResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "MODERATION_DECISION_CREATED");
+    assert_eq!(response.decision.unwrap().story_id, Some(story_id));
+    (status, response.story)
+}
+
+#[sqlx::test]
+async fn reported_stories_go_through_the_moderation_queue(pool: PgPool) {
+    let story_repo = StoryRepositoryImpl::new(pool.clone());
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+    user_creates_a_challenge_participation(&app, &access_token, challenge_id).await;
+    let story = user_creates_a_story(&app, &access_token, challenge_id).await;
+
+    let (other_access_token, _) = user_signs_up(&app, Some("testusername2")).await;
+    user_creates_a_challenge_participation(&app, &other_access_token, challenge_id).await;
+
+    assert_eq!(
+        user_reports_a_story(&app, &other_access_token, story.id).await,
+        200
+    );
+    assert_eq!(
+        user_reports_a_story(&app, &other_access_token, Uuid::new_v4()).await,
+        404
+    );
+
+    // Users outside the challenge cannot see the story, so they cannot report it either
+    let (outsider_access_token, _) = user_signs_up(&app, Some("testusername3")).await;
+    assert_eq!(
+        user_reports_a_story(&app, &outsider_access_token, story.id).await,
+        404
+    );
+
+    // Reported stories outlive their expiry until a moderator looked at them
+    override_now(Some((Utc::now() + Duration::hours(25)).fixed_offset()));
+    assert_eq!(story_repo.delete_expired(now()).await.unwrap(), 0);
+
+    let (other_access_token, _) = user_logs_in(&app, "testusername2", "password1_").await;
+    let req = test::TestRequest::get()
+        .uri("/api/moderation-queue/")
+        .insert_header((
+            header::AUTHORIZATION,
+            format!("Bearer {}", other_access_token),
+        ))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(403, response.status().as_u16());
+
+    let (admin_access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let queue = user_gets_the_moderation_queue(&app, &admin_access_token).await;
+    assert_eq!(queue.len(), 1);
+    assert!(queue[0].message.is_none());
+    assert_eq!(queue[0].story.as_ref().unwrap().id, story.id);
+    assert_eq!(queue[0].reporter_count, 1);
+
+    let (status, resolved_story) = user_resolves_story_reports(
+        &app,
+        &admin_access_token,
+        story.id,
+        ResolvePublicMessageReportsRequest {
+            action: ModerationAction::Delete,
+            note: None,
+            suspension_in_days: None,
+        },
+    )
+    .await;
+    assert_eq!(status, 200);
+    let resolved_story = resolved_story.unwrap();
+    assert!(resolved_story.hidden_by_moderation);
+    assert!(resolved_story.content.is_empty());
+
+    assert!(user_gets_the_moderation_queue(&app, &admin_access_token)
+        .await
+        .is_empty());
+    let (status, _) = user_resolves_story_reports(
+        &app,
+        &admin_access_token,
+        story.id,
+        ResolvePublicMessageReportsRequest {
+            action: ModerationAction::Dismiss,
+            note: None,
+            suspension_in_days: None,
+        },
+    )
+    .await;
+    assert_eq!(status, 404);
+
+    // Once resolved, the story goes away with the next purge
+    assert_eq!(story_repo.delete_expired(now()).await.unwrap(), 1);
+    assert!(story_repo.get_by_id(story.id).await.unwrap().is_none());
+}
+
+#[sqlx::test]
+async fn story_is_hidden_once_enough_users_reported_it(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+    user_creates_a_challenge_participation(&app, &access_token, challenge_id).await;
+    let story = user_creates_a_story(&app, &access_token, challenge_id).await;
+
+    let (viewer_token, _) = user_signs_up(&app, Some("viewer")).await;
+    user_creates_a_challenge_participation(&app, &viewer_token, challenge_id).await;
+
+    for i in 0..3 {
+        let (reporter_token, _) = user_signs_up(&app, Some(&format!("reporter{}", i))).await;
+        user_creates_a_challenge_participation(&app, &reporter_token, challenge_id).await;
+        user_reports_a_story(&app, &reporter_token, story.id).await;
+    }
+
+    // Only its creator still sees it
+    assert!(user_gets_stories(&app, &viewer_token).await.is_empty());
+    let stories = user_gets_stories(&app, &access_token).await;
+    assert!(stories[0].hidden_by_moderation);
+
+    // Moderators can take the story down
+    let (admin_access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/stories/{}", story.id))
+        .insert_header((
+            header::AUTHORIZATION,
+            format!("Bearer {}", admin_access_token),
+        ))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+    assert!(user_gets_stories(&app, &access_token).await.is_empty());
+}
@@ -0,0 +1,300 @@
+use actix_http::{header, Request};
+use actix_web::{
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::ContentType,
+    test, Error,
+};
+use api::{
+    core::structs::responses::GenericResponse,
+    features::{
+        private_discussions::application::dto::requests::private_discussion::PrivateDiscussionCreateRequest,
+        public_discussions::application::dto::requests::public_message_like::PublicMessageLikeCreateRequest,
+        user_blocks::{
+            application::dto::{
+                requests::user_block::UserBlockCreateRequest,
+                responses::user_block::{UserBlockResponse, UserBlocksResponse},
+            },
+            domain::entities::user_block::UserBlockData,
+        },
+    },
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::{login::user_logs_in, signup::user_signs_up},
+    challenges::{
+        challenge::user_creates_a_challenge,
+        challenge_participation::user_creates_a_challenge_participation,
+    },
+    helpers::spawn_app,
+    notifications::notifications::user_gets_notifications,
+    profile::profile::user_has_access_to_protected_route,
+    public_discussions::public_message::{
+        user_creates_a_public_message, user_gets_a_page_of_public_messages,
+        user_gets_public_messages, user_gets_replies,
+    },
+};
+
+pub async fn user_blocks_a_user(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    user_id: Uuid,
+) -> u16 {
+    let req = test::TestRequest::post()
+        .uri("/api/user-blocks/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(UserBlockCreateRequest { user_id })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status == 200 {
+        let body = test::read_body(response).await;
+        let response: UserBlockResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.code, "USER_BLOCKED");
+        assert!(response.user_block.is_some());
+    }
+
+    status
+}
+
+pub async fn user_unblocks_a_user(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+    user_id: Uuid,
+) -> u16 {
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/user-blocks/{}", user_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+    let status = response.status().as_u16();
+
+    if status == 200 {
+        let body = test::read_body(response).await;
+        let response: UserBlockResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.code, "USER_UNBLOCKED");
+    }
+
+    status
+}
+
+pub async fn user_gets_user_blocks(
+    app: impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = Error>,
+    access_token: &str,
+) -> Vec<UserBlockData> {
+    let req = test::TestRequest::get()
+        .uri("/api/user-blocks/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: UserBlocksResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "USER_BLOCKS_FETCHED");
+    response.user_blocks
+}
+
+#[sqlx::test]
+pub async fn user_can_block_and_unblock_a_user(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &access_token)
+        .await
+        .id;
+
+    let (access_token, _) = user_signs_up(&app, None).await;
+    let user_id = user_has_access_to_protected_route(&app, &access_token)
+        .await
+        .id;
+
+    assert_eq!(user_blocks_a_user(&app, &access_token, user_id).await, 400);
+    assert_eq!(
+        user_blocks_a_user(&app, &access_token, Uuid::new_v4()).await,
+        404
+    );
+
+    assert_eq!(
+        user_blocks_a_user(&app, &access_token, thomas_id).await,
+        200
+    );
+    // Blocking twice is harmless
+    assert_eq!(
+        user_blocks_a_user(&app, &access_token, thomas_id).await,
+        200
+    );
+
+    let user_blocks = user_gets_user_blocks(&app, &access_token).await;
+    assert_eq!(user_blocks.len(), 1);
+    assert_eq!(user_blocks[0].blocked, thomas_id);
+
+    assert_eq!(
+        user_unblocks_a_user(&app, &access_token, thomas_id).await,
+        200
+    );
+    assert_eq!(
+        user_unblocks_a_user(&app, &access_token, thomas_id).await,
+        404
+    );
+
+    let user_blocks = user_gets_user_blocks(&app, &access_token).await;
+    assert!(user_blocks.is_empty());
+}
+
+#[sqlx::test]
+pub async fn content_from_blocked_users_is_hidden(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &access_token)
+        .await
+        .id;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+    let message_id = user_creates_a_public_message(
+        &app,
+        &access_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Hello".to_string(),
+    )
+    .await;
+    user_creates_a_public_message(
+        &app,
+        &access_token,
+        Some(challenge_id),
+        None,
+        Some(message_id),
+        Some(message_id),
+        "Anyone there?".to_string(),
+    )
+    .await;
+
+    let (access_token, _) = user_signs_up(&app, None).await;
+
+    let messages = user_gets_public_messages(&app, &access_token, Some(challenge_id), None).await;
+    assert_eq!(messages.len(), 1);
+    let replies = user_gets_replies(&app, &access_token, message_id).await;
+    assert_eq!(replies.len(), 1);
+
+    user_blocks_a_user(&app, &access_token, thomas_id).await;
+
+    let messages = user_gets_public_messages(&app, &access_token, Some(challenge_id), None).await;
+    assert!(messages.is_empty());
+    let replies = user_gets_replies(&app, &access_token, message_id).await;
+    assert!(replies.is_empty());
+
+    let req = test::TestRequest::post()
+        .uri("/api/public-message-likes/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", access_token)))
+        .insert_header(ContentType::json())
+        .set_json(PublicMessageLikeCreateRequest { message_id })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(403, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "USER_BLOCKED");
+}
+
+#[sqlx::test]
+pub async fn blocked_users_do_not_shorten_a_page_of_public_messages(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &access_token)
+        .await
+        .id;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+
+    let (user_access_token, _) = user_signs_up(&app, None).await;
+    user_creates_a_challenge_participation(&app, &user_access_token, challenge_id).await;
+    let message_id = user_creates_a_public_message(
+        &app,
+        &user_access_token,
+        Some(challenge_id),
+        None,
+        None,
+        None,
+        "Mine".to_string(),
+    )
+    .await;
+
+    for content in ["Newer", "Newest"] {
+        user_creates_a_public_message(
+            &app,
+            &access_token,
+            Some(challenge_id),
+            None,
+            None,
+            None,
+            content.to_string(),
+        )
+        .await;
+    }
+
+    user_blocks_a_user(&app, &user_access_token, thomas_id).await;
+
+    let (_, page) =
+        user_gets_a_page_of_public_messages(&app, &user_access_token, challenge_id, "limit=1")
+            .await;
+    let page = page.unwrap();
+    assert_eq!(
+        page.messages.iter().map(|m| m.id).collect::<Vec<_>>(),
+        vec![message_id]
+    );
+    assert!(page.next_cursor.is_none());
+}
+
+#[sqlx::test]
+pub async fn blocked_user_can_not_reach_the_blocker(pool: PgPool) {
+    let app = spawn_app(pool).await;
+    let (access_token, _) = user_logs_in(&app, "thomas", "").await;
+    let thomas_id = user_has_access_to_protected_route(&app, &access_token)
+        .await
+        .id;
+    let challenge_id = user_creates_a_challenge(&app, &access_token).await;
+
+    let (user_access_token, _) = user_signs_up(&app, None).await;
+    let user_id = user_has_access_to_protected_route(&app, &user_access_token)
+        .await
+        .id;
+
+    user_blocks_a_user(&app, &access_token, user_id).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/private-discussions/")
+        .insert_header((
+            header::AUTHORIZATION,
+            format!("Bearer {}", user_access_token),
+        ))
+        .insert_header(ContentType::json())
+        .set_json(PrivateDiscussionCreateRequest {
+            recipient: thomas_id,
+            color: "blue".to_string(),
+        })
+        .to_request();
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(403, response.status().as_u16());
+
+    let body = test::read_body(response).await;
+    let response: GenericResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response.code, "USER_BLOCKED");
+
+    user_creates_a_challenge_participation(&app, &user_access_token, challenge_id).await;
+
+    let notifications = user_gets_notifications(&app, &access_token).await;
+    assert!(notifications.is_empty());
+}
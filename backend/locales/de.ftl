@@ -6,7 +6,35 @@ message-created-title = Neue Nachricht
 message-created-body = { $username } hat dir gerade eine Nachricht geschickt.
 user-liked-your-message-title = Jemand hat deine Nachricht gemocht
 user-liked-your-message-body = { $username } hat gerade deine Nachricht gemocht.
+user-reacted-to-your-message-title = Neue Reaktion
+user-reacted-to-your-message-body = { $username } hat mit { $reaction } auf deine Nachricht reagiert.
 user-replied-to-your-message-title = Jemand hat auf deine Nachricht geantwortet
 user-replied-to-your-message-body = { $username } hat gerade auf deine Nachricht geantwortet.
+user-mentioned-you-title = Jemand hat dich erwähnt
+user-mentioned-you-body = { $username } hat dich in einer Nachricht erwähnt.
+new-replies-in-followed-thread-title = Neue Antworten
+new-replies-in-followed-thread-body = { $count ->
+        [one] Eine neue Antwort
+       *[other] { $count } neue Antworten
+    } in einem Thread, dem du folgst.
+new-messages-in-followed-discussion-title = Neue Nachrichten
+new-messages-in-followed-discussion-body = { $count ->
+        [one] Eine neue Nachricht
+       *[other] { $count } neue Nachrichten
+    } in einer Diskussion, der du folgst.
+held-private-messages-title = Nachrichten von { $username }
+held-private-messages-body = { $count ->
+        [one] Eine Nachricht
+       *[other] { $count } Nachrichten
+    } von { $username } warteten auf deine Antwort auf die Nachrichtenanfrage.
+challenge-announcement-title = Neue Ankündigung
+challenge-announcement-body = { $username } hat eine Ankündigung in einer Challenge veröffentlicht, an der du teilnimmst.
+report-resolved-title = Deine Meldung wurde geprüft
+report-resolved-dismissed-body = Ein Moderator hat die gemeldete Nachricht geprüft und keinen Regelverstoß festgestellt.
+report-resolved-action-taken-body = Ein Moderator hat die gemeldete Nachricht geprüft und Maßnahmen ergriffen. Danke, dass du ReallyStick sicher hältst.
+moderation-warning-title = Verwarnung durch die Moderatoren
+moderation-warning-body = Eine deiner Nachrichten wurde moderiert: { $note }
+moderation-suspension-title = Dein Konto wurde gesperrt
+moderation-suspension-body = Du kannst { $days } Tage lang nicht in öffentlichen Diskussionen schreiben: { $note }
 reminder-title = Erinnerung
 welcome-private-message = Willkommen bei ReallyStick { $username }!\n\nDu kannst alle deine Anmerkungen in diesem Gespräch schreiben. Unser Team wird dir so schnell wie möglich antworten.\n\n🫶
\ No newline at end of file
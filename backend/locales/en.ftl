@@ -6,7 +6,35 @@ message-created-title = New message
 message-created-body = { $username } just sent you a message.
 user-liked-your-message-title = New like
 user-liked-your-message-body = { $username } just liked your message.
+user-reacted-to-your-message-title = New reaction
+user-reacted-to-your-message-body = { $username } reacted { $reaction } to your message.
 user-replied-to-your-message-title = New reply
 user-replied-to-your-message-body = { $username } just replied to your message.
+user-mentioned-you-title = New mention
+user-mentioned-you-body = { $username } mentioned you in a message.
+new-replies-in-followed-thread-title = New replies
+new-replies-in-followed-thread-body = { $count ->
+        [one] One new reply
+       *[other] { $count } new replies
+    } in a thread you follow.
+new-messages-in-followed-discussion-title = New messages
+new-messages-in-followed-discussion-body = { $count ->
+        [one] One new message
+       *[other] { $count } new messages
+    } in a discussion you follow.
+held-private-messages-title = Messages from { $username }
+held-private-messages-body = { $count ->
+        [one] One message
+       *[other] { $count } messages
+    } from { $username } waited for your answer to their message request.
+challenge-announcement-title = New announcement
+challenge-announcement-body = { $username } posted an announcement in a challenge you take part in.
+report-resolved-title = Your report was reviewed
+report-resolved-dismissed-body = A moderator reviewed the message you reported and found it does not break the rules.
+report-resolved-action-taken-body = A moderator reviewed the message you reported and took action. Thank you for helping keep ReallyStick safe.
+moderation-warning-title = Warning from the moderators
+moderation-warning-body = One of your messages was moderated: { $note }
+moderation-suspension-title = Your account was suspended
+moderation-suspension-body = You can not write in public discussions for { $days } days: { $note }
 reminder-title = Reminder
 welcome-private-message = Welcome on ReallyStick { $username }!\n\nYou can write all your feedbacks in here. Our teams will answer you as soon as possible.\n\n🫶
\ No newline at end of file
@@ -6,7 +6,35 @@ message-created-title = Nuevo mensaje
 message-created-body = { $username } acaba de enviarte un mensaje.
 user-liked-your-message-title = Alguien le dio like a tu mensaje
 user-liked-your-message-body = { $username } acaba de darle like a tu mensaje.
+user-reacted-to-your-message-title = Nueva reacción
+user-reacted-to-your-message-body = { $username } reaccionó con { $reaction } a tu mensaje.
 user-replied-to-your-message-title = Alguien respondió a tu mensaje
 user-replied-to-your-message-body = { $username } acaba de responder a tu mensaje.
+user-mentioned-you-title = Alguien te mencionó
+user-mentioned-you-body = { $username } te mencionó en un mensaje.
+new-replies-in-followed-thread-title = Nuevas respuestas
+new-replies-in-followed-thread-body = { $count ->
+        [one] Una nueva respuesta
+       *[other] { $count } nuevas respuestas
+    } en un hilo que sigues.
+new-messages-in-followed-discussion-title = Nuevos mensajes
+new-messages-in-followed-discussion-body = { $count ->
+        [one] Un nuevo mensaje
+       *[other] { $count } nuevos mensajes
+    } en una discusión que sigues.
+held-private-messages-title = Mensajes de { $username }
+held-private-messages-body = { $count ->
+        [one] Un mensaje
+       *[other] { $count } mensajes
+    } de { $username } esperaban tu respuesta a su solicitud de mensaje.
+challenge-announcement-title = Nuevo anuncio
+challenge-announcement-body = { $username } publicó un anuncio en un reto en el que participas.
+report-resolved-title = Tu denuncia fue revisada
+report-resolved-dismissed-body = Un moderador revisó el mensaje que denunciaste y considera que no infringe las normas.
+report-resolved-action-taken-body = Un moderador revisó el mensaje que denunciaste y tomó medidas. Gracias por ayudar a mantener ReallyStick seguro.
+moderation-warning-title = Advertencia de los moderadores
+moderation-warning-body = Uno de tus mensajes fue moderado: { $note }
+moderation-suspension-title = Tu cuenta fue suspendida
+moderation-suspension-body = No puedes escribir en las discusiones públicas durante { $days } días: { $note }
 reminder-title = Recordatorio
 welcome-private-message = ¡Bienvenido a ReallyStick { $username }! \n\nPuedes escribir todas tus observaciones en esta conversación. Nuestro equipo te responderá lo más rápido posible. \n\n🫶
\ No newline at end of file
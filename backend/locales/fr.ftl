@@ -6,7 +6,35 @@ message-created-title = Nouveau message
 message-created-body = { $username } vient juste de vous envoyer un message.
 user-liked-your-message-title = Quelqu'un a aimé votre message
 user-liked-your-message-body = { $username } vient juste d'aimer votre message.
+user-reacted-to-your-message-title = Nouvelle réaction
+user-reacted-to-your-message-body = { $username } a réagi { $reaction } à votre message.
 user-replied-to-your-message-title = Quelqu'un a répondu à votre message
 user-replied-to-your-message-body = { $username } vient juste de répondre à votre message.
+user-mentioned-you-title = Quelqu'un vous a mentionné
+user-mentioned-you-body = { $username } vous a mentionné dans un message.
+new-replies-in-followed-thread-title = Nouvelles réponses
+new-replies-in-followed-thread-body = { $count ->
+        [one] Une nouvelle réponse
+       *[other] { $count } nouvelles réponses
+    } dans un fil que vous suivez.
+new-messages-in-followed-discussion-title = Nouveaux messages
+new-messages-in-followed-discussion-body = { $count ->
+        [one] Un nouveau message
+       *[other] { $count } nouveaux messages
+    } dans une discussion que vous suivez.
+held-private-messages-title = Messages de { $username }
+held-private-messages-body = { $count ->
+        [one] Un message
+       *[other] { $count } messages
+    } de { $username } attendaient votre réponse à sa demande de message.
+challenge-announcement-title = Nouvelle annonce
+challenge-announcement-body = { $username } a publié une annonce dans un défi auquel vous participez.
+report-resolved-title = Votre signalement a été examiné
+report-resolved-dismissed-body = Un modérateur a examiné le message que vous avez signalé et estime qu'il respecte les règles.
+report-resolved-action-taken-body = Un modérateur a examiné le message que vous avez signalé et a pris des mesures. Merci de contribuer à la sécurité de ReallyStick.
+moderation-warning-title = Avertissement des modérateurs
+moderation-warning-body = Un de vos messages a été modéré : { $note }
+moderation-suspension-title = Votre compte a été suspendu
+moderation-suspension-body = Vous ne pouvez plus écrire dans les discussions publiques pendant { $days } jours : { $note }
 reminder-title = Rappel
 welcome-private-message = Bienvenue sur ReallyStick { $username } !\n\nVous pouvez écrire toutes vos remarques dans cette discussion. Nos équipes vous répondront le plus vite possible.\n\n🫶
\ No newline at end of file
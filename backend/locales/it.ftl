@@ -6,7 +6,35 @@ message-created-title = Nuovo messaggio
 message-created-body = { $username } ti ha appena inviato un messaggio.
 user-liked-your-message-title = Qualcuno ha messo mi piace al tuo messaggio
 user-liked-your-message-body = { $username } ha appena messo mi piace al tuo messaggio.
+user-reacted-to-your-message-title = Nuova reazione
+user-reacted-to-your-message-body = { $username } ha reagito con { $reaction } al tuo messaggio.
 user-replied-to-your-message-title = Qualcuno ha risposto al tuo messaggio
 user-replied-to-your-message-body = { $username } ha appena risposto al tuo messaggio.
+user-mentioned-you-title = Qualcuno ti ha menzionato
+user-mentioned-you-body = { $username } ti ha menzionato in un messaggio.
+new-replies-in-followed-thread-title = Nuove risposte
+new-replies-in-followed-thread-body = { $count ->
+        [one] Una nuova risposta
+       *[other] { $count } nuove risposte
+    } in una discussione che segui.
+new-messages-in-followed-discussion-title = Nuovi messaggi
+new-messages-in-followed-discussion-body = { $count ->
+        [one] Un nuovo messaggio
+       *[other] { $count } nuovi messaggi
+    } in una conversazione che segui.
+held-private-messages-title = Messaggi da { $username }
+held-private-messages-body = { $count ->
+        [one] Un messaggio
+       *[other] { $count } messaggi
+    } da { $username } attendevano la tua risposta alla richiesta di messaggio.
+challenge-announcement-title = Nuovo annuncio
+challenge-announcement-body = { $username } ha pubblicato un annuncio in una sfida a cui partecipi.
+report-resolved-title = La tua segnalazione è stata esaminata
+report-resolved-dismissed-body = Un moderatore ha esaminato il messaggio che hai segnalato e ritiene che rispetti le regole.
+report-resolved-action-taken-body = Un moderatore ha esaminato il messaggio che hai segnalato e ha preso provvedimenti. Grazie per aiutarci a mantenere ReallyStick sicuro.
+moderation-warning-title = Avvertimento dai moderatori
+moderation-warning-body = Uno dei tuoi messaggi è stato moderato: { $note }
+moderation-suspension-title = Il tuo account è stato sospeso
+moderation-suspension-body = Non puoi scrivere nelle discussioni pubbliche per { $days } giorni: { $note }
 reminder-title = Promemoria
 welcome-private-message = Benvenuto su ReallyStick { $username } !\n\nPuoi scrivere tutte le tue osservazioni in questa discussione. Il nostro team ti risponderà il prima possibile.\n\n🫶
\ No newline at end of file
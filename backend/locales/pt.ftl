@@ -6,7 +6,35 @@ message-created-title = Nova mensagem
 message-created-body = { $username } acaba de te enviar uma mensagem.
 user-liked-your-message-title = Alguém curtiu sua mensagem
 user-liked-your-message-body = { $username } acabou de curtir sua mensagem.
+user-reacted-to-your-message-title = Nova reação
+user-reacted-to-your-message-body = { $username } reagiu com { $reaction } à sua mensagem.
 user-replied-to-your-message-title = Alguém respondeu à sua mensagem
 user-replied-to-your-message-body = { $username } acabou de responder à sua mensagem.
+user-mentioned-you-title = Alguém mencionou você
+user-mentioned-you-body = { $username } mencionou você numa mensagem.
+new-replies-in-followed-thread-title = Novas respostas
+new-replies-in-followed-thread-body = { $count ->
+        [one] Uma nova resposta
+       *[other] { $count } novas respostas
+    } num tópico que segue.
+new-messages-in-followed-discussion-title = Novas mensagens
+new-messages-in-followed-discussion-body = { $count ->
+        [one] Uma nova mensagem
+       *[other] { $count } novas mensagens
+    } numa discussão que segue.
+held-private-messages-title = Mensagens de { $username }
+held-private-messages-body = { $count ->
+        [one] Uma mensagem
+       *[other] { $count } mensagens
+    } de { $username } aguardavam a sua resposta ao pedido de mensagem.
+challenge-announcement-title = Novo anúncio
+challenge-announcement-body = { $username } publicou um anúncio num desafio em que participa.
+report-resolved-title = A sua denúncia foi analisada
+report-resolved-dismissed-body = Um moderador analisou a mensagem que denunciou e considera que ela não viola as regras.
+report-resolved-action-taken-body = Um moderador analisou a mensagem que denunciou e tomou medidas. Obrigado por ajudar a manter o ReallyStick seguro.
+moderation-warning-title = Aviso dos moderadores
+moderation-warning-body = Uma das suas mensagens foi moderada: { $note }
+moderation-suspension-title = A sua conta foi suspensa
+moderation-suspension-body = Não pode escrever nas discussões públicas durante { $days } dias: { $note }
 reminder-title = Lembrete
 welcome-private-message = Bem-vindo ao ReallyStick { $username }!\n\nVocê pode escrever todos os seus comentários nesta conversa. Nossas equipes responderão o mais rápido possível.\n\n🫶
\ No newline at end of file
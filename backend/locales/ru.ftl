@@ -6,7 +6,38 @@ message-created-title = Новое сообщение
 message-created-body = { $username } только что отправил вам сообщение.
 user-liked-your-message-title = Кто-то оценил ваше сообщение
 user-liked-your-message-body = { $username } только что поставил лайк вашему сообщению.
+user-reacted-to-your-message-title = Новая реакция
+user-reacted-to-your-message-body = { $username } отреагировал(а) { $reaction } на ваше сообщение.
 user-replied-to-your-message-title = Кто-то ответил на ваше сообщение
 user-replied-to-your-message-body = { $username } только что ответил на ваше сообщение.
+user-mentioned-you-title = Вас упомянули
+user-mentioned-you-body = { $username } упомянул вас в сообщении.
+new-replies-in-followed-thread-title = Новые ответы
+new-replies-in-followed-thread-body = { $count ->
+        [one] { $count } новый ответ
+        [few] { $count } новых ответа
+       *[other] { $count } новых ответов
+    } в ветке, за которой вы следите.
+new-messages-in-followed-discussion-title = Новые сообщения
+new-messages-in-followed-discussion-body = { $count ->
+        [one] { $count } новое сообщение
+        [few] { $count } новых сообщения
+       *[other] { $count } новых сообщений
+    } в обсуждении, за которым вы следите.
+held-private-messages-title = Сообщения от { $username }
+held-private-messages-body = { $count ->
+        [one] { $count } сообщение
+        [few] { $count } сообщения
+       *[other] { $count } сообщений
+    } от { $username } ожидали ответа на запрос на переписку.
+challenge-announcement-title = Новое объявление
+challenge-announcement-body = { $username } опубликовал объявление в челлендже, в котором вы участвуете.
+report-resolved-title = Ваша жалоба рассмотрена
+report-resolved-dismissed-body = Модератор проверил сообщение, на которое вы пожаловались, и не нашёл нарушений правил.
+report-resolved-action-taken-body = Модератор проверил сообщение, на которое вы пожаловались, и принял меры. Спасибо, что помогаете сделать ReallyStick безопаснее.
+moderation-warning-title = Предупреждение от модераторов
+moderation-warning-body = Одно из ваших сообщений было отмодерировано: { $note }
+moderation-suspension-title = Ваш аккаунт заблокирован
+moderation-suspension-body = Вы не можете писать в публичных обсуждениях { $days } дн.: { $note }
 reminder-title = Напоминание
 welcome-private-message = Добро пожаловать на ReallyStick, { $username }!\n\nВы можете оставлять все свои замечания в этом чате. Наша команда ответит как можно скорее.\n\n🫶
\ No newline at end of file
@@ -0,0 +1,27 @@
+-- Add migration script here
+
+CREATE TABLE roles (
+    id UUID PRIMARY KEY,
+    name VARCHAR(50) NOT NULL UNIQUE,
+    permissions TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE user_role_grants (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role_id UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    granted_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ,
+    UNIQUE (user_id, role_id)
+);
+
+CREATE INDEX idx_user_role_grants_user_id ON user_role_grants(user_id);
+
+-- Roles that can be earned by trusted users. Users with is_admin keep every permission.
+INSERT INTO roles (id, name, permissions) VALUES
+    (gen_random_uuid(), 'moderator', ARRAY['moderate_public_messages', 'block_reported_users']),
+    (gen_random_uuid(), 'habit_editor', ARRAY['manage_habits']),
+    (gen_random_uuid(), 'challenge_editor', ARRAY['manage_challenges']),
+    (gen_random_uuid(), 'role_manager', ARRAY['manage_roles']);
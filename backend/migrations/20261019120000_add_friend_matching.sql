@@ -0,0 +1,12 @@
+-- Add migration script here
+
+ALTER TABLE users ADD COLUMN open_to_meeting_people BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE users ADD COLUMN matching_uses_demographics BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE friend_match_searches (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_friend_match_searches_user_id ON friend_match_searches(user_id, created_at);
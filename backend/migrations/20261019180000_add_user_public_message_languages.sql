@@ -0,0 +1,5 @@
+-- Add migration script here
+ALTER TABLE users
+ADD COLUMN public_message_languages TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX IF NOT EXISTS idx_public_messages_language_code ON public_messages(language_code);
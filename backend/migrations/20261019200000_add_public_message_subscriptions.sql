@@ -0,0 +1,35 @@
+-- Add migration script here
+
+-- A subscription follows either a whole habit/challenge channel (thread_id is NULL)
+-- or a single thread of that channel
+CREATE TABLE public_message_subscriptions (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    habit_id UUID REFERENCES habits(id) ON DELETE CASCADE,
+    challenge_id UUID REFERENCES challenges(id) ON DELETE CASCADE,
+    thread_id UUID,
+    muted BOOLEAN NOT NULL DEFAULT FALSE,
+    pending_count INT NOT NULL DEFAULT 0,
+    last_notified_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (num_nonnulls(habit_id, challenge_id) = 1)
+);
+
+CREATE UNIQUE INDEX idx_public_message_subscriptions_thread
+ON public_message_subscriptions(user_id, thread_id)
+WHERE thread_id IS NOT NULL;
+
+CREATE UNIQUE INDEX idx_public_message_subscriptions_habit
+ON public_message_subscriptions(user_id, habit_id)
+WHERE thread_id IS NULL AND habit_id IS NOT NULL;
+
+CREATE UNIQUE INDEX idx_public_message_subscriptions_challenge
+ON public_message_subscriptions(user_id, challenge_id)
+WHERE thread_id IS NULL AND challenge_id IS NOT NULL;
+
+CREATE INDEX idx_public_message_subscriptions_thread_id ON public_message_subscriptions(thread_id);
+CREATE INDEX idx_public_message_subscriptions_habit_id ON public_message_subscriptions(habit_id);
+CREATE INDEX idx_public_message_subscriptions_challenge_id ON public_message_subscriptions(challenge_id);
+CREATE INDEX idx_public_message_subscriptions_pending
+ON public_message_subscriptions(last_notified_at)
+WHERE pending_count > 0;
@@ -0,0 +1,11 @@
+-- Add migration script here
+
+ALTER TABLE public_messages
+ADD COLUMN pinned_at TIMESTAMPTZ,
+ADD COLUMN pinned_by UUID REFERENCES users(id) ON DELETE SET NULL,
+ADD COLUMN is_announcement BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_public_messages_pinned_habit_id ON public_messages(habit_id, pinned_at)
+WHERE pinned_at IS NOT NULL;
+CREATE INDEX idx_public_messages_pinned_challenge_id ON public_messages(challenge_id, pinned_at)
+WHERE pinned_at IS NOT NULL;
@@ -0,0 +1,36 @@
+-- Add migration script here
+
+CREATE TABLE public_message_reactions (
+    id UUID PRIMARY KEY,
+    message_id UUID NOT NULL REFERENCES public_messages(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reaction VARCHAR(20) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (message_id, user_id, reaction)
+);
+
+CREATE INDEX idx_public_message_reactions_user_id ON public_message_reactions(user_id, reaction);
+
+-- Existing likes become "like" reactions
+INSERT INTO public_message_reactions (id, message_id, user_id, reaction, created_at)
+SELECT id, message_id, user_id, 'like', created_at
+FROM public_message_likes;
+
+DROP TABLE public_message_likes;
+
+-- Per reaction counters, like_count stays the count of "like" reactions
+ALTER TABLE public_messages ADD COLUMN reaction_counts JSONB NOT NULL DEFAULT '{}'::JSONB;
+
+UPDATE public_messages pm
+SET reaction_counts = counts.reaction_counts,
+    like_count = COALESCE((counts.reaction_counts->>'like')::INT, 0)
+FROM (
+    SELECT message_id, jsonb_object_agg(reaction, count) AS reaction_counts
+    FROM (
+        SELECT message_id, reaction, COUNT(*)::INT AS count
+        FROM public_message_reactions
+        GROUP BY message_id, reaction
+    ) grouped
+    GROUP BY message_id
+) counts
+WHERE pm.id = counts.message_id;
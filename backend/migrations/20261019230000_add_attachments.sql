@@ -0,0 +1,20 @@
+-- Add migration script here
+
+CREATE TABLE attachments (
+    id UUID PRIMARY KEY,
+    uploader UUID REFERENCES users(id) ON DELETE SET NULL,
+    -- Uploads are linked to a message when it is created
+    public_message_id UUID REFERENCES public_messages(id) ON DELETE CASCADE,
+    content_type VARCHAR(50) NOT NULL,
+    size_in_bytes BIGINT NOT NULL,
+    width INT NOT NULL,
+    height INT NOT NULL,
+    storage_key TEXT NOT NULL,
+    thumbnail_storage_key TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_attachments_public_message_id ON attachments(public_message_id);
+CREATE INDEX idx_attachments_unused ON attachments(created_at) WHERE public_message_id IS NULL;
+
+ALTER TABLE public_messages ADD COLUMN attachment_ids UUID[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,17 @@
+-- Add migration script here
+
+-- Optional key pair of a device, it follows the device when its token is refreshed and goes
+-- away with it when the device logs out or is revoked
+CREATE TABLE device_keys (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_id UUID NOT NULL UNIQUE REFERENCES user_tokens(token_id) ON DELETE CASCADE,
+    public_key TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_device_keys_user_id ON device_keys(user_id);
+
+-- Session keys of private messages, encrypted with the public key of each device
+ALTER TABLE private_messages
+    ADD COLUMN device_encrypted_session_keys JSONB NOT NULL DEFAULT '{}';
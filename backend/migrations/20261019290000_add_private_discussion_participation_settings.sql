@@ -0,0 +1,8 @@
+-- Add migration script here
+
+-- Per-member settings of a discussion, none of them is visible to the other members
+ALTER TABLE private_discussion_participations
+    ADD COLUMN muted_until TIMESTAMPTZ,
+    -- Archived discussions come back as soon as a newer message is sent
+    ADD COLUMN archived_at TIMESTAMPTZ,
+    ADD COLUMN pinned_at TIMESTAMPTZ;
@@ -0,0 +1,33 @@
+-- Add migration script here
+
+CREATE TABLE private_discussion_reports (
+    id UUID PRIMARY KEY,
+    discussion_id UUID NOT NULL REFERENCES private_discussions(id) ON DELETE CASCADE,
+    reporter UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reported_user UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ,
+    moderator UUID REFERENCES users(id) ON DELETE SET NULL,
+    action TEXT,
+    note TEXT
+);
+
+CREATE INDEX idx_private_discussion_reports_pending ON private_discussion_reports(created_at)
+WHERE resolved_at IS NULL;
+
+-- Messages the reporter chose to disclose, kept in clear once they matched the stored ciphertext
+CREATE TABLE private_discussion_report_messages (
+    id UUID PRIMARY KEY,
+    report_id UUID NOT NULL REFERENCES private_discussion_reports(id) ON DELETE CASCADE,
+    message_id UUID REFERENCES private_messages(id) ON DELETE SET NULL,
+    creator UUID REFERENCES users(id) ON DELETE SET NULL,
+    content TEXT NOT NULL,
+    message_created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_private_discussion_report_messages_report_id ON private_discussion_report_messages(report_id);
+
+UPDATE roles
+SET permissions = array_append(permissions, 'review_private_reports')
+WHERE name = 'moderator';
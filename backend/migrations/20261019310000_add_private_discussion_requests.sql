@@ -0,0 +1,7 @@
+-- Add migration script here
+ALTER TABLE private_discussions
+ADD COLUMN request_status TEXT NOT NULL DEFAULT 'accepted',
+ADD COLUMN requested_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_private_discussions_requested_by ON private_discussions(requested_by, created_at)
+WHERE requested_by IS NOT NULL;
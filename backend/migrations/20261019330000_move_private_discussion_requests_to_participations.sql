@@ -0,0 +1,23 @@
+-- Add migration script here
+ALTER TABLE private_discussion_participations
+ADD COLUMN request_status TEXT NOT NULL DEFAULT 'accepted',
+ADD COLUMN requested_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+-- The recipient of a request is the participant who did not send it
+UPDATE private_discussion_participations p
+SET request_status = d.request_status,
+    requested_by = d.requested_by
+FROM private_discussions d
+WHERE p.discussion_id = d.id
+AND d.requested_by IS NOT NULL
+AND p.user_id <> d.requested_by;
+
+DROP INDEX idx_private_discussions_requested_by;
+
+ALTER TABLE private_discussions
+DROP COLUMN request_status,
+DROP COLUMN requested_by;
+
+CREATE INDEX idx_private_discussion_participations_requested_by
+ON private_discussion_participations(requested_by, created_at)
+WHERE requested_by IS NOT NULL;
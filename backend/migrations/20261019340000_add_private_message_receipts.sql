@@ -0,0 +1,23 @@
+-- Add migration script here
+
+-- Delivery and reading state of a message for each of its recipients
+CREATE TABLE private_message_receipts (
+    message_id UUID NOT NULL REFERENCES private_messages(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    delivered_at TIMESTAMPTZ,
+    seen_at TIMESTAMPTZ,
+    PRIMARY KEY (message_id, user_id)
+);
+
+CREATE INDEX idx_private_message_receipts_unseen ON private_message_receipts(user_id) WHERE seen_at IS NULL;
+
+-- Until now the status of a message was shared by all its recipients
+INSERT INTO private_message_receipts (message_id, user_id, delivered_at, seen_at)
+SELECT
+    pm.id,
+    p.user_id,
+    pm.delivered_at,
+    CASE WHEN pm.seen THEN COALESCE(pm.seen_at, pm.delivered_at, pm.created_at) END
+FROM private_messages pm
+JOIN private_discussion_participations p ON p.discussion_id = pm.discussion_id
+WHERE p.user_id <> pm.creator;
@@ -0,0 +1,13 @@
+-- Add migration script here
+
+-- Devices revoked by their user, kept so that a device offline at the time is told to wipe
+-- its key material when it comes back
+CREATE TABLE device_revocations (
+    token_id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    -- Key the device had registered, if any
+    device_key_id UUID,
+    revoked_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_device_revocations_user_id ON device_revocations(user_id);
@@ -62,13 +62,14 @@ use api::{
             infrastructure::repositories::user_repository::UserRepositoryImpl,
         },
         public_discussions::{
+            domain::entities::public_message_reaction::PublicMessageReactionKind,
             domain::repositories::{
-                public_message_like_repository::PublicMessageLikeRepository,
+                public_message_reaction_repository::PublicMessageReactionRepository,
                 public_message_report_repository::PublicMessageReportRepository,
                 public_message_repository::PublicMessageRepository,
             },
             infrastructure::repositories::{
-                public_message_like_repository::PublicMessageLikeRepositoryImpl,
+                public_message_reaction_repository::PublicMessageReactionRepositoryImpl,
                 public_message_report_repository::PublicMessageReportRepositoryImpl,
                 public_message_repository::PublicMessageRepositoryImpl,
             },
@@ -212,9 +213,9 @@ pub async fn statistics(
             HttpResponse::InternalServerError().json(AppError::DatabaseConnection.to_response())
         })
         .unwrap();
-    let public_message_like_repo = PublicMessageLikeRepositoryImpl::new((**pool).clone());
-    let public_message_like_count = public_message_like_repo
-        .count()
+    let public_message_reaction_repo = PublicMessageReactionRepositoryImpl::new((**pool).clone());
+    let public_message_like_count = public_message_reaction_repo
+        .count_by_reaction(PublicMessageReactionKind::Like.as_str())
         .await
         .map_err(|e| {
             error!("Error: {}", e);
@@ -5,19 +5,27 @@ use serde_json::json;
 use sqlx::{Pool, Postgres};
 use tokio::sync::Mutex;
 use tracing::{error, info};
+use uuid::Uuid;
 
 use api::{
-    core::structs::redis_messages::{
-        NotificationEvent, UserRemovedEvent, UserTokenRemovedEvent, UserTokenUpdatedEvent,
-        UserUpdatedEvent,
+    core::{
+        helpers::mock_now::now,
+        structs::redis_messages::{
+            DeviceRevokedEvent, NotificationEvent, UserRemovedEvent, UserTokenRemovedEvent,
+            UserTokenUpdatedEvent, UserUpdatedEvent,
+        },
     },
     features::{
         auth::{
             domain::repositories::UserTokenRepository,
             infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl,
         },
-        private_discussions::domain::entities::{
-            channels_data::ChannelsData, users_data::UsersData,
+        private_discussions::{
+            domain::entities::{
+                channels_data::ChannelsData, private_message::PrivateMessageData,
+                users_data::UsersData,
+            },
+            infrastructure::repositories::private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl,
         },
         profile::{
             domain::repositories::UserRepository,
@@ -46,14 +54,37 @@ pub async fn handle_redis_messages(
     pub_sub.subscribe("user_deleted").unwrap();
     pub_sub.subscribe("user_token_updated").unwrap();
     pub_sub.subscribe("user_token_removed").unwrap();
+    pub_sub.subscribe("device_revoked").unwrap();
     pub_sub.subscribe("private_message_created").unwrap();
     pub_sub.subscribe("private_message_deleted").unwrap();
     pub_sub.subscribe("private_message_marked_as_seen").unwrap();
+    pub_sub
+        .subscribe("private_message_marked_as_delivered")
+        .unwrap();
     pub_sub.subscribe("private_message_updated").unwrap();
+    pub_sub.subscribe("private_messages_held").unwrap();
+    pub_sub
+        .subscribe("private_discussion_members_updated")
+        .unwrap();
+    pub_sub
+        .subscribe("private_discussion_marked_as_seen")
+        .unwrap();
+    pub_sub
+        .subscribe("private_discussion_retention_updated")
+        .unwrap();
+    pub_sub.subscribe("private_messages_expired").unwrap();
+    pub_sub.subscribe("private_discussion_typing").unwrap();
+    pub_sub.subscribe("user_presence_updated").unwrap();
     pub_sub.subscribe("public_message_liked").unwrap();
     pub_sub.subscribe("public_message_replied").unwrap();
+    pub_sub.subscribe("public_message_mentioned").unwrap();
+    pub_sub
+        .subscribe("public_message_subscription_activity")
+        .unwrap();
+    pub_sub.subscribe("public_message_announcement").unwrap();
     pub_sub.subscribe("challenge_joined").unwrap();
     pub_sub.subscribe("challenge_duplicated").unwrap();
+    pub_sub.subscribe("moderation_decision").unwrap();
 
     info!("Listening for notifications...");
 
@@ -69,6 +100,8 @@ pub async fn handle_redis_messages(
             handle_user_token_update(&users_data, payload).await;
         } else if msg_type == "user_token_removed" {
             handle_user_token_deletion(&users_data, payload).await;
+        } else if msg_type == "device_revoked" {
+            handle_device_revocation(&channels_data, payload).await;
         } else {
             handle_notification(
                 &connection_pool,
@@ -109,6 +142,34 @@ pub async fn handle_user_token_deletion(users_data: &UsersData, payload: String)
     }
 }
 
+pub async fn handle_device_revocation(channels_data: &ChannelsData, payload: String) {
+    if let Ok(event) = serde_json::from_str::<DeviceRevokedEvent>(&payload) {
+        if let Some(sessions) = channels_data
+            .get_value_for_key(event.user_id, event.user_token_id)
+            .await
+        {
+            // Tell the revoked device to wipe its keys before closing its sockets
+            for (session_uuid, mut session) in sessions {
+                let json = json!(
+                    {
+                        "type": "device_revoked",
+                        "data": json!({ "token_id": event.token_id }).to_string()
+                    }
+                );
+
+                if let Err(e) = session.text(json.to_string()).await {
+                    error!("Error: {}", e);
+                }
+                let _ = session.close(None).await;
+
+                channels_data
+                    .remove_key(event.user_id, event.user_token_id, session_uuid)
+                    .await;
+            }
+        }
+    }
+}
+
 pub async fn handle_notification(
     connection_pool: &Pool<Postgres>,
     channels_data: &ChannelsData,
@@ -149,6 +210,14 @@ pub async fn handle_notification(
                 }
             };
 
+            let are_notifications_held = msg_type == "private_message_created"
+                && are_private_message_notifications_held(
+                    connection_pool,
+                    event.recipient,
+                    &event.data,
+                )
+                .await;
+
             for (token_id, token) in user_data.tokens {
                 match channels_data
                     .get_value_for_key(event.recipient, token_id)
@@ -203,6 +272,12 @@ pub async fn handle_notification(
                                         .notifications_user_duplicated_your_challenge_enabled
                             }
                             "private_message_created" => {
+                                user_data.user.notifications_enabled
+                                    && user_data.user.notifications_for_private_messages_enabled
+                                    && !are_notifications_held
+                            }
+                            // Sums up the messages held while a message request was pending
+                            "private_messages_held" => {
                                 user_data.user.notifications_enabled
                                     && user_data.user.notifications_for_private_messages_enabled
                             }
@@ -218,6 +293,21 @@ pub async fn handle_notification(
                                         .user
                                         .notifications_for_public_message_replies_enabled
                             }
+                            "public_message_mentioned" => {
+                                user_data.user.notifications_enabled
+                                    && user_data
+                                        .user
+                                        .notifications_for_public_message_mentions_enabled
+                            }
+                            // Followed threads and channels count as replies
+                            "public_message_subscription_activity" => {
+                                user_data.user.notifications_enabled
+                                    && user_data
+                                        .user
+                                        .notifications_for_public_message_replies_enabled
+                            }
+                            "public_message_announcement" => user_data.user.notifications_enabled,
+                            "moderation_decision" => user_data.user.notifications_enabled,
                             _ => false,
                         };
 
@@ -249,6 +339,32 @@ pub async fn handle_notification(
     }
 }
 
+// Muted discussions and message requests not accepted still reach the sockets of the user,
+// only push notifications are skipped. Accepting a request sends a single
+// `private_messages_held` notification for the messages held meanwhile.
+async fn are_private_message_notifications_held(
+    connection_pool: &Pool<Postgres>,
+    user_id: Uuid,
+    data: &str,
+) -> bool {
+    let Ok(message) = serde_json::from_str::<PrivateMessageData>(data) else {
+        return false;
+    };
+
+    match PrivateDiscussionParticipationRepositoryImpl::new(connection_pool.clone())
+        .get_by_user_and_discussion_with_executor(user_id, message.discussion_id, connection_pool)
+        .await
+    {
+        Ok(participation) => participation.is_some_and(|p| {
+            p.is_muted(now()) || p.is_request_pending() || p.is_request_declined()
+        }),
+        Err(e) => {
+            error!("Error: {}", e);
+            false
+        }
+    }
+}
+
 pub async fn send_push_notification(
     token_manager: &Arc<Mutex<TokenManager>>,
     fcm_token: String,
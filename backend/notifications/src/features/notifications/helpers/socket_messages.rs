@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+
+use actix_web::web::Data;
+use redis::Client;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+use api::core::constants::errors::AppError;
+use api::core::helpers::mock_now::now;
+use api::features::private_discussions::{
+    application::use_cases::{
+        mark_private_message_as_delivered::MarkPrivateMessageAsDeliveredUseCase,
+        mark_private_message_as_seen::MarkPrivateMessageAsSeenUseCase,
+    },
+    domain::entities::{
+        private_discussion_participation::PrivateDiscussionParticipation,
+        socket_message::{SocketClientFrame, SocketClientMessage},
+    },
+    infrastructure::{
+        repositories::{
+            private_discussion_participation_repository::PrivateDiscussionParticipationRepositoryImpl,
+            private_message_receipt_repository::PrivateMessageReceiptRepositoryImpl,
+            private_message_repository::PrivateMessageRepositoryImpl,
+        },
+        services::private_discussion_event_service::PrivateDiscussionEventService,
+    },
+};
+use api::features::user_blocks::infrastructure::repositories::user_block_repository::UserBlockRepositoryImpl;
+
+// Handles a frame sent by a client, relaying it to the other members of the discussion
+// through Redis so that clients connected to other instances receive it too
+pub async fn handle_socket_message(
+    pool: &PgPool,
+    redis_client: Data<Client>,
+    user_id: Uuid,
+    text: &str,
+) -> Result<(), AppError> {
+    let event_service = PrivateDiscussionEventService::new(redis_client);
+    let participation_repo = PrivateDiscussionParticipationRepositoryImpl::new(pool.clone());
+
+    match SocketClientFrame::parse(text)? {
+        SocketClientMessage::TypingStarted { discussion_id } => {
+            relay_typing(
+                &participation_repo,
+                &event_service,
+                pool,
+                user_id,
+                discussion_id,
+                true,
+            )
+            .await?;
+        }
+        SocketClientMessage::TypingStopped { discussion_id } => {
+            relay_typing(
+                &participation_repo,
+                &event_service,
+                pool,
+                user_id,
+                discussion_id,
+                false,
+            )
+            .await?;
+        }
+        SocketClientMessage::PresenceHeartbeat => {
+            // Presence is shared with everyone the user has an accepted discussion with
+            let discussion_ids = participation_repo
+                .get_by_user_id_with_executor(user_id, pool)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?
+                .iter()
+                .filter(|p| p.receives_activity())
+                .map(|p| p.discussion_id)
+                .collect::<Vec<_>>();
+
+            let participations = participation_repo
+                .get_recipients_with_executor(discussion_ids, user_id, pool)
+                .await
+                .map_err(|_| AppError::DatabaseQuery)?;
+            let recipients = filter_activity_recipients(pool, user_id, &participations).await?;
+
+            if let Err(e) = event_service
+                .publish_presence_event(user_id, now(), &recipients)
+                .await
+            {
+                error!("Error: {}", e);
+            }
+        }
+        SocketClientMessage::MessageDelivered { message_id } => {
+            let mut transaction = pool
+                .begin()
+                .await
+                .map_err(|_| AppError::DatabaseConnection)?;
+
+            let use_case = MarkPrivateMessageAsDeliveredUseCase::new(
+                PrivateMessageRepositoryImpl::new(pool.clone()),
+                participation_repo,
+                PrivateMessageReceiptRepositoryImpl::new(pool.clone()),
+            );
+
+            let (private_message, participations) = match use_case
+                .execute(message_id, user_id, &mut transaction)
+                .await
+            {
+                Ok(r) => r,
+                Err(e) => {
+                    if let Err(e) = transaction.rollback().await {
+                        error!("Error rolling back: {}", e);
+                    }
+                    return Err(e);
+                }
+            };
+
+            transaction
+                .commit()
+                .await
+                .map_err(|_| AppError::DatabaseTransaction)?;
+
+            if let Err(e) = event_service
+                .publish_message_delivered_event(
+                    &private_message,
+                    &participations.iter().map(|p| p.user_id).collect::<Vec<_>>(),
+                )
+                .await
+            {
+                error!("Error: {}", e);
+            }
+        }
+        SocketClientMessage::MessageRead { message_id } => {
+            let mut transaction = pool
+                .begin()
+                .await
+                .map_err(|_| AppError::DatabaseConnection)?;
+
+            let use_case = MarkPrivateMessageAsSeenUseCase::new(
+                PrivateMessageRepositoryImpl::new(pool.clone()),
+                participation_repo,
+                PrivateMessageReceiptRepositoryImpl::new(pool.clone()),
+            );
+
+            let (private_message, participations) = match use_case
+                .execute(message_id, user_id, &mut transaction)
+                .await
+            {
+                Ok(r) => r,
+                Err(e) => {
+                    if let Err(e) = transaction.rollback().await {
+                        error!("Error rolling back: {}", e);
+                    }
+                    return Err(e);
+                }
+            };
+
+            transaction
+                .commit()
+                .await
+                .map_err(|_| AppError::DatabaseTransaction)?;
+
+            if let Err(e) = event_service
+                .publish_message_seen_event(
+                    &private_message,
+                    &participations.iter().map(|p| p.user_id).collect::<Vec<_>>(),
+                )
+                .await
+            {
+                error!("Error: {}", e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn relay_typing(
+    participation_repo: &PrivateDiscussionParticipationRepositoryImpl,
+    event_service: &PrivateDiscussionEventService,
+    pool: &PgPool,
+    user_id: Uuid,
+    discussion_id: Uuid,
+    is_typing: bool,
+) -> Result<(), AppError> {
+    let participations = participation_repo
+        .get_by_discussion_id_with_executor(discussion_id, pool)
+        .await
+        .map_err(|_| AppError::DatabaseQuery)?;
+
+    if !participations.iter().any(|p| p.user_id == user_id) {
+        return Err(AppError::PrivateDiscussionNotFound);
+    }
+
+    let participations = participations
+        .into_iter()
+        .filter(|p| p.user_id != user_id)
+        .collect::<Vec<_>>();
+    let recipients = filter_activity_recipients(pool, user_id, &participations).await?;
+
+    if let Err(e) = event_service
+        .publish_typing_event(discussion_id, user_id, is_typing, &recipients)
+        .await
+    {
+        error!("Error: {}", e);
+    }
+
+    Ok(())
+}
+
+// Leaves out members who did not accept the discussion, blocked it, or are blocked either way
+async fn filter_activity_recipients(
+    pool: &PgPool,
+    user_id: Uuid,
+    participations: &[PrivateDiscussionParticipation],
+) -> Result<Vec<Uuid>, AppError> {
+    let user_block_repo = UserBlockRepositoryImpl::new(pool.clone());
+    let candidates = participations
+        .iter()
+        .filter(|p| p.receives_activity())
+        .map(|p| p.user_id)
+        .collect::<HashSet<_>>();
+
+    let mut recipients = vec![];
+    for recipient in candidates {
+        if !user_block_repo
+            .exists_between_with_executor(user_id, recipient, pool)
+            .await
+            .map_err(|_| AppError::DatabaseQuery)?
+        {
+            recipients.push(recipient);
+        }
+    }
+
+    Ok(recipients)
+}
@@ -7,6 +7,8 @@ use actix_ws::{self, Message};
 use chrono::{DateTime, Utc};
 use futures::{select, FutureExt, StreamExt};
 use jsonwebtoken::{decode, DecodingKey, Validation};
+use redis::Client;
+use serde_json::json;
 use sqlx::PgPool;
 use tracing::{debug, error, info};
 use uuid::Uuid;
@@ -14,12 +16,18 @@ use uuid::Uuid;
 use api::core::constants::errors::AppError;
 use api::core::helpers::mock_now::now;
 use api::features::auth::{
-    domain::repositories::UserTokenRepository,
-    infrastructure::repositories::user_token_repository::UserTokenRepositoryImpl,
+    domain::repositories::{DeviceRevocationRepository, UserTokenRepository},
+    infrastructure::repositories::{
+        device_revocation_repository::DeviceRevocationRepositoryImpl,
+        user_token_repository::UserTokenRepositoryImpl,
+    },
     structs::models::Claims,
 };
 use api::features::private_discussions::application::dto::requests::private_message::ListenForNewMessages;
 use api::features::private_discussions::domain::entities::channels_data::ChannelsData;
+use api::features::private_discussions::domain::entities::socket_message::SocketRateLimiter;
+
+use crate::features::notifications::helpers::socket_messages::handle_socket_message;
 
 #[get("/ws")]
 async fn broadcast_ws(
@@ -28,6 +36,7 @@ async fn broadcast_ws(
     query: Query<ListenForNewMessages>,
     pool: Data<PgPool>,
     channels_data: Data<ChannelsData>,
+    redis_client: Data<Client>,
     secret: Data<String>,
 ) -> impl Responder {
     let params = query.into_inner();
@@ -66,7 +75,38 @@ async fn broadcast_ws(
     {
         Ok(Some(token)) => token,
         Ok(None) => {
-            return HttpResponse::Unauthorized().json(AppError::UserTokenNotFound.to_response());
+            let device_revocation_repo = DeviceRevocationRepositoryImpl::new((**pool).clone());
+            return match device_revocation_repo
+                .get_by_token_id(request_claims.jti)
+                .await
+            {
+                // A device revoked while it was offline learns about it when it comes back
+                Ok(Some(device_revocation)) => {
+                    rt::spawn(async move {
+                        let json = json!(
+                            {
+                                "type": "device_revoked",
+                                "data": json!({ "token_id": device_revocation.token_id }).to_string()
+                            }
+                        );
+
+                        if let Err(e) = session.text(json.to_string()).await {
+                            error!("Error: {}", e);
+                        }
+                        let _ = session.close(None).await;
+                    });
+
+                    res
+                }
+                Ok(None) => {
+                    HttpResponse::Unauthorized().json(AppError::UserTokenNotFound.to_response())
+                }
+                Err(e) => {
+                    error!("Error: {}", e);
+                    HttpResponse::InternalServerError()
+                        .json(AppError::DatabaseConnection.to_response())
+                }
+            };
         }
         Err(e) => {
             error!("Error: {}", e);
@@ -95,6 +135,7 @@ async fn broadcast_ws(
     rt::spawn(async move {
         let mut interval = tokio::time::interval(Duration::from_secs(3));
         let mut msg_stream = msg_stream.fuse();
+        let mut rate_limiter = SocketRateLimiter::default();
 
         loop {
             select! {
@@ -115,9 +156,35 @@ async fn broadcast_ws(
                         Some(Ok(Message::Pong(_))) => {
                             debug!("Received pong from {}", request_claims.username);
                         }
-                        Some(Ok(_)) => {
-                            // You can handle other messages here
+                        Some(Ok(Message::Text(text))) => {
+                            let result = if rate_limiter.allow(now()) {
+                                handle_socket_message(
+                                    &pool,
+                                    redis_client.clone(),
+                                    request_claims.user_id,
+                                    &text,
+                                )
+                                .await
+                            } else {
+                                Err(AppError::SocketMessageRateLimited)
+                            };
+
+                            if let Err(e) = result {
+                                debug!("Rejected message from {}: {:?}", request_claims.username, e);
+
+                                let json = json!(
+                                    {
+                                        "type": "error",
+                                        "data": json!(e.to_response()).to_string()
+                                    }
+                                );
+
+                                if session.text(json.to_string()).await.is_err() {
+                                    break;
+                                }
+                            }
                         }
+                        Some(Ok(_)) => {}
                         Some(Err(e)) => {
                             error!("WebSocket error: {:?}", e);
                             break;
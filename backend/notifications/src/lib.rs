@@ -13,6 +13,7 @@ pub mod features {
         pub mod helpers {
             pub mod redis_handler;
             pub mod reminders;
+            pub mod socket_messages;
         }
 
         pub mod presentation {
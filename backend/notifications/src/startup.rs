@@ -44,6 +44,8 @@ pub async fn run(listener: TcpListener, configuration: Settings) -> Result<Serve
 
     let redis_client = Client::open("redis://redis:6379").unwrap();
 
+    let redis_client_for_websocket = redis_client.clone();
+
     let connection_pool_for_redis_handler = connection_pool.clone();
     let channels_data_for_redis_handler = channels_data.clone();
     let users_data_for_redis_handler = users_data.clone();
@@ -93,6 +95,7 @@ pub async fn run(listener: TcpListener, configuration: Settings) -> Result<Serve
             token_cache.clone(),
             channels_data.clone(),
             users_data.clone(),
+            redis_client_for_websocket.clone(),
         )
     })
     .listen(listener)?
@@ -107,6 +110,7 @@ pub fn create_app(
     token_cache: TokenCache,
     channels_data: ChannelsData,
     users_data: UsersData,
+    redis_client: Client,
 ) -> App<
     impl ServiceFactory<
         ServiceRequest,
@@ -142,6 +146,7 @@ pub fn create_app(
         .app_data(web::Data::new(token_cache))
         .app_data(web::Data::new(channels_data))
         .app_data(web::Data::new(users_data))
+        .app_data(web::Data::new(redis_client))
 }
 
 pub struct Application {